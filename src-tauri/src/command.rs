@@ -1,8 +1,19 @@
 //! This module contains tauri command handlers for the [`tauri::command`] macro.
 
+pub mod change_log;
+pub mod dietary;
 pub mod entity;
 mod error;
 pub mod external_recipe;
+pub mod journal;
+pub mod nutrition;
 pub mod ocr;
+pub mod ocr_recipe;
+pub mod pdf;
+pub mod recipe_revision;
+pub mod recipe_time;
+pub mod settings;
+pub mod step_duration;
+pub mod timer;
 pub mod unit_conversion;
 pub mod unit_list;
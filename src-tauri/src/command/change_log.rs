@@ -0,0 +1,16 @@
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+
+use crate::{command::error::CommandError, database, entity::change_log};
+
+/// Get all entity changes with a sequence number greater than `seq`, in order.
+///
+/// A window which missed change events can pass the last sequence number it has seen to catch up.
+#[tauri::command]
+pub async fn changes_since(seq: i64) -> Result<Vec<change_log::Model>, CommandError> {
+    let changes = change_log::Entity::find()
+        .filter(change_log::Column::Seq.gt(seq))
+        .order_by_asc(change_log::Column::Seq)
+        .all(database::connect().await)
+        .await?;
+    Ok(changes)
+}
@@ -0,0 +1,13 @@
+use crate::{
+    command::error::{CommandError, CommandError::NotFound},
+    dietary,
+    dietary::DietarySummary,
+};
+
+/// Summarize the allergens and diets of a recipe, see [`dietary::summary`].
+#[tauri::command]
+pub async fn dietary_summary(recipe_id: i64) -> Result<DietarySummary, CommandError> {
+    let dietary_summary_option = dietary::summary(recipe_id).await?;
+    let dietary_summary = dietary_summary_option.ok_or(NotFound)?;
+    Ok(dietary_summary)
+}
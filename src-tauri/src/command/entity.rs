@@ -1,5 +1,13 @@
+pub mod collection;
+pub mod collection_recipe;
+pub mod cook_log;
+pub mod cook_log_file;
 pub mod file;
 pub mod ingredient;
+pub mod ingredient_alias;
+pub mod ingredient_allergen;
+pub mod ingredient_category;
+pub mod nutrient_profile;
 pub mod recipe;
 pub mod recipe_file;
 pub mod recipe_ingredient_draft;
@@ -7,4 +15,6 @@ pub mod recipe_step;
 pub mod recipe_step_file;
 pub mod recipe_step_ingredient;
 pub mod recipe_step_ingredient_draft;
+pub mod recipe_tag;
+pub mod tag;
 pub mod unit_name;
@@ -0,0 +1,86 @@
+use crate::{
+    command::error::{CommandError, CommandError::NotFound},
+    entity::collection::Model,
+    entity_crud::{
+        collection::{
+            CollectionCondition, CollectionCreate, CollectionCrud, CollectionExport,
+            CollectionFilter, CollectionUpdate,
+        },
+        EntityCrudTrait, ListPage,
+    },
+};
+
+#[tauri::command]
+pub async fn entity_create_collection(create: CollectionCreate) -> Result<i64, CommandError> {
+    let id = CollectionCrud::create(create).await?;
+    Ok(id)
+}
+
+#[tauri::command]
+pub async fn entity_read_collection(id: i64) -> Result<Model, CommandError> {
+    let model_option = CollectionCrud::read(id).await?;
+    let model = model_option.ok_or(NotFound)?;
+    Ok(model)
+}
+
+#[tauri::command]
+pub async fn entity_update_collection(update: CollectionUpdate) -> Result<(), CommandError> {
+    CollectionCrud::update(update).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_collection(id: i64) -> Result<(), CommandError> {
+    CollectionCrud::delete(id).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_create_many_collection(
+    creates: Vec<CollectionCreate>,
+) -> Result<Vec<i64>, CommandError> {
+    let ids = CollectionCrud::create_many(creates).await?;
+    Ok(ids)
+}
+
+#[tauri::command]
+pub async fn entity_update_many_collection(
+    updates: Vec<CollectionUpdate>,
+) -> Result<(), CommandError> {
+    CollectionCrud::update_many(updates).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_many_collection(ids: Vec<i64>) -> Result<(), CommandError> {
+    CollectionCrud::delete_many(ids).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_list_collection(filter: CollectionFilter) -> Result<Vec<i64>, CommandError> {
+    let list = CollectionCrud::list(filter).await?;
+    Ok(list)
+}
+
+#[tauri::command]
+pub async fn entity_list_page_collection(
+    filter: CollectionFilter,
+) -> Result<ListPage<i64>, CommandError> {
+    let page = CollectionCrud::list_page(filter).await?;
+    Ok(page)
+}
+
+#[tauri::command]
+pub async fn entity_count_collection(
+    condition: Option<CollectionCondition>,
+) -> Result<i64, CommandError> {
+    let count = CollectionCrud::count(condition).await?;
+    Ok(count)
+}
+
+#[tauri::command]
+pub async fn collection_export(id: i64) -> Result<CollectionExport, CommandError> {
+    let export = CollectionCrud::export(id).await?.ok_or(NotFound)?;
+    Ok(export)
+}
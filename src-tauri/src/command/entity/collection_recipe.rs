@@ -0,0 +1,103 @@
+use crate::{
+    command::error::{CommandError, CommandError::NotFound},
+    entity::collection_recipe::Model,
+    entity_crud::{
+        collection_recipe::{
+            CollectionRecipeCondition, CollectionRecipeCreate, CollectionRecipeCrud,
+            CollectionRecipeFilter, CollectionRecipeUpdate,
+        },
+        EntityCrudTrait, ListPage, OrderedEntityCrudTrait,
+    },
+};
+
+#[tauri::command]
+pub async fn entity_create_collection_recipe(
+    create: CollectionRecipeCreate,
+) -> Result<i64, CommandError> {
+    let id = CollectionRecipeCrud::create(create).await?;
+    Ok(id)
+}
+
+#[tauri::command]
+pub async fn entity_read_collection_recipe(id: i64) -> Result<Model, CommandError> {
+    let model_option = CollectionRecipeCrud::read(id).await?;
+    let model = model_option.ok_or(NotFound)?;
+    Ok(model)
+}
+
+#[tauri::command]
+pub async fn entity_update_collection_recipe(
+    update: CollectionRecipeUpdate,
+) -> Result<(), CommandError> {
+    CollectionRecipeCrud::update(update).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_collection_recipe(id: i64) -> Result<(), CommandError> {
+    CollectionRecipeCrud::delete(id).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_create_many_collection_recipe(
+    creates: Vec<CollectionRecipeCreate>,
+) -> Result<Vec<i64>, CommandError> {
+    let ids = CollectionRecipeCrud::create_many(creates).await?;
+    Ok(ids)
+}
+
+#[tauri::command]
+pub async fn entity_update_many_collection_recipe(
+    updates: Vec<CollectionRecipeUpdate>,
+) -> Result<(), CommandError> {
+    CollectionRecipeCrud::update_many(updates).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_many_collection_recipe(ids: Vec<i64>) -> Result<(), CommandError> {
+    CollectionRecipeCrud::delete_many(ids).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_list_collection_recipe(
+    filter: CollectionRecipeFilter,
+) -> Result<Vec<i64>, CommandError> {
+    let list = CollectionRecipeCrud::list(filter).await?;
+    Ok(list)
+}
+
+#[tauri::command]
+pub async fn entity_list_page_collection_recipe(
+    filter: CollectionRecipeFilter,
+) -> Result<ListPage<i64>, CommandError> {
+    let page = CollectionRecipeCrud::list_page(filter).await?;
+    Ok(page)
+}
+
+#[tauri::command]
+pub async fn entity_count_collection_recipe(
+    condition: Option<CollectionRecipeCondition>,
+) -> Result<i64, CommandError> {
+    let count = CollectionRecipeCrud::count(condition).await?;
+    Ok(count)
+}
+
+#[tauri::command]
+pub async fn entity_move_collection_recipe(id: i64, position: u64) -> Result<(), CommandError> {
+    if !CollectionRecipeCrud::move_to(id, position).await? {
+        return Err(NotFound);
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_reorder_collection_recipe(
+    collection_id: i64,
+    ids: Vec<i64>,
+) -> Result<(), CommandError> {
+    CollectionRecipeCrud::reorder(collection_id, ids).await?;
+    Ok(())
+}
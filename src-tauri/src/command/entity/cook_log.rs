@@ -0,0 +1,75 @@
+use crate::{
+    command::error::{CommandError, CommandError::NotFound},
+    entity::cook_log::Model,
+    entity_crud::{
+        cook_log::{CookLogCondition, CookLogCreate, CookLogCrud, CookLogFilter, CookLogUpdate},
+        EntityCrudTrait, ListPage,
+    },
+};
+
+#[tauri::command]
+pub async fn entity_create_cook_log(create: CookLogCreate) -> Result<i64, CommandError> {
+    let id = CookLogCrud::create(create).await?;
+    Ok(id)
+}
+
+#[tauri::command]
+pub async fn entity_read_cook_log(id: i64) -> Result<Model, CommandError> {
+    let model_option = CookLogCrud::read(id).await?;
+    let model = model_option.ok_or(NotFound)?;
+    Ok(model)
+}
+
+#[tauri::command]
+pub async fn entity_update_cook_log(update: CookLogUpdate) -> Result<(), CommandError> {
+    CookLogCrud::update(update).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_cook_log(id: i64) -> Result<(), CommandError> {
+    CookLogCrud::delete(id).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_create_many_cook_log(
+    creates: Vec<CookLogCreate>,
+) -> Result<Vec<i64>, CommandError> {
+    let ids = CookLogCrud::create_many(creates).await?;
+    Ok(ids)
+}
+
+#[tauri::command]
+pub async fn entity_update_many_cook_log(updates: Vec<CookLogUpdate>) -> Result<(), CommandError> {
+    CookLogCrud::update_many(updates).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_many_cook_log(ids: Vec<i64>) -> Result<(), CommandError> {
+    CookLogCrud::delete_many(ids).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_list_cook_log(filter: CookLogFilter) -> Result<Vec<i64>, CommandError> {
+    let list = CookLogCrud::list(filter).await?;
+    Ok(list)
+}
+
+#[tauri::command]
+pub async fn entity_list_page_cook_log(
+    filter: CookLogFilter,
+) -> Result<ListPage<i64>, CommandError> {
+    let page = CookLogCrud::list_page(filter).await?;
+    Ok(page)
+}
+
+#[tauri::command]
+pub async fn entity_count_cook_log(
+    condition: Option<CookLogCondition>,
+) -> Result<i64, CommandError> {
+    let count = CookLogCrud::count(condition).await?;
+    Ok(count)
+}
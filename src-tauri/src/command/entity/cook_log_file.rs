@@ -0,0 +1,99 @@
+use crate::{
+    command::error::{CommandError, CommandError::NotFound},
+    entity::cook_log_file::Model,
+    entity_crud::{
+        cook_log_file::{
+            CookLogFileCondition, CookLogFileCreate, CookLogFileCrud, CookLogFileFilter,
+            CookLogFileUpdate,
+        },
+        EntityCrudTrait, ListPage, OrderedEntityCrudTrait,
+    },
+};
+
+#[tauri::command]
+pub async fn entity_create_cook_log_file(create: CookLogFileCreate) -> Result<i64, CommandError> {
+    let id = CookLogFileCrud::create(create).await?;
+    Ok(id)
+}
+
+#[tauri::command]
+pub async fn entity_read_cook_log_file(id: i64) -> Result<Model, CommandError> {
+    let model_option = CookLogFileCrud::read(id).await?;
+    let model = model_option.ok_or(NotFound)?;
+    Ok(model)
+}
+
+#[tauri::command]
+pub async fn entity_update_cook_log_file(update: CookLogFileUpdate) -> Result<(), CommandError> {
+    CookLogFileCrud::update(update).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_cook_log_file(id: i64) -> Result<(), CommandError> {
+    CookLogFileCrud::delete(id).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_create_many_cook_log_file(
+    creates: Vec<CookLogFileCreate>,
+) -> Result<Vec<i64>, CommandError> {
+    let ids = CookLogFileCrud::create_many(creates).await?;
+    Ok(ids)
+}
+
+#[tauri::command]
+pub async fn entity_update_many_cook_log_file(
+    updates: Vec<CookLogFileUpdate>,
+) -> Result<(), CommandError> {
+    CookLogFileCrud::update_many(updates).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_many_cook_log_file(ids: Vec<i64>) -> Result<(), CommandError> {
+    CookLogFileCrud::delete_many(ids).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_list_cook_log_file(
+    filter: CookLogFileFilter,
+) -> Result<Vec<i64>, CommandError> {
+    let list = CookLogFileCrud::list(filter).await?;
+    Ok(list)
+}
+
+#[tauri::command]
+pub async fn entity_list_page_cook_log_file(
+    filter: CookLogFileFilter,
+) -> Result<ListPage<i64>, CommandError> {
+    let page = CookLogFileCrud::list_page(filter).await?;
+    Ok(page)
+}
+
+#[tauri::command]
+pub async fn entity_count_cook_log_file(
+    condition: Option<CookLogFileCondition>,
+) -> Result<i64, CommandError> {
+    let count = CookLogFileCrud::count(condition).await?;
+    Ok(count)
+}
+
+#[tauri::command]
+pub async fn entity_move_cook_log_file(id: i64, position: u64) -> Result<(), CommandError> {
+    if !CookLogFileCrud::move_to(id, position).await? {
+        return Err(NotFound);
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_reorder_cook_log_file(
+    cook_log_id: i64,
+    ids: Vec<i64>,
+) -> Result<(), CommandError> {
+    CookLogFileCrud::reorder(cook_log_id, ids).await?;
+    Ok(())
+}
@@ -5,7 +5,7 @@ use crate::{
     entity::file::Model,
     entity_crud::{
         file::{FileCondition, FileCreate, FileCrud, FileFilter, FileUpdate},
-        EntityCrudTrait,
+        EntityCrudTrait, ListPage,
     },
 };
 
@@ -35,12 +35,36 @@ pub async fn entity_delete_file(id: i64) -> Result<(), CommandError> {
     Ok(())
 }
 
+#[tauri::command]
+pub async fn entity_create_many_file(creates: Vec<FileCreate>) -> Result<Vec<i64>, CommandError> {
+    let ids = FileCrud::create_many(creates).await?;
+    Ok(ids)
+}
+
+#[tauri::command]
+pub async fn entity_update_many_file(updates: Vec<FileUpdate>) -> Result<(), CommandError> {
+    FileCrud::update_many(updates).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_many_file(ids: Vec<i64>) -> Result<(), CommandError> {
+    FileCrud::delete_many(ids).await?;
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn entity_list_file(filter: FileFilter) -> Result<Vec<i64>, CommandError> {
     let list = FileCrud::list(filter).await?;
     Ok(list)
 }
 
+#[tauri::command]
+pub async fn entity_list_page_file(filter: FileFilter) -> Result<ListPage<i64>, CommandError> {
+    let page = FileCrud::list_page(filter).await?;
+    Ok(page)
+}
+
 #[tauri::command]
 pub async fn entity_count_file(condition: Option<FileCondition>) -> Result<i64, CommandError> {
     let count = FileCrud::count(condition).await?;
@@ -6,7 +6,7 @@ use crate::{
             IngredientCondition, IngredientCreate, IngredientCrud, IngredientFilter,
             IngredientUpdate,
         },
-        EntityCrudTrait,
+        EntityCrudTrait, ListPage,
     },
 };
 
@@ -35,12 +35,42 @@ pub async fn entity_delete_ingredient(id: i64) -> Result<(), CommandError> {
     Ok(())
 }
 
+#[tauri::command]
+pub async fn entity_create_many_ingredient(
+    creates: Vec<IngredientCreate>,
+) -> Result<Vec<i64>, CommandError> {
+    let ids = IngredientCrud::create_many(creates).await?;
+    Ok(ids)
+}
+
+#[tauri::command]
+pub async fn entity_update_many_ingredient(
+    updates: Vec<IngredientUpdate>,
+) -> Result<(), CommandError> {
+    IngredientCrud::update_many(updates).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_many_ingredient(ids: Vec<i64>) -> Result<(), CommandError> {
+    IngredientCrud::delete_many(ids).await?;
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn entity_list_ingredient(filter: IngredientFilter) -> Result<Vec<i64>, CommandError> {
     let list = IngredientCrud::list(filter).await?;
     Ok(list)
 }
 
+#[tauri::command]
+pub async fn entity_list_page_ingredient(
+    filter: IngredientFilter,
+) -> Result<ListPage<i64>, CommandError> {
+    let page = IngredientCrud::list_page(filter).await?;
+    Ok(page)
+}
+
 #[tauri::command]
 pub async fn entity_count_ingredient(
     condition: Option<IngredientCondition>,
@@ -48,3 +78,17 @@ pub async fn entity_count_ingredient(
     let count = IngredientCrud::count(condition).await?;
     Ok(count)
 }
+
+#[tauri::command]
+pub async fn ingredient_merge(target_id: i64, source_ids: Vec<i64>) -> Result<(), CommandError> {
+    if !IngredientCrud::merge(target_id, source_ids).await? {
+        return Err(NotFound);
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn ingredient_merge_suggestions() -> Result<Vec<Vec<i64>>, CommandError> {
+    let suggestions = IngredientCrud::merge_suggestions().await?;
+    Ok(suggestions)
+}
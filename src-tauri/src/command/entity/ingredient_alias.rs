@@ -0,0 +1,86 @@
+use crate::{
+    command::error::{CommandError, CommandError::NotFound},
+    entity::ingredient_alias::Model,
+    entity_crud::{
+        ingredient_alias::{
+            IngredientAliasCondition, IngredientAliasCreate, IngredientAliasCrud,
+            IngredientAliasFilter, IngredientAliasUpdate,
+        },
+        EntityCrudTrait, ListPage,
+    },
+};
+
+#[tauri::command]
+pub async fn entity_create_ingredient_alias(
+    create: IngredientAliasCreate,
+) -> Result<i64, CommandError> {
+    let id = IngredientAliasCrud::create(create).await?;
+    Ok(id)
+}
+
+#[tauri::command]
+pub async fn entity_read_ingredient_alias(id: i64) -> Result<Model, CommandError> {
+    let model_option = IngredientAliasCrud::read(id).await?;
+    let model = model_option.ok_or(NotFound)?;
+    Ok(model)
+}
+
+#[tauri::command]
+pub async fn entity_update_ingredient_alias(
+    update: IngredientAliasUpdate,
+) -> Result<(), CommandError> {
+    IngredientAliasCrud::update(update).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_ingredient_alias(id: i64) -> Result<(), CommandError> {
+    IngredientAliasCrud::delete(id).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_create_many_ingredient_alias(
+    creates: Vec<IngredientAliasCreate>,
+) -> Result<Vec<i64>, CommandError> {
+    let ids = IngredientAliasCrud::create_many(creates).await?;
+    Ok(ids)
+}
+
+#[tauri::command]
+pub async fn entity_update_many_ingredient_alias(
+    updates: Vec<IngredientAliasUpdate>,
+) -> Result<(), CommandError> {
+    IngredientAliasCrud::update_many(updates).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_many_ingredient_alias(ids: Vec<i64>) -> Result<(), CommandError> {
+    IngredientAliasCrud::delete_many(ids).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_list_ingredient_alias(
+    filter: IngredientAliasFilter,
+) -> Result<Vec<i64>, CommandError> {
+    let list = IngredientAliasCrud::list(filter).await?;
+    Ok(list)
+}
+
+#[tauri::command]
+pub async fn entity_list_page_ingredient_alias(
+    filter: IngredientAliasFilter,
+) -> Result<ListPage<i64>, CommandError> {
+    let page = IngredientAliasCrud::list_page(filter).await?;
+    Ok(page)
+}
+
+#[tauri::command]
+pub async fn entity_count_ingredient_alias(
+    condition: Option<IngredientAliasCondition>,
+) -> Result<i64, CommandError> {
+    let count = IngredientAliasCrud::count(condition).await?;
+    Ok(count)
+}
@@ -0,0 +1,86 @@
+use crate::{
+    command::error::{CommandError, CommandError::NotFound},
+    entity::ingredient_allergen::Model,
+    entity_crud::{
+        ingredient_allergen::{
+            IngredientAllergenCondition, IngredientAllergenCreate, IngredientAllergenCrud,
+            IngredientAllergenFilter, IngredientAllergenUpdate,
+        },
+        EntityCrudTrait, ListPage,
+    },
+};
+
+#[tauri::command]
+pub async fn entity_create_ingredient_allergen(
+    create: IngredientAllergenCreate,
+) -> Result<i64, CommandError> {
+    let id = IngredientAllergenCrud::create(create).await?;
+    Ok(id)
+}
+
+#[tauri::command]
+pub async fn entity_read_ingredient_allergen(id: i64) -> Result<Model, CommandError> {
+    let model_option = IngredientAllergenCrud::read(id).await?;
+    let model = model_option.ok_or(NotFound)?;
+    Ok(model)
+}
+
+#[tauri::command]
+pub async fn entity_update_ingredient_allergen(
+    update: IngredientAllergenUpdate,
+) -> Result<(), CommandError> {
+    IngredientAllergenCrud::update(update).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_ingredient_allergen(id: i64) -> Result<(), CommandError> {
+    IngredientAllergenCrud::delete(id).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_create_many_ingredient_allergen(
+    creates: Vec<IngredientAllergenCreate>,
+) -> Result<Vec<i64>, CommandError> {
+    let ids = IngredientAllergenCrud::create_many(creates).await?;
+    Ok(ids)
+}
+
+#[tauri::command]
+pub async fn entity_update_many_ingredient_allergen(
+    updates: Vec<IngredientAllergenUpdate>,
+) -> Result<(), CommandError> {
+    IngredientAllergenCrud::update_many(updates).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_many_ingredient_allergen(ids: Vec<i64>) -> Result<(), CommandError> {
+    IngredientAllergenCrud::delete_many(ids).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_list_ingredient_allergen(
+    filter: IngredientAllergenFilter,
+) -> Result<Vec<i64>, CommandError> {
+    let list = IngredientAllergenCrud::list(filter).await?;
+    Ok(list)
+}
+
+#[tauri::command]
+pub async fn entity_list_page_ingredient_allergen(
+    filter: IngredientAllergenFilter,
+) -> Result<ListPage<i64>, CommandError> {
+    let page = IngredientAllergenCrud::list_page(filter).await?;
+    Ok(page)
+}
+
+#[tauri::command]
+pub async fn entity_count_ingredient_allergen(
+    condition: Option<IngredientAllergenCondition>,
+) -> Result<i64, CommandError> {
+    let count = IngredientAllergenCrud::count(condition).await?;
+    Ok(count)
+}
@@ -0,0 +1,86 @@
+use crate::{
+    command::error::{CommandError, CommandError::NotFound},
+    entity::ingredient_category::Model,
+    entity_crud::{
+        ingredient_category::{
+            IngredientCategoryCondition, IngredientCategoryCreate, IngredientCategoryCrud,
+            IngredientCategoryFilter, IngredientCategoryUpdate,
+        },
+        EntityCrudTrait, ListPage,
+    },
+};
+
+#[tauri::command]
+pub async fn entity_create_ingredient_category(
+    create: IngredientCategoryCreate,
+) -> Result<i64, CommandError> {
+    let id = IngredientCategoryCrud::create(create).await?;
+    Ok(id)
+}
+
+#[tauri::command]
+pub async fn entity_read_ingredient_category(id: i64) -> Result<Model, CommandError> {
+    let model_option = IngredientCategoryCrud::read(id).await?;
+    let model = model_option.ok_or(NotFound)?;
+    Ok(model)
+}
+
+#[tauri::command]
+pub async fn entity_update_ingredient_category(
+    update: IngredientCategoryUpdate,
+) -> Result<(), CommandError> {
+    IngredientCategoryCrud::update(update).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_ingredient_category(id: i64) -> Result<(), CommandError> {
+    IngredientCategoryCrud::delete(id).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_create_many_ingredient_category(
+    creates: Vec<IngredientCategoryCreate>,
+) -> Result<Vec<i64>, CommandError> {
+    let ids = IngredientCategoryCrud::create_many(creates).await?;
+    Ok(ids)
+}
+
+#[tauri::command]
+pub async fn entity_update_many_ingredient_category(
+    updates: Vec<IngredientCategoryUpdate>,
+) -> Result<(), CommandError> {
+    IngredientCategoryCrud::update_many(updates).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_many_ingredient_category(ids: Vec<i64>) -> Result<(), CommandError> {
+    IngredientCategoryCrud::delete_many(ids).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_list_ingredient_category(
+    filter: IngredientCategoryFilter,
+) -> Result<Vec<i64>, CommandError> {
+    let list = IngredientCategoryCrud::list(filter).await?;
+    Ok(list)
+}
+
+#[tauri::command]
+pub async fn entity_list_page_ingredient_category(
+    filter: IngredientCategoryFilter,
+) -> Result<ListPage<i64>, CommandError> {
+    let page = IngredientCategoryCrud::list_page(filter).await?;
+    Ok(page)
+}
+
+#[tauri::command]
+pub async fn entity_count_ingredient_category(
+    condition: Option<IngredientCategoryCondition>,
+) -> Result<i64, CommandError> {
+    let count = IngredientCategoryCrud::count(condition).await?;
+    Ok(count)
+}
@@ -0,0 +1,86 @@
+use crate::{
+    command::error::{CommandError, CommandError::NotFound},
+    entity::nutrient_profile::Model,
+    entity_crud::{
+        nutrient_profile::{
+            NutrientProfileCondition, NutrientProfileCreate, NutrientProfileCrud,
+            NutrientProfileFilter, NutrientProfileUpdate,
+        },
+        EntityCrudTrait, ListPage,
+    },
+};
+
+#[tauri::command]
+pub async fn entity_create_nutrient_profile(
+    create: NutrientProfileCreate,
+) -> Result<i64, CommandError> {
+    let id = NutrientProfileCrud::create(create).await?;
+    Ok(id)
+}
+
+#[tauri::command]
+pub async fn entity_read_nutrient_profile(id: i64) -> Result<Model, CommandError> {
+    let model_option = NutrientProfileCrud::read(id).await?;
+    let model = model_option.ok_or(NotFound)?;
+    Ok(model)
+}
+
+#[tauri::command]
+pub async fn entity_update_nutrient_profile(
+    update: NutrientProfileUpdate,
+) -> Result<(), CommandError> {
+    NutrientProfileCrud::update(update).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_nutrient_profile(id: i64) -> Result<(), CommandError> {
+    NutrientProfileCrud::delete(id).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_create_many_nutrient_profile(
+    creates: Vec<NutrientProfileCreate>,
+) -> Result<Vec<i64>, CommandError> {
+    let ids = NutrientProfileCrud::create_many(creates).await?;
+    Ok(ids)
+}
+
+#[tauri::command]
+pub async fn entity_update_many_nutrient_profile(
+    updates: Vec<NutrientProfileUpdate>,
+) -> Result<(), CommandError> {
+    NutrientProfileCrud::update_many(updates).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_many_nutrient_profile(ids: Vec<i64>) -> Result<(), CommandError> {
+    NutrientProfileCrud::delete_many(ids).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_list_nutrient_profile(
+    filter: NutrientProfileFilter,
+) -> Result<Vec<i64>, CommandError> {
+    let list = NutrientProfileCrud::list(filter).await?;
+    Ok(list)
+}
+
+#[tauri::command]
+pub async fn entity_list_page_nutrient_profile(
+    filter: NutrientProfileFilter,
+) -> Result<ListPage<i64>, CommandError> {
+    let page = NutrientProfileCrud::list_page(filter).await?;
+    Ok(page)
+}
+
+#[tauri::command]
+pub async fn entity_count_nutrient_profile(
+    condition: Option<NutrientProfileCondition>,
+) -> Result<i64, CommandError> {
+    let count = NutrientProfileCrud::count(condition).await?;
+    Ok(count)
+}
@@ -2,8 +2,10 @@ use crate::{
     command::error::{CommandError, CommandError::NotFound},
     entity::recipe::Model,
     entity_crud::{
-        recipe::{RecipeCondition, RecipeCreate, RecipeCrud, RecipeFilter, RecipeUpdate},
-        EntityCrudTrait,
+        recipe::{
+            RecipeCondition, RecipeCreate, RecipeCrud, RecipeExport, RecipeFilter, RecipeUpdate,
+        },
+        EntityCrudTrait, ListPage,
     },
 };
 
@@ -26,9 +28,31 @@ pub async fn entity_update_recipe(update: RecipeUpdate) -> Result<(), CommandErr
     Ok(())
 }
 
+/// Move a recipe into the trash, it is only deleted for good by [`entity_purge_recipe`].
 #[tauri::command]
 pub async fn entity_delete_recipe(id: i64) -> Result<(), CommandError> {
-    RecipeCrud::delete(id).await?;
+    RecipeCrud::trash(id).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_create_many_recipe(
+    creates: Vec<RecipeCreate>,
+) -> Result<Vec<i64>, CommandError> {
+    let ids = RecipeCrud::create_many(creates).await?;
+    Ok(ids)
+}
+
+#[tauri::command]
+pub async fn entity_update_many_recipe(updates: Vec<RecipeUpdate>) -> Result<(), CommandError> {
+    RecipeCrud::update_many(updates).await?;
+    Ok(())
+}
+
+/// Move recipes into the trash, they are only deleted for good by [`entity_purge_recipe`].
+#[tauri::command]
+pub async fn entity_delete_many_recipe(ids: Vec<i64>) -> Result<(), CommandError> {
+    RecipeCrud::trash_many(ids).await?;
     Ok(())
 }
 
@@ -38,8 +62,55 @@ pub async fn entity_list_recipe(filter: RecipeFilter) -> Result<Vec<i64>, Comman
     Ok(list)
 }
 
+#[tauri::command]
+pub async fn entity_list_page_recipe(filter: RecipeFilter) -> Result<ListPage<i64>, CommandError> {
+    let page = RecipeCrud::list_page(filter).await?;
+    Ok(page)
+}
+
 #[tauri::command]
 pub async fn entity_count_recipe(condition: Option<RecipeCondition>) -> Result<i64, CommandError> {
     let count = RecipeCrud::count(condition).await?;
     Ok(count)
 }
+
+#[tauri::command]
+pub async fn entity_trash_recipe(id: i64) -> Result<(), CommandError> {
+    RecipeCrud::trash(id).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_restore_recipe(id: i64) -> Result<(), CommandError> {
+    RecipeCrud::restore(id).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_purge_recipe(ids: Vec<i64>) -> Result<(), CommandError> {
+    RecipeCrud::purge(ids).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_purge_trash_recipe(older_than_days: Option<u64>) -> Result<(), CommandError> {
+    RecipeCrud::purge_trash(older_than_days).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn recipe_duplicate(
+    id: i64,
+    name: Option<String>,
+    fork: bool,
+) -> Result<i64, CommandError> {
+    let id_option = RecipeCrud::duplicate(id, name, fork).await?;
+    let id = id_option.ok_or(NotFound)?;
+    Ok(id)
+}
+
+#[tauri::command]
+pub async fn recipe_export(id: i64) -> Result<RecipeExport, CommandError> {
+    let export = RecipeCrud::export(id).await?.ok_or(NotFound)?;
+    Ok(export)
+}
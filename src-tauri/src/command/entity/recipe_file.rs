@@ -6,7 +6,7 @@ use crate::{
             RecipeFileCondition, RecipeFileCreate, RecipeFileCrud, RecipeFileFilter,
             RecipeFileUpdate,
         },
-        EntityCrudTrait,
+        EntityCrudTrait, ListPage, OrderedEntityCrudTrait,
     },
 };
 
@@ -35,12 +35,42 @@ pub async fn entity_delete_recipe_file(id: i64) -> Result<(), CommandError> {
     Ok(())
 }
 
+#[tauri::command]
+pub async fn entity_create_many_recipe_file(
+    creates: Vec<RecipeFileCreate>,
+) -> Result<Vec<i64>, CommandError> {
+    let ids = RecipeFileCrud::create_many(creates).await?;
+    Ok(ids)
+}
+
+#[tauri::command]
+pub async fn entity_update_many_recipe_file(
+    updates: Vec<RecipeFileUpdate>,
+) -> Result<(), CommandError> {
+    RecipeFileCrud::update_many(updates).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_many_recipe_file(ids: Vec<i64>) -> Result<(), CommandError> {
+    RecipeFileCrud::delete_many(ids).await?;
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn entity_list_recipe_file(filter: RecipeFileFilter) -> Result<Vec<i64>, CommandError> {
     let list = RecipeFileCrud::list(filter).await?;
     Ok(list)
 }
 
+#[tauri::command]
+pub async fn entity_list_page_recipe_file(
+    filter: RecipeFileFilter,
+) -> Result<ListPage<i64>, CommandError> {
+    let page = RecipeFileCrud::list_page(filter).await?;
+    Ok(page)
+}
+
 #[tauri::command]
 pub async fn entity_count_recipe_file(
     condition: Option<RecipeFileCondition>,
@@ -48,3 +78,17 @@ pub async fn entity_count_recipe_file(
     let count = RecipeFileCrud::count(condition).await?;
     Ok(count)
 }
+
+#[tauri::command]
+pub async fn entity_move_recipe_file(id: i64, position: u64) -> Result<(), CommandError> {
+    if !RecipeFileCrud::move_to(id, position).await? {
+        return Err(NotFound);
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_reorder_recipe_file(recipe_id: i64, ids: Vec<i64>) -> Result<(), CommandError> {
+    RecipeFileCrud::reorder(recipe_id, ids).await?;
+    Ok(())
+}
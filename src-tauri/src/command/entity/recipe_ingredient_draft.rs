@@ -6,7 +6,7 @@ use crate::{
             RecipeIngredientDraftCondition, RecipeIngredientDraftCreate, RecipeIngredientDraftCrud,
             RecipeIngredientDraftFilter, RecipeIngredientDraftUpdate,
         },
-        EntityCrudTrait,
+        EntityCrudTrait, ListPage, OrderedEntityCrudTrait,
     },
 };
 
@@ -39,6 +39,28 @@ pub async fn entity_delete_recipe_ingredient_draft(id: i64) -> Result<(), Comman
     Ok(())
 }
 
+#[tauri::command]
+pub async fn entity_create_many_recipe_ingredient_draft(
+    creates: Vec<RecipeIngredientDraftCreate>,
+) -> Result<Vec<i64>, CommandError> {
+    let ids = RecipeIngredientDraftCrud::create_many(creates).await?;
+    Ok(ids)
+}
+
+#[tauri::command]
+pub async fn entity_update_many_recipe_ingredient_draft(
+    updates: Vec<RecipeIngredientDraftUpdate>,
+) -> Result<(), CommandError> {
+    RecipeIngredientDraftCrud::update_many(updates).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_many_recipe_ingredient_draft(ids: Vec<i64>) -> Result<(), CommandError> {
+    RecipeIngredientDraftCrud::delete_many(ids).await?;
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn entity_list_recipe_ingredient_draft(
     filter: RecipeIngredientDraftFilter,
@@ -47,6 +69,14 @@ pub async fn entity_list_recipe_ingredient_draft(
     Ok(list)
 }
 
+#[tauri::command]
+pub async fn entity_list_page_recipe_ingredient_draft(
+    filter: RecipeIngredientDraftFilter,
+) -> Result<ListPage<i64>, CommandError> {
+    let page = RecipeIngredientDraftCrud::list_page(filter).await?;
+    Ok(page)
+}
+
 #[tauri::command]
 pub async fn entity_count_recipe_ingredient_draft(
     condition: Option<RecipeIngredientDraftCondition>,
@@ -54,3 +84,23 @@ pub async fn entity_count_recipe_ingredient_draft(
     let count = RecipeIngredientDraftCrud::count(condition).await?;
     Ok(count)
 }
+
+#[tauri::command]
+pub async fn entity_move_recipe_ingredient_draft(
+    id: i64,
+    position: u64,
+) -> Result<(), CommandError> {
+    if !RecipeIngredientDraftCrud::move_to(id, position).await? {
+        return Err(NotFound);
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_reorder_recipe_ingredient_draft(
+    recipe_id: i64,
+    ids: Vec<i64>,
+) -> Result<(), CommandError> {
+    RecipeIngredientDraftCrud::reorder(recipe_id, ids).await?;
+    Ok(())
+}
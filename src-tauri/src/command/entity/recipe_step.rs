@@ -6,7 +6,7 @@ use crate::{
             RecipeStepCondition, RecipeStepCreate, RecipeStepCrud, RecipeStepFilter,
             RecipeStepUpdate,
         },
-        EntityCrudTrait,
+        EntityCrudTrait, ListPage, OrderedEntityCrudTrait,
     },
 };
 
@@ -35,12 +35,42 @@ pub async fn entity_delete_recipe_step(id: i64) -> Result<(), CommandError> {
     Ok(())
 }
 
+#[tauri::command]
+pub async fn entity_create_many_recipe_step(
+    creates: Vec<RecipeStepCreate>,
+) -> Result<Vec<i64>, CommandError> {
+    let ids = RecipeStepCrud::create_many(creates).await?;
+    Ok(ids)
+}
+
+#[tauri::command]
+pub async fn entity_update_many_recipe_step(
+    updates: Vec<RecipeStepUpdate>,
+) -> Result<(), CommandError> {
+    RecipeStepCrud::update_many(updates).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_many_recipe_step(ids: Vec<i64>) -> Result<(), CommandError> {
+    RecipeStepCrud::delete_many(ids).await?;
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn entity_list_recipe_step(filter: RecipeStepFilter) -> Result<Vec<i64>, CommandError> {
     let list = RecipeStepCrud::list(filter).await?;
     Ok(list)
 }
 
+#[tauri::command]
+pub async fn entity_list_page_recipe_step(
+    filter: RecipeStepFilter,
+) -> Result<ListPage<i64>, CommandError> {
+    let page = RecipeStepCrud::list_page(filter).await?;
+    Ok(page)
+}
+
 #[tauri::command]
 pub async fn entity_count_recipe_step(
     condition: Option<RecipeStepCondition>,
@@ -48,3 +78,17 @@ pub async fn entity_count_recipe_step(
     let count = RecipeStepCrud::count(condition).await?;
     Ok(count)
 }
+
+#[tauri::command]
+pub async fn entity_move_recipe_step(id: i64, position: u64) -> Result<(), CommandError> {
+    if !RecipeStepCrud::move_to(id, position).await? {
+        return Err(NotFound);
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_reorder_recipe_step(recipe_id: i64, ids: Vec<i64>) -> Result<(), CommandError> {
+    RecipeStepCrud::reorder(recipe_id, ids).await?;
+    Ok(())
+}
@@ -6,7 +6,7 @@ use crate::{
             RecipeStepFileCondition, RecipeStepFileCreate, RecipeStepFileCrud,
             RecipeStepFileFilter, RecipeStepFileUpdate,
         },
-        EntityCrudTrait,
+        EntityCrudTrait, ListPage, OrderedEntityCrudTrait,
     },
 };
 
@@ -39,6 +39,28 @@ pub async fn entity_delete_recipe_step_file(id: i64) -> Result<(), CommandError>
     Ok(())
 }
 
+#[tauri::command]
+pub async fn entity_create_many_recipe_step_file(
+    creates: Vec<RecipeStepFileCreate>,
+) -> Result<Vec<i64>, CommandError> {
+    let ids = RecipeStepFileCrud::create_many(creates).await?;
+    Ok(ids)
+}
+
+#[tauri::command]
+pub async fn entity_update_many_recipe_step_file(
+    updates: Vec<RecipeStepFileUpdate>,
+) -> Result<(), CommandError> {
+    RecipeStepFileCrud::update_many(updates).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_many_recipe_step_file(ids: Vec<i64>) -> Result<(), CommandError> {
+    RecipeStepFileCrud::delete_many(ids).await?;
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn entity_list_recipe_step_file(
     filter: RecipeStepFileFilter,
@@ -47,6 +69,14 @@ pub async fn entity_list_recipe_step_file(
     Ok(list)
 }
 
+#[tauri::command]
+pub async fn entity_list_page_recipe_step_file(
+    filter: RecipeStepFileFilter,
+) -> Result<ListPage<i64>, CommandError> {
+    let page = RecipeStepFileCrud::list_page(filter).await?;
+    Ok(page)
+}
+
 #[tauri::command]
 pub async fn entity_count_recipe_step_file(
     condition: Option<RecipeStepFileCondition>,
@@ -54,3 +84,20 @@ pub async fn entity_count_recipe_step_file(
     let count = RecipeStepFileCrud::count(condition).await?;
     Ok(count)
 }
+
+#[tauri::command]
+pub async fn entity_move_recipe_step_file(id: i64, position: u64) -> Result<(), CommandError> {
+    if !RecipeStepFileCrud::move_to(id, position).await? {
+        return Err(NotFound);
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_reorder_recipe_step_file(
+    recipe_step_id: i64,
+    ids: Vec<i64>,
+) -> Result<(), CommandError> {
+    RecipeStepFileCrud::reorder(recipe_step_id, ids).await?;
+    Ok(())
+}
@@ -6,7 +6,7 @@ use crate::{
             RecipeStepIngredientCondition, RecipeStepIngredientCreate, RecipeStepIngredientCrud,
             RecipeStepIngredientFilter, RecipeStepIngredientUpdate,
         },
-        EntityCrudTrait,
+        EntityCrudTrait, ListPage, OrderedEntityCrudTrait,
     },
 };
 
@@ -39,6 +39,28 @@ pub async fn entity_delete_recipe_step_ingredient(id: i64) -> Result<(), Command
     Ok(())
 }
 
+#[tauri::command]
+pub async fn entity_create_many_recipe_step_ingredient(
+    creates: Vec<RecipeStepIngredientCreate>,
+) -> Result<Vec<i64>, CommandError> {
+    let ids = RecipeStepIngredientCrud::create_many(creates).await?;
+    Ok(ids)
+}
+
+#[tauri::command]
+pub async fn entity_update_many_recipe_step_ingredient(
+    updates: Vec<RecipeStepIngredientUpdate>,
+) -> Result<(), CommandError> {
+    RecipeStepIngredientCrud::update_many(updates).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_many_recipe_step_ingredient(ids: Vec<i64>) -> Result<(), CommandError> {
+    RecipeStepIngredientCrud::delete_many(ids).await?;
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn entity_list_recipe_step_ingredient(
     filter: RecipeStepIngredientFilter,
@@ -47,6 +69,14 @@ pub async fn entity_list_recipe_step_ingredient(
     Ok(list)
 }
 
+#[tauri::command]
+pub async fn entity_list_page_recipe_step_ingredient(
+    filter: RecipeStepIngredientFilter,
+) -> Result<ListPage<i64>, CommandError> {
+    let page = RecipeStepIngredientCrud::list_page(filter).await?;
+    Ok(page)
+}
+
 #[tauri::command]
 pub async fn entity_count_recipe_step_ingredient(
     condition: Option<RecipeStepIngredientCondition>,
@@ -54,3 +84,23 @@ pub async fn entity_count_recipe_step_ingredient(
     let count = RecipeStepIngredientCrud::count(condition).await?;
     Ok(count)
 }
+
+#[tauri::command]
+pub async fn entity_move_recipe_step_ingredient(
+    id: i64,
+    position: u64,
+) -> Result<(), CommandError> {
+    if !RecipeStepIngredientCrud::move_to(id, position).await? {
+        return Err(NotFound);
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_reorder_recipe_step_ingredient(
+    recipe_step_id: i64,
+    ids: Vec<i64>,
+) -> Result<(), CommandError> {
+    RecipeStepIngredientCrud::reorder(recipe_step_id, ids).await?;
+    Ok(())
+}
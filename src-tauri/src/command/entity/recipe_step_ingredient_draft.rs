@@ -7,7 +7,7 @@ use crate::{
             RecipeStepIngredientDraftCrud, RecipeStepIngredientDraftFilter,
             RecipeStepIngredientDraftUpdate,
         },
-        EntityCrudTrait,
+        EntityCrudTrait, ListPage, OrderedEntityCrudTrait,
     },
 };
 
@@ -40,6 +40,30 @@ pub async fn entity_delete_recipe_step_ingredient_draft(id: i64) -> Result<(), C
     Ok(())
 }
 
+#[tauri::command]
+pub async fn entity_create_many_recipe_step_ingredient_draft(
+    creates: Vec<RecipeStepIngredientDraftCreate>,
+) -> Result<Vec<i64>, CommandError> {
+    let ids = RecipeStepIngredientDraftCrud::create_many(creates).await?;
+    Ok(ids)
+}
+
+#[tauri::command]
+pub async fn entity_update_many_recipe_step_ingredient_draft(
+    updates: Vec<RecipeStepIngredientDraftUpdate>,
+) -> Result<(), CommandError> {
+    RecipeStepIngredientDraftCrud::update_many(updates).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_many_recipe_step_ingredient_draft(
+    ids: Vec<i64>,
+) -> Result<(), CommandError> {
+    RecipeStepIngredientDraftCrud::delete_many(ids).await?;
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn entity_list_recipe_step_ingredient_draft(
     filter: RecipeStepIngredientDraftFilter,
@@ -48,6 +72,14 @@ pub async fn entity_list_recipe_step_ingredient_draft(
     Ok(list)
 }
 
+#[tauri::command]
+pub async fn entity_list_page_recipe_step_ingredient_draft(
+    filter: RecipeStepIngredientDraftFilter,
+) -> Result<ListPage<i64>, CommandError> {
+    let page = RecipeStepIngredientDraftCrud::list_page(filter).await?;
+    Ok(page)
+}
+
 #[tauri::command]
 pub async fn entity_count_recipe_step_ingredient_draft(
     condition: Option<RecipeStepIngredientDraftCondition>,
@@ -55,3 +87,23 @@ pub async fn entity_count_recipe_step_ingredient_draft(
     let count = RecipeStepIngredientDraftCrud::count(condition).await?;
     Ok(count)
 }
+
+#[tauri::command]
+pub async fn entity_move_recipe_step_ingredient_draft(
+    id: i64,
+    position: u64,
+) -> Result<(), CommandError> {
+    if !RecipeStepIngredientDraftCrud::move_to(id, position).await? {
+        return Err(NotFound);
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_reorder_recipe_step_ingredient_draft(
+    recipe_step_id: i64,
+    ids: Vec<i64>,
+) -> Result<(), CommandError> {
+    RecipeStepIngredientDraftCrud::reorder(recipe_step_id, ids).await?;
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use crate::{
+    command::error::{CommandError, CommandError::NotFound},
+    entity::tag::Model,
+    entity_crud::{
+        tag::{TagCondition, TagCreate, TagCrud, TagFilter, TagUpdate, TagUsage},
+        EntityCrudTrait, ListPage,
+    },
+};
+
+#[tauri::command]
+pub async fn entity_create_tag(create: TagCreate) -> Result<i64, CommandError> {
+    let id = TagCrud::create(create).await?;
+    Ok(id)
+}
+
+#[tauri::command]
+pub async fn entity_read_tag(id: i64) -> Result<Model, CommandError> {
+    let model_option = TagCrud::read(id).await?;
+    let model = model_option.ok_or(NotFound)?;
+    Ok(model)
+}
+
+#[tauri::command]
+pub async fn entity_update_tag(update: TagUpdate) -> Result<(), CommandError> {
+    TagCrud::update(update).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_tag(id: i64) -> Result<(), CommandError> {
+    TagCrud::delete(id).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_create_many_tag(creates: Vec<TagCreate>) -> Result<Vec<i64>, CommandError> {
+    let ids = TagCrud::create_many(creates).await?;
+    Ok(ids)
+}
+
+#[tauri::command]
+pub async fn entity_update_many_tag(updates: Vec<TagUpdate>) -> Result<(), CommandError> {
+    TagCrud::update_many(updates).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_many_tag(ids: Vec<i64>) -> Result<(), CommandError> {
+    TagCrud::delete_many(ids).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_list_tag(filter: TagFilter) -> Result<Vec<i64>, CommandError> {
+    let list = TagCrud::list(filter).await?;
+    Ok(list)
+}
+
+#[tauri::command]
+pub async fn entity_list_page_tag(filter: TagFilter) -> Result<ListPage<i64>, CommandError> {
+    let page = TagCrud::list_page(filter).await?;
+    Ok(page)
+}
+
+#[tauri::command]
+pub async fn entity_count_tag(condition: Option<TagCondition>) -> Result<i64, CommandError> {
+    let count = TagCrud::count(condition).await?;
+    Ok(count)
+}
+
+#[tauri::command]
+pub async fn tag_rename(id: i64, name: String) -> Result<i64, CommandError> {
+    let id = TagCrud::rename(id, name).await?.ok_or(NotFound)?;
+    Ok(id)
+}
+
+#[tauri::command]
+pub async fn tag_cloud() -> Result<Vec<TagUsage>, CommandError> {
+    let tag_usages = TagCrud::cloud().await?;
+    Ok(tag_usages)
+}
@@ -5,7 +5,7 @@ use crate::{
         unit_name::{
             UnitNameCondition, UnitNameCreate, UnitNameCrud, UnitNameFilter, UnitNameUpdate,
         },
-        EntityCrudTrait,
+        EntityCrudTrait, ListPage,
     },
 };
 
@@ -34,12 +34,42 @@ pub async fn entity_delete_unit_name(id: String) -> Result<(), CommandError> {
     Ok(())
 }
 
+#[tauri::command]
+pub async fn entity_create_many_unit_name(
+    creates: Vec<UnitNameCreate>,
+) -> Result<Vec<String>, CommandError> {
+    let ids = UnitNameCrud::create_many(creates).await?;
+    Ok(ids)
+}
+
+#[tauri::command]
+pub async fn entity_update_many_unit_name(
+    updates: Vec<UnitNameUpdate>,
+) -> Result<(), CommandError> {
+    UnitNameCrud::update_many(updates).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_many_unit_name(ids: Vec<String>) -> Result<(), CommandError> {
+    UnitNameCrud::delete_many(ids).await?;
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn entity_list_unit_name(filter: UnitNameFilter) -> Result<Vec<String>, CommandError> {
     let list = UnitNameCrud::list(filter).await?;
     Ok(list)
 }
 
+#[tauri::command]
+pub async fn entity_list_page_unit_name(
+    filter: UnitNameFilter,
+) -> Result<ListPage<String>, CommandError> {
+    let page = UnitNameCrud::list_page(filter).await?;
+    Ok(page)
+}
+
 #[tauri::command]
 pub async fn entity_count_unit_name(
     condition: Option<UnitNameCondition>,
@@ -4,7 +4,10 @@ use serde::Serialize;
 use serde_with::{serde_as, DisplayFromStr};
 use thiserror::Error;
 
-use crate::external_recipe::error::ExternalRecipeError;
+use crate::{
+    external_recipe::error::ExternalRecipeError, hocr::error::HocrError, ocr::error::OcrError,
+    pdf::error::PdfError,
+};
 
 #[serde_as]
 #[derive(Debug, Error, Serialize)]
@@ -34,6 +37,18 @@ pub enum CommandError {
         tesseract::plumbing::TessBaseApiGetHocrTextError,
     ),
     #[error(transparent)]
+    Hocr(
+        #[serde_as(as = "DisplayFromStr")]
+        #[from]
+        HocrError,
+    ),
+    #[error("There is no trained data for the OCR language \"{0}\".")]
+    OcrLanguageUnavailable(String),
+    #[error(transparent)]
+    Ocr(#[serde_as(as = "DisplayFromStr")] OcrError),
+    #[error(transparent)]
+    Pdf(#[serde_as(as = "DisplayFromStr")] PdfError),
+    #[error(transparent)]
     Reqwest(
         #[serde_as(as = "DisplayFromStr")]
         #[from]
@@ -69,3 +84,24 @@ impl From<ExternalRecipeError> for CommandError {
         }
     }
 }
+
+impl From<OcrError> for CommandError {
+    fn from(value: OcrError) -> Self {
+        match value {
+            OcrError::Initialize(initialize) => Self::OcrInitialize(initialize),
+            OcrError::SetImage(set_image) => Self::OcrSetImage(set_image),
+            OcrError::GetHocrText(get_hocr_text) => Self::OcrGetHocrText(get_hocr_text),
+            OcrError::LanguageUnavailable(language) => Self::OcrLanguageUnavailable(language),
+            _ => Self::Ocr(value),
+        }
+    }
+}
+
+impl From<PdfError> for CommandError {
+    fn from(value: PdfError) -> Self {
+        match value {
+            PdfError::Ocr(ocr) => ocr.into(),
+            _ => Self::Pdf(value),
+        }
+    }
+}
@@ -1,92 +1,130 @@
+use sea_orm::ActiveValue;
+
 use crate::{
-    command::error::CommandError,
+    command::{self, error::CommandError},
+    entity::file,
     entity_crud::{
-        file::{FileCreate, FileCreateUri, FileCrud},
-        recipe::{RecipeCreate, RecipeCrud},
-        recipe_file::{RecipeFileCreate, RecipeFileCrud},
-        recipe_ingredient_draft::{RecipeIngredientDraftCreate, RecipeIngredientDraftCrud},
-        recipe_step::{RecipeStepCreate, RecipeStepCrud},
-        recipe_step_file::{RecipeStepFileCreate, RecipeStepFileCrud},
-        recipe_step_ingredient_draft::{
-            RecipeStepIngredientDraftCreate, RecipeStepIngredientDraftCrud,
-        },
-        EntityCrudTrait,
+        file::{FileCreate, FileCreateUri},
+        recipe::{RecipeCreate, RecipeCrud, RecipeImport, RecipeImportFile, RecipeStepImport},
+        TryIntoActiveModel,
     },
+    ocr, pdf, recipe_text,
+    recipe_text::RecipeText,
+    recipe_time,
+    recipe_time::RecipeTime,
+    step_duration,
 };
 
 #[tauri::command]
 pub async fn external_recipe(url: String) -> Result<i64, CommandError> {
     let external_recipe = crate::external_recipe::get(url).await?;
-    let recipe_id = RecipeCrud::create(RecipeCreate {
-        name: external_recipe.name,
+    let mut ingredients = external_recipe.ingredients;
+    let files = download(external_recipe.files).await?;
+    let mut steps = vec![];
+    for step in external_recipe.steps {
+        let files = download(step.files).await?;
+        let mut step = RecipeStepImport {
+            description: step.description,
+            ingredients: step.ingredients,
+            files,
+        };
+        if !step.description.trim().is_empty() {
+            steps.push(step);
+            continue;
+        }
+        // The first recognized step completes this step, the others follow right after it.
+        let recipe_text = recognize_pdf_files(&step.files).await;
+        ingredients.extend(recipe_text.ingredients);
+        let mut recognized_steps = recipe_text.steps.into_iter();
+        if let Some(description) = recognized_steps.next() {
+            step.description = description;
+        }
+        steps.push(step);
+        steps.extend(recognized_steps.map(|description| RecipeStepImport {
+            description,
+            ingredients: vec![],
+            files: vec![],
+        }));
+    }
+    let recipe_time = RecipeTime {
+        prep_time: external_recipe.prep_time,
+        cook_time: external_recipe.cook_time,
+        rest_time: None,
+        total_time: external_recipe.total_time,
+    }
+    .complete(recipe_time::estimate(steps.iter().map(|step| {
+        (
+            step.description.as_str(),
+            step_duration::parse(&step.description),
+        )
+    })));
+    let recipe_id = RecipeCrud::import(RecipeImport {
+        recipe: RecipeCreate {
+            name: external_recipe.name,
+            prep_time: recipe_time.prep_time,
+            cook_time: recipe_time.cook_time,
+            rest_time: recipe_time.rest_time,
+            total_time: recipe_time.total_time,
+        },
+        ingredients,
+        files,
+        steps,
     })
     .await?;
-    for (i, ingredient) in external_recipe.ingredients.into_iter().enumerate() {
-        tokio::spawn(async move {
-            RecipeIngredientDraftCrud::create(RecipeIngredientDraftCreate {
-                order: (i + 1) as i64,
-                text: ingredient,
-                recipe_id,
-            })
-            .await
-            .unwrap();
-        });
-    }
-    for (i, file) in external_recipe.files.into_iter().enumerate() {
-        tokio::spawn(async move {
-            let file_id = FileCrud::create(FileCreate {
-                name: file.clone(),
-                uri: FileCreateUri::Url(file),
-            })
-            .await
-            .unwrap();
-            RecipeFileCrud::create(RecipeFileCreate {
-                order: (i + 1) as i64,
-                recipe_id,
-                file_id,
-            })
-            .await
-            .unwrap();
-        });
+    Ok(recipe_id)
+}
+
+/// Download files concurrently into the file storage, keeping their order.
+async fn download(urls: Vec<String>) -> Result<Vec<RecipeImportFile>, CommandError> {
+    let handles: Vec<_> = urls
+        .into_iter()
+        .map(|url| {
+            tokio::spawn(
+                FileCreate {
+                    name: url.clone(),
+                    uri: FileCreateUri::Url(url),
+                }
+                .try_into_active_model(),
+            )
+        })
+        .collect();
+    let mut files = vec![];
+    for handle in handles {
+        let active_model = handle.await.map_err(anyhow::Error::from)??;
+        files.push(RecipeImportFile::Created(active_model));
     }
-    for (i, step) in external_recipe.steps.into_iter().enumerate() {
-        tokio::spawn(async move {
-            let recipe_step_id = RecipeStepCrud::create(RecipeStepCreate {
-                recipe_id,
-                description: step.description,
-                order: (i + 1) as i64,
-            })
+    Ok(files)
+}
+
+/// Recognize the recipe text of all PDF files, in order.
+///
+/// Other files are skipped, as are PDF files whose text cannot be recognized.
+async fn recognize_pdf_files(files: &[RecipeImportFile]) -> RecipeText {
+    let mut recipe_text = RecipeText::default();
+    for file in files {
+        let RecipeImportFile::Created(file::ActiveModel {
+            mime: ActiveValue::Set(mime),
+            path: ActiveValue::Set(path),
+            ..
+        }) = file
+        else {
+            continue;
+        };
+        if mime != pdf::MIME {
+            continue;
+        }
+        let text = match command::pdf::path_text(path.clone(), String::from(ocr::DEFAULT_LANGUAGE))
             .await
-            .unwrap();
-            for (i, ingredient) in step.ingredients.into_iter().enumerate() {
-                tokio::spawn(async move {
-                    RecipeStepIngredientDraftCrud::create(RecipeStepIngredientDraftCreate {
-                        order: (i + 1) as i64,
-                        text: ingredient,
-                        recipe_step_id,
-                    })
-                    .await
-                    .unwrap();
-                });
+        {
+            Ok(text) => text,
+            Err(err) => {
+                log::warn!("Could not get the text of PDF file: {err}");
+                continue;
             }
-            for (i, file) in step.files.into_iter().enumerate() {
-                tokio::spawn(async move {
-                    let file_id = FileCrud::create(FileCreate {
-                        name: file.clone(),
-                        uri: FileCreateUri::Url(file),
-                    })
-                    .await
-                    .unwrap();
-                    RecipeStepFileCrud::create(RecipeStepFileCreate {
-                        order: (i + 1) as i64,
-                        recipe_step_id,
-                        file_id,
-                    })
-                    .await
-                    .unwrap();
-                });
-            }
-        });
+        };
+        let file_recipe_text = recipe_text::parse(&text);
+        recipe_text.ingredients.extend(file_recipe_text.ingredients);
+        recipe_text.steps.extend(file_recipe_text.steps);
     }
-    Ok(recipe_id)
+    recipe_text
 }
@@ -0,0 +1,15 @@
+use crate::{command::error::CommandError, journal};
+
+/// Undo the last entity mutation, returning whether there was one to undo.
+#[tauri::command]
+pub async fn undo() -> Result<bool, CommandError> {
+    let undone = journal::undo().await?;
+    Ok(undone)
+}
+
+/// Redo the last undone entity mutation, returning whether there was one to redo.
+#[tauri::command]
+pub async fn redo() -> Result<bool, CommandError> {
+    let redone = journal::redo().await?;
+    Ok(redone)
+}
@@ -0,0 +1,28 @@
+use std::path::PathBuf;
+
+use crate::{
+    command::error::{CommandError, CommandError::NotFound},
+    nutrition,
+    nutrition::{NutrientColumns, NutrientImport, RecipeNutrition},
+};
+
+/// Import nutrient profiles from a CSV dump of a food composition database, see [`nutrition::import`].
+#[tauri::command]
+pub async fn nutrition_import(
+    path: PathBuf,
+    columns: NutrientColumns,
+) -> Result<NutrientImport, CommandError> {
+    let nutrient_import = nutrition::import(&path, columns).await?;
+    Ok(nutrient_import)
+}
+
+/// Calculate the nutrition of a recipe and optionally per serving, see [`nutrition::calculate`].
+#[tauri::command]
+pub async fn nutrition_calculate(
+    recipe_id: i64,
+    servings: Option<f64>,
+) -> Result<RecipeNutrition, CommandError> {
+    let recipe_nutrition_option = nutrition::calculate(recipe_id, servings).await?;
+    let recipe_nutrition = recipe_nutrition_option.ok_or(NotFound)?;
+    Ok(recipe_nutrition)
+}
@@ -1,30 +1,167 @@
-use std::path::PathBuf;
-
-use entity_crud::file::FileCrud;
-use tesseract::Tesseract;
+use image::ImageFormat;
+use sea_orm::{sea_query::OnConflict, ActiveValue, ColumnTrait, EntityTrait, QueryFilter};
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    app_handle::get_app_handle, command::error::CommandError, entity_crud,
-    entity_crud::EntityCrudTrait,
+    command::error::CommandError,
+    database,
+    entity::ocr_result,
+    entity_crud::{
+        file::{FileCreate, FileCreateUri, FileCrud},
+        recipe_step_file::RecipeStepFileCrud,
+        EntityCrudTrait,
+    },
+    hocr::{self, HocrPage},
+    ocr::{self, preprocessing::OcrPreprocessing, OcrImage, OcrLanguage, OcrSettings},
 };
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OcrFormat {
+    /// The pages with their blocks, paragraphs, lines and words.
+    Structure,
+    /// The text in reading order.
+    Text,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OcrOutput {
+    Structure(Vec<HocrPage>),
+    Text(String),
+}
+
+/// Get the optically recognized characters from the specified file as hOCR.
+///
+/// The `language` is a Tesseract language specification like `deu+eng` and defaults to [`ocr::DEFAULT_LANGUAGE`].
+///
+/// The result is cached in [`ocr_result`] per file, language and settings.
+pub async fn file_hocr(
+    file_id: i64,
+    language: Option<String>,
+    settings: Option<OcrSettings>,
+) -> Result<String, CommandError> {
+    let model_option = FileCrud::read(file_id).await?;
+    let Some(model) = model_option else {
+        return Err(CommandError::NotFound);
+    };
+    let language = language.unwrap_or(String::from(ocr::DEFAULT_LANGUAGE));
+    let settings = settings.unwrap_or_default();
+    let settings_key = serde_json::to_string(&settings).map_err(anyhow::Error::from)?;
+    let ocr_result_option = ocr_result::Entity::find()
+        .filter(ocr_result::Column::FileId.eq(file_id))
+        .filter(ocr_result::Column::Language.eq(&language))
+        .filter(ocr_result::Column::Settings.eq(&settings_key))
+        .one(database::connect().await)
+        .await?;
+    if let Some(ocr_result) = ocr_result_option {
+        return Ok(ocr_result.hocr);
+    }
+    let hocr_string = ocr::hocr(OcrImage::Path(model.path), language.clone(), settings).await?;
+    let db = database::connect_writing().await;
+    let insert_result = ocr_result::Entity::insert(ocr_result::ActiveModel {
+        id: ActiveValue::NotSet,
+        file_id: ActiveValue::Set(file_id),
+        language: ActiveValue::Set(language),
+        settings: ActiveValue::Set(settings_key),
+        hocr: ActiveValue::Set(hocr_string.clone()),
+    })
+    .on_conflict(
+        OnConflict::columns([
+            ocr_result::Column::FileId,
+            ocr_result::Column::Language,
+            ocr_result::Column::Settings,
+        ])
+        .do_nothing()
+        .to_owned(),
+    )
+    .exec_without_returning(*db)
+    .await;
+    if let Err(err) = insert_result {
+        log::warn!("Could not cache OCR result: {err}");
+    }
+    Ok(hocr_string)
+}
+
 /// Get the optically recognized characters from the specified recipe step file.
+///
+/// See [`file_hocr`] for more information.
 #[tauri::command]
-pub async fn ocr(file_id: i64) -> Result<String, CommandError> {
+pub async fn ocr(
+    file_id: i64,
+    language: Option<String>,
+    settings: Option<OcrSettings>,
+) -> Result<String, CommandError> {
+    file_hocr(file_id, language, settings).await
+}
+
+/// Get the optically recognized characters from the specified file in the specified format.
+///
+/// Words with a confidence below `min_confidence` are left out.
+#[tauri::command]
+pub async fn ocr_structured(
+    file_id: i64,
+    language: Option<String>,
+    settings: Option<OcrSettings>,
+    format: OcrFormat,
+    min_confidence: Option<f32>,
+) -> Result<OcrOutput, CommandError> {
+    let hocr_string = file_hocr(file_id, language, settings).await?;
+    let mut pages = hocr::parse(&hocr_string)?;
+    if let Some(min_confidence) = min_confidence {
+        hocr::retain_confident(&mut pages, min_confidence);
+    }
+    Ok(match format {
+        OcrFormat::Structure => OcrOutput::Structure(pages),
+        OcrFormat::Text => OcrOutput::Text(hocr::text(&pages)),
+    })
+}
+
+/// Store the preprocessed image of the specified file as a new file, see [`OcrPreprocessing`].
+///
+/// The `language` is used for detecting the orientation and defaults to [`ocr::DEFAULT_LANGUAGE`].
+///
+/// When a `recipe_step_id` is specified, the new file is attached after the other files of that recipe step in the same transaction.
+/// Otherwise, it must be referenced within a day, before it is collected as garbage, see [`FileCrud::collect_garbage`].
+///
+/// Returns the id of the new file.
+#[tauri::command]
+pub async fn ocr_preprocess(
+    file_id: i64,
+    language: Option<String>,
+    preprocessing: OcrPreprocessing,
+    recipe_step_id: Option<i64>,
+) -> Result<i64, CommandError> {
     let model_option = FileCrud::read(file_id).await?;
     let Some(model) = model_option else {
         return Err(CommandError::NotFound);
     };
-    let file = PathBuf::from(&model.path);
-    let tessdata = get_app_handle()
-        .path_resolver()
-        .resolve_resource("tessdata")
-        .unwrap()
-        .to_string_lossy()
-        .to_string();
-    std::env::set_var("TESSDATA_PREFIX", tessdata);
-    let mut tesseract = Tesseract::new(None, Some("Latin"))?;
-    tesseract = tesseract.set_image(&file.to_string_lossy())?;
-    let hocr_string = tesseract.get_hocr_text(1)?;
-    Ok(hocr_string)
+    let language = language.unwrap_or(String::from(ocr::DEFAULT_LANGUAGE));
+    let image = ocr::preprocessed(OcrImage::Path(model.path), language, preprocessing).await?;
+    let temp_file = tokio::task::spawn_blocking(move || {
+        let temp_file = tempfile::Builder::new().suffix(".png").tempfile()?;
+        image.save_with_format(temp_file.path(), ImageFormat::Png)?;
+        Ok::<_, anyhow::Error>(temp_file)
+    })
+    .await
+    .map_err(anyhow::Error::from)??;
+    let file_create = FileCreate {
+        name: format!("{} (preprocessed)", model.name),
+        uri: FileCreateUri::Path(temp_file.path().to_string_lossy().to_string()),
+    };
+    let id = match recipe_step_id {
+        Some(recipe_step_id) => {
+            RecipeStepFileCrud::create_with_file(recipe_step_id, file_create).await?
+        }
+        None => FileCrud::create(file_create).await?,
+    };
+    Ok(id)
+}
+
+/// List the languages with trained data in the bundled and the user's tessdata directory.
+#[tauri::command]
+pub async fn ocr_language_list() -> Result<Vec<OcrLanguage>, CommandError> {
+    Ok(tokio::task::spawn_blocking(ocr::languages)
+        .await
+        .map_err(anyhow::Error::from)?)
 }
@@ -0,0 +1,69 @@
+use crate::{
+    command::{error::CommandError, ocr::file_hocr},
+    entity_crud::{
+        file::FileCrud,
+        recipe::{RecipeCreate, RecipeCrud, RecipeImport, RecipeImportFile, RecipeStepImport},
+        EntityCrudTrait,
+    },
+    hocr,
+    ocr::{preprocessing::OcrPreprocessing, OcrPageSegMode, OcrSettings},
+    recipe_text, recipe_time, step_duration,
+};
+
+/// Create a recipe from a photographed recipe, e.g. a cookbook page.
+///
+/// The title, ingredients and steps are recognized via [`recipe_text::from_hocr`].
+/// The file is attached to the recipe and its name is used when no title is recognized.
+/// The recipe is created with all its children as one journal action, see [`RecipeCrud::import`].
+/// The recipe's times are estimated from the steps, see [`recipe_time::estimate`].
+/// The `language` is a Tesseract language specification like `deu+eng`, see [`file_hocr`].
+/// The `preprocessing` helps with skewed and unevenly lit photos, see [`OcrPreprocessing`].
+///
+/// Returns the id of the new recipe.
+#[tauri::command]
+pub async fn ocr_recipe(
+    file_id: i64,
+    language: Option<String>,
+    preprocessing: Option<OcrPreprocessing>,
+) -> Result<i64, CommandError> {
+    let model_option = FileCrud::read(file_id).await?;
+    let Some(model) = model_option else {
+        return Err(CommandError::NotFound);
+    };
+    // The layout is only meaningful with automatic page segmentation.
+    let settings = OcrSettings {
+        page_seg_mode: OcrPageSegMode::Auto,
+        preprocessing: preprocessing.unwrap_or_default(),
+    };
+    let hocr_string = file_hocr(file_id, language, Some(settings)).await?;
+    let pages = hocr::parse(&hocr_string)?;
+    let recipe_text = recipe_text::from_hocr(&pages);
+    let recipe_time = recipe_time::estimate(
+        recipe_text
+            .steps
+            .iter()
+            .map(|step| (step.as_str(), step_duration::parse(step))),
+    );
+    let recipe_id = RecipeCrud::import(RecipeImport {
+        recipe: RecipeCreate {
+            name: recipe_text.name.unwrap_or(model.name),
+            prep_time: recipe_time.prep_time,
+            cook_time: recipe_time.cook_time,
+            rest_time: recipe_time.rest_time,
+            total_time: recipe_time.total_time,
+        },
+        ingredients: recipe_text.ingredients,
+        files: vec![RecipeImportFile::Existing(file_id)],
+        steps: recipe_text
+            .steps
+            .into_iter()
+            .map(|description| RecipeStepImport {
+                description,
+                ingredients: vec![],
+                files: vec![],
+            })
+            .collect(),
+    })
+    .await?;
+    Ok(recipe_id)
+}
@@ -0,0 +1,28 @@
+use crate::{
+    command::error::CommandError,
+    entity_crud::{file::FileCrud, EntityCrudTrait},
+    ocr, pdf,
+};
+
+/// Get the text of the specified PDF file, see [`pdf::text`].
+///
+/// The `language` is used for pages without text layer and defaults to [`ocr::DEFAULT_LANGUAGE`].
+///
+/// Returns [`CommandError::NotFound`] when the file does not exist or is not a PDF file.
+#[tauri::command]
+pub async fn pdf_text(file_id: i64, language: Option<String>) -> Result<String, CommandError> {
+    let model_option = FileCrud::read(file_id).await?;
+    let Some(model) = model_option.filter(|model| model.mime == pdf::MIME) else {
+        return Err(CommandError::NotFound);
+    };
+    let language = language.unwrap_or(String::from(ocr::DEFAULT_LANGUAGE));
+    path_text(model.path, language).await
+}
+
+/// Get the text of a PDF file without blocking the async runtime.
+pub async fn path_text(path: String, language: String) -> Result<String, CommandError> {
+    let text = tokio::task::spawn_blocking(move || pdf::text(path, &language))
+        .await
+        .map_err(anyhow::Error::from)??;
+    Ok(text)
+}
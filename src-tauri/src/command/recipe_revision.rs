@@ -0,0 +1,42 @@
+use crate::{
+    command::error::{CommandError, CommandError::NotFound},
+    recipe_revision,
+    recipe_revision::{RecipeRevisionSummary, RevisionDifference},
+};
+
+/// Save the current state of a recipe as a new revision, returning the revision's id.
+#[tauri::command]
+pub async fn recipe_revision_save(recipe_id: i64) -> Result<i64, CommandError> {
+    let id_option = recipe_revision::save(recipe_id).await?;
+    let id = id_option.ok_or(NotFound)?;
+    Ok(id)
+}
+
+/// List the revisions of a recipe, the newest first.
+#[tauri::command]
+pub async fn recipe_revision_list(
+    recipe_id: i64,
+) -> Result<Vec<RecipeRevisionSummary>, CommandError> {
+    let list = recipe_revision::list(recipe_id).await?;
+    Ok(list)
+}
+
+/// Compare two revisions field by field.
+#[tauri::command]
+pub async fn recipe_revision_diff(
+    old_id: i64,
+    new_id: i64,
+) -> Result<Vec<RevisionDifference>, CommandError> {
+    let differences_option = recipe_revision::diff(old_id, new_id).await?;
+    let differences = differences_option.ok_or(NotFound)?;
+    Ok(differences)
+}
+
+/// Revert a recipe to a revision.
+#[tauri::command]
+pub async fn recipe_revision_revert(id: i64) -> Result<(), CommandError> {
+    if !recipe_revision::revert(id).await? {
+        return Err(NotFound);
+    }
+    Ok(())
+}
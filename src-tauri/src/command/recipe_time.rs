@@ -0,0 +1,13 @@
+use crate::{
+    command::error::{CommandError, CommandError::NotFound},
+    entity::recipe::Model,
+    recipe_time,
+};
+
+/// Fill the missing times of a recipe with estimates from its steps, see [`recipe_time::fill`].
+#[tauri::command]
+pub async fn recipe_time_fill(recipe_id: i64) -> Result<Model, CommandError> {
+    let model_option = recipe_time::fill(recipe_id).await?;
+    let model = model_option.ok_or(NotFound)?;
+    Ok(model)
+}
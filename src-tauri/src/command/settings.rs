@@ -0,0 +1,15 @@
+use crate::{command::error::CommandError, settings, settings::Settings};
+
+/// Get the settings of the application.
+#[tauri::command]
+pub async fn settings_get() -> Result<Settings, CommandError> {
+    let settings = settings::get().await?;
+    Ok(settings)
+}
+
+/// Set and store all settings of the application.
+#[tauri::command]
+pub async fn settings_set(settings: Settings) -> Result<(), CommandError> {
+    settings::set(settings).await?;
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use crate::{
+    command::error::{CommandError, CommandError::NotFound},
+    entity_crud::{recipe_step::RecipeStepCrud, EntityCrudTrait},
+    step_duration,
+    step_duration::StepDuration,
+};
+
+/// Get the durations of a recipe step, see [`step_duration::from_recipe_step`].
+#[tauri::command]
+pub async fn step_duration_list(recipe_step_id: i64) -> Result<Vec<StepDuration>, CommandError> {
+    let model_option = RecipeStepCrud::read(recipe_step_id).await?;
+    let model = model_option.ok_or(NotFound)?;
+    Ok(step_duration::from_recipe_step(&model))
+}
+
+/// Recognize the durations in a text, e.g. a recipe step description while it is being edited.
+#[tauri::command]
+pub async fn step_duration_parse(text: String) -> Vec<StepDuration> {
+    step_duration::parse(&text)
+}
@@ -0,0 +1,20 @@
+use crate::{command::error::CommandError, timer, timer::Timer};
+
+/// Start a named timer running for a duration in seconds.
+#[tauri::command]
+pub async fn timer_start(name: String, duration: u64) -> Result<Timer, CommandError> {
+    let timer = timer::start(name, duration)?;
+    Ok(timer)
+}
+
+/// Cancel a timer, returning whether it was running.
+#[tauri::command]
+pub async fn timer_cancel(id: String) -> bool {
+    timer::cancel(&id)
+}
+
+/// List all running timers, the one running out first first.
+#[tauri::command]
+pub async fn timer_list() -> Vec<Timer> {
+    timer::list()
+}
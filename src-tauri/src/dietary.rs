@@ -0,0 +1,339 @@
+//! This module handles the allergens and diets of ingredients and recipes.
+//!
+//! The [allergens](crate::entity::ingredient_allergen) and [diets](Diet) are flagged on ingredients,
+//! those of a recipe are derived from the ingredients of its recipe steps, see [`summary`].
+
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use sea_orm::{
+    sea_query::{Expr, Func, SelectStatement, SimpleExpr},
+    ColumnTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    database,
+    entity::{
+        ingredient, ingredient_allergen, ingredient_allergen::allergen::Allergen, recipe,
+        recipe_step, recipe_step_ingredient,
+    },
+};
+
+/// This enum represents a diet which ingredients and recipes can be compatible with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Diet {
+    Vegetarian,
+    Vegan,
+    GlutenFree,
+    LactoseFree,
+}
+
+impl Diet {
+    pub const ALL: [Diet; 4] = [
+        Diet::Vegetarian,
+        Diet::Vegan,
+        Diet::GlutenFree,
+        Diet::LactoseFree,
+    ];
+
+    /// Get whether an ingredient is compatible with the diet, where [`None`] is unknown.
+    ///
+    /// Vegan ingredients are also vegetarian and lactose-free, unless flagged otherwise.
+    pub fn ingredient_compatibility(self, ingredient: &ingredient::Model) -> Option<bool> {
+        let vegan = ingredient.vegan.filter(|vegan| *vegan);
+        match self {
+            Diet::Vegetarian => ingredient.vegetarian.or(vegan),
+            Diet::Vegan => ingredient.vegan,
+            Diet::GlutenFree => ingredient.gluten_free,
+            Diet::LactoseFree => ingredient.lactose_free.or(vegan),
+        }
+    }
+
+    /// Get an expression which is true for the ingredients known to be compatible with the diet and false otherwise,
+    /// see [`Self::ingredient_compatibility`].
+    pub fn ingredient_compatible(self) -> SimpleExpr {
+        let columns = match self {
+            Diet::Vegetarian => vec![ingredient::Column::Vegetarian, ingredient::Column::Vegan],
+            Diet::Vegan => vec![ingredient::Column::Vegan],
+            Diet::GlutenFree => vec![ingredient::Column::GlutenFree],
+            Diet::LactoseFree => vec![ingredient::Column::LactoseFree, ingredient::Column::Vegan],
+        };
+        Func::coalesce(
+            columns
+                .into_iter()
+                .map(|column| Expr::col(column).into())
+                .chain([Expr::val(false).into()]),
+        )
+        .into()
+    }
+}
+
+/// This struct represents an allergen of a recipe.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeAllergen {
+    pub allergen: Allergen,
+    /// the ingredients containing the allergen
+    pub ingredient_ids: Vec<i64>,
+}
+
+/// This struct represents the compatibility of a recipe with a diet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeDiet {
+    pub diet: Diet,
+    /// whether all ingredients are compatible, where [`None`] is unknown
+    pub compatible: Option<bool>,
+    /// the ingredients which are incompatible or whose compatibility is unknown
+    pub ingredient_ids: Vec<i64>,
+}
+
+/// This struct summarizes the allergens and diets of a recipe.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DietarySummary {
+    pub allergens: Vec<RecipeAllergen>,
+    pub diets: Vec<RecipeDiet>,
+}
+
+/// Summarize the allergens and diets of a recipe, see [`summarize`].
+///
+/// Returns [`None`] when the recipe does not exist.
+///
+/// # Errors
+///
+/// - when there is any problem with the database
+pub async fn summary(recipe_id: i64) -> Result<Option<DietarySummary>> {
+    let db = database::connect().await;
+    if recipe::Entity::find_by_id(recipe_id)
+        .one(db)
+        .await?
+        .is_none()
+    {
+        return Ok(None);
+    }
+    let ingredients = ingredient::Entity::find()
+        .filter(ingredient::Column::Id.in_subquery(recipe_ingredient_ids(recipe_id)))
+        .order_by_asc(ingredient::Column::Id)
+        .all(db)
+        .await?;
+    let ingredient_allergens = ingredient_allergen::Entity::find()
+        .filter(
+            ingredient_allergen::Column::IngredientId.in_subquery(recipe_ingredient_ids(recipe_id)),
+        )
+        .order_by_asc(ingredient_allergen::Column::IngredientId)
+        .all(db)
+        .await?;
+    Ok(Some(summarize(&ingredients, &ingredient_allergens)))
+}
+
+/// Summarize the allergens and diets of the ingredients of a recipe.
+///
+/// A recipe contains the allergens of all its ingredients.
+/// It is compatible with a diet when all its ingredients are, incompatible when any is not, and unknown otherwise.
+/// A recipe without ingredients contains no allergens and is compatible with all diets.
+pub fn summarize(
+    ingredients: &[ingredient::Model],
+    ingredient_allergens: &[ingredient_allergen::Model],
+) -> DietarySummary {
+    let mut allergens: BTreeMap<Allergen, Vec<i64>> = BTreeMap::new();
+    for ingredient_allergen in ingredient_allergens {
+        allergens
+            .entry(ingredient_allergen.allergen)
+            .or_default()
+            .push(ingredient_allergen.ingredient_id);
+    }
+    let diets = Diet::ALL
+        .into_iter()
+        .map(|diet| {
+            let mut compatible = Some(true);
+            let mut ingredient_ids = Vec::new();
+            for ingredient in ingredients {
+                let compatibility = diet.ingredient_compatibility(ingredient);
+                if compatibility != Some(true) {
+                    ingredient_ids.push(ingredient.id);
+                    if compatible != Some(false) {
+                        compatible = compatibility;
+                    }
+                }
+            }
+            RecipeDiet {
+                diet,
+                compatible,
+                ingredient_ids,
+            }
+        })
+        .collect();
+    DietarySummary {
+        allergens: allergens
+            .into_iter()
+            .map(|(allergen, ingredient_ids)| RecipeAllergen {
+                allergen,
+                ingredient_ids,
+            })
+            .collect(),
+        diets,
+    }
+}
+
+/// Select the ids of the ingredients of the recipe steps of a recipe.
+fn recipe_ingredient_ids(recipe_id: i64) -> SelectStatement {
+    recipe_step_ingredient::Entity::find()
+        .select_only()
+        .column(recipe_step_ingredient::Column::IngredientId)
+        .inner_join(recipe_step::Entity)
+        .filter(recipe_step::Column::RecipeId.eq(recipe_id))
+        .into_query()
+}
+
+/// Select the ids of the recipes using any of the given ingredients in their recipe steps.
+fn ingredient_recipe_ids(ingredient_ids: SelectStatement) -> SelectStatement {
+    recipe_step::Entity::find()
+        .select_only()
+        .column(recipe_step::Column::RecipeId)
+        .inner_join(recipe_step_ingredient::Entity)
+        .filter(recipe_step_ingredient::Column::IngredientId.in_subquery(ingredient_ids))
+        .into_query()
+}
+
+/// Select the ids of the recipes containing any of the given allergens.
+pub fn allergen_recipe_ids(allergens: Vec<Allergen>) -> SelectStatement {
+    ingredient_recipe_ids(
+        ingredient_allergen::Entity::find()
+            .select_only()
+            .column(ingredient_allergen::Column::IngredientId)
+            .filter(ingredient_allergen::Column::Allergen.is_in(allergens))
+            .into_query(),
+    )
+}
+
+/// Select the ids of the recipes which are not known to be compatible with the diet,
+/// as they have an ingredient which is incompatible or whose compatibility is unknown.
+pub fn incompatible_recipe_ids(diet: Diet) -> SelectStatement {
+    ingredient_recipe_ids(
+        ingredient::Entity::find()
+            .select_only()
+            .column(ingredient::Column::Id)
+            .filter(Expr::expr(diet.ingredient_compatible()).eq(false))
+            .into_query(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn ingredient(id: i64, diets: [Option<bool>; 4]) -> ingredient::Model {
+        let [vegetarian, vegan, gluten_free, lactose_free] = diets;
+        ingredient::Model {
+            id,
+            name: id.to_string(),
+            ingredient_category_id: None,
+            vegetarian,
+            vegan,
+            gluten_free,
+            lactose_free,
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    fn ingredient_allergen(ingredient_id: i64, allergen: Allergen) -> ingredient_allergen::Model {
+        ingredient_allergen::Model {
+            id: 0,
+            ingredient_id,
+            allergen,
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_ingredient_compatibility() {
+        let tofu = ingredient(1, [None, Some(true), Some(true), None]);
+        let lactose_free_milk = ingredient(2, [Some(true), Some(false), Some(true), Some(true)]);
+        for (diet, expected) in [
+            (Diet::Vegetarian, [Some(true), Some(true)]),
+            (Diet::Vegan, [Some(true), Some(false)]),
+            (Diet::GlutenFree, [Some(true), Some(true)]),
+            (Diet::LactoseFree, [Some(true), Some(true)]),
+        ] {
+            assert_eq!(
+                [
+                    diet.ingredient_compatibility(&tofu),
+                    diet.ingredient_compatibility(&lactose_free_milk)
+                ],
+                expected,
+                "{diet:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_summarize() {
+        let ingredients = [
+            ingredient(1, [Some(true), Some(true), Some(true), Some(true)]),
+            ingredient(2, [Some(true), Some(false), None, Some(false)]),
+            ingredient(3, [Some(true), None, Some(false), None]),
+        ];
+        let ingredient_allergens = [
+            ingredient_allergen(2, Allergen::Milk),
+            ingredient_allergen(3, Allergen::Gluten),
+            ingredient_allergen(2, Allergen::Eggs),
+            ingredient_allergen(3, Allergen::Eggs),
+        ];
+        assert_eq!(
+            summarize(&ingredients, &ingredient_allergens),
+            DietarySummary {
+                allergens: vec![
+                    RecipeAllergen {
+                        allergen: Allergen::Gluten,
+                        ingredient_ids: vec![3],
+                    },
+                    RecipeAllergen {
+                        allergen: Allergen::Eggs,
+                        ingredient_ids: vec![2, 3],
+                    },
+                    RecipeAllergen {
+                        allergen: Allergen::Milk,
+                        ingredient_ids: vec![2],
+                    },
+                ],
+                diets: vec![
+                    RecipeDiet {
+                        diet: Diet::Vegetarian,
+                        compatible: Some(true),
+                        ingredient_ids: vec![],
+                    },
+                    RecipeDiet {
+                        diet: Diet::Vegan,
+                        compatible: Some(false),
+                        ingredient_ids: vec![2, 3],
+                    },
+                    RecipeDiet {
+                        diet: Diet::GlutenFree,
+                        compatible: Some(false),
+                        ingredient_ids: vec![2, 3],
+                    },
+                    RecipeDiet {
+                        diet: Diet::LactoseFree,
+                        compatible: Some(false),
+                        ingredient_ids: vec![2, 3],
+                    },
+                ],
+            }
+        );
+        assert_eq!(
+            summarize(&[ingredient(1, [None, None, Some(true), Some(true)])], &[]).diets[0],
+            RecipeDiet {
+                diet: Diet::Vegetarian,
+                compatible: None,
+                ingredient_ids: vec![1],
+            }
+        );
+    }
+}
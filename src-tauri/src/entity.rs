@@ -1,12 +1,57 @@
 //! This module contains the [`sea_orm`] entities.
 
+use sea_orm::{ActiveValue, DbErr};
+
+use crate::timestamp::unix_timestamp;
+
+pub mod change_log;
+pub mod collection;
+pub mod collection_recipe;
+pub mod cook_log;
+pub mod cook_log_file;
 pub mod file;
 pub mod ingredient;
+pub mod ingredient_alias;
+pub mod ingredient_allergen;
+pub mod ingredient_category;
+pub mod journal_action;
+pub mod nutrient_profile;
+pub mod ocr_result;
 pub mod recipe;
 pub mod recipe_file;
 pub mod recipe_ingredient_draft;
+pub mod recipe_revision;
 pub mod recipe_step;
 pub mod recipe_step_file;
 pub mod recipe_step_ingredient;
 pub mod recipe_step_ingredient_draft;
+pub mod recipe_tag;
+pub mod setting;
+pub mod tag;
 pub mod unit_name;
+
+/// Maintain the `created_at` and `updated_at` timestamps of an active model, see [`sea_orm::ActiveModelBehavior::before_save`].
+///
+/// Inserted models are timestamped with the current time.
+/// Updated models get the current time as `updated_at` unless it is set explicitly,
+/// which the journal and recipe revisions do to restore an earlier state exactly.
+///
+/// The timestamps are deserialized with a default, as models serialized before they existed are still in the journal and in recipe revisions.
+///
+/// # Errors
+///
+/// - when the current time can't be determined
+pub fn touch_timestamps(
+    created_at: &mut ActiveValue<i64>,
+    updated_at: &mut ActiveValue<i64>,
+    insert: bool,
+) -> Result<(), DbErr> {
+    let now = unix_timestamp().map_err(|err| DbErr::Custom(err.to_string()))?;
+    if insert {
+        *created_at = ActiveValue::Set(now);
+        *updated_at = ActiveValue::Set(now);
+    } else if !matches!(updated_at, ActiveValue::Set(_)) {
+        *updated_at = ActiveValue::Set(now);
+    }
+    Ok(())
+}
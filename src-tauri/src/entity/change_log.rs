@@ -0,0 +1,50 @@
+//! This module implements the change log entity.
+//!
+//! See [`Model`] for more information.
+
+use sea_orm::entity::prelude::*;
+use serde::Serialize;
+
+use crate::entity::change_log::action::Action;
+
+pub mod action;
+
+/// This struct represents a logged change of an entity.
+///
+/// The sequence number increases monotonically, so a window can catch up on all changes after the last one it has seen.
+/// The old and new models and the parent keys are stored as they are sent to the frontend.
+/// Changes of user actions belong to a [journal action](super::journal_action::Model), changes by undo, redo and purging do not.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[sea_orm(table_name = "change_log")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub seq: i64,
+    pub entity: String,
+    pub action: Action,
+    pub entity_id: Json,
+    pub old: Option<Json>,
+    pub new: Option<Json>,
+    pub parents: Json,
+    pub journal_action_id: Option<i64>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::journal_action::Entity",
+        from = "Column::JournalActionId",
+        to = "super::journal_action::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    JournalAction,
+}
+
+impl Related<super::journal_action::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::JournalAction.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
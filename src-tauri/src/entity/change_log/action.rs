@@ -0,0 +1,16 @@
+//! This module implements the [`Action`] enum for usage in the [change log entity](super::Model).
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// This enum represents what happened to an entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(Some(1))")]
+pub enum Action {
+    #[sea_orm(string_value = "Created")]
+    Created,
+    #[sea_orm(string_value = "Updated")]
+    Updated,
+    #[sea_orm(string_value = "Deleted")]
+    Deleted,
+}
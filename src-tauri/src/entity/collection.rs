@@ -0,0 +1,47 @@
+//! This module implements the collection entity.
+//!
+//! See [`Model`] for more information.
+
+use async_trait::async_trait;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// This struct represents a collection of [recipes](super::recipe::Model), like _Christmas baking_ or _weeknight dinners_.
+///
+/// A collection holds an ordered list of recipes, see [`super::collection_recipe::Model`].
+/// A recipe may be in many collections.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[sea_orm(table_name = "collection")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub name: String,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub updated_at: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::collection_recipe::Entity")]
+    CollectionRecipe,
+}
+
+impl Related<super::collection_recipe::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CollectionRecipe.def()
+    }
+}
+
+#[async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        super::touch_timestamps(&mut self.created_at, &mut self.updated_at, insert)?;
+        Ok(self)
+    }
+}
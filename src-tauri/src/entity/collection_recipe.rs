@@ -0,0 +1,68 @@
+//! This module implements the collection recipe entity.
+//!
+//! See [`Model`] for more information.
+
+use async_trait::async_trait;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// This struct represents the membership of a [recipe](super::recipe::Model) in a [collection](super::collection::Model).
+///
+/// The recipes of a collection are ordered, a recipe can be in a collection only once.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[sea_orm(table_name = "collection_recipe")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub order: i64,
+    pub collection_id: i64,
+    pub recipe_id: i64,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub updated_at: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::collection::Entity",
+        from = "Column::CollectionId",
+        to = "super::collection::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Collection,
+    #[sea_orm(
+        belongs_to = "super::recipe::Entity",
+        from = "Column::RecipeId",
+        to = "super::recipe::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Recipe,
+}
+
+impl Related<super::collection::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Collection.def()
+    }
+}
+
+impl Related<super::recipe::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Recipe.def()
+    }
+}
+
+#[async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        super::touch_timestamps(&mut self.created_at, &mut self.updated_at, insert)?;
+        Ok(self)
+    }
+}
@@ -0,0 +1,66 @@
+//! This module implements the cook log entity.
+//!
+//! See [`Model`] for more information.
+
+use async_trait::async_trait;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// This struct represents a cook log.
+///
+/// A cook log records that a [recipe](super::recipe::Model) was cooked at a point in time, which is stored as seconds since the Unix epoch.
+/// It may record the number of servings cooked, a rating from 1 to 5 and notes.
+/// Photos are linked via [cook log files](super::cook_log_file::Model).
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[sea_orm(table_name = "cook_log")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub recipe_id: i64,
+    pub cooked_at: i64,
+    pub servings: Option<i64>,
+    pub rating: Option<i64>,
+    pub notes: Option<String>,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub updated_at: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::recipe::Entity",
+        from = "Column::RecipeId",
+        to = "super::recipe::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Recipe,
+    #[sea_orm(has_many = "super::cook_log_file::Entity")]
+    CookLogFile,
+}
+
+impl Related<super::recipe::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Recipe.def()
+    }
+}
+
+impl Related<super::cook_log_file::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CookLogFile.def()
+    }
+}
+
+#[async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        super::touch_timestamps(&mut self.created_at, &mut self.updated_at, insert)?;
+        Ok(self)
+    }
+}
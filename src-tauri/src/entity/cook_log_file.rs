@@ -0,0 +1,68 @@
+//! This module implements the cook log file entity.
+//!
+//! See [`Model`] for more information.
+
+use async_trait::async_trait;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// This struct represents a cook log file.
+///
+/// A cook log file is a photo or other binary file of a [cook log](super::cook_log::Model), e.g. of the cooked dish.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[sea_orm(table_name = "cook_log_file")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub order: i64,
+    pub cook_log_id: i64,
+    pub file_id: i64,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub updated_at: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::cook_log::Entity",
+        from = "Column::CookLogId",
+        to = "super::cook_log::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    CookLog,
+    #[sea_orm(
+        belongs_to = "super::file::Entity",
+        from = "Column::FileId",
+        to = "super::file::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Restrict"
+    )]
+    File,
+}
+
+impl Related<super::cook_log::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CookLog.def()
+    }
+}
+
+impl Related<super::file::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::File.def()
+    }
+}
+
+#[async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        super::touch_timestamps(&mut self.created_at, &mut self.updated_at, insert)?;
+        Ok(self)
+    }
+}
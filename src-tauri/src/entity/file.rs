@@ -2,15 +2,17 @@
 //!
 //! See [`Model`] for more information.
 
+use std::{collections::HashSet, path::PathBuf};
+
 use async_trait::async_trait;
-use log;
-use sea_orm::{entity::prelude::*, IntoActiveModel, TryIntoModel};
-use serde::Serialize;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// This struct represents a file.
 ///
 /// A file is a binary file.
-#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize)]
+/// Deleting a file entity keeps the stored file, as undo may restore the entity, see [`stored_paths`].
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[sea_orm(table_name = "file")]
 pub struct Model {
@@ -19,6 +21,10 @@ pub struct Model {
     pub name: String,
     pub mime: String,
     pub path: String,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub updated_at: i64,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -27,6 +33,10 @@ pub enum Relation {
     RecipeStepFile,
     #[sea_orm(has_many = "super::recipe_file::Entity")]
     RecipeFile,
+    #[sea_orm(has_many = "super::ocr_result::Entity")]
+    OcrResult,
+    #[sea_orm(has_many = "super::cook_log_file::Entity")]
+    CookLogFile,
 }
 
 impl Related<super::recipe_step_file::Entity> for Entity {
@@ -41,35 +51,60 @@ impl Related<super::recipe_file::Entity> for Entity {
     }
 }
 
+impl Related<super::ocr_result::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::OcrResult.def()
+    }
+}
+
+impl Related<super::cook_log_file::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CookLogFile.def()
+    }
+}
+
 #[async_trait]
 impl ActiveModelBehavior for ActiveModel {
-    async fn after_delete<C>(self, _db: &C) -> Result<Self, DbErr>
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
     where
         C: ConnectionTrait,
     {
-        let model = self.try_into_model()?;
-        if let Err(err) = crate::file_storage::delete(&model.path).await {
-            log::warn!(
-                "Could not delete file from storage after deleting entity: {}",
-                err
-            );
-        };
-        Ok(model.into_active_model())
+        super::touch_timestamps(&mut self.created_at, &mut self.updated_at, insert)?;
+        Ok(self)
     }
 }
 
-/// Remove orphaned file entities.
+/// Remove orphaned file entities which were created before a unix timestamp.
 ///
-/// An entity is orphaned if no other entity references it.
-pub async fn remove_orphans<C>(db: &C) -> Result<(), DbErr>
+/// An entity is orphaned if no other entity references it, and undo or redo can't restore such a reference.
+/// Newer entities are kept, as they are usually about to be referenced.
+pub async fn remove_orphans<C>(created_before: i64, db: &C) -> Result<(), DbErr>
 where
     C: ConnectionTrait,
 {
+    let mut journal_file_ids = HashSet::new();
+    for change in journal_changes(db).await? {
+        let key = if change.entity == "file" {
+            "id"
+        } else {
+            "fileId"
+        };
+        journal_file_ids.extend(
+            [change.old, change.new]
+                .iter()
+                .flatten()
+                .filter_map(|model| model[key].as_i64()),
+        );
+    }
     let orphaned_files = Entity::find()
         .left_join(super::recipe_step_file::Entity)
         .filter(super::recipe_step_file::Column::Id.is_null())
         .left_join(super::recipe_file::Entity)
         .filter(super::recipe_file::Column::Id.is_null())
+        .left_join(super::cook_log_file::Entity)
+        .filter(super::cook_log_file::Column::Id.is_null())
+        .filter(Column::CreatedAt.lt(created_before))
+        .filter(Column::Id.is_not_in(journal_file_ids))
         .all(db)
         .await?;
     for orphaned_file in orphaned_files {
@@ -78,12 +113,55 @@ where
     Ok(())
 }
 
+/// Get the paths of all stored files which file entities reference, or which undo or redo can restore a file entity for.
+///
+/// All other stored files can be deleted.
+pub async fn stored_paths<C>(db: &C) -> Result<HashSet<PathBuf>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let mut paths: HashSet<PathBuf> = Entity::find()
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|model| PathBuf::from(model.path))
+        .collect();
+    for change in journal_changes(db).await? {
+        if change.entity == "file" {
+            paths.extend(
+                [change.old, change.new]
+                    .iter()
+                    .flatten()
+                    .filter_map(|model| model["path"].as_str().map(PathBuf::from)),
+            );
+        }
+    }
+    Ok(paths)
+}
+
+/// Get the changes of file entities and their references which belong to a journal action, so undo or redo can apply them.
+async fn journal_changes<C>(db: &C) -> Result<Vec<super::change_log::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    super::change_log::Entity::find()
+        .filter(super::change_log::Column::JournalActionId.is_not_null())
+        .filter(super::change_log::Column::Entity.is_in([
+            "file",
+            "recipe_step_file",
+            "recipe_file",
+            "cook_log_file",
+        ]))
+        .all(db)
+        .await
+}
+
 #[cfg(test)]
 mod tests {
     use std::future::Future;
 
     use mime_guess::mime;
-    use sea_orm::{ActiveValue, Iterable};
+    use sea_orm::{ActiveValue, IntoActiveModel, Iterable};
     use tokio::fs;
 
     use super::*;
@@ -94,12 +172,12 @@ mod tests {
     };
 
     #[tokio::test]
-    async fn test_after_delete() {
-        TEST_NAME.set(Some("entity__file__test_after_delete".to_string()));
+    async fn test_stored_paths() {
+        TEST_NAME.set(Some("entity__file__test_stored_paths".to_string()));
         crate::tests::run();
 
         let db = get_memory_database_migrated().await;
-        let temp_path = create_temp_file("entity__file__test_after_delete.bin", "");
+        let temp_path = create_temp_file("entity__file__test_stored_paths.bin", "");
         let mime = mime::APPLICATION_OCTET_STREAM.to_string();
         let path = file_storage::create(&temp_path.to_string_lossy(), &mime)
             .await
@@ -111,9 +189,10 @@ mod tests {
             ..Default::default()
         };
         let model = active_model.insert(&db).await.unwrap();
-        assert!(path.exists());
+        assert!(stored_paths(&db).await.unwrap().contains(&path));
         model.delete(&db).await.unwrap();
-        assert!(!path.exists());
+        assert!(path.exists());
+        assert!(!stored_paths(&db).await.unwrap().contains(&path));
 
         TEST_NAME.set(None);
     }
@@ -126,7 +205,10 @@ mod tests {
         let db = get_memory_database_migrated().await;
         for relation in Relation::iter() {
             match relation {
-                Relation::RecipeStepFile | Relation::RecipeFile => {
+                Relation::RecipeStepFile
+                | Relation::RecipeFile
+                | Relation::OcrResult
+                | Relation::CookLogFile => {
                     // known relation, add other known relations here if they are tested below or are irrelevant for orphan removal
                 }
             }
@@ -227,6 +309,46 @@ mod tests {
             recipe_file_am.update(db).await.unwrap()
         }
 
+        async fn cook_log_file_create(
+            file_id: i64,
+            db: &DatabaseConnection,
+        ) -> super::super::cook_log_file::Model {
+            let recipe = super::super::recipe::ActiveModel {
+                name: ActiveValue::Set("Recipe".to_string()),
+                ..Default::default()
+            }
+            .insert(db)
+            .await
+            .unwrap();
+            let cook_log = super::super::cook_log::ActiveModel {
+                recipe_id: ActiveValue::Set(recipe.id),
+                cooked_at: ActiveValue::Set(0),
+                ..Default::default()
+            }
+            .insert(db)
+            .await
+            .unwrap();
+            super::super::cook_log_file::ActiveModel {
+                order: ActiveValue::Set(1),
+                cook_log_id: ActiveValue::Set(cook_log.id),
+                file_id: ActiveValue::Set(file_id),
+                ..Default::default()
+            }
+            .insert(db)
+            .await
+            .unwrap()
+        }
+
+        async fn cook_log_file_update(
+            cook_log_file: super::super::cook_log_file::Model,
+            file_id: i64,
+            db: &DatabaseConnection,
+        ) -> super::super::cook_log_file::Model {
+            let mut cook_log_file_am = cook_log_file.into_active_model();
+            cook_log_file_am.file_id = ActiveValue::Set(file_id);
+            cook_log_file_am.update(db).await.unwrap()
+        }
+
         async fn test_related_entity_orphan_removal<
             'db,
             RelatedModel,
@@ -252,7 +374,7 @@ mod tests {
         {
             let file_a = create_file(db).await;
             let related_model = related_model_create(file_a.id, db).await;
-            remove_orphans(db).await.unwrap();
+            remove_orphans(i64::MAX, db).await.unwrap();
             assert!(
                 Entity::find_by_id(file_a.id)
                     .one(db)
@@ -263,7 +385,7 @@ mod tests {
             assert!(fs::try_exists(&file_a.path).await.unwrap());
             let file_b = create_file(db).await;
             let related_model = related_model_update(related_model, file_b.id, db).await;
-            remove_orphans(db).await.unwrap();
+            remove_orphans(i64::MAX, db).await.unwrap();
             assert!(
                 Entity::find_by_id(file_b.id)
                     .one(db)
@@ -279,8 +401,14 @@ mod tests {
                     .unwrap()
                     .is_none()
             );
-            assert!(!fs::try_exists(&file_a.path).await.unwrap());
+            assert!(
+                !stored_paths(db)
+                    .await
+                    .unwrap()
+                    .contains(&PathBuf::from(&file_a.path))
+            );
             related_model.delete(db).await.unwrap();
+            remove_orphans(i64::MAX, db).await.unwrap();
             assert!(
                 Entity::find_by_id(file_b.id)
                     .one(db)
@@ -288,12 +416,18 @@ mod tests {
                     .unwrap()
                     .is_none()
             );
-            assert!(!fs::try_exists(&file_b.path).await.unwrap());
+            assert!(
+                !stored_paths(db)
+                    .await
+                    .unwrap()
+                    .contains(&PathBuf::from(&file_b.path))
+            );
         }
 
         test_related_entity_orphan_removal(recipe_step_file_create, recipe_step_file_update, &db)
             .await;
         test_related_entity_orphan_removal(recipe_file_create, recipe_file_update, &db).await;
+        test_related_entity_orphan_removal(cook_log_file_create, cook_log_file_update, &db).await;
 
         TEST_NAME.set(None);
     }
@@ -2,34 +2,94 @@
 //!
 //! See [`Model`] for more information.
 
+use async_trait::async_trait;
 use sea_orm::entity::prelude::*;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// This struct represents an ingredient.
 ///
 /// _Flour_ is an ingredient. A _pot_ is not an ingredient.
 ///
 /// An ingredient is used up in a recipe.
-/// An ingredient is identifiable by its name.
-#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize)]
+/// An ingredient is identifiable by its name or one of its [aliases](super::ingredient_alias::Model).
+/// An ingredient can belong to a [category](super::ingredient_category::Model), which groups it by supermarket aisle.
+/// An ingredient can contain [allergens](super::ingredient_allergen::Model) and be compatible with [diets](crate::dietary::Diet),
+/// where an unknown compatibility is [`None`].
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[sea_orm(table_name = "ingredient")]
 pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i64,
     pub name: String,
+    pub ingredient_category_id: Option<i64>,
+    pub vegetarian: Option<bool>,
+    pub vegan: Option<bool>,
+    pub gluten_free: Option<bool>,
+    pub lactose_free: Option<bool>,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub updated_at: i64,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::ingredient_alias::Entity")]
+    IngredientAlias,
+    #[sea_orm(has_many = "super::ingredient_allergen::Entity")]
+    IngredientAllergen,
+    #[sea_orm(
+        belongs_to = "super::ingredient_category::Entity",
+        from = "Column::IngredientCategoryId",
+        to = "super::ingredient_category::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    IngredientCategory,
+    #[sea_orm(has_one = "super::nutrient_profile::Entity")]
+    NutrientProfile,
     #[sea_orm(has_many = "super::recipe_step_ingredient::Entity")]
     RecipeStepIngredient,
 }
 
+impl Related<super::ingredient_alias::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::IngredientAlias.def()
+    }
+}
+
+impl Related<super::ingredient_allergen::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::IngredientAllergen.def()
+    }
+}
+
+impl Related<super::ingredient_category::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::IngredientCategory.def()
+    }
+}
+
+impl Related<super::nutrient_profile::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::NutrientProfile.def()
+    }
+}
+
 impl Related<super::recipe_step_ingredient::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::RecipeStepIngredient.def()
     }
 }
 
-impl ActiveModelBehavior for ActiveModel {}
+#[async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        super::touch_timestamps(&mut self.created_at, &mut self.updated_at, insert)?;
+        Ok(self)
+    }
+}
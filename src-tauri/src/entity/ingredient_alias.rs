@@ -0,0 +1,60 @@
+//! This module implements the ingredient alias entity.
+//!
+//! See [`Model`] for more information.
+
+pub mod kind;
+
+use async_trait::async_trait;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::entity::ingredient_alias::kind::Kind;
+
+/// This struct represents another name of an [`super::ingredient::Model`].
+///
+/// _Eggs_ is the plural of the ingredient _egg_, _Ei_ is its singular in German.
+/// The language is an optional language tag, e.g. `de`.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[sea_orm(table_name = "ingredient_alias")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub name: String,
+    pub language: Option<String>,
+    pub kind: Kind,
+    pub ingredient_id: i64,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub updated_at: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::ingredient::Entity",
+        from = "Column::IngredientId",
+        to = "super::ingredient::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Ingredient,
+}
+
+impl Related<super::ingredient::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Ingredient.def()
+    }
+}
+
+#[async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        super::touch_timestamps(&mut self.created_at, &mut self.updated_at, insert)?;
+        Ok(self)
+    }
+}
@@ -0,0 +1,25 @@
+//! This module implements the [`Kind`] enum for usage in the [ingredient alias entity](super::Model).
+
+use sea_orm::{entity::prelude::*, ActiveValue, IntoActiveValue};
+use serde::{Deserialize, Serialize};
+
+/// This enum represents how an alias names its ingredient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(Some(1))")]
+pub enum Kind {
+    /// the name of a single piece of the ingredient
+    #[sea_orm(string_value = "Singular")]
+    Singular,
+    /// the name of multiple pieces of the ingredient
+    #[sea_orm(string_value = "Plural")]
+    Plural,
+    /// any other name of the ingredient, e.g. a synonym
+    #[sea_orm(string_value = "Alias")]
+    Alias,
+}
+
+impl IntoActiveValue<Kind> for Kind {
+    fn into_active_value(self) -> ActiveValue<Kind> {
+        ActiveValue::Set(self)
+    }
+}
@@ -0,0 +1,57 @@
+//! This module implements the ingredient allergen entity.
+//!
+//! See [`Model`] for more information.
+
+pub mod allergen;
+
+use async_trait::async_trait;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::entity::ingredient_allergen::allergen::Allergen;
+
+/// This struct represents an allergen contained in an [`super::ingredient::Model`].
+///
+/// Each allergen is flagged at most once per ingredient, an ingredient without flags is assumed to contain no allergens.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[sea_orm(table_name = "ingredient_allergen")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub ingredient_id: i64,
+    pub allergen: Allergen,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub updated_at: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::ingredient::Entity",
+        from = "Column::IngredientId",
+        to = "super::ingredient::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Ingredient,
+}
+
+impl Related<super::ingredient::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Ingredient.def()
+    }
+}
+
+#[async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        super::touch_timestamps(&mut self.created_at, &mut self.updated_at, insert)?;
+        Ok(self)
+    }
+}
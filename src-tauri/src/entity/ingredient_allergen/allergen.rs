@@ -0,0 +1,61 @@
+//! This module implements the [`Allergen`] enum for usage in the [ingredient allergen entity](super::Model).
+
+use sea_orm::{entity::prelude::*, ActiveValue, IntoActiveValue};
+use serde::{Deserialize, Serialize};
+
+/// This enum represents the 14 allergens which must be declared in the EU, see Annex II of Regulation (EU) No 1169/2011.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    EnumIter,
+    DeriveActiveEnum,
+    Serialize,
+    Deserialize,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(Some(1))")]
+pub enum Allergen {
+    /// cereals containing gluten, e.g. wheat, rye, barley and oats
+    #[sea_orm(string_value = "Gluten")]
+    Gluten,
+    #[sea_orm(string_value = "Crustaceans")]
+    Crustaceans,
+    #[sea_orm(string_value = "Eggs")]
+    Eggs,
+    #[sea_orm(string_value = "Fish")]
+    Fish,
+    #[sea_orm(string_value = "Peanuts")]
+    Peanuts,
+    #[sea_orm(string_value = "Soybeans")]
+    Soybeans,
+    /// milk including lactose
+    #[sea_orm(string_value = "Milk")]
+    Milk,
+    /// tree nuts, e.g. almonds, hazelnuts and walnuts
+    #[sea_orm(string_value = "Nuts")]
+    Nuts,
+    #[sea_orm(string_value = "Celery")]
+    Celery,
+    #[sea_orm(string_value = "Mustard")]
+    Mustard,
+    #[sea_orm(string_value = "Sesame")]
+    Sesame,
+    /// sulphur dioxide and sulphites
+    #[sea_orm(string_value = "Sulphites")]
+    Sulphites,
+    #[sea_orm(string_value = "Lupin")]
+    Lupin,
+    #[sea_orm(string_value = "Molluscs")]
+    Molluscs,
+}
+
+impl IntoActiveValue<Allergen> for Allergen {
+    fn into_active_value(self) -> ActiveValue<Allergen> {
+        ActiveValue::Set(self)
+    }
+}
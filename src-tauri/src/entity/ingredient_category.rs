@@ -0,0 +1,47 @@
+//! This module implements the ingredient category entity.
+//!
+//! See [`Model`] for more information.
+
+use async_trait::async_trait;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// This struct represents a category of [ingredients](super::ingredient::Model), like _produce_, _dairy_ or _spices_.
+///
+/// The categories are sorted by their user-defined order, which is the order of the aisles when walking through the supermarket.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[sea_orm(table_name = "ingredient_category")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub name: String,
+    pub order: i64,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub updated_at: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::ingredient::Entity")]
+    Ingredient,
+}
+
+impl Related<super::ingredient::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Ingredient.def()
+    }
+}
+
+#[async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        super::touch_timestamps(&mut self.created_at, &mut self.updated_at, insert)?;
+        Ok(self)
+    }
+}
@@ -0,0 +1,33 @@
+//! This module implements the journal action entity.
+//!
+//! See [`Model`] for more information.
+
+use sea_orm::entity::prelude::*;
+use serde::Serialize;
+
+/// This struct represents a user action in the undo/redo journal.
+///
+/// A journal action groups all [changes](super::change_log::Model) of one entity mutation, including cascaded ones.
+/// Undone actions form the redo stack, which is cleared by the next new action.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[sea_orm(table_name = "journal_action")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub undone: bool,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::change_log::Entity")]
+    ChangeLog,
+}
+
+impl Related<super::change_log::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ChangeLog.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,62 @@
+//! This module implements the nutrient profile entity.
+//!
+//! See [`Model`] for more information.
+
+use async_trait::async_trait;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// This struct represents the nutrients of an [`super::ingredient::Model`].
+///
+/// Each ingredient has at most one nutrient profile, which is usually imported from a food composition database, see [`crate::nutrition`].
+/// The nutrients are given per 100 g, the energy in kcal and all others in g.
+/// The density in g/ml and the mass of a piece in g allow converting volumes and unitless quantities to grams.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[sea_orm(table_name = "nutrient_profile")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub ingredient_id: i64,
+    pub kcal: Option<f64>,
+    pub protein: Option<f64>,
+    pub fat: Option<f64>,
+    pub carbohydrate: Option<f64>,
+    pub fibre: Option<f64>,
+    pub salt: Option<f64>,
+    pub density: Option<f64>,
+    pub piece_mass: Option<f64>,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub updated_at: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::ingredient::Entity",
+        from = "Column::IngredientId",
+        to = "super::ingredient::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Ingredient,
+}
+
+impl Related<super::ingredient::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Ingredient.def()
+    }
+}
+
+#[async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        super::touch_timestamps(&mut self.created_at, &mut self.updated_at, insert)?;
+        Ok(self)
+    }
+}
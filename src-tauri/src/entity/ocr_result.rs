@@ -0,0 +1,41 @@
+//! This module implements the OCR result entity.
+//!
+//! See [`Model`] for more information.
+
+use sea_orm::entity::prelude::*;
+use serde::Serialize;
+
+/// This struct represents an OCR result.
+///
+/// An OCR result caches the hOCR of a file for a language and the serialized [`crate::ocr::OcrSettings`].
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[sea_orm(table_name = "ocr_result")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub file_id: i64,
+    pub language: String,
+    pub settings: String,
+    pub hocr: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::file::Entity",
+        from = "Column::FileId",
+        to = "super::file::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    File,
+}
+
+impl Related<super::file::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::File.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -2,26 +2,66 @@
 //!
 //! See [`Model`] for more information.
 
+use async_trait::async_trait;
 use sea_orm::entity::prelude::*;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// This struct represents a recipe.
 ///
 /// A recipe is a series of instructions.
 /// A recipe is identifiable by its name.
-#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize)]
+/// A recipe with a deletion timestamp is in the trash, it is hidden but can be restored until it is purged.
+/// A recipe may be forked from another recipe, see [`crate::entity_crud::recipe::RecipeCrud::duplicate`].
+/// A recipe's times are in seconds, split into active preparation, passive cooking and resting, see [`crate::recipe_time`].
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[sea_orm(table_name = "recipe")]
 pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i64,
     pub name: String,
+    pub deleted_at: Option<i64>,
+    pub forked_from_id: Option<i64>,
+    pub prep_time: Option<i64>,
+    pub cook_time: Option<i64>,
+    pub rest_time: Option<i64>,
+    pub total_time: Option<i64>,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub updated_at: i64,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(
+        belongs_to = "Entity",
+        from = "Column::ForkedFromId",
+        to = "Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    ForkedFrom,
+    #[sea_orm(has_many = "super::collection_recipe::Entity")]
+    CollectionRecipe,
+    #[sea_orm(has_many = "super::cook_log::Entity")]
+    CookLog,
     #[sea_orm(has_many = "super::recipe_step::Entity")]
     RecipeStep,
+    #[sea_orm(has_many = "super::recipe_tag::Entity")]
+    RecipeTag,
+}
+
+impl Related<super::collection_recipe::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CollectionRecipe.def()
+    }
+}
+
+impl Related<super::cook_log::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CookLog.def()
+    }
 }
 
 impl Related<super::recipe_step::Entity> for Entity {
@@ -30,4 +70,19 @@ impl Related<super::recipe_step::Entity> for Entity {
     }
 }
 
-impl ActiveModelBehavior for ActiveModel {}
+impl Related<super::recipe_tag::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RecipeTag.def()
+    }
+}
+
+#[async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        super::touch_timestamps(&mut self.created_at, &mut self.updated_at, insert)?;
+        Ok(self)
+    }
+}
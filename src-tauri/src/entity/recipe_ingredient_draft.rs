@@ -2,14 +2,15 @@
 //!
 //! See [`Model`] for more information.
 
+use async_trait::async_trait;
 use sea_orm::entity::prelude::*;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// This struct represents a recipe ingredient draft.
 ///
 /// Just like [`super::recipe_step_ingredient::Model`] it is not yet split up into quantity, unit, and does not include a reference to an [`super::ingredient::Model`].
 /// But in contrast to [`super::recipe_step_ingredient::Model`] this entity is related to the recipe directly because a [`super::recipe_step::Model`] could not be determined.
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize)]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[sea_orm(table_name = "recipe_ingredient_draft")]
 pub struct Model {
@@ -18,6 +19,10 @@ pub struct Model {
     pub order: i64,
     pub text: String,
     pub recipe_id: i64,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub updated_at: i64,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -38,4 +43,13 @@ impl Related<super::recipe::Entity> for Entity {
     }
 }
 
-impl ActiveModelBehavior for ActiveModel {}
+#[async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        super::touch_timestamps(&mut self.created_at, &mut self.updated_at, insert)?;
+        Ok(self)
+    }
+}
@@ -0,0 +1,41 @@
+//! This module implements the recipe revision entity.
+//!
+//! See [`Model`] for more information.
+
+use sea_orm::entity::prelude::*;
+use serde::Serialize;
+
+/// This struct represents a saved revision of a recipe.
+///
+/// The snapshot contains the full recipe aggregate, see [`crate::recipe_revision::RecipeSnapshot`].
+/// The creation time is stored as seconds since the Unix epoch.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[sea_orm(table_name = "recipe_revision")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub recipe_id: i64,
+    pub created_at: i64,
+    pub snapshot: Json,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::recipe::Entity",
+        from = "Column::RecipeId",
+        to = "super::recipe::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Recipe,
+}
+
+impl Related<super::recipe::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Recipe.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -2,13 +2,14 @@
 //!
 //! See [`Model`] for more information.
 
+use async_trait::async_trait;
 use sea_orm::entity::prelude::*;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// This struct represents a recipe step.
 ///
 /// A recipe step is a part of a [`super::recipe::Model`].
-#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[sea_orm(table_name = "recipe_step")]
 pub struct Model {
@@ -16,7 +17,13 @@ pub struct Model {
     pub id: i64,
     pub order: i64,
     pub description: String,
+    /// the explicit duration in seconds, taking precedence over durations parsed from the description
+    pub duration: Option<i64>,
     pub recipe_id: i64,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub updated_at: i64,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -53,4 +60,13 @@ impl Related<super::recipe_step_file::Entity> for Entity {
     }
 }
 
-impl ActiveModelBehavior for ActiveModel {}
+#[async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        super::touch_timestamps(&mut self.created_at, &mut self.updated_at, insert)?;
+        Ok(self)
+    }
+}
@@ -4,12 +4,12 @@
 
 use async_trait::async_trait;
 use sea_orm::entity::prelude::*;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// This struct represents a recipe step file.
 ///
 /// A recipe step file is a supplementary binary file to a recipe step.
-#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[sea_orm(table_name = "recipe_step_file")]
 pub struct Model {
@@ -18,6 +18,10 @@ pub struct Model {
     pub order: i64,
     pub recipe_step_id: i64,
     pub file_id: i64,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub updated_at: i64,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -54,11 +58,11 @@ impl Related<super::file::Entity> for Entity {
 
 #[async_trait]
 impl ActiveModelBehavior for ActiveModel {
-    async fn after_delete<C>(self, db: &C) -> Result<Self, DbErr>
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
     where
         C: ConnectionTrait,
     {
-        super::file::remove_orphans(db).await?;
+        super::touch_timestamps(&mut self.created_at, &mut self.updated_at, insert)?;
         Ok(self)
     }
 }
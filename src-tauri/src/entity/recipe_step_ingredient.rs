@@ -2,13 +2,14 @@
 //!
 //! See [`Model`] for more information.
 
+use async_trait::async_trait;
 use sea_orm::entity::prelude::*;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// This struct represents a recipe step ingredient.
 ///
 /// A recipe step ingredient describes the quantity and quality of an [`super::ingredient::Model`] to be used in a [`super::recipe_step::Model`].
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize)]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[sea_orm(table_name = "recipe_step_ingredient")]
 pub struct Model {
@@ -20,6 +21,10 @@ pub struct Model {
     pub quality: Option<String>,
     pub recipe_step_id: i64,
     pub ingredient_id: i64,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub updated_at: i64,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -54,4 +59,13 @@ impl Related<super::recipe_step::Entity> for Entity {
     }
 }
 
-impl ActiveModelBehavior for ActiveModel {}
+#[async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        super::touch_timestamps(&mut self.created_at, &mut self.updated_at, insert)?;
+        Ok(self)
+    }
+}
@@ -0,0 +1,21 @@
+//! This module implements the setting entity.
+//!
+//! See [`Model`] for more information.
+
+use sea_orm::entity::prelude::*;
+
+/// This struct represents a setting of the application.
+///
+/// The value is stored as it is serialized by [`crate::settings::Settings`], settings which are not stored have their default value.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "setting")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub key: String,
+    pub value: Json,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -2,21 +2,37 @@
 
 use std::fmt::Debug;
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use async_trait::async_trait;
 use sea_orm::{
-    sea_query, sea_query::IntoCondition, ActiveModelBehavior, ActiveModelTrait, ColumnTrait,
-    EntityTrait, FromQueryResult, IntoActiveModel, ModelTrait, PrimaryKeyToColumn, PrimaryKeyTrait,
-    QueryFilter, QuerySelect, RelationTrait, Select, TransactionTrait, TryFromU64, TryGetable,
-    TryGetableMany,
+    sea_query,
+    sea_query::{Expr, Func, IntoCondition, Query, SimpleExpr},
+    ActiveModelBehavior, ActiveModelTrait, ActiveValue, ColumnTrait, Condition, DatabaseConnection,
+    DatabaseTransaction, EntityName, EntityTrait, FromQueryResult, IntoActiveModel, IntoSimpleExpr,
+    Iterable, JsonValue, ModelTrait, PrimaryKeyToColumn, PrimaryKeyTrait, QueryFilter, QueryOrder,
+    QuerySelect, RelationTrait, Select, TransactionTrait, TryFromU64, TryGetable, TryGetableMany,
+    Value,
 };
 use sea_query::{FromValueTuple, IntoValueTuple};
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
-use crate::{database, window::get_window};
+use crate::{
+    database,
+    entity::{change_log, change_log::action::Action},
+    journal,
+    window::get_window,
+};
 
+pub mod collection;
+pub mod collection_recipe;
+pub mod cook_log;
+pub mod cook_log_file;
 pub mod file;
 pub mod ingredient;
+pub mod ingredient_alias;
+pub mod ingredient_allergen;
+pub mod ingredient_category;
+pub mod nutrient_profile;
 pub mod recipe;
 pub mod recipe_file;
 pub mod recipe_ingredient_draft;
@@ -24,6 +40,8 @@ pub mod recipe_step;
 pub mod recipe_step_file;
 pub mod recipe_step_ingredient;
 pub mod recipe_step_ingredient_draft;
+pub mod recipe_tag;
+pub mod tag;
 pub mod unit_name;
 
 /// A trait to transform from any type into an [active model](ActiveModelTrait).
@@ -73,6 +91,19 @@ impl Default for Order {
     }
 }
 
+impl Order {
+    /// Coalesce a nullable numeric expression with a value which comes last in this order.
+    ///
+    /// So null comes last in both orders instead of first in ascending order, and it can be compared by the keyset cursor.
+    pub fn nulls_last(&self, expr: SimpleExpr) -> SimpleExpr {
+        let last = match self {
+            Order::Asc => f64::MAX,
+            Order::Desc => f64::MIN,
+        };
+        Func::coalesce([expr, Expr::val(last).into()]).into()
+    }
+}
+
 impl From<Order> for sea_query::Order {
     fn from(value: Order) -> Self {
         match value {
@@ -82,22 +113,66 @@ impl From<Order> for sea_query::Order {
     }
 }
 
-/// This struct combines conditional filtering and ordering when listing entities.
+/// This struct combines conditional filtering, ordering and pagination when listing entities.
+///
+/// Pages can be selected by `offset` or by the keyset cursor `after`, which is the id of the last entity of the previous page.
+/// The keyset cursor is stable when entities are created or deleted before it, but listing fails when the cursor entity itself is deleted.
 ///
 /// This struct is used in [`EntityCrudTrait`].
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct Filter<Condition, OrderBy> {
+pub struct Filter<Condition, OrderBy, Id = i64> {
     pub condition: Option<Condition>,
     pub order_by: Option<Vec<OrderBy>>,
+    pub limit: Option<u64>,
+    pub offset: Option<u64>,
+    pub after: Option<Id>,
+}
+
+/// This struct represents a page of a listed entity.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListPage<Id> {
+    /// the ids on this page
+    pub ids: Vec<Id>,
+    /// the number of all entities matching the condition, regardless of pagination
+    pub total: i64,
+}
+
+/// This struct represents the conditions on the creation and update timestamps, which every entity's condition includes.
+///
+/// The bounds are unix timestamps, `*_since` is inclusive and `*_before` is exclusive.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimestampCondition {
+    pub created_since: Option<i64>,
+    pub created_before: Option<i64>,
+    pub updated_since: Option<i64>,
+    pub updated_before: Option<i64>,
 }
 
-/// Implementors of this trait should add their order-by statements in the [`Self::add`] function.
+impl TimestampCondition {
+    /// Get the condition on the given timestamp columns.
+    pub fn into_condition<C>(self, created_at: C, updated_at: C) -> Condition
+    where
+        C: ColumnTrait,
+    {
+        Condition::all()
+            .add_option(self.created_since.map(|since| created_at.gte(since)))
+            .add_option(self.created_before.map(|before| created_at.lt(before)))
+            .add_option(self.updated_since.map(|since| updated_at.gte(since)))
+            .add_option(self.updated_before.map(|before| updated_at.lt(before)))
+    }
+}
+
+/// Implementors of this trait should return their order-by expression in the [`Self::order`] function.
 pub trait OrderBy {
     type Entity: EntityTrait;
 
-    /// Add an ordering to the select statement.
-    fn add(self, select: Select<Self::Entity>) -> Select<Self::Entity>;
+    /// Get the expression to order by and its order.
+    ///
+    /// The expression is also evaluated for the keyset cursor, so it must only depend on the entity's row.
+    fn order(self) -> (SimpleExpr, Order);
 }
 
 /// This trait implements create, read, update, delete, list, and count operation for an entity.
@@ -119,8 +194,12 @@ pub trait EntityCrudTrait {
 
     /// the entity's model, implementing [`ModelTrait`]
     type Model: ModelTrait<Entity = Self::Entity>
+        + PartialEq
         + FromQueryResult
-        + IntoActiveModel<Self::ActiveModel>;
+        + IntoActiveModel<Self::ActiveModel>
+        + Sync
+        + Serialize
+        + DeserializeOwned;
 
     /// the entity's active model, implementing [`ActiveModelTrait`]
     type ActiveModel: ActiveModelTrait<Entity = Self::Entity> + ActiveModelBehavior + Send;
@@ -146,7 +225,8 @@ pub trait EntityCrudTrait {
         + TryFromU64
         + TryGetable
         + Serialize
-        + Clone;
+        + Clone
+        + Into<Value>;
 
     /// the struct with which to create an entity, implementing [`TryIntoActiveModel<Self::ActiveModel>`]
     type EntityCreate: TryIntoActiveModel<Self::ActiveModel> + Send;
@@ -165,16 +245,19 @@ pub trait EntityCrudTrait {
     /// # Errors
     ///
     /// - when there is any problem with the database
+    /// - when the created entity can't be serialized for the change log
     /// - when the tauri window can't be messaged about the created entity
     async fn create(
         create: Self::EntityCreate,
     ) -> Result<<Self::PrimaryKey as PrimaryKeyTrait>::ValueType> {
         let db = database::connect_writing().await;
         let txn = db.begin().await?;
+        let journal_action_id = journal::record_action(&txn).await?;
         let active_model = create.try_into_active_model().await?;
-        let model = active_model.insert(&txn).await?;
+        let (model, change) =
+            Self::create_logged(active_model, Some(journal_action_id), &txn).await?;
         txn.commit().await?;
-        get_window().emit(Self::entity_action_created_channel(), ())?;
+        get_window().emit(Self::entity_action_created_channel(), change)?;
         Ok(Self::primary_key_value(&model))
     }
 
@@ -196,57 +279,444 @@ pub trait EntityCrudTrait {
     /// # Errors
     ///
     /// - when there is any problem with the database
+    /// - when the updated entity can't be serialized for the change log
     /// - when the tauri window can't be messaged about the updated entity
     async fn update(update: Self::EntityUpdate) -> Result<Self::Model> {
         let db = database::connect_writing().await;
         let txn = db.begin().await?;
-        let model = update.try_into_active_model().await?.update(&txn).await?;
+        let journal_action_id = journal::record_action(&txn).await?;
+        let active_model = update.try_into_active_model().await?;
+        let (model, change) =
+            Self::update_logged(active_model, Some(journal_action_id), &txn).await?;
         txn.commit().await?;
-        get_window().emit(
-            Self::entity_action_updated_channel(),
-            Self::primary_key_value(&model),
-        )?;
+        get_window().emit(Self::entity_action_updated_channel(), change)?;
         Ok(model)
     }
 
     /// Delete an entity.
     ///
+    /// The children which the database would delete in cascade are deleted and emitted first.
+    ///
     /// # Errors
     ///
     /// - when there is any problem with the database
-    /// - when the tauri window can't be messaged about the deleted entity
+    /// - when a deleted entity can't be serialized for the change log
+    /// - when the tauri window can't be messaged about the deleted entities
     /// - when there is an error in [`Self::pre_delete`]
     async fn delete(id: <Self::PrimaryKey as PrimaryKeyTrait>::ValueType) -> Result<()> {
         let db = database::connect_writing().await;
         let txn = db.begin().await?;
-        let model_option = Self::Entity::find_by_id(id.clone()).one(&txn).await?;
-        let Some(model) = model_option else {
+        let journal_action_id = journal::record_action(&txn).await?;
+        let mut changes = Self::delete_logged(id, Some(journal_action_id), &txn).await?;
+        let Some(change) = changes.pop() else {
             return Ok(());
         };
-        model.delete(&txn).await?;
         txn.commit().await?;
-        get_window().emit(Self::entity_action_deleted_channel(), id)?;
+        journal::emit(&changes)?;
+        get_window().emit(Self::entity_action_deleted_channel(), change)?;
+        Ok(())
+    }
+
+    /// Create multiple entities atomically.
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database, no entity is created then
+    /// - when a created entity can't be serialized for the change log
+    /// - when the tauri window can't be messaged about the created entities
+    async fn create_many(
+        creates: Vec<Self::EntityCreate>,
+    ) -> Result<Vec<<Self::PrimaryKey as PrimaryKeyTrait>::ValueType>> {
+        if creates.is_empty() {
+            return Ok(Vec::new());
+        }
+        let db = database::connect_writing().await;
+        let txn = db.begin().await?;
+        let journal_action_id = journal::record_action(&txn).await?;
+        let mut ids = Vec::with_capacity(creates.len());
+        let mut changes = Vec::with_capacity(creates.len());
+        for create in creates {
+            let active_model = create.try_into_active_model().await?;
+            let (model, change) =
+                Self::create_logged(active_model, Some(journal_action_id), &txn).await?;
+            changes.push(change);
+            ids.push(Self::primary_key_value(&model));
+        }
+        txn.commit().await?;
+        get_window().emit(Self::entity_action_created_many_channel(), changes)?;
+        Ok(ids)
+    }
+
+    /// Update multiple entities atomically.
+    ///
+    /// Orders can be swapped between the entities, see [`Self::update_many_logged`].
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database, no entity is updated then
+    /// - when an updated entity can't be serialized for the change log
+    /// - when the tauri window can't be messaged about the updated entities
+    async fn update_many(updates: Vec<Self::EntityUpdate>) -> Result<Vec<Self::Model>> {
+        if updates.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut active_models = Vec::with_capacity(updates.len());
+        for update in updates {
+            active_models.push(update.try_into_active_model().await?);
+        }
+        let db = database::connect_writing().await;
+        let txn = db.begin().await?;
+        let journal_action_id = journal::record_action(&txn).await?;
+        let (models, changes) =
+            Self::update_many_logged(active_models, Some(journal_action_id), &txn).await?;
+        txn.commit().await?;
+        get_window().emit(Self::entity_action_updated_many_channel(), changes)?;
+        Ok(models)
+    }
+
+    /// Delete multiple entities atomically.
+    ///
+    /// Missing entities are skipped and not part of the emitted changes.
+    /// The children which the database would delete in cascade are deleted and emitted first.
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database, no entity is deleted then
+    /// - when a deleted entity can't be serialized for the change log
+    /// - when the tauri window can't be messaged about the deleted entities
+    /// - when there is an error in [`Self::pre_delete`]
+    async fn delete_many(ids: Vec<<Self::PrimaryKey as PrimaryKeyTrait>::ValueType>) -> Result<()> {
+        let db = database::connect_writing().await;
+        let txn = db.begin().await?;
+        let journal_action_id = journal::record_action(&txn).await?;
+        let mut cascaded_changes = Vec::new();
+        let mut changes = Vec::with_capacity(ids.len());
+        for id in ids {
+            let mut entity_changes = Self::delete_logged(id, Some(journal_action_id), &txn).await?;
+            if let Some(change) = entity_changes.pop() {
+                changes.push(change);
+            }
+            cascaded_changes.append(&mut entity_changes);
+        }
+        if changes.is_empty() {
+            return Ok(());
+        }
+        txn.commit().await?;
+        journal::emit(&cascaded_changes)?;
+        get_window().emit(Self::entity_action_deleted_many_channel(), changes)?;
+        Ok(())
+    }
+
+    /// Create an entity inside a transaction and log the change.
+    ///
+    /// Changes without a journal action can't be undone.
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database
+    /// - when the entity can't be serialized for the change log
+    async fn create_logged(
+        active_model: Self::ActiveModel,
+        journal_action_id: Option<i64>,
+        txn: &DatabaseTransaction,
+    ) -> Result<(Self::Model, change_log::Model)> {
+        let model = active_model.insert(txn).await?;
+        let change = Self::change(Action::Created, None, Some(&model), journal_action_id)?
+            .insert(txn)
+            .await?;
+        Ok((model, change))
+    }
+
+    /// Update an entity inside a transaction and log the change.
+    ///
+    /// Changes without a journal action can't be undone.
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database
+    /// - when the entity can't be serialized for the change log
+    async fn update_logged(
+        active_model: Self::ActiveModel,
+        journal_action_id: Option<i64>,
+        txn: &DatabaseTransaction,
+    ) -> Result<(Self::Model, change_log::Model)> {
+        let old = match active_model.get_primary_key_value() {
+            Some(id) => {
+                Self::Entity::find_by_id(Self::PrimaryKeyValue::from_value_tuple(id))
+                    .one(txn)
+                    .await?
+            }
+            None => None,
+        };
+        let model = active_model.update(txn).await?;
+        let change = Self::change(
+            Action::Updated,
+            old.as_ref(),
+            Some(&model),
+            journal_action_id,
+        )?
+        .insert(txn)
+        .await?;
+        Ok((model, change))
+    }
+
+    /// Update multiple entities inside a transaction and log the changes.
+    ///
+    /// As the [`Self::unique_order_column`] is unique within a parent,
+    /// entities whose order is set are updated to a temporary negative order first and to the final one afterwards.
+    /// Both steps are logged, so undoing them one by one never violates the uniqueness of the order.
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database
+    /// - when an updated entity can't be serialized for the change log
+    async fn update_many_logged(
+        active_models: Vec<Self::ActiveModel>,
+        journal_action_id: Option<i64>,
+        txn: &DatabaseTransaction,
+    ) -> Result<(Vec<Self::Model>, Vec<change_log::Model>)> {
+        let mut temporary_active_models = Vec::new();
+        if let Some(order_column) = Self::unique_order_column() {
+            for active_model in &active_models {
+                let ActiveValue::Set(Value::BigInt(Some(order))) = active_model.get(order_column)
+                else {
+                    continue;
+                };
+                let mut temporary_active_model = active_model.clone();
+                temporary_active_model.set(order_column, Value::from(-order));
+                temporary_active_models.push(temporary_active_model);
+            }
+        }
+        let mut changes = Vec::new();
+        for active_model in temporary_active_models {
+            let (_, change) = Self::update_logged(active_model, journal_action_id, txn).await?;
+            changes.push(change);
+        }
+        let mut models = Vec::with_capacity(active_models.len());
+        for active_model in active_models {
+            let (model, change) = Self::update_logged(active_model, journal_action_id, txn).await?;
+            models.push(model);
+            changes.push(change);
+        }
+        Ok((models, changes))
+    }
+
+    /// Delete an entity and its cascaded children inside a transaction and log the changes.
+    ///
+    /// The entity's change comes last, the result is empty if the entity does not exist.
+    /// Changes without a journal action can't be undone.
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database
+    /// - when an entity can't be serialized for the change log
+    /// - when there is an error in [`Self::pre_delete`]
+    async fn delete_logged(
+        id: <Self::PrimaryKey as PrimaryKeyTrait>::ValueType,
+        journal_action_id: Option<i64>,
+        txn: &DatabaseTransaction,
+    ) -> Result<Vec<change_log::Model>> {
+        let Some(model) = Self::Entity::find_by_id(id).one(txn).await? else {
+            return Ok(Vec::new());
+        };
+        Self::delete_model_logged(model, journal_action_id, txn).await
+    }
+
+    /// Delete all entities matching the condition and their cascaded children inside a transaction and log the changes.
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database
+    /// - when an entity can't be serialized for the change log
+    /// - when there is an error in [`Self::pre_delete`]
+    async fn delete_all_logged(
+        condition: Condition,
+        journal_action_id: Option<i64>,
+        txn: &DatabaseTransaction,
+    ) -> Result<Vec<change_log::Model>> {
+        let models = Self::Entity::find().filter(condition).all(txn).await?;
+        let mut changes = Vec::with_capacity(models.len());
+        for model in models {
+            changes.append(&mut Self::delete_model_logged(model, journal_action_id, txn).await?);
+        }
+        Ok(changes)
+    }
+
+    /// Delete an entity model and its cascaded children inside a transaction and log the changes.
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database
+    /// - when an entity can't be serialized for the change log
+    /// - when there is an error in [`Self::pre_delete`]
+    async fn delete_model_logged(
+        model: Self::Model,
+        journal_action_id: Option<i64>,
+        txn: &DatabaseTransaction,
+    ) -> Result<Vec<change_log::Model>> {
+        let mut changes = Self::delete_children(&model, journal_action_id, txn).await?;
+        let change = Self::change(Action::Deleted, Some(&model), None, journal_action_id)?;
+        model.delete(txn).await?;
+        changes.push(change.insert(txn).await?);
+        Ok(changes)
+    }
+
+    /// Delete the children which the database would delete in cascade with the entity, and log the changes.
+    ///
+    /// Deleting them explicitly makes them part of the change log and the journal, so they can be restored.
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database
+    /// - when an entity can't be serialized for the change log
+    async fn delete_children(
+        _model: &Self::Model,
+        _journal_action_id: Option<i64>,
+        _txn: &DatabaseTransaction,
+    ) -> Result<Vec<change_log::Model>> {
+        Ok(Vec::new())
+    }
+
+    /// Apply a logged change to the database, turning the entity from its `from` state into its `to` state, and log that as a new change.
+    ///
+    /// The states are serialized models, [`None`] meaning the entity does not exist.
+    /// This is used to undo and redo changes, whose new changes do not belong to a journal action, and to revert recipe revisions.
+    /// The entity is loaded first, so a change is never applied over a state it was not made from, e.g. after the entity was changed again.
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database
+    /// - when the entity is not in the `from` state
+    /// - when a state can't be deserialized or both states are [`None`]
+    async fn apply(
+        from: Option<JsonValue>,
+        to: Option<JsonValue>,
+        journal_action_id: Option<i64>,
+        txn: &DatabaseTransaction,
+    ) -> Result<change_log::Model> {
+        let from = from
+            .map(serde_json::from_value::<Self::Model>)
+            .transpose()?;
+        let to = to.map(serde_json::from_value::<Self::Model>).transpose()?;
+        let Some(model) = from.as_ref().or(to.as_ref()) else {
+            bail!("A change needs an old or a new model");
+        };
+        let current = Self::Entity::find_by_id(Self::primary_key_value(model))
+            .one(txn)
+            .await?;
+        if current != from {
+            bail!("The entity is not in the state the change was made from");
+        }
+        let action = match (&from, &to) {
+            (None, Some(to_model)) => {
+                Self::Entity::insert(Self::active_model_set(to_model.clone()))
+                    .exec_without_returning(txn)
+                    .await?;
+                Action::Created
+            }
+            (Some(_), Some(to_model)) => {
+                Self::active_model_set(to_model.clone()).update(txn).await?;
+                Action::Updated
+            }
+            (Some(from_model), None) => {
+                from_model.clone().delete(txn).await?;
+                Action::Deleted
+            }
+            (None, None) => unreachable!("a change has an old or a new model"),
+        };
+        let change = Self::change(action, from.as_ref(), to.as_ref(), journal_action_id)?
+            .insert(txn)
+            .await?;
+        Ok(change)
+    }
+
+    /// Convert a model into an active model with all columns set.
+    fn active_model_set(model: Self::Model) -> Self::ActiveModel {
+        let mut active_model = model.into_active_model();
+        for column in Self::Column::iter() {
+            if let Some(value) = active_model.get(column).into_value() {
+                active_model.set(column, value);
+            }
+        }
+        active_model
+    }
+
+    /// Emit a logged change on the tauri event channel of its action.
+    ///
+    /// # Errors
+    ///
+    /// - when the tauri window can't be messaged about the change
+    fn emit_change(change: &change_log::Model) -> Result<()> {
+        let channel = match change.action {
+            Action::Created => Self::entity_action_created_channel(),
+            Action::Updated => Self::entity_action_updated_channel(),
+            Action::Deleted => Self::entity_action_deleted_channel(),
+        };
+        get_window().emit(channel, change)?;
         Ok(())
     }
 
+    /// Build the change log entry of an entity change.
+    ///
+    /// The primary key and parent keys are taken from the new model, or the old one for deletions.
+    ///
+    /// # Errors
+    ///
+    /// - when neither an old nor a new model is given
+    /// - when a model can't be serialized
+    fn change(
+        action: Action,
+        old: Option<&Self::Model>,
+        new: Option<&Self::Model>,
+        journal_action_id: Option<i64>,
+    ) -> Result<change_log::ActiveModel> {
+        let Some(model) = new.or(old) else {
+            bail!("A change needs an old or a new model");
+        };
+        let JsonValue::Object(fields) = serde_json::to_value(model)? else {
+            bail!("A model must be serialized to an object");
+        };
+        let parents = Self::parent_keys()
+            .iter()
+            .filter_map(|key| Some((key.to_string(), fields.get(*key)?.clone())))
+            .collect();
+        Ok(change_log::ActiveModel {
+            seq: ActiveValue::NotSet,
+            entity: ActiveValue::Set(Self::Entity::default().table_name().to_owned()),
+            action: ActiveValue::Set(action),
+            entity_id: ActiveValue::Set(serde_json::to_value(Self::primary_key_value(model))?),
+            old: ActiveValue::Set(old.map(serde_json::to_value).transpose()?),
+            new: ActiveValue::Set(new.map(serde_json::to_value).transpose()?),
+            parents: ActiveValue::Set(JsonValue::Object(parents)),
+            journal_action_id: ActiveValue::Set(journal_action_id),
+        })
+    }
+
     /// List entities.
     ///
     /// # Errors
     ///
+    /// - when the keyset cursor entity does not exist, see [`Self::check_cursor`]
     /// - when there is any problem with the database
     async fn list(
-        filter: Filter<Self::EntityCondition, Self::EntityOrderBy>,
+        filter: Filter<Self::EntityCondition, Self::EntityOrderBy, Self::PrimaryKeyValue>,
     ) -> Result<Vec<Self::PrimaryKeyValue>> {
         let db = database::connect().await;
-        let mut select = Self::Entity::find()
-            .select_only()
-            .column_as(Self::primary_key_colum(), "id");
-        if let Some(condition) = filter.condition {
-            select = select.filter(condition);
-        }
-        for order_by in filter.order_by.into_iter().flatten() {
-            select = order_by.add(select);
-        }
+        let Filter {
+            condition,
+            order_by,
+            limit,
+            offset,
+            after,
+        } = filter;
+        Self::check_cursor(after.clone(), db).await?;
+        let select = Self::paginate(
+            Self::select_ids(condition),
+            order_by.unwrap_or_default(),
+            limit,
+            offset,
+            after,
+        );
         let models = select
             .into_model::<IdColumn<Self::PrimaryKeyValue>>()
             .all(db)
@@ -254,6 +724,43 @@ pub trait EntityCrudTrait {
         Ok(models.into_iter().map(|id_column| id_column.id).collect())
     }
 
+    /// List a page of entities together with the total number of entities matching the condition.
+    ///
+    /// # Errors
+    ///
+    /// - when the keyset cursor entity does not exist, see [`Self::check_cursor`]
+    /// - when there is any problem with the database
+    async fn list_page(
+        filter: Filter<Self::EntityCondition, Self::EntityOrderBy, Self::PrimaryKeyValue>,
+    ) -> Result<ListPage<Self::PrimaryKeyValue>> {
+        let db = database::connect().await;
+        let Filter {
+            condition,
+            order_by,
+            limit,
+            offset,
+            after,
+        } = filter;
+        Self::check_cursor(after.clone(), db).await?;
+        let select = Self::select_ids(condition);
+        let total_option = select
+            .clone()
+            .select_only()
+            .column_as(Self::primary_key_colum().count(), "id")
+            .into_model::<IdColumn<i64>>()
+            .one(db)
+            .await?;
+        let total = total_option.map_or(0, |id_column| id_column.id);
+        let models = Self::paginate(select, order_by.unwrap_or_default(), limit, offset, after)
+            .into_model::<IdColumn<Self::PrimaryKeyValue>>()
+            .all(db)
+            .await?;
+        Ok(ListPage {
+            ids: models.into_iter().map(|id_column| id_column.id).collect(),
+            total,
+        })
+    }
+
     /// Count entities.
     ///
     /// # Errors
@@ -261,12 +768,10 @@ pub trait EntityCrudTrait {
     /// - when there is any problem with the database
     async fn count(condition: Option<Self::EntityCondition>) -> Result<i64> {
         let db = database::connect().await;
-        let mut select = Self::Entity::find()
+        let select = Self::Entity::find()
             .select_only()
-            .column_as(Self::primary_key_colum().count(), "id");
-        if let Some(condition) = condition {
-            select = select.filter(condition);
-        }
+            .column_as(Self::primary_key_colum().count(), "id")
+            .filter(Self::condition(condition));
         let count_option = select.into_model::<IdColumn<i64>>().one(db).await?;
         let count = match count_option {
             Some(id_column) => id_column.id,
@@ -275,12 +780,122 @@ pub trait EntityCrudTrait {
         Ok(count)
     }
 
+    /// Get the select statement of the ids of the entities matching the condition.
+    fn select_ids(condition: Option<Self::EntityCondition>) -> Select<Self::Entity> {
+        Self::Entity::find()
+            .select_only()
+            .column_as(Self::primary_key_colum(), "id")
+            .filter(Self::condition(condition))
+    }
+
+    /// Get the condition with which to list and count entities.
+    ///
+    /// Override this when an entity should be filtered even without a given condition.
+    fn condition(condition: Option<Self::EntityCondition>) -> Condition {
+        condition.map_or_else(Condition::all, IntoCondition::into_condition)
+    }
+
+    /// Order and paginate a select statement, see [`Filter`].
+    ///
+    /// The primary key is always the last ordering, so that the order is total and the keyset cursor is unambiguous.
+    fn paginate(
+        select: Select<Self::Entity>,
+        order_by: Vec<Self::EntityOrderBy>,
+        limit: Option<u64>,
+        offset: Option<u64>,
+        after: Option<Self::PrimaryKeyValue>,
+    ) -> Select<Self::Entity> {
+        let mut select = select;
+        let mut orders: Vec<(SimpleExpr, Order)> =
+            order_by.into_iter().map(OrderBy::order).collect();
+        orders.push((Self::primary_key_colum().into_simple_expr(), Order::Asc));
+        if let Some(after) = after {
+            select = select.filter(Self::keyset_condition(&orders, after));
+        }
+        for (expr, order) in orders {
+            select = select.order_by(expr, order.into());
+        }
+        // SQLite does not support an offset without a limit.
+        let limit = limit.or(offset.map(|_| i64::MAX as u64));
+        select.limit(limit).offset(offset)
+    }
+
+    /// Ensure that the keyset cursor entity exists, as there would be no entities after it otherwise.
+    ///
+    /// # Errors
+    ///
+    /// - when the cursor entity does not exist
+    /// - when there is any problem with the database
+    async fn check_cursor(
+        after: Option<Self::PrimaryKeyValue>,
+        db: &DatabaseConnection,
+    ) -> Result<()> {
+        let Some(after) = after else {
+            return Ok(());
+        };
+        if Self::Entity::find_by_id(after.clone())
+            .one(db)
+            .await?
+            .is_none()
+        {
+            bail!("The cursor entity {after:?} does not exist");
+        }
+        Ok(())
+    }
+
+    /// Get the condition matching all entities which come after the cursor entity in the given orders.
+    ///
+    /// The cursor's values are selected via subqueries, so that only its id needs to be known.
+    fn keyset_condition(orders: &[(SimpleExpr, Order)], after: Self::PrimaryKeyValue) -> Condition {
+        let cursor_value = |expr: &SimpleExpr| {
+            SimpleExpr::SubQuery(
+                None,
+                Box::new(
+                    Query::select()
+                        .expr(expr.clone())
+                        .from(Self::Entity::default())
+                        .and_where(Self::primary_key_colum().eq(after.clone()))
+                        .to_owned()
+                        .into_sub_query_statement(),
+                ),
+            )
+        };
+        let mut condition = Condition::any();
+        for (i, (expr, order)) in orders.iter().enumerate() {
+            let mut order_condition = Condition::all();
+            for (previous_expr, _) in &orders[..i] {
+                order_condition = order_condition
+                    .add(Expr::expr(previous_expr.clone()).eq(cursor_value(previous_expr)));
+            }
+            let cursor = cursor_value(expr);
+            let expr = Expr::expr(expr.clone());
+            order_condition = order_condition.add(match order {
+                Order::Asc => expr.gt(cursor),
+                Order::Desc => expr.lt(cursor),
+            });
+            condition = condition.add(order_condition);
+        }
+        condition
+    }
+
     /// Get the primary key value from the entity model.
     fn primary_key_value(model: &Self::Model) -> <Self::PrimaryKey as PrimaryKeyTrait>::ValueType;
 
     /// Get the primary key column.
     fn primary_key_colum() -> Self::Column;
 
+    /// Get the column which orders the entity uniquely within its parent, see [`OrderedEntityCrudTrait`].
+    fn unique_order_column() -> Option<Self::Column> {
+        None
+    }
+
+    /// Get the serialized names of the model fields referencing parent entities.
+    ///
+    /// Their values are sent with every change, so listeners know the parents of a changed entity.
+    fn parent_keys() -> &'static [&'static str] {
+        &[]
+    }
+
     /// Get the tauri event channel for a created entity.
     fn entity_action_created_channel() -> &'static str;
 
@@ -289,4 +904,580 @@ pub trait EntityCrudTrait {
 
     /// Get the tauri event channel for a deleted entity.
     fn entity_action_deleted_channel() -> &'static str;
+
+    /// Get the tauri event channel for multiple created entities.
+    fn entity_action_created_many_channel() -> &'static str;
+
+    /// Get the tauri event channel for multiple updated entities.
+    fn entity_action_updated_many_channel() -> &'static str;
+
+    /// Get the tauri event channel for multiple deleted entities.
+    fn entity_action_deleted_many_channel() -> &'static str;
+}
+
+/// This trait implements moving and reordering for entities which are ordered within a parent entity.
+///
+/// The siblings of a parent are renumbered from 1 without gaps in a single transaction.
+/// As the order is unique within a parent, entities are moved to temporary negative orders first.
+#[async_trait]
+pub trait OrderedEntityCrudTrait: EntityCrudTrait<PrimaryKeyValue = i64> {
+    /// Move an entity to a 1-based position among its siblings.
+    ///
+    /// Positions beyond the last sibling move the entity to the end.
+    /// Returns `false` when the entity does not exist.
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database, nothing is moved then
+    /// - when an updated entity can't be serialized for the change log
+    /// - when the tauri window can't be messaged about the updated entities
+    async fn move_to(id: i64, position: u64) -> Result<bool> {
+        let db = database::connect_writing().await;
+        let txn = db.begin().await?;
+        let Some(model) = Self::Entity::find_by_id(id).one(&txn).await? else {
+            return Ok(false);
+        };
+        let mut siblings = Self::siblings(Self::parent_id(&model), &txn).await?;
+        siblings.retain(|sibling| Self::primary_key_value(sibling) != id);
+        let index = usize::try_from(position.saturating_sub(1))?.min(siblings.len());
+        siblings.insert(index, model);
+        Self::renumber(siblings, txn).await?;
+        Ok(true)
+    }
+
+    /// Set the full ordering of the children of a parent.
+    ///
+    /// # Errors
+    ///
+    /// - when the ids are not exactly the ids of the children
+    /// - when there is any problem with the database, nothing is reordered then
+    /// - when an updated entity can't be serialized for the change log
+    /// - when the tauri window can't be messaged about the updated entities
+    async fn reorder(parent_id: i64, ids: Vec<i64>) -> Result<()> {
+        let db = database::connect_writing().await;
+        let txn = db.begin().await?;
+        let mut siblings = Self::siblings(parent_id, &txn).await?;
+        if siblings.len() != ids.len() {
+            bail!("The ordering must contain every child exactly once");
+        }
+        let mut ordered = Vec::with_capacity(ids.len());
+        for id in ids {
+            let Some(index) = siblings
+                .iter()
+                .position(|sibling| Self::primary_key_value(sibling) == id)
+            else {
+                bail!("The ordering must contain every child exactly once");
+            };
+            ordered.push(siblings.swap_remove(index));
+        }
+        Self::renumber(ordered, txn).await
+    }
+
+    /// Renumber the ordered siblings as a journal action, commit the transaction and emit the changes.
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database
+    /// - when an updated entity can't be serialized for the change log
+    /// - when the tauri window can't be messaged about the updated entities
+    async fn renumber(ordered: Vec<Self::Model>, txn: DatabaseTransaction) -> Result<()> {
+        let journal_action_id = journal::record_action(&txn).await?;
+        let changes = Self::renumber_logged(ordered, Some(journal_action_id), &txn).await?;
+        if changes.is_empty() {
+            return Ok(());
+        }
+        txn.commit().await?;
+        get_window().emit(Self::entity_action_updated_many_channel(), changes)?;
+        Ok(())
+    }
+
+    /// Give the ordered siblings the orders 1 to n inside a transaction and log the changes.
+    ///
+    /// Only entities whose order changes are updated, first to a temporary negative order and then to the final one.
+    /// Both steps are logged, so undoing them one by one never violates the uniqueness of the order.
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database
+    /// - when an updated entity can't be serialized for the change log
+    async fn renumber_logged(
+        ordered: Vec<Self::Model>,
+        journal_action_id: Option<i64>,
+        txn: &DatabaseTransaction,
+    ) -> Result<Vec<change_log::Model>> {
+        let mut moved = Vec::new();
+        let mut changes = Vec::new();
+        for (index, model) in ordered.into_iter().enumerate() {
+            let order = i64::try_from(index)? + 1;
+            if Self::order(&model) == order {
+                continue;
+            }
+            let mut active_model = model.into_active_model();
+            active_model.set(Self::order_column(), Value::from(-order));
+            let (model, change) = Self::update_logged(active_model, journal_action_id, txn).await?;
+            changes.push(change);
+            moved.push((model, order));
+        }
+        for (model, order) in moved {
+            let mut active_model = model.into_active_model();
+            active_model.set(Self::order_column(), Value::from(order));
+            let (_, change) = Self::update_logged(active_model, journal_action_id, txn).await?;
+            changes.push(change);
+        }
+        Ok(changes)
+    }
+
+    /// Get the children of a parent by their current order.
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database
+    async fn siblings(parent_id: i64, txn: &DatabaseTransaction) -> Result<Vec<Self::Model>> {
+        let siblings = Self::Entity::find()
+            .filter(Self::parent_column().eq(parent_id))
+            .order_by_asc(Self::order_column())
+            .order_by_asc(Self::primary_key_colum())
+            .all(txn)
+            .await?;
+        Ok(siblings)
+    }
+
+    /// Get the parent id from the entity model.
+    fn parent_id(model: &Self::Model) -> i64;
+
+    /// Get the order from the entity model.
+    fn order(model: &Self::Model) -> i64;
+
+    /// Get the column referencing the parent entity.
+    fn parent_column() -> Self::Column;
+
+    /// Get the order column.
+    fn order_column() -> Self::Column;
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        entity::{recipe, recipe_step},
+        entity_crud::{
+            recipe::{RecipeCondition, RecipeCrud, RecipeOrderBy},
+            recipe_step::RecipeStepCrud,
+        },
+        migrator::tests::get_memory_database_migrated,
+        timestamp::unix_timestamp,
+    };
+
+    async fn list_ids(
+        db: &DatabaseConnection,
+        order: Order,
+        limit: Option<u64>,
+        offset: Option<u64>,
+        after: Option<i64>,
+    ) -> Vec<i64> {
+        RecipeCrud::paginate(
+            RecipeCrud::select_ids(None),
+            vec![RecipeOrderBy::Name(order)],
+            limit,
+            offset,
+            after,
+        )
+        .into_model::<IdColumn<i64>>()
+        .all(db)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|id_column| id_column.id)
+        .collect()
+    }
+
+    #[tokio::test]
+    async fn test_paginate() {
+        let db = get_memory_database_migrated().await;
+        for name in ["b", "a", "c", "a"] {
+            recipe::ActiveModel {
+                name: ActiveValue::Set(name.to_string()),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        assert_eq!(
+            list_ids(&db, Order::Asc, None, None, None).await,
+            vec![2, 4, 1, 3]
+        );
+        assert_eq!(
+            list_ids(&db, Order::Asc, Some(2), None, None).await,
+            vec![2, 4]
+        );
+        assert_eq!(
+            list_ids(&db, Order::Asc, Some(2), Some(1), None).await,
+            vec![4, 1]
+        );
+        assert_eq!(
+            list_ids(&db, Order::Asc, Some(2), None, Some(4)).await,
+            vec![1, 3]
+        );
+        assert_eq!(
+            list_ids(&db, Order::Asc, None, None, Some(2)).await,
+            vec![4, 1, 3]
+        );
+        assert_eq!(
+            list_ids(&db, Order::Desc, None, None, Some(1)).await,
+            vec![2, 4]
+        );
+        assert_eq!(
+            list_ids(&db, Order::Asc, None, Some(3), None).await,
+            vec![3]
+        );
+        assert_eq!(
+            list_ids(&db, Order::Asc, None, None, Some(5)).await,
+            Vec::<i64>::new()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_check_cursor() {
+        let db = get_memory_database_migrated().await;
+        recipe::ActiveModel {
+            name: ActiveValue::Set(String::from("a")),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        assert!(RecipeCrud::check_cursor(None, &db).await.is_ok());
+        assert!(RecipeCrud::check_cursor(Some(1), &db).await.is_ok());
+        assert!(RecipeCrud::check_cursor(Some(2), &db).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_change() {
+        let db = get_memory_database_migrated().await;
+        for name in ["a", "b"] {
+            recipe::ActiveModel {
+                name: ActiveValue::Set(name.to_string()),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        let old = recipe_step::Model {
+            id: 1,
+            order: 1,
+            description: String::from("stir"),
+            duration: None,
+            recipe_id: 1,
+            created_at: 0,
+            updated_at: 0,
+        };
+        let new = recipe_step::Model {
+            recipe_id: 2,
+            ..old.clone()
+        };
+        for expected_seq in 1..=2 {
+            let change = RecipeStepCrud::change(Action::Updated, Some(&old), Some(&new), None)
+                .unwrap()
+                .insert(&db)
+                .await
+                .unwrap();
+            assert_eq!(
+                change,
+                change_log::Model {
+                    seq: expected_seq,
+                    entity: String::from("recipe_step"),
+                    action: Action::Updated,
+                    entity_id: json!(1),
+                    old: Some(
+                        json!({"id": 1, "order": 1, "description": "stir", "duration": null, "recipeId": 1, "createdAt": 0, "updatedAt": 0})
+                    ),
+                    new: Some(
+                        json!({"id": 1, "order": 1, "description": "stir", "duration": null, "recipeId": 2, "createdAt": 0, "updatedAt": 0})
+                    ),
+                    parents: json!({"recipeId": 2}),
+                    journal_action_id: None,
+                }
+            );
+        }
+        let change = RecipeCrud::change(
+            Action::Deleted,
+            Some(&recipe::Model {
+                id: 1,
+                name: String::from("a"),
+                deleted_at: None,
+                forked_from_id: None,
+                prep_time: None,
+                cook_time: None,
+                rest_time: None,
+                total_time: None,
+                created_at: 0,
+                updated_at: 0,
+            }),
+            None,
+            Some(1),
+        )
+        .unwrap();
+        assert_eq!(change.entity_id, ActiveValue::Set(json!(1)));
+        assert_eq!(change.new, ActiveValue::Set(None));
+        assert_eq!(change.parents, ActiveValue::Set(json!({})));
+        assert!(RecipeCrud::change(Action::Deleted, None, None, None).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_apply() {
+        let db = get_memory_database_migrated().await;
+        let model = recipe::ActiveModel {
+            name: ActiveValue::Set(String::from("a")),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        let renamed = recipe::Model {
+            name: String::from("b"),
+            ..model.clone()
+        };
+        let value = |model: &recipe::Model| Some(serde_json::to_value(model).unwrap());
+
+        let txn = db.begin().await.unwrap();
+        assert!(
+            RecipeCrud::apply(value(&renamed), value(&model), None, &txn)
+                .await
+                .is_err()
+        );
+        RecipeCrud::apply(value(&model), value(&renamed), None, &txn)
+            .await
+            .unwrap();
+        assert!(
+            RecipeCrud::apply(None, value(&renamed), None, &txn)
+                .await
+                .is_err()
+        );
+        assert!(
+            RecipeCrud::apply(value(&model), None, None, &txn)
+                .await
+                .is_err()
+        );
+        RecipeCrud::apply(value(&renamed), None, None, &txn)
+            .await
+            .unwrap();
+        assert!(
+            RecipeCrud::apply(value(&renamed), None, None, &txn)
+                .await
+                .is_err()
+        );
+        RecipeCrud::apply(None, value(&model), None, &txn)
+            .await
+            .unwrap();
+        txn.commit().await.unwrap();
+        assert_eq!(
+            recipe::Entity::find_by_id(model.id).one(&db).await.unwrap(),
+            Some(model)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_timestamps() {
+        let db = get_memory_database_migrated().await;
+        let before = unix_timestamp().unwrap();
+        let inserted = recipe::ActiveModel {
+            name: ActiveValue::Set(String::from("a")),
+            created_at: ActiveValue::Set(1),
+            updated_at: ActiveValue::Set(1),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        assert!(inserted.created_at >= before);
+        assert_eq!(inserted.updated_at, inserted.created_at);
+        // inserting via the entity skips the active model behavior, like the journal does
+        recipe::Entity::insert(recipe::ActiveModel {
+            name: ActiveValue::Set(String::from("b")),
+            created_at: ActiveValue::Set(10),
+            updated_at: ActiveValue::Set(20),
+            ..Default::default()
+        })
+        .exec(&db)
+        .await
+        .unwrap();
+        let mut active_model = recipe::Entity::find_by_id(2)
+            .one(&db)
+            .await
+            .unwrap()
+            .unwrap()
+            .into_active_model();
+        active_model.name = ActiveValue::Set(String::from("c"));
+        let updated = active_model.update(&db).await.unwrap();
+        assert_eq!(updated.created_at, 10);
+        assert!(updated.updated_at >= before);
+        let restored = RecipeCrud::active_model_set(recipe::Model {
+            updated_at: 20,
+            ..updated
+        })
+        .update(&db)
+        .await
+        .unwrap();
+        assert_eq!(restored.updated_at, 20);
+        for (timestamps, expected_ids) in [
+            (
+                TimestampCondition {
+                    created_since: Some(before),
+                    ..Default::default()
+                },
+                vec![1],
+            ),
+            (
+                TimestampCondition {
+                    created_before: Some(before),
+                    ..Default::default()
+                },
+                vec![2],
+            ),
+            (
+                TimestampCondition {
+                    updated_since: Some(20),
+                    updated_before: Some(21),
+                    ..Default::default()
+                },
+                vec![2],
+            ),
+        ] {
+            let condition = RecipeCondition {
+                timestamps,
+                ..Default::default()
+            };
+            let ids: Vec<i64> = RecipeCrud::select_ids(Some(condition))
+                .into_model::<IdColumn<i64>>()
+                .all(&db)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|id_column| id_column.id)
+                .collect();
+            assert_eq!(ids, expected_ids);
+        }
+        for (order_by, expected_ids) in [
+            (RecipeOrderBy::CreatedAt(Order::Desc), vec![1, 2]),
+            (RecipeOrderBy::UpdatedAt(Order::Asc), vec![2, 1]),
+        ] {
+            let ids: Vec<i64> = RecipeCrud::paginate(
+                RecipeCrud::select_ids(None),
+                vec![order_by],
+                None,
+                None,
+                None,
+            )
+            .into_model::<IdColumn<i64>>()
+            .all(&db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|id_column| id_column.id)
+            .collect();
+            assert_eq!(ids, expected_ids);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_renumber_logged() {
+        let db = get_memory_database_migrated().await;
+        recipe::ActiveModel {
+            name: ActiveValue::Set(String::from("a")),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        for order in [1, 2, 4] {
+            recipe_step::ActiveModel {
+                order: ActiveValue::Set(order),
+                description: ActiveValue::Set(order.to_string()),
+                recipe_id: ActiveValue::Set(1),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        let txn = db.begin().await.unwrap();
+        let mut siblings = RecipeStepCrud::siblings(1, &txn).await.unwrap();
+        siblings.rotate_right(1);
+        let changes = RecipeStepCrud::renumber_logged(siblings, None, &txn)
+            .await
+            .unwrap();
+        assert_eq!(changes.len(), 6);
+        let orders: Vec<(i64, i64)> = RecipeStepCrud::siblings(1, &txn)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|model| (model.id, model.order))
+            .collect();
+        assert_eq!(orders, vec![(3, 1), (1, 2), (2, 3)]);
+        let siblings = RecipeStepCrud::siblings(1, &txn).await.unwrap();
+        let changes = RecipeStepCrud::renumber_logged(siblings, None, &txn)
+            .await
+            .unwrap();
+        assert!(changes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_update_many_logged() {
+        let db = get_memory_database_migrated().await;
+        recipe::ActiveModel {
+            name: ActiveValue::Set(String::from("a")),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        for order in [1, 2] {
+            recipe_step::ActiveModel {
+                order: ActiveValue::Set(order),
+                description: ActiveValue::Set(order.to_string()),
+                recipe_id: ActiveValue::Set(1),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        let txn = db.begin().await.unwrap();
+        let (models, changes) = RecipeStepCrud::update_many_logged(
+            vec![
+                recipe_step::ActiveModel {
+                    id: ActiveValue::Unchanged(1),
+                    order: ActiveValue::Set(2),
+                    ..Default::default()
+                },
+                recipe_step::ActiveModel {
+                    id: ActiveValue::Unchanged(2),
+                    order: ActiveValue::Set(1),
+                    description: ActiveValue::Set(String::from("b")),
+                    ..Default::default()
+                },
+            ],
+            None,
+            &txn,
+        )
+        .await
+        .unwrap();
+        assert_eq!(models.len(), 2);
+        assert_eq!(changes.len(), 4);
+        let steps: Vec<(i64, i64, String)> = RecipeStepCrud::siblings(1, &txn)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|model| (model.id, model.order, model.description))
+            .collect();
+        assert_eq!(
+            steps,
+            vec![(2, 1, String::from("b")), (1, 2, String::from("1"))]
+        );
+    }
 }
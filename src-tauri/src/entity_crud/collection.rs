@@ -0,0 +1,286 @@
+//! This module implements [`EntityCrudTrait`] for [`crate::entity::collection`].
+
+use anyhow::Result;
+use async_trait::async_trait;
+use sea_orm::{
+    sea_query::{IntoCondition, SimpleExpr},
+    ActiveValue, ColumnTrait, Condition, ConnectionTrait, DatabaseTransaction,
+    DeriveIntoActiveModel, EntityTrait, IntoActiveModel, IntoSimpleExpr, QueryFilter, QueryOrder,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    database,
+    entity::{
+        change_log,
+        collection::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
+        collection_recipe, recipe,
+    },
+    entity_crud::{
+        collection_recipe::CollectionRecipeCrud,
+        recipe::{read_export as read_recipe_export, RecipeExport},
+        EntityCrudTrait, Filter, Order, OrderBy, TimestampCondition,
+    },
+    event::channel::{
+        ENTITY_ACTION_CREATED_COLLECTION, ENTITY_ACTION_CREATED_MANY_COLLECTION,
+        ENTITY_ACTION_DELETED_COLLECTION, ENTITY_ACTION_DELETED_MANY_COLLECTION,
+        ENTITY_ACTION_UPDATED_COLLECTION, ENTITY_ACTION_UPDATED_MANY_COLLECTION,
+    },
+};
+
+#[derive(Debug, Deserialize, DeriveIntoActiveModel)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionCreate {
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionUpdate {
+    pub id: i64,
+    pub name: Option<String>,
+}
+
+impl IntoActiveModel<ActiveModel> for CollectionUpdate {
+    fn into_active_model(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Unchanged(self.id),
+            name: match self.name {
+                Some(name) => ActiveValue::Set(name),
+                _ => ActiveValue::NotSet,
+            },
+            created_at: ActiveValue::NotSet,
+            updated_at: ActiveValue::NotSet,
+        }
+    }
+}
+
+pub type CollectionFilter = Filter<CollectionCondition, CollectionOrderBy>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionCondition {
+    pub name: Option<String>,
+    #[serde(flatten)]
+    pub timestamps: TimestampCondition,
+}
+
+impl IntoCondition for CollectionCondition {
+    fn into_condition(self) -> Condition {
+        Condition::all()
+            .add(
+                self.timestamps
+                    .into_condition(Column::CreatedAt, Column::UpdatedAt),
+            )
+            .add_option(self.name.map(|name| Column::Name.like(format!("%{name}%"))))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CollectionOrderBy {
+    Name(Order),
+    CreatedAt(Order),
+    UpdatedAt(Order),
+}
+
+impl OrderBy for CollectionOrderBy {
+    type Entity = Entity;
+
+    fn order(self) -> (SimpleExpr, Order) {
+        match self {
+            CollectionOrderBy::Name(order) => (Column::Name.into_simple_expr(), order),
+            CollectionOrderBy::CreatedAt(order) => (Column::CreatedAt.into_simple_expr(), order),
+            CollectionOrderBy::UpdatedAt(order) => (Column::UpdatedAt.into_simple_expr(), order),
+        }
+    }
+}
+
+/// This struct represents an exported collection.
+///
+/// Its recipes are in the order of the collection and in the same format as a single exported recipe, see [`RecipeExport`].
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionExport {
+    pub collection: Model,
+    pub recipes: Vec<RecipeExport>,
+}
+
+pub struct CollectionCrud {}
+
+impl CollectionCrud {
+    /// Export a collection with its recipes.
+    ///
+    /// Recipes in the trash are left out.
+    /// Returns [`None`] when the collection does not exist.
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database
+    pub async fn export(id: i64) -> Result<Option<CollectionExport>> {
+        let db = database::connect().await;
+        read_export(id, db).await
+    }
+}
+
+#[async_trait]
+impl EntityCrudTrait for CollectionCrud {
+    type Entity = Entity;
+    type Model = Model;
+    type ActiveModel = ActiveModel;
+    type Column = Column;
+    type Relation = Relation;
+    type PrimaryKey = PrimaryKey;
+    type PrimaryKeyValue = i64;
+    type EntityCreate = CollectionCreate;
+    type EntityUpdate = CollectionUpdate;
+    type EntityCondition = CollectionCondition;
+    type EntityOrderBy = CollectionOrderBy;
+
+    fn primary_key_value(model: &Model) -> i64 {
+        model.id
+    }
+
+    fn primary_key_colum() -> Column {
+        Column::Id
+    }
+
+    async fn delete_children(
+        model: &Model,
+        journal_action_id: Option<i64>,
+        txn: &DatabaseTransaction,
+    ) -> Result<Vec<change_log::Model>> {
+        CollectionRecipeCrud::delete_all_logged(
+            collection_recipe::Column::CollectionId
+                .eq(model.id)
+                .into_condition(),
+            journal_action_id,
+            txn,
+        )
+        .await
+    }
+
+    fn entity_action_created_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_COLLECTION
+    }
+
+    fn entity_action_updated_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_COLLECTION
+    }
+
+    fn entity_action_deleted_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_COLLECTION
+    }
+
+    fn entity_action_created_many_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_MANY_COLLECTION
+    }
+
+    fn entity_action_updated_many_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_MANY_COLLECTION
+    }
+
+    fn entity_action_deleted_many_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_MANY_COLLECTION
+    }
+}
+
+/// Read a collection and the exports of its recipes not in the trash, in the order of the collection.
+async fn read_export<C>(id: i64, db: &C) -> Result<Option<CollectionExport>>
+where
+    C: ConnectionTrait,
+{
+    let Some(collection) = Entity::find_by_id(id).one(db).await? else {
+        return Ok(None);
+    };
+    let collection_recipes = collection_recipe::Entity::find()
+        .inner_join(recipe::Entity)
+        .filter(collection_recipe::Column::CollectionId.eq(id))
+        .filter(recipe::Column::DeletedAt.is_null())
+        .order_by_asc(collection_recipe::Column::Order)
+        .all(db)
+        .await?;
+    let mut recipes = Vec::with_capacity(collection_recipes.len());
+    for collection_recipe in collection_recipes {
+        if let Some(recipe) = read_recipe_export(collection_recipe.recipe_id, db).await? {
+            recipes.push(recipe);
+        }
+    }
+    Ok(Some(CollectionExport {
+        collection,
+        recipes,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use sea_orm::ActiveModelTrait;
+
+    use super::*;
+    use crate::{
+        entity_crud::{
+            recipe::{RecipeCondition, RecipeCrud},
+            IdColumn,
+        },
+        migrator::tests::get_memory_database_migrated,
+    };
+
+    #[tokio::test]
+    async fn test_read_export() {
+        let db = get_memory_database_migrated().await;
+        for (name, deleted_at) in [("cookies", None), ("stollen", None), ("punch", Some(1))] {
+            recipe::ActiveModel {
+                name: ActiveValue::Set(name.to_string()),
+                deleted_at: ActiveValue::Set(deleted_at),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        let collection = ActiveModel {
+            name: ActiveValue::Set(String::from("Christmas baking")),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        for (order, recipe_id) in [(1, 2), (2, 3), (3, 1)] {
+            collection_recipe::ActiveModel {
+                order: ActiveValue::Set(order),
+                collection_id: ActiveValue::Set(collection.id),
+                recipe_id: ActiveValue::Set(recipe_id),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+
+        let export = read_export(collection.id, &db).await.unwrap().unwrap();
+        assert_eq!(export.collection, collection);
+        assert_eq!(
+            export
+                .recipes
+                .iter()
+                .map(|recipe| recipe.snapshot.recipe.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["stollen", "cookies"]
+        );
+        assert_eq!(read_export(2, &db).await.unwrap(), None);
+
+        let ids: Vec<i64> = RecipeCrud::select_ids(Some(RecipeCondition {
+            collection_id: Some(collection.id),
+            ..Default::default()
+        }))
+        .into_model::<IdColumn<i64>>()
+        .all(&db)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|id_column| id_column.id)
+        .collect();
+        assert_eq!(ids, vec![1, 2]);
+    }
+}
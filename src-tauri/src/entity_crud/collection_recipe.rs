@@ -0,0 +1,179 @@
+//! This module implements [`EntityCrudTrait`] for [`crate::entity::collection_recipe`].
+
+use async_trait::async_trait;
+use sea_orm::{
+    sea_query::{IntoCondition, SimpleExpr},
+    ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel, IntoActiveModel, IntoSimpleExpr,
+};
+use serde::Deserialize;
+
+use crate::{
+    entity::collection_recipe::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
+    entity_crud::{
+        EntityCrudTrait, Filter, Order, OrderBy, OrderedEntityCrudTrait, TimestampCondition,
+    },
+    event::channel::{
+        ENTITY_ACTION_CREATED_COLLECTION_RECIPE, ENTITY_ACTION_CREATED_MANY_COLLECTION_RECIPE,
+        ENTITY_ACTION_DELETED_COLLECTION_RECIPE, ENTITY_ACTION_DELETED_MANY_COLLECTION_RECIPE,
+        ENTITY_ACTION_UPDATED_COLLECTION_RECIPE, ENTITY_ACTION_UPDATED_MANY_COLLECTION_RECIPE,
+    },
+};
+
+#[derive(Debug, Deserialize, DeriveIntoActiveModel)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionRecipeCreate {
+    pub order: i64,
+    pub collection_id: i64,
+    pub recipe_id: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionRecipeUpdate {
+    pub id: i64,
+    pub order: Option<i64>,
+}
+
+impl IntoActiveModel<ActiveModel> for CollectionRecipeUpdate {
+    fn into_active_model(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Unchanged(self.id),
+            order: match self.order {
+                Some(order) => ActiveValue::Set(order),
+                _ => ActiveValue::NotSet,
+            },
+            collection_id: ActiveValue::NotSet,
+            recipe_id: ActiveValue::NotSet,
+            created_at: ActiveValue::NotSet,
+            updated_at: ActiveValue::NotSet,
+        }
+    }
+}
+
+pub type CollectionRecipeFilter = Filter<CollectionRecipeCondition, CollectionRecipeOrderBy>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionRecipeCondition {
+    pub collection_id: Option<i64>,
+    pub recipe_id: Option<i64>,
+    #[serde(flatten)]
+    pub timestamps: TimestampCondition,
+}
+
+impl IntoCondition for CollectionRecipeCondition {
+    fn into_condition(self) -> Condition {
+        Condition::all()
+            .add(
+                self.timestamps
+                    .into_condition(Column::CreatedAt, Column::UpdatedAt),
+            )
+            .add_option(
+                self.collection_id
+                    .map(|collection_id| Column::CollectionId.eq(collection_id)),
+            )
+            .add_option(
+                self.recipe_id
+                    .map(|recipe_id| Column::RecipeId.eq(recipe_id)),
+            )
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CollectionRecipeOrderBy {
+    Order(Order),
+    CreatedAt(Order),
+    UpdatedAt(Order),
+}
+
+impl OrderBy for CollectionRecipeOrderBy {
+    type Entity = Entity;
+
+    fn order(self) -> (SimpleExpr, Order) {
+        match self {
+            CollectionRecipeOrderBy::Order(order) => (Column::Order.into_simple_expr(), order),
+            CollectionRecipeOrderBy::CreatedAt(order) => {
+                (Column::CreatedAt.into_simple_expr(), order)
+            }
+            CollectionRecipeOrderBy::UpdatedAt(order) => {
+                (Column::UpdatedAt.into_simple_expr(), order)
+            }
+        }
+    }
+}
+
+pub struct CollectionRecipeCrud {}
+
+#[async_trait]
+impl EntityCrudTrait for CollectionRecipeCrud {
+    type Entity = Entity;
+    type Model = Model;
+    type ActiveModel = ActiveModel;
+    type Column = Column;
+    type Relation = Relation;
+    type PrimaryKey = PrimaryKey;
+    type PrimaryKeyValue = i64;
+    type EntityCreate = CollectionRecipeCreate;
+    type EntityUpdate = CollectionRecipeUpdate;
+    type EntityCondition = CollectionRecipeCondition;
+    type EntityOrderBy = CollectionRecipeOrderBy;
+
+    fn primary_key_value(model: &Model) -> i64 {
+        model.id
+    }
+
+    fn primary_key_colum() -> Column {
+        Column::Id
+    }
+
+    fn unique_order_column() -> Option<Column> {
+        Some(Column::Order)
+    }
+
+    fn parent_keys() -> &'static [&'static str] {
+        &["collectionId", "recipeId"]
+    }
+
+    fn entity_action_created_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_COLLECTION_RECIPE
+    }
+
+    fn entity_action_updated_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_COLLECTION_RECIPE
+    }
+
+    fn entity_action_deleted_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_COLLECTION_RECIPE
+    }
+
+    fn entity_action_created_many_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_MANY_COLLECTION_RECIPE
+    }
+
+    fn entity_action_updated_many_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_MANY_COLLECTION_RECIPE
+    }
+
+    fn entity_action_deleted_many_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_MANY_COLLECTION_RECIPE
+    }
+}
+
+impl OrderedEntityCrudTrait for CollectionRecipeCrud {
+    fn parent_id(model: &Model) -> i64 {
+        model.collection_id
+    }
+
+    fn order(model: &Model) -> i64 {
+        model.order
+    }
+
+    fn parent_column() -> Column {
+        Column::CollectionId
+    }
+
+    fn order_column() -> Column {
+        Column::Order
+    }
+}
@@ -0,0 +1,189 @@
+//! This module implements [`EntityCrudTrait`] for [`crate::entity::cook_log`].
+
+use anyhow::Result;
+use async_trait::async_trait;
+use sea_orm::{
+    sea_query::{IntoCondition, SimpleExpr},
+    ActiveValue, ColumnTrait, Condition, DatabaseTransaction, DeriveIntoActiveModel,
+    IntoActiveModel, IntoSimpleExpr,
+};
+use serde::Deserialize;
+
+use crate::{
+    entity::{
+        change_log,
+        cook_log::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
+        cook_log_file,
+    },
+    entity_crud::{
+        cook_log_file::CookLogFileCrud, EntityCrudTrait, Filter, Order, OrderBy, TimestampCondition,
+    },
+    event::channel::{
+        ENTITY_ACTION_CREATED_COOK_LOG, ENTITY_ACTION_CREATED_MANY_COOK_LOG,
+        ENTITY_ACTION_DELETED_COOK_LOG, ENTITY_ACTION_DELETED_MANY_COOK_LOG,
+        ENTITY_ACTION_UPDATED_COOK_LOG, ENTITY_ACTION_UPDATED_MANY_COOK_LOG,
+    },
+};
+
+#[derive(Debug, Deserialize, DeriveIntoActiveModel)]
+#[serde(rename_all = "camelCase")]
+pub struct CookLogCreate {
+    pub recipe_id: i64,
+    pub cooked_at: i64,
+    pub servings: Option<i64>,
+    pub rating: Option<i64>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CookLogUpdate {
+    pub id: i64,
+    pub cooked_at: Option<i64>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub servings: Option<Option<i64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub rating: Option<Option<i64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub notes: Option<Option<String>>,
+}
+
+impl IntoActiveModel<ActiveModel> for CookLogUpdate {
+    fn into_active_model(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Unchanged(self.id),
+            recipe_id: ActiveValue::NotSet,
+            cooked_at: match self.cooked_at {
+                Some(cooked_at) => ActiveValue::Set(cooked_at),
+                _ => ActiveValue::NotSet,
+            },
+            servings: match self.servings {
+                Some(servings) => ActiveValue::Set(servings),
+                _ => ActiveValue::NotSet,
+            },
+            rating: match self.rating {
+                Some(rating) => ActiveValue::Set(rating),
+                _ => ActiveValue::NotSet,
+            },
+            notes: match self.notes {
+                Some(notes) => ActiveValue::Set(notes),
+                _ => ActiveValue::NotSet,
+            },
+            created_at: ActiveValue::NotSet,
+            updated_at: ActiveValue::NotSet,
+        }
+    }
+}
+
+pub type CookLogFilter = Filter<CookLogCondition, CookLogOrderBy>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CookLogCondition {
+    pub recipe_id: Option<i64>,
+    #[serde(flatten)]
+    pub timestamps: TimestampCondition,
+}
+
+impl IntoCondition for CookLogCondition {
+    fn into_condition(self) -> Condition {
+        Condition::all()
+            .add(
+                self.timestamps
+                    .into_condition(Column::CreatedAt, Column::UpdatedAt),
+            )
+            .add_option(
+                self.recipe_id
+                    .map(|recipe_id| Column::RecipeId.eq(recipe_id)),
+            )
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::enum_variant_names)]
+pub enum CookLogOrderBy {
+    CookedAt(Order),
+    CreatedAt(Order),
+    UpdatedAt(Order),
+}
+
+impl OrderBy for CookLogOrderBy {
+    type Entity = Entity;
+
+    fn order(self) -> (SimpleExpr, Order) {
+        match self {
+            CookLogOrderBy::CookedAt(order) => (Column::CookedAt.into_simple_expr(), order),
+            CookLogOrderBy::CreatedAt(order) => (Column::CreatedAt.into_simple_expr(), order),
+            CookLogOrderBy::UpdatedAt(order) => (Column::UpdatedAt.into_simple_expr(), order),
+        }
+    }
+}
+
+pub struct CookLogCrud {}
+
+#[async_trait]
+impl EntityCrudTrait for CookLogCrud {
+    type Entity = Entity;
+    type Model = Model;
+    type ActiveModel = ActiveModel;
+    type Column = Column;
+    type Relation = Relation;
+    type PrimaryKey = PrimaryKey;
+    type PrimaryKeyValue = i64;
+    type EntityCreate = CookLogCreate;
+    type EntityUpdate = CookLogUpdate;
+    type EntityCondition = CookLogCondition;
+    type EntityOrderBy = CookLogOrderBy;
+
+    fn primary_key_value(model: &Model) -> i64 {
+        model.id
+    }
+
+    fn primary_key_colum() -> Column {
+        Column::Id
+    }
+
+    fn parent_keys() -> &'static [&'static str] {
+        &["recipeId"]
+    }
+
+    async fn delete_children(
+        model: &Model,
+        journal_action_id: Option<i64>,
+        txn: &DatabaseTransaction,
+    ) -> Result<Vec<change_log::Model>> {
+        CookLogFileCrud::delete_all_logged(
+            cook_log_file::Column::CookLogId
+                .eq(model.id)
+                .into_condition(),
+            journal_action_id,
+            txn,
+        )
+        .await
+    }
+
+    fn entity_action_created_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_COOK_LOG
+    }
+
+    fn entity_action_updated_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_COOK_LOG
+    }
+
+    fn entity_action_deleted_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_COOK_LOG
+    }
+
+    fn entity_action_created_many_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_MANY_COOK_LOG
+    }
+
+    fn entity_action_updated_many_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_MANY_COOK_LOG
+    }
+
+    fn entity_action_deleted_many_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_MANY_COOK_LOG
+    }
+}
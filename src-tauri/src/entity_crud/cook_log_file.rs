@@ -0,0 +1,170 @@
+//! This module implements [`EntityCrudTrait`] for [`crate::entity::cook_log_file`].
+
+use async_trait::async_trait;
+use sea_orm::{
+    sea_query::{IntoCondition, SimpleExpr},
+    ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel, IntoActiveModel, IntoSimpleExpr,
+};
+use serde::Deserialize;
+
+use crate::{
+    entity::cook_log_file::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
+    entity_crud::{
+        EntityCrudTrait, Filter, Order, OrderBy, OrderedEntityCrudTrait, TimestampCondition,
+    },
+    event::channel::{
+        ENTITY_ACTION_CREATED_COOK_LOG_FILE, ENTITY_ACTION_CREATED_MANY_COOK_LOG_FILE,
+        ENTITY_ACTION_DELETED_COOK_LOG_FILE, ENTITY_ACTION_DELETED_MANY_COOK_LOG_FILE,
+        ENTITY_ACTION_UPDATED_COOK_LOG_FILE, ENTITY_ACTION_UPDATED_MANY_COOK_LOG_FILE,
+    },
+};
+
+#[derive(Debug, Deserialize, DeriveIntoActiveModel)]
+#[serde(rename_all = "camelCase")]
+pub struct CookLogFileCreate {
+    pub order: i64,
+    pub cook_log_id: i64,
+    pub file_id: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CookLogFileUpdate {
+    pub id: i64,
+    pub order: Option<i64>,
+}
+
+impl IntoActiveModel<ActiveModel> for CookLogFileUpdate {
+    fn into_active_model(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Unchanged(self.id),
+            order: match self.order {
+                Some(order) => ActiveValue::Set(order),
+                _ => ActiveValue::NotSet,
+            },
+            cook_log_id: ActiveValue::NotSet,
+            file_id: ActiveValue::NotSet,
+            created_at: ActiveValue::NotSet,
+            updated_at: ActiveValue::NotSet,
+        }
+    }
+}
+
+pub type CookLogFileFilter = Filter<CookLogFileCondition, CookLogFileOrderBy>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CookLogFileCondition {
+    pub cook_log_id: Option<i64>,
+    #[serde(flatten)]
+    pub timestamps: TimestampCondition,
+}
+
+impl IntoCondition for CookLogFileCondition {
+    fn into_condition(self) -> Condition {
+        Condition::all()
+            .add(
+                self.timestamps
+                    .into_condition(Column::CreatedAt, Column::UpdatedAt),
+            )
+            .add_option(
+                self.cook_log_id
+                    .map(|cook_log_id| Column::CookLogId.eq(cook_log_id)),
+            )
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CookLogFileOrderBy {
+    Order(Order),
+    CreatedAt(Order),
+    UpdatedAt(Order),
+}
+
+impl OrderBy for CookLogFileOrderBy {
+    type Entity = Entity;
+
+    fn order(self) -> (SimpleExpr, Order) {
+        match self {
+            CookLogFileOrderBy::Order(order) => (Column::Order.into_simple_expr(), order),
+            CookLogFileOrderBy::CreatedAt(order) => (Column::CreatedAt.into_simple_expr(), order),
+            CookLogFileOrderBy::UpdatedAt(order) => (Column::UpdatedAt.into_simple_expr(), order),
+        }
+    }
+}
+
+pub struct CookLogFileCrud {}
+
+#[async_trait]
+impl EntityCrudTrait for CookLogFileCrud {
+    type Entity = Entity;
+    type Model = Model;
+    type ActiveModel = ActiveModel;
+    type Column = Column;
+    type Relation = Relation;
+    type PrimaryKey = PrimaryKey;
+    type PrimaryKeyValue = i64;
+    type EntityCreate = CookLogFileCreate;
+    type EntityUpdate = CookLogFileUpdate;
+    type EntityCondition = CookLogFileCondition;
+    type EntityOrderBy = CookLogFileOrderBy;
+
+    fn primary_key_value(model: &Model) -> i64 {
+        model.id
+    }
+
+    fn primary_key_colum() -> Column {
+        Column::Id
+    }
+
+    fn unique_order_column() -> Option<Column> {
+        Some(Column::Order)
+    }
+
+    fn parent_keys() -> &'static [&'static str] {
+        &["cookLogId", "fileId"]
+    }
+
+    fn entity_action_created_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_COOK_LOG_FILE
+    }
+
+    fn entity_action_updated_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_COOK_LOG_FILE
+    }
+
+    fn entity_action_deleted_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_COOK_LOG_FILE
+    }
+
+    fn entity_action_created_many_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_MANY_COOK_LOG_FILE
+    }
+
+    fn entity_action_updated_many_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_MANY_COOK_LOG_FILE
+    }
+
+    fn entity_action_deleted_many_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_MANY_COOK_LOG_FILE
+    }
+}
+
+impl OrderedEntityCrudTrait for CookLogFileCrud {
+    fn parent_id(model: &Model) -> i64 {
+        model.cook_log_id
+    }
+
+    fn order(model: &Model) -> i64 {
+        model.order
+    }
+
+    fn parent_column() -> Column {
+        Column::CookLogId
+    }
+
+    fn order_column() -> Column {
+        Column::Order
+    }
+}
@@ -1,28 +1,45 @@
 //! This module implements [`EntityCrudTrait`] for [`crate::entity::file`].
 
-use std::{fs, str::FromStr};
+use std::{
+    fs,
+    str::FromStr,
+    time::{Duration, SystemTime},
+};
 
 use anyhow::Result;
 use async_trait::async_trait;
 use mime_guess::mime;
 use reqwest::header;
 use sea_orm::{
-    sea_query::IntoCondition, ActiveValue, ColumnTrait, Condition, IntoActiveModel, QueryOrder,
-    Select,
+    sea_query::{IntoCondition, SimpleExpr},
+    ActiveValue, ColumnTrait, Condition, DatabaseConnection, IntoActiveModel, IntoSimpleExpr,
+    TransactionTrait,
 };
 use serde::Deserialize;
 use tempfile::NamedTempFile;
 use url::Url;
 
 use crate::{
-    entity::file::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
-    entity_crud::{EntityCrudTrait, Filter, Order, OrderBy, TryIntoActiveModel},
+    database,
+    entity::{
+        file,
+        file::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
+    },
+    entity_crud::{
+        EntityCrudTrait, Filter, Order, OrderBy, TimestampCondition, TryIntoActiveModel,
+    },
     event::channel::{
-        ENTITY_ACTION_CREATED_FILE, ENTITY_ACTION_DELETED_FILE, ENTITY_ACTION_UPDATED_FILE,
+        ENTITY_ACTION_CREATED_FILE, ENTITY_ACTION_CREATED_MANY_FILE, ENTITY_ACTION_DELETED_FILE,
+        ENTITY_ACTION_DELETED_MANY_FILE, ENTITY_ACTION_UPDATED_FILE,
+        ENTITY_ACTION_UPDATED_MANY_FILE,
     },
     file_storage,
+    timestamp::unix_timestamp,
 };
 
+/// the time for which new files are spared from garbage collection, as they are usually referenced right after
+const GARBAGE_GRACE_PERIOD: Duration = Duration::from_secs(24 * 60 * 60);
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum FileCreateUri {
@@ -73,6 +90,8 @@ impl TryIntoActiveModel<ActiveModel> for FileCreate {
             name: ActiveValue::Set(self.name),
             mime: ActiveValue::Set(mime),
             path: ActiveValue::Set(path.to_string_lossy().to_string()),
+            created_at: ActiveValue::NotSet,
+            updated_at: ActiveValue::NotSet,
         })
     }
 }
@@ -95,6 +114,8 @@ impl IntoActiveModel<ActiveModel> for FileUpdate {
             },
             mime: ActiveValue::NotSet,
             path: ActiveValue::NotSet,
+            created_at: ActiveValue::NotSet,
+            updated_at: ActiveValue::NotSet,
         }
     }
 }
@@ -105,11 +126,18 @@ pub type FileFilter = Filter<FileCondition, FileOrderBy>;
 #[serde(rename_all = "camelCase")]
 pub struct FileCondition {
     pub name: Option<String>,
+    #[serde(flatten)]
+    pub timestamps: TimestampCondition,
 }
 
 impl IntoCondition for FileCondition {
     fn into_condition(self) -> Condition {
-        Condition::all().add_option(self.name.map(|name| Column::Name.like(format!("%{name}%"))))
+        Condition::all()
+            .add(
+                self.timestamps
+                    .into_condition(Column::CreatedAt, Column::UpdatedAt),
+            )
+            .add_option(self.name.map(|name| Column::Name.like(format!("%{name}%"))))
     }
 }
 
@@ -117,20 +145,64 @@ impl IntoCondition for FileCondition {
 #[serde(rename_all = "camelCase")]
 pub enum FileOrderBy {
     Name(Order),
+    CreatedAt(Order),
+    UpdatedAt(Order),
 }
 
 impl OrderBy for FileOrderBy {
     type Entity = Entity;
 
-    fn add(self, select: Select<Self::Entity>) -> Select<Self::Entity> {
+    fn order(self) -> (SimpleExpr, Order) {
         match self {
-            FileOrderBy::Name(order) => select.order_by(Column::Name, order.into()),
+            FileOrderBy::Name(order) => (Column::Name.into_simple_expr(), order),
+            FileOrderBy::CreatedAt(order) => (Column::CreatedAt.into_simple_expr(), order),
+            FileOrderBy::UpdatedAt(order) => (Column::UpdatedAt.into_simple_expr(), order),
         }
     }
 }
 
 pub struct FileCrud {}
 
+impl FileCrud {
+    /// Remove orphaned files, then delete all stored files which no file entity references anymore.
+    ///
+    /// Deleting entities which reference files does not do this, so undo can restore them, see [`file::remove_orphans`].
+    /// The stored files are only deleted once the removal is committed, see [`file::stored_paths`].
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database
+    /// - when the file storage can't be listed
+    pub async fn collect_garbage() -> Result<()> {
+        let created_before = unix_timestamp()? - i64::try_from(GARBAGE_GRACE_PERIOD.as_secs())?;
+        let modified_before = SystemTime::now() - GARBAGE_GRACE_PERIOD;
+        let db = database::connect_writing().await;
+        Self::collect_garbage_before(created_before, modified_before, &db).await
+    }
+
+    /// Collect the garbage of file entities created before a unix timestamp and of stored files modified before a time,
+    /// see [`Self::collect_garbage`].
+    async fn collect_garbage_before(
+        created_before: i64,
+        modified_before: SystemTime,
+        db: &DatabaseConnection,
+    ) -> Result<()> {
+        let txn = db.begin().await?;
+        file::remove_orphans(created_before, &txn).await?;
+        txn.commit().await?;
+        let stored_paths = file::stored_paths(db).await?;
+        for path in file_storage::list(modified_before).await? {
+            if stored_paths.contains(&path) {
+                continue;
+            }
+            if let Err(err) = file_storage::delete(&path.to_string_lossy()).await {
+                log::warn!("Could not delete unreferenced file from storage: {err}");
+            }
+        }
+        Ok(())
+    }
+}
+
 #[async_trait]
 impl EntityCrudTrait for FileCrud {
     type Entity = Entity;
@@ -164,12 +236,29 @@ impl EntityCrudTrait for FileCrud {
     fn entity_action_deleted_channel() -> &'static str {
         ENTITY_ACTION_DELETED_FILE
     }
+
+    fn entity_action_created_many_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_MANY_FILE
+    }
+
+    fn entity_action_updated_many_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_MANY_FILE
+    }
+
+    fn entity_action_deleted_many_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_MANY_FILE
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use sea_orm::{ActiveModelTrait, EntityTrait};
+
     use super::*;
-    use crate::tests::{create_temp_file, TEST_NAME};
+    use crate::{
+        migrator::tests::get_memory_database_migrated,
+        tests::{create_temp_file, TEST_NAME},
+    };
 
     #[tokio::test]
     async fn test_try_into_active_model_local() {
@@ -198,4 +287,70 @@ mod tests {
 
         TEST_NAME.set(None);
     }
+
+    #[tokio::test]
+    async fn test_collect_garbage_before() {
+        TEST_NAME.set(Some(
+            "entity_crud__file__test_collect_garbage_before".to_string(),
+        ));
+        crate::tests::run();
+
+        let db = get_memory_database_migrated().await;
+        let temp_path = create_temp_file("entity_crud__file__test_collect_garbage_before.bin", "");
+        let mut paths = vec![];
+        for _ in 0..3 {
+            let path = file_storage::create(
+                &temp_path.to_string_lossy(),
+                mime::APPLICATION_OCTET_STREAM.as_ref(),
+            )
+            .await
+            .unwrap();
+            paths.push(path.to_string_lossy().to_string());
+        }
+        let orphaned_file = ActiveModel {
+            name: ActiveValue::Set("orphaned".to_string()),
+            mime: ActiveValue::Set(mime::APPLICATION_OCTET_STREAM.to_string()),
+            path: ActiveValue::Set(paths[0].clone()),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        let new_file = ActiveModel {
+            name: ActiveValue::Set("new".to_string()),
+            mime: ActiveValue::Set(mime::APPLICATION_OCTET_STREAM.to_string()),
+            path: ActiveValue::Set(paths[1].clone()),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        let created_before = new_file.created_at;
+        let mut orphaned_file: ActiveModel = orphaned_file.into();
+        orphaned_file.created_at = ActiveValue::Set(created_before - 1);
+        let orphaned_file = Entity::update(orphaned_file).exec(&db).await.unwrap();
+        let modified_before = SystemTime::now() + Duration::from_secs(1);
+        FileCrud::collect_garbage_before(created_before, modified_before, &db)
+            .await
+            .unwrap();
+        assert!(
+            Entity::find_by_id(orphaned_file.id)
+                .one(&db)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            Entity::find_by_id(new_file.id)
+                .one(&db)
+                .await
+                .unwrap()
+                .is_some()
+        );
+        assert!(fs::metadata(&paths[0]).is_err());
+        assert!(fs::metadata(&paths[1]).is_ok());
+        assert!(fs::metadata(&paths[2]).is_err());
+
+        TEST_NAME.set(None);
+    }
 }
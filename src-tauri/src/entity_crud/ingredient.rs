@@ -1,27 +1,50 @@
 //! This module implements [`EntityCrudTrait`] for [`crate::entity::ingredient`].
 
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use anyhow::{bail, Result};
+use async_trait::async_trait;
 use sea_orm::{
-    sea_query::IntoCondition, ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel,
-    EntityTrait, IntoActiveModel, QueryFilter, QueryOrder, QuerySelect, QueryTrait, Select,
+    sea_query::{Expr, Func, IntoCondition, Query, SimpleExpr},
+    ActiveValue, ColumnTrait, Condition, DatabaseTransaction, DeriveIntoActiveModel, EntityTrait,
+    IntoActiveModel, IntoSimpleExpr, QueryFilter, QueryOrder, QuerySelect, QueryTrait,
+    TransactionTrait,
 };
 use serde::Deserialize;
 
 use crate::{
+    database,
     entity::{
+        change_log,
         ingredient::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
-        recipe_step_ingredient,
+        ingredient_alias,
+        ingredient_alias::kind::Kind,
+        ingredient_allergen,
+        ingredient_allergen::allergen::Allergen,
+        ingredient_category, nutrient_profile, recipe_step, recipe_step_ingredient,
+    },
+    entity_crud::{
+        ingredient_alias::IngredientAliasCrud, ingredient_allergen::IngredientAllergenCrud,
+        nutrient_profile::NutrientProfileCrud, recipe_step_ingredient::RecipeStepIngredientCrud,
+        EntityCrudTrait, Filter, Order, OrderBy, OrderedEntityCrudTrait, TimestampCondition,
     },
-    entity_crud::{EntityCrudTrait, Filter, Order, OrderBy},
     event::channel::{
-        ENTITY_ACTION_CREATED_INGREDIENT, ENTITY_ACTION_DELETED_INGREDIENT,
-        ENTITY_ACTION_UPDATED_INGREDIENT,
+        ENTITY_ACTION_CREATED_INGREDIENT, ENTITY_ACTION_CREATED_MANY_INGREDIENT,
+        ENTITY_ACTION_DELETED_INGREDIENT, ENTITY_ACTION_DELETED_MANY_INGREDIENT,
+        ENTITY_ACTION_UPDATED_INGREDIENT, ENTITY_ACTION_UPDATED_MANY_INGREDIENT,
     },
+    ingredient_similarity, journal,
 };
 
 #[derive(Debug, Deserialize, DeriveIntoActiveModel)]
 #[serde(rename_all = "camelCase")]
 pub struct IngredientCreate {
     pub name: String,
+    pub ingredient_category_id: Option<i64>,
+    pub vegetarian: Option<bool>,
+    pub vegan: Option<bool>,
+    pub gluten_free: Option<bool>,
+    pub lactose_free: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -29,6 +52,16 @@ pub struct IngredientCreate {
 pub struct IngredientUpdate {
     pub id: i64,
     pub name: Option<String>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub ingredient_category_id: Option<Option<i64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub vegetarian: Option<Option<bool>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub vegan: Option<Option<bool>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub gluten_free: Option<Option<bool>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub lactose_free: Option<Option<bool>>,
 }
 
 impl IntoActiveModel<ActiveModel> for IngredientUpdate {
@@ -39,6 +72,28 @@ impl IntoActiveModel<ActiveModel> for IngredientUpdate {
                 Some(name) => ActiveValue::Set(name),
                 _ => ActiveValue::NotSet,
             },
+            ingredient_category_id: match self.ingredient_category_id {
+                Some(ingredient_category_id) => ActiveValue::Set(ingredient_category_id),
+                _ => ActiveValue::NotSet,
+            },
+            vegetarian: match self.vegetarian {
+                Some(vegetarian) => ActiveValue::Set(vegetarian),
+                _ => ActiveValue::NotSet,
+            },
+            vegan: match self.vegan {
+                Some(vegan) => ActiveValue::Set(vegan),
+                _ => ActiveValue::NotSet,
+            },
+            gluten_free: match self.gluten_free {
+                Some(gluten_free) => ActiveValue::Set(gluten_free),
+                _ => ActiveValue::NotSet,
+            },
+            lactose_free: match self.lactose_free {
+                Some(lactose_free) => ActiveValue::Set(lactose_free),
+                _ => ActiveValue::NotSet,
+            },
+            created_at: ActiveValue::NotSet,
+            updated_at: ActiveValue::NotSet,
         }
     }
 }
@@ -51,13 +106,32 @@ pub struct IngredientCondition {
     pub name: Option<String>,
     pub name_exact: Option<String>,
     pub recipe_step_id: Option<i64>,
+    pub recipe_id: Option<i64>,
+    pub ingredient_category_id: Option<i64>,
+    #[serde(flatten)]
+    pub timestamps: TimestampCondition,
 }
 
 impl IntoCondition for IngredientCondition {
     fn into_condition(self) -> Condition {
         Condition::all()
-            .add_option(self.name.map(|name| Column::Name.like(format!("%{name}%"))))
-            .add_option(self.name_exact.map(|name| Column::Name.eq(name)))
+            .add(
+                self.timestamps
+                    .into_condition(Column::CreatedAt, Column::UpdatedAt),
+            )
+            .add_option(self.name.map(|name| {
+                let pattern = format!("%{name}%");
+                Condition::any()
+                    .add(Column::Name.like(&pattern))
+                    .add(alias_condition(
+                        ingredient_alias::Column::Name.like(pattern),
+                    ))
+            }))
+            .add_option(self.name_exact.map(|name| {
+                Condition::any()
+                    .add(Column::Name.eq(&name))
+                    .add(alias_condition(ingredient_alias::Column::Name.eq(name)))
+            }))
             .add_option(self.recipe_step_id.map(|recipe_step_id| {
                 Column::Id.in_subquery(
                     recipe_step_ingredient::Entity::find()
@@ -67,27 +141,358 @@ impl IntoCondition for IngredientCondition {
                         .into_query(),
                 )
             }))
+            .add_option(self.recipe_id.map(|recipe_id| {
+                Column::Id.in_subquery(
+                    recipe_step_ingredient::Entity::find()
+                        .select_only()
+                        .column(recipe_step_ingredient::Column::IngredientId)
+                        .inner_join(recipe_step::Entity)
+                        .filter(recipe_step::Column::RecipeId.eq(recipe_id))
+                        .into_query(),
+                )
+            }))
+            .add_option(self.ingredient_category_id.map(|ingredient_category_id| {
+                Column::IngredientCategoryId.eq(ingredient_category_id)
+            }))
     }
 }
 
+/// Get the condition for ingredients having an alias which matches the alias condition.
+fn alias_condition(alias_condition: SimpleExpr) -> SimpleExpr {
+    Column::Id.in_subquery(
+        ingredient_alias::Entity::find()
+            .select_only()
+            .column(ingredient_alias::Column::IngredientId)
+            .filter(alias_condition)
+            .into_query(),
+    )
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum IngredientOrderBy {
     Name(Order),
+    /// Order by the order of the ingredient's category, which groups the ingredients by supermarket aisle.
+    ///
+    /// Ingredients without a category come last in ascending order.
+    IngredientCategory(Order),
+    CreatedAt(Order),
+    UpdatedAt(Order),
 }
 
 impl OrderBy for IngredientOrderBy {
     type Entity = Entity;
 
-    fn add(self, select: Select<Self::Entity>) -> Select<Self::Entity> {
+    fn order(self) -> (SimpleExpr, Order) {
         match self {
-            IngredientOrderBy::Name(order) => select.order_by(Column::Name, order.into()),
+            IngredientOrderBy::Name(order) => (Column::Name.into_simple_expr(), order),
+            IngredientOrderBy::IngredientCategory(order) => (
+                Func::coalesce([
+                    SimpleExpr::SubQuery(
+                        None,
+                        Box::new(
+                            Query::select()
+                                .column(ingredient_category::Column::Order)
+                                .from(ingredient_category::Entity)
+                                .and_where(
+                                    Expr::col((
+                                        ingredient_category::Entity,
+                                        ingredient_category::Column::Id,
+                                    ))
+                                    .equals((Entity, Column::IngredientCategoryId)),
+                                )
+                                .to_owned()
+                                .into_sub_query_statement(),
+                        ),
+                    ),
+                    Expr::val(i64::MAX).into(),
+                ])
+                .into(),
+                order,
+            ),
+            IngredientOrderBy::CreatedAt(order) => (Column::CreatedAt.into_simple_expr(), order),
+            IngredientOrderBy::UpdatedAt(order) => (Column::UpdatedAt.into_simple_expr(), order),
         }
     }
 }
 
 pub struct IngredientCrud {}
 
+impl IngredientCrud {
+    /// Merge source ingredients into a target ingredient atomically.
+    ///
+    /// Returns `false` when the target ingredient does not exist.
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database, nothing is merged then
+    /// - when a changed entity can't be serialized for the change log
+    /// - when the tauri window can't be messaged about the changed entities
+    pub async fn merge(target_id: i64, source_ids: Vec<i64>) -> Result<bool> {
+        let db = database::connect_writing().await;
+        let txn = db.begin().await?;
+        if Entity::find_by_id(target_id).one(&txn).await?.is_none() {
+            return Ok(false);
+        }
+        let journal_action_id = journal::record_action(&txn).await?;
+        let changes =
+            Self::merge_logged(target_id, source_ids, Some(journal_action_id), &txn).await?;
+        if changes.is_empty() {
+            return Ok(true);
+        }
+        txn.commit().await?;
+        journal::emit(&changes)?;
+        Ok(true)
+    }
+
+    /// Merge source ingredients into a target ingredient inside a transaction and log the changes.
+    ///
+    /// Every recipe step ingredient and alias of a source ingredient is re-pointed to the target ingredient, then the source ingredients are deleted.
+    /// The names of the source ingredients are kept as aliases of the target ingredient, so they still resolve to it.
+    /// When the target ingredient has no nutrient profile, it takes over the first one of the source ingredients.
+    /// The target ingredient takes over the allergens of the source ingredients,
+    /// and its unknown diets are taken from the source ingredients, where an incompatible source ingredient wins.
+    /// As an ingredient can only be used once per recipe step, a recipe step ingredient is merged instead when its recipe step already uses the target ingredient, see [`merge_recipe_step_ingredient`].
+    /// The recipe step ingredients of such a recipe step are renumbered afterwards.
+    ///
+    /// # Errors
+    ///
+    /// - when the target ingredient does not exist
+    /// - when the quantities of recipe step ingredients to be merged can't be added up
+    /// - when there is any problem with the database
+    /// - when a changed entity can't be serialized for the change log
+    pub async fn merge_logged(
+        target_id: i64,
+        source_ids: Vec<i64>,
+        journal_action_id: Option<i64>,
+        txn: &DatabaseTransaction,
+    ) -> Result<Vec<change_log::Model>> {
+        let Some(target) = Entity::find_by_id(target_id).one(txn).await? else {
+            bail!("The target ingredient does not exist");
+        };
+        let source_ids: Vec<i64> = source_ids
+            .into_iter()
+            .filter(|source_id| *source_id != target_id)
+            .collect();
+        let mut target_recipe_step_ingredients: HashMap<i64, recipe_step_ingredient::Model> =
+            recipe_step_ingredient::Entity::find()
+                .filter(recipe_step_ingredient::Column::IngredientId.eq(target_id))
+                .all(txn)
+                .await?
+                .into_iter()
+                .map(|recipe_step_ingredient| {
+                    (
+                        recipe_step_ingredient.recipe_step_id,
+                        recipe_step_ingredient,
+                    )
+                })
+                .collect();
+        let recipe_step_ingredients = recipe_step_ingredient::Entity::find()
+            .filter(recipe_step_ingredient::Column::IngredientId.is_in(source_ids.clone()))
+            .order_by_asc(recipe_step_ingredient::Column::Id)
+            .all(txn)
+            .await?;
+        let mut changes = Vec::new();
+        let mut renumber_recipe_step_ids = BTreeSet::new();
+        for recipe_step_ingredient in recipe_step_ingredients {
+            let recipe_step_id = recipe_step_ingredient.recipe_step_id;
+            if let Some(target_recipe_step_ingredient) =
+                target_recipe_step_ingredients.remove(&recipe_step_id)
+            {
+                let Some(active_model) = merge_recipe_step_ingredient(
+                    target_recipe_step_ingredient,
+                    &recipe_step_ingredient,
+                ) else {
+                    bail!(
+                        "The merged quantities of recipe step {recipe_step_id} can't be added up"
+                    );
+                };
+                let (model, change) =
+                    RecipeStepIngredientCrud::update_logged(active_model, journal_action_id, txn)
+                        .await?;
+                changes.push(change);
+                target_recipe_step_ingredients.insert(recipe_step_id, model);
+                changes.append(
+                    &mut RecipeStepIngredientCrud::delete_model_logged(
+                        recipe_step_ingredient,
+                        journal_action_id,
+                        txn,
+                    )
+                    .await?,
+                );
+                renumber_recipe_step_ids.insert(recipe_step_id);
+            } else {
+                let mut active_model = recipe_step_ingredient.into_active_model();
+                active_model.ingredient_id = ActiveValue::Set(target_id);
+                let (model, change) =
+                    RecipeStepIngredientCrud::update_logged(active_model, journal_action_id, txn)
+                        .await?;
+                changes.push(change);
+                target_recipe_step_ingredients.insert(recipe_step_id, model);
+            }
+        }
+        for recipe_step_id in renumber_recipe_step_ids {
+            let siblings = RecipeStepIngredientCrud::siblings(recipe_step_id, txn).await?;
+            changes.append(
+                &mut RecipeStepIngredientCrud::renumber_logged(siblings, journal_action_id, txn)
+                    .await?,
+            );
+        }
+        let aliases = ingredient_alias::Entity::find()
+            .filter(ingredient_alias::Column::IngredientId.is_in(source_ids.clone()))
+            .order_by_asc(ingredient_alias::Column::Id)
+            .all(txn)
+            .await?;
+        for alias in aliases {
+            let mut active_model = alias.into_active_model();
+            active_model.ingredient_id = ActiveValue::Set(target_id);
+            let (_, change) =
+                IngredientAliasCrud::update_logged(active_model, journal_action_id, txn).await?;
+            changes.push(change);
+        }
+        let has_nutrient_profile = nutrient_profile::Entity::find()
+            .filter(nutrient_profile::Column::IngredientId.eq(target_id))
+            .one(txn)
+            .await?
+            .is_some();
+        if !has_nutrient_profile {
+            let nutrient_profile = nutrient_profile::Entity::find()
+                .filter(nutrient_profile::Column::IngredientId.is_in(source_ids.clone()))
+                .order_by_asc(nutrient_profile::Column::Id)
+                .one(txn)
+                .await?;
+            if let Some(nutrient_profile) = nutrient_profile {
+                let mut active_model = nutrient_profile.into_active_model();
+                active_model.ingredient_id = ActiveValue::Set(target_id);
+                let (_, change) =
+                    NutrientProfileCrud::update_logged(active_model, journal_action_id, txn)
+                        .await?;
+                changes.push(change);
+            }
+        }
+        let mut target_allergens: HashSet<Allergen> = ingredient_allergen::Entity::find()
+            .filter(ingredient_allergen::Column::IngredientId.eq(target_id))
+            .all(txn)
+            .await?
+            .into_iter()
+            .map(|ingredient_allergen| ingredient_allergen.allergen)
+            .collect();
+        let ingredient_allergens = ingredient_allergen::Entity::find()
+            .filter(ingredient_allergen::Column::IngredientId.is_in(source_ids.clone()))
+            .order_by_asc(ingredient_allergen::Column::Id)
+            .all(txn)
+            .await?;
+        for ingredient_allergen in ingredient_allergens {
+            if target_allergens.insert(ingredient_allergen.allergen) {
+                let mut active_model = ingredient_allergen.into_active_model();
+                active_model.ingredient_id = ActiveValue::Set(target_id);
+                let (_, change) =
+                    IngredientAllergenCrud::update_logged(active_model, journal_action_id, txn)
+                        .await?;
+                changes.push(change);
+            }
+        }
+        let sources = Entity::find()
+            .filter(Column::Id.is_in(source_ids))
+            .order_by_asc(Column::Id)
+            .all(txn)
+            .await?;
+        let merge_diet = |target_diet: Option<bool>, source_diet: fn(&Model) -> Option<bool>| {
+            target_diet.or_else(|| {
+                sources
+                    .iter()
+                    .filter_map(source_diet)
+                    .reduce(|diet, source_diet| diet && source_diet)
+            })
+        };
+        let vegetarian = merge_diet(target.vegetarian, |source| source.vegetarian);
+        let vegan = merge_diet(target.vegan, |source| source.vegan);
+        let gluten_free = merge_diet(target.gluten_free, |source| source.gluten_free);
+        let lactose_free = merge_diet(target.lactose_free, |source| source.lactose_free);
+        if (vegetarian, vegan, gluten_free, lactose_free)
+            != (
+                target.vegetarian,
+                target.vegan,
+                target.gluten_free,
+                target.lactose_free,
+            )
+        {
+            let mut active_model = target.clone().into_active_model();
+            active_model.vegetarian = ActiveValue::Set(vegetarian);
+            active_model.vegan = ActiveValue::Set(vegan);
+            active_model.gluten_free = ActiveValue::Set(gluten_free);
+            active_model.lactose_free = ActiveValue::Set(lactose_free);
+            let (_, change) = Self::update_logged(active_model, journal_action_id, txn).await?;
+            changes.push(change);
+        }
+        for source in sources {
+            if source.name != target.name {
+                let (_, change) = IngredientAliasCrud::create_logged(
+                    ingredient_alias::ActiveModel {
+                        id: ActiveValue::NotSet,
+                        name: ActiveValue::Set(source.name.clone()),
+                        language: ActiveValue::Set(None),
+                        kind: ActiveValue::Set(Kind::Alias),
+                        ingredient_id: ActiveValue::Set(target_id),
+                        created_at: ActiveValue::NotSet,
+                        updated_at: ActiveValue::NotSet,
+                    },
+                    journal_action_id,
+                    txn,
+                )
+                .await?;
+                changes.push(change);
+            }
+            changes.append(&mut Self::delete_model_logged(source, journal_action_id, txn).await?);
+        }
+        Ok(changes)
+    }
+
+    /// Suggest groups of ingredients which are likely duplicates of each other.
+    ///
+    /// See [`ingredient_similarity::duplicate_groups`].
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database
+    pub async fn merge_suggestions() -> Result<Vec<Vec<i64>>> {
+        let db = database::connect().await;
+        let ingredients = Entity::find().all(db).await?;
+        Ok(ingredient_similarity::duplicate_groups(&ingredients))
+    }
+}
+
+/// Merge a recipe step ingredient into the one of the same recipe step which is kept.
+///
+/// The quantities are added up when the units are the same and either both or none of the quantities are known.
+/// Differing qualities are joined.
+/// Returns [`None`] when the quantities can't be added up.
+fn merge_recipe_step_ingredient(
+    kept: recipe_step_ingredient::Model,
+    merged: &recipe_step_ingredient::Model,
+) -> Option<recipe_step_ingredient::ActiveModel> {
+    if kept.unit != merged.unit {
+        return None;
+    }
+    let quantity = match (kept.quantity, merged.quantity) {
+        (Some(kept_quantity), Some(merged_quantity)) => Some(kept_quantity + merged_quantity),
+        (None, None) => None,
+        _ => return None,
+    };
+    let quality = match (&kept.quality, &merged.quality) {
+        (Some(kept_quality), Some(merged_quality)) if kept_quality != merged_quality => {
+            Some(format!("{kept_quality}, {merged_quality}"))
+        }
+        (None, merged_quality) => merged_quality.clone(),
+        (kept_quality, _) => kept_quality.clone(),
+    };
+    let mut active_model = kept.into_active_model();
+    active_model.quantity = ActiveValue::Set(quantity);
+    active_model.quality = ActiveValue::Set(quality);
+    Some(active_model)
+}
+
+#[async_trait]
 impl EntityCrudTrait for IngredientCrud {
     type Entity = Entity;
     type Model = Model;
@@ -109,6 +514,42 @@ impl EntityCrudTrait for IngredientCrud {
         Column::Id
     }
 
+    async fn delete_children(
+        model: &Model,
+        journal_action_id: Option<i64>,
+        txn: &DatabaseTransaction,
+    ) -> Result<Vec<change_log::Model>> {
+        let mut changes = IngredientAliasCrud::delete_all_logged(
+            ingredient_alias::Column::IngredientId
+                .eq(model.id)
+                .into_condition(),
+            journal_action_id,
+            txn,
+        )
+        .await?;
+        changes.append(
+            &mut IngredientAllergenCrud::delete_all_logged(
+                ingredient_allergen::Column::IngredientId
+                    .eq(model.id)
+                    .into_condition(),
+                journal_action_id,
+                txn,
+            )
+            .await?,
+        );
+        changes.append(
+            &mut NutrientProfileCrud::delete_all_logged(
+                nutrient_profile::Column::IngredientId
+                    .eq(model.id)
+                    .into_condition(),
+                journal_action_id,
+                txn,
+            )
+            .await?,
+        );
+        Ok(changes)
+    }
+
     fn entity_action_created_channel() -> &'static str {
         ENTITY_ACTION_CREATED_INGREDIENT
     }
@@ -120,4 +561,299 @@ impl EntityCrudTrait for IngredientCrud {
     fn entity_action_deleted_channel() -> &'static str {
         ENTITY_ACTION_DELETED_INGREDIENT
     }
+
+    fn entity_action_created_many_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_MANY_INGREDIENT
+    }
+
+    fn entity_action_updated_many_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_MANY_INGREDIENT
+    }
+
+    fn entity_action_deleted_many_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_MANY_INGREDIENT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use sea_orm::ActiveModelTrait;
+
+    use super::*;
+    use crate::{
+        entity::{recipe, recipe_step},
+        entity_crud::{ingredient_category::IngredientCategoryCrud, IdColumn},
+        migrator::tests::get_memory_database_migrated,
+    };
+
+    #[tokio::test]
+    async fn test_merge_logged() {
+        let db = get_memory_database_migrated().await;
+        recipe::ActiveModel {
+            name: ActiveValue::Set(String::from("soup")),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        for order in [1, 2] {
+            recipe_step::ActiveModel {
+                order: ActiveValue::Set(order),
+                description: ActiveValue::Set(String::from("cook")),
+                recipe_id: ActiveValue::Set(1),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        for name in ["onion", "onions", "Onion", "salt"] {
+            ActiveModel {
+                name: ActiveValue::Set(name.to_string()),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        for (order, recipe_step_id, ingredient_id) in [(1, 1, 2), (2, 1, 1), (3, 1, 4), (1, 2, 3)] {
+            recipe_step_ingredient::ActiveModel {
+                order: ActiveValue::Set(order),
+                recipe_step_id: ActiveValue::Set(recipe_step_id),
+                ingredient_id: ActiveValue::Set(ingredient_id),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        ingredient_alias::ActiveModel {
+            name: ActiveValue::Set(String::from("Zwiebel")),
+            language: ActiveValue::Set(Some(String::from("de"))),
+            kind: ActiveValue::Set(Kind::Singular),
+            ingredient_id: ActiveValue::Set(2),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        let txn = db.begin().await.unwrap();
+        IngredientCrud::merge_logged(1, vec![1, 2, 3], None, &txn)
+            .await
+            .unwrap();
+        txn.commit().await.unwrap();
+        let recipe_step_ingredients: Vec<(i64, i64, i64, i64)> =
+            recipe_step_ingredient::Entity::find()
+                .order_by_asc(recipe_step_ingredient::Column::Id)
+                .all(&db)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|model| {
+                    (
+                        model.id,
+                        model.order,
+                        model.recipe_step_id,
+                        model.ingredient_id,
+                    )
+                })
+                .collect();
+        assert_eq!(
+            recipe_step_ingredients,
+            vec![(2, 1, 1, 1), (3, 2, 1, 4), (4, 1, 2, 1)]
+        );
+        let ingredient_ids: Vec<i64> = Entity::find()
+            .order_by_asc(Column::Id)
+            .all(&db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|model| model.id)
+            .collect();
+        assert_eq!(ingredient_ids, vec![1, 4]);
+        let aliases: Vec<(String, Kind, i64)> = ingredient_alias::Entity::find()
+            .order_by_asc(ingredient_alias::Column::Id)
+            .all(&db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|model| (model.name, model.kind, model.ingredient_id))
+            .collect();
+        assert_eq!(
+            aliases,
+            vec![
+                (String::from("Zwiebel"), Kind::Singular, 1),
+                (String::from("onions"), Kind::Alias, 1),
+                (String::from("Onion"), Kind::Alias, 1),
+            ]
+        );
+        for name in ["onion", "Zwiebel", "Onion"] {
+            let ids: Vec<i64> = IngredientCrud::select_ids(Some(IngredientCondition {
+                name: None,
+                name_exact: Some(name.to_string()),
+                recipe_step_id: None,
+                recipe_id: None,
+                ingredient_category_id: None,
+                timestamps: TimestampCondition::default(),
+            }))
+            .into_model::<IdColumn<i64>>()
+            .all(&db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|id_column| id_column.id)
+            .collect();
+            assert_eq!(ids, vec![1]);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_merge_logged_quantities() {
+        let db = get_memory_database_migrated().await;
+        recipe::ActiveModel {
+            name: ActiveValue::Set(String::from("soup")),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        for order in [1, 2] {
+            recipe_step::ActiveModel {
+                order: ActiveValue::Set(order),
+                description: ActiveValue::Set(String::from("cook")),
+                recipe_id: ActiveValue::Set(1),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        for name in ["onion", "onions", "Onion"] {
+            ActiveModel {
+                name: ActiveValue::Set(name.to_string()),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        for (recipe_step_id, ingredient_id, quantity, unit, quality) in [
+            (1, 1, 100.0, "g", Some("red")),
+            (1, 2, 50.0, "g", Some("white")),
+            (2, 1, 1.0, "kg", None),
+            (2, 3, 2.0, "piece", None),
+        ] {
+            recipe_step_ingredient::ActiveModel {
+                order: ActiveValue::Set(ingredient_id),
+                quantity: ActiveValue::Set(Some(quantity)),
+                unit: ActiveValue::Set(Some(String::from(unit))),
+                quality: ActiveValue::Set(quality.map(String::from)),
+                recipe_step_id: ActiveValue::Set(recipe_step_id),
+                ingredient_id: ActiveValue::Set(ingredient_id),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        let txn = db.begin().await.unwrap();
+        assert!(
+            IngredientCrud::merge_logged(1, vec![2, 3], None, &txn)
+                .await
+                .is_err()
+        );
+        txn.rollback().await.unwrap();
+        let txn = db.begin().await.unwrap();
+        IngredientCrud::merge_logged(1, vec![2], None, &txn)
+            .await
+            .unwrap();
+        txn.commit().await.unwrap();
+        let recipe_step_ingredients: Vec<(i64, Option<f64>, Option<String>)> =
+            recipe_step_ingredient::Entity::find()
+                .filter(recipe_step_ingredient::Column::RecipeStepId.eq(1))
+                .all(&db)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|model| (model.ingredient_id, model.quantity, model.quality))
+                .collect();
+        assert_eq!(
+            recipe_step_ingredients,
+            vec![(1, Some(150.0), Some(String::from("red, white")))]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_order_by_ingredient_category() {
+        let db = get_memory_database_migrated().await;
+        for (name, order) in [("dairy", 2), ("produce", 1)] {
+            ingredient_category::ActiveModel {
+                name: ActiveValue::Set(name.to_string()),
+                order: ActiveValue::Set(order),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        for (name, ingredient_category_id) in [
+            ("milk", Some(1)),
+            ("salt", None),
+            ("apple", Some(2)),
+            ("cheese", Some(1)),
+        ] {
+            ActiveModel {
+                name: ActiveValue::Set(name.to_string()),
+                ingredient_category_id: ActiveValue::Set(ingredient_category_id),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        let list_ids = |after: Option<i64>| {
+            IngredientCrud::paginate(
+                IngredientCrud::select_ids(None),
+                vec![
+                    IngredientOrderBy::IngredientCategory(Order::Asc),
+                    IngredientOrderBy::Name(Order::Asc),
+                ],
+                None,
+                None,
+                after,
+            )
+            .into_model::<IdColumn<i64>>()
+            .all(&db)
+        };
+        let ids = |id_columns: Vec<IdColumn<i64>>| -> Vec<i64> {
+            id_columns
+                .into_iter()
+                .map(|id_column| id_column.id)
+                .collect()
+        };
+        assert_eq!(ids(list_ids(None).await.unwrap()), vec![3, 4, 1, 2]);
+        assert_eq!(ids(list_ids(Some(4)).await.unwrap()), vec![1, 2]);
+        assert_eq!(ids(list_ids(Some(1)).await.unwrap()), vec![2]);
+        let txn = db.begin().await.unwrap();
+        let changes = IngredientCategoryCrud::delete_logged(1, None, &txn)
+            .await
+            .unwrap();
+        txn.commit().await.unwrap();
+        assert_eq!(changes.len(), 3);
+        assert_eq!(ids(list_ids(None).await.unwrap()), vec![3, 4, 1, 2]);
+        let uncategorized = IngredientCrud::select_ids(Some(IngredientCondition {
+            name: None,
+            name_exact: None,
+            recipe_step_id: None,
+            recipe_id: None,
+            ingredient_category_id: Some(1),
+            timestamps: TimestampCondition::default(),
+        }))
+        .into_model::<IdColumn<i64>>()
+        .all(&db)
+        .await
+        .unwrap();
+        assert!(uncategorized.is_empty());
+    }
 }
@@ -0,0 +1,165 @@
+//! This module implements [`EntityCrudTrait`] for [`crate::entity::ingredient_alias`].
+
+use sea_orm::{
+    sea_query::{IntoCondition, SimpleExpr},
+    ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel, IntoActiveModel, IntoSimpleExpr,
+};
+use serde::Deserialize;
+
+use crate::{
+    entity::ingredient_alias::{
+        kind::Kind, ActiveModel, Column, Entity, Model, PrimaryKey, Relation,
+    },
+    entity_crud::{EntityCrudTrait, Filter, Order, OrderBy, TimestampCondition},
+    event::channel::{
+        ENTITY_ACTION_CREATED_INGREDIENT_ALIAS, ENTITY_ACTION_CREATED_MANY_INGREDIENT_ALIAS,
+        ENTITY_ACTION_DELETED_INGREDIENT_ALIAS, ENTITY_ACTION_DELETED_MANY_INGREDIENT_ALIAS,
+        ENTITY_ACTION_UPDATED_INGREDIENT_ALIAS, ENTITY_ACTION_UPDATED_MANY_INGREDIENT_ALIAS,
+    },
+};
+
+#[derive(Debug, Deserialize, DeriveIntoActiveModel)]
+#[serde(rename_all = "camelCase")]
+pub struct IngredientAliasCreate {
+    pub name: String,
+    pub language: Option<String>,
+    pub kind: Kind,
+    pub ingredient_id: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IngredientAliasUpdate {
+    pub id: i64,
+    pub name: Option<String>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub language: Option<Option<String>>,
+    pub kind: Option<Kind>,
+}
+
+impl IntoActiveModel<ActiveModel> for IngredientAliasUpdate {
+    fn into_active_model(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Unchanged(self.id),
+            name: match self.name {
+                Some(name) => ActiveValue::Set(name),
+                _ => ActiveValue::NotSet,
+            },
+            language: match self.language {
+                Some(language) => ActiveValue::Set(language),
+                _ => ActiveValue::NotSet,
+            },
+            kind: match self.kind {
+                Some(kind) => ActiveValue::Set(kind),
+                _ => ActiveValue::NotSet,
+            },
+            ingredient_id: ActiveValue::NotSet,
+            created_at: ActiveValue::NotSet,
+            updated_at: ActiveValue::NotSet,
+        }
+    }
+}
+
+pub type IngredientAliasFilter = Filter<IngredientAliasCondition, IngredientAliasOrderBy>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IngredientAliasCondition {
+    pub ingredient_id: Option<i64>,
+    pub language: Option<String>,
+    pub kind: Option<Kind>,
+    #[serde(flatten)]
+    pub timestamps: TimestampCondition,
+}
+
+impl IntoCondition for IngredientAliasCondition {
+    fn into_condition(self) -> Condition {
+        Condition::all()
+            .add(
+                self.timestamps
+                    .into_condition(Column::CreatedAt, Column::UpdatedAt),
+            )
+            .add_option(
+                self.ingredient_id
+                    .map(|ingredient_id| Column::IngredientId.eq(ingredient_id)),
+            )
+            .add_option(self.language.map(|language| Column::Language.eq(language)))
+            .add_option(self.kind.map(|kind| Column::Kind.eq(kind)))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IngredientAliasOrderBy {
+    Name(Order),
+    CreatedAt(Order),
+    UpdatedAt(Order),
+}
+
+impl OrderBy for IngredientAliasOrderBy {
+    type Entity = Entity;
+
+    fn order(self) -> (SimpleExpr, Order) {
+        match self {
+            IngredientAliasOrderBy::Name(order) => (Column::Name.into_simple_expr(), order),
+            IngredientAliasOrderBy::CreatedAt(order) => {
+                (Column::CreatedAt.into_simple_expr(), order)
+            }
+            IngredientAliasOrderBy::UpdatedAt(order) => {
+                (Column::UpdatedAt.into_simple_expr(), order)
+            }
+        }
+    }
+}
+
+pub struct IngredientAliasCrud {}
+
+impl EntityCrudTrait for IngredientAliasCrud {
+    type Entity = Entity;
+    type Model = Model;
+    type ActiveModel = ActiveModel;
+    type Column = Column;
+    type Relation = Relation;
+    type PrimaryKey = PrimaryKey;
+    type PrimaryKeyValue = i64;
+    type EntityCreate = IngredientAliasCreate;
+    type EntityUpdate = IngredientAliasUpdate;
+    type EntityCondition = IngredientAliasCondition;
+    type EntityOrderBy = IngredientAliasOrderBy;
+
+    fn primary_key_value(model: &Model) -> i64 {
+        model.id
+    }
+
+    fn primary_key_colum() -> Column {
+        Column::Id
+    }
+
+    fn parent_keys() -> &'static [&'static str] {
+        &["ingredientId"]
+    }
+
+    fn entity_action_created_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_INGREDIENT_ALIAS
+    }
+
+    fn entity_action_updated_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_INGREDIENT_ALIAS
+    }
+
+    fn entity_action_deleted_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_INGREDIENT_ALIAS
+    }
+
+    fn entity_action_created_many_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_MANY_INGREDIENT_ALIAS
+    }
+
+    fn entity_action_updated_many_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_MANY_INGREDIENT_ALIAS
+    }
+
+    fn entity_action_deleted_many_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_MANY_INGREDIENT_ALIAS
+    }
+}
@@ -0,0 +1,152 @@
+//! This module implements [`EntityCrudTrait`] for [`crate::entity::ingredient_allergen`].
+
+use sea_orm::{
+    sea_query::{IntoCondition, SimpleExpr},
+    ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel, IntoActiveModel, IntoSimpleExpr,
+};
+use serde::Deserialize;
+
+use crate::{
+    entity::ingredient_allergen::{
+        allergen::Allergen, ActiveModel, Column, Entity, Model, PrimaryKey, Relation,
+    },
+    entity_crud::{EntityCrudTrait, Filter, Order, OrderBy, TimestampCondition},
+    event::channel::{
+        ENTITY_ACTION_CREATED_INGREDIENT_ALLERGEN, ENTITY_ACTION_CREATED_MANY_INGREDIENT_ALLERGEN,
+        ENTITY_ACTION_DELETED_INGREDIENT_ALLERGEN, ENTITY_ACTION_DELETED_MANY_INGREDIENT_ALLERGEN,
+        ENTITY_ACTION_UPDATED_INGREDIENT_ALLERGEN, ENTITY_ACTION_UPDATED_MANY_INGREDIENT_ALLERGEN,
+    },
+};
+
+#[derive(Debug, Deserialize, DeriveIntoActiveModel)]
+#[serde(rename_all = "camelCase")]
+pub struct IngredientAllergenCreate {
+    pub ingredient_id: i64,
+    pub allergen: Allergen,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IngredientAllergenUpdate {
+    pub id: i64,
+    pub allergen: Option<Allergen>,
+}
+
+impl IntoActiveModel<ActiveModel> for IngredientAllergenUpdate {
+    fn into_active_model(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Unchanged(self.id),
+            ingredient_id: ActiveValue::NotSet,
+            allergen: match self.allergen {
+                Some(allergen) => ActiveValue::Set(allergen),
+                _ => ActiveValue::NotSet,
+            },
+            created_at: ActiveValue::NotSet,
+            updated_at: ActiveValue::NotSet,
+        }
+    }
+}
+
+pub type IngredientAllergenFilter = Filter<IngredientAllergenCondition, IngredientAllergenOrderBy>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IngredientAllergenCondition {
+    pub ingredient_id: Option<i64>,
+    pub allergen: Option<Allergen>,
+    #[serde(flatten)]
+    pub timestamps: TimestampCondition,
+}
+
+impl IntoCondition for IngredientAllergenCondition {
+    fn into_condition(self) -> Condition {
+        Condition::all()
+            .add(
+                self.timestamps
+                    .into_condition(Column::CreatedAt, Column::UpdatedAt),
+            )
+            .add_option(
+                self.ingredient_id
+                    .map(|ingredient_id| Column::IngredientId.eq(ingredient_id)),
+            )
+            .add_option(self.allergen.map(|allergen| Column::Allergen.eq(allergen)))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IngredientAllergenOrderBy {
+    Allergen(Order),
+    CreatedAt(Order),
+    UpdatedAt(Order),
+}
+
+impl OrderBy for IngredientAllergenOrderBy {
+    type Entity = Entity;
+
+    fn order(self) -> (SimpleExpr, Order) {
+        match self {
+            IngredientAllergenOrderBy::Allergen(order) => {
+                (Column::Allergen.into_simple_expr(), order)
+            }
+            IngredientAllergenOrderBy::CreatedAt(order) => {
+                (Column::CreatedAt.into_simple_expr(), order)
+            }
+            IngredientAllergenOrderBy::UpdatedAt(order) => {
+                (Column::UpdatedAt.into_simple_expr(), order)
+            }
+        }
+    }
+}
+
+pub struct IngredientAllergenCrud {}
+
+impl EntityCrudTrait for IngredientAllergenCrud {
+    type Entity = Entity;
+    type Model = Model;
+    type ActiveModel = ActiveModel;
+    type Column = Column;
+    type Relation = Relation;
+    type PrimaryKey = PrimaryKey;
+    type PrimaryKeyValue = i64;
+    type EntityCreate = IngredientAllergenCreate;
+    type EntityUpdate = IngredientAllergenUpdate;
+    type EntityCondition = IngredientAllergenCondition;
+    type EntityOrderBy = IngredientAllergenOrderBy;
+
+    fn primary_key_value(model: &Model) -> i64 {
+        model.id
+    }
+
+    fn primary_key_colum() -> Column {
+        Column::Id
+    }
+
+    fn parent_keys() -> &'static [&'static str] {
+        &["ingredientId"]
+    }
+
+    fn entity_action_created_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_INGREDIENT_ALLERGEN
+    }
+
+    fn entity_action_updated_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_INGREDIENT_ALLERGEN
+    }
+
+    fn entity_action_deleted_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_INGREDIENT_ALLERGEN
+    }
+
+    fn entity_action_created_many_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_MANY_INGREDIENT_ALLERGEN
+    }
+
+    fn entity_action_updated_many_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_MANY_INGREDIENT_ALLERGEN
+    }
+
+    fn entity_action_deleted_many_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_MANY_INGREDIENT_ALLERGEN
+    }
+}
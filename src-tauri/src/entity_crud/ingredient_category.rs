@@ -0,0 +1,176 @@
+//! This module implements [`EntityCrudTrait`] for [`crate::entity::ingredient_category`].
+
+use anyhow::Result;
+use async_trait::async_trait;
+use sea_orm::{
+    sea_query::{IntoCondition, SimpleExpr},
+    ActiveValue, ColumnTrait, Condition, DatabaseTransaction, DeriveIntoActiveModel, EntityTrait,
+    IntoActiveModel, IntoSimpleExpr, QueryFilter, QueryOrder,
+};
+use serde::Deserialize;
+
+use crate::{
+    entity::{
+        change_log, ingredient,
+        ingredient_category::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
+    },
+    entity_crud::{
+        ingredient::IngredientCrud, EntityCrudTrait, Filter, Order, OrderBy, TimestampCondition,
+    },
+    event::channel::{
+        ENTITY_ACTION_CREATED_INGREDIENT_CATEGORY, ENTITY_ACTION_CREATED_MANY_INGREDIENT_CATEGORY,
+        ENTITY_ACTION_DELETED_INGREDIENT_CATEGORY, ENTITY_ACTION_DELETED_MANY_INGREDIENT_CATEGORY,
+        ENTITY_ACTION_UPDATED_INGREDIENT_CATEGORY, ENTITY_ACTION_UPDATED_MANY_INGREDIENT_CATEGORY,
+    },
+};
+
+#[derive(Debug, Deserialize, DeriveIntoActiveModel)]
+#[serde(rename_all = "camelCase")]
+pub struct IngredientCategoryCreate {
+    pub name: String,
+    pub order: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IngredientCategoryUpdate {
+    pub id: i64,
+    pub name: Option<String>,
+    pub order: Option<i64>,
+}
+
+impl IntoActiveModel<ActiveModel> for IngredientCategoryUpdate {
+    fn into_active_model(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Unchanged(self.id),
+            name: match self.name {
+                Some(name) => ActiveValue::Set(name),
+                _ => ActiveValue::NotSet,
+            },
+            order: match self.order {
+                Some(order) => ActiveValue::Set(order),
+                _ => ActiveValue::NotSet,
+            },
+            created_at: ActiveValue::NotSet,
+            updated_at: ActiveValue::NotSet,
+        }
+    }
+}
+
+pub type IngredientCategoryFilter = Filter<IngredientCategoryCondition, IngredientCategoryOrderBy>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IngredientCategoryCondition {
+    pub name: Option<String>,
+    #[serde(flatten)]
+    pub timestamps: TimestampCondition,
+}
+
+impl IntoCondition for IngredientCategoryCondition {
+    fn into_condition(self) -> Condition {
+        Condition::all()
+            .add(
+                self.timestamps
+                    .into_condition(Column::CreatedAt, Column::UpdatedAt),
+            )
+            .add_option(self.name.map(|name| Column::Name.like(format!("%{name}%"))))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IngredientCategoryOrderBy {
+    Name(Order),
+    Order(Order),
+    CreatedAt(Order),
+    UpdatedAt(Order),
+}
+
+impl OrderBy for IngredientCategoryOrderBy {
+    type Entity = Entity;
+
+    fn order(self) -> (SimpleExpr, Order) {
+        match self {
+            IngredientCategoryOrderBy::Name(order) => (Column::Name.into_simple_expr(), order),
+            IngredientCategoryOrderBy::Order(order) => (Column::Order.into_simple_expr(), order),
+            IngredientCategoryOrderBy::CreatedAt(order) => {
+                (Column::CreatedAt.into_simple_expr(), order)
+            }
+            IngredientCategoryOrderBy::UpdatedAt(order) => {
+                (Column::UpdatedAt.into_simple_expr(), order)
+            }
+        }
+    }
+}
+
+pub struct IngredientCategoryCrud {}
+
+#[async_trait]
+impl EntityCrudTrait for IngredientCategoryCrud {
+    type Entity = Entity;
+    type Model = Model;
+    type ActiveModel = ActiveModel;
+    type Column = Column;
+    type Relation = Relation;
+    type PrimaryKey = PrimaryKey;
+    type PrimaryKeyValue = i64;
+    type EntityCreate = IngredientCategoryCreate;
+    type EntityUpdate = IngredientCategoryUpdate;
+    type EntityCondition = IngredientCategoryCondition;
+    type EntityOrderBy = IngredientCategoryOrderBy;
+
+    fn primary_key_value(model: &Model) -> i64 {
+        model.id
+    }
+
+    fn primary_key_colum() -> Column {
+        Column::Id
+    }
+
+    /// The database would set the category of its ingredients to null, this is done explicitly instead.
+    async fn delete_children(
+        model: &Model,
+        journal_action_id: Option<i64>,
+        txn: &DatabaseTransaction,
+    ) -> Result<Vec<change_log::Model>> {
+        let ingredients = ingredient::Entity::find()
+            .filter(ingredient::Column::IngredientCategoryId.eq(model.id))
+            .order_by_asc(ingredient::Column::Id)
+            .all(txn)
+            .await?;
+        let mut changes = Vec::with_capacity(ingredients.len());
+        for ingredient in ingredients {
+            let mut active_model = ingredient.into_active_model();
+            active_model.ingredient_category_id = ActiveValue::Set(None);
+            let (_, change) =
+                IngredientCrud::update_logged(active_model, journal_action_id, txn).await?;
+            changes.push(change);
+        }
+        Ok(changes)
+    }
+
+    fn entity_action_created_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_INGREDIENT_CATEGORY
+    }
+
+    fn entity_action_updated_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_INGREDIENT_CATEGORY
+    }
+
+    fn entity_action_deleted_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_INGREDIENT_CATEGORY
+    }
+
+    fn entity_action_created_many_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_MANY_INGREDIENT_CATEGORY
+    }
+
+    fn entity_action_updated_many_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_MANY_INGREDIENT_CATEGORY
+    }
+
+    fn entity_action_deleted_many_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_MANY_INGREDIENT_CATEGORY
+    }
+}
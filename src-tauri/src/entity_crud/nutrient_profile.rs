@@ -0,0 +1,262 @@
+//! This module implements [`EntityCrudTrait`] for [`crate::entity::nutrient_profile`].
+
+use sea_orm::{
+    sea_query::{IntoCondition, SimpleExpr},
+    ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel, IntoActiveModel, IntoSimpleExpr,
+};
+use serde::Deserialize;
+
+use crate::{
+    entity::nutrient_profile::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
+    entity_crud::{EntityCrudTrait, Filter, Order, OrderBy, TimestampCondition},
+    event::channel::{
+        ENTITY_ACTION_CREATED_MANY_NUTRIENT_PROFILE, ENTITY_ACTION_CREATED_NUTRIENT_PROFILE,
+        ENTITY_ACTION_DELETED_MANY_NUTRIENT_PROFILE, ENTITY_ACTION_DELETED_NUTRIENT_PROFILE,
+        ENTITY_ACTION_UPDATED_MANY_NUTRIENT_PROFILE, ENTITY_ACTION_UPDATED_NUTRIENT_PROFILE,
+    },
+};
+
+#[derive(Debug, Deserialize, DeriveIntoActiveModel)]
+#[serde(rename_all = "camelCase")]
+pub struct NutrientProfileCreate {
+    pub ingredient_id: i64,
+    pub kcal: Option<f64>,
+    pub protein: Option<f64>,
+    pub fat: Option<f64>,
+    pub carbohydrate: Option<f64>,
+    pub fibre: Option<f64>,
+    pub salt: Option<f64>,
+    pub density: Option<f64>,
+    pub piece_mass: Option<f64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NutrientProfileUpdate {
+    pub id: i64,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub kcal: Option<Option<f64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub protein: Option<Option<f64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub fat: Option<Option<f64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub carbohydrate: Option<Option<f64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub fibre: Option<Option<f64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub salt: Option<Option<f64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub density: Option<Option<f64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub piece_mass: Option<Option<f64>>,
+}
+
+impl IntoActiveModel<ActiveModel> for NutrientProfileUpdate {
+    fn into_active_model(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Unchanged(self.id),
+            ingredient_id: ActiveValue::NotSet,
+            kcal: match self.kcal {
+                Some(kcal) => ActiveValue::Set(kcal),
+                _ => ActiveValue::NotSet,
+            },
+            protein: match self.protein {
+                Some(protein) => ActiveValue::Set(protein),
+                _ => ActiveValue::NotSet,
+            },
+            fat: match self.fat {
+                Some(fat) => ActiveValue::Set(fat),
+                _ => ActiveValue::NotSet,
+            },
+            carbohydrate: match self.carbohydrate {
+                Some(carbohydrate) => ActiveValue::Set(carbohydrate),
+                _ => ActiveValue::NotSet,
+            },
+            fibre: match self.fibre {
+                Some(fibre) => ActiveValue::Set(fibre),
+                _ => ActiveValue::NotSet,
+            },
+            salt: match self.salt {
+                Some(salt) => ActiveValue::Set(salt),
+                _ => ActiveValue::NotSet,
+            },
+            density: match self.density {
+                Some(density) => ActiveValue::Set(density),
+                _ => ActiveValue::NotSet,
+            },
+            piece_mass: match self.piece_mass {
+                Some(piece_mass) => ActiveValue::Set(piece_mass),
+                _ => ActiveValue::NotSet,
+            },
+            created_at: ActiveValue::NotSet,
+            updated_at: ActiveValue::NotSet,
+        }
+    }
+}
+
+pub type NutrientProfileFilter = Filter<NutrientProfileCondition, NutrientProfileOrderBy>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NutrientProfileCondition {
+    pub ingredient_id: Option<i64>,
+    #[serde(flatten)]
+    pub timestamps: TimestampCondition,
+}
+
+impl IntoCondition for NutrientProfileCondition {
+    fn into_condition(self) -> Condition {
+        Condition::all()
+            .add(
+                self.timestamps
+                    .into_condition(Column::CreatedAt, Column::UpdatedAt),
+            )
+            .add_option(
+                self.ingredient_id
+                    .map(|ingredient_id| Column::IngredientId.eq(ingredient_id)),
+            )
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum NutrientProfileOrderBy {
+    Kcal(Order),
+    CreatedAt(Order),
+    UpdatedAt(Order),
+}
+
+impl OrderBy for NutrientProfileOrderBy {
+    type Entity = Entity;
+
+    fn order(self) -> (SimpleExpr, Order) {
+        match self {
+            NutrientProfileOrderBy::Kcal(order) => {
+                (order.nulls_last(Column::Kcal.into_simple_expr()), order)
+            }
+            NutrientProfileOrderBy::CreatedAt(order) => {
+                (Column::CreatedAt.into_simple_expr(), order)
+            }
+            NutrientProfileOrderBy::UpdatedAt(order) => {
+                (Column::UpdatedAt.into_simple_expr(), order)
+            }
+        }
+    }
+}
+
+pub struct NutrientProfileCrud {}
+
+impl EntityCrudTrait for NutrientProfileCrud {
+    type Entity = Entity;
+    type Model = Model;
+    type ActiveModel = ActiveModel;
+    type Column = Column;
+    type Relation = Relation;
+    type PrimaryKey = PrimaryKey;
+    type PrimaryKeyValue = i64;
+    type EntityCreate = NutrientProfileCreate;
+    type EntityUpdate = NutrientProfileUpdate;
+    type EntityCondition = NutrientProfileCondition;
+    type EntityOrderBy = NutrientProfileOrderBy;
+
+    fn primary_key_value(model: &Model) -> i64 {
+        model.id
+    }
+
+    fn primary_key_colum() -> Column {
+        Column::Id
+    }
+
+    fn parent_keys() -> &'static [&'static str] {
+        &["ingredientId"]
+    }
+
+    fn entity_action_created_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_NUTRIENT_PROFILE
+    }
+
+    fn entity_action_updated_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_NUTRIENT_PROFILE
+    }
+
+    fn entity_action_deleted_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_NUTRIENT_PROFILE
+    }
+
+    fn entity_action_created_many_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_MANY_NUTRIENT_PROFILE
+    }
+
+    fn entity_action_updated_many_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_MANY_NUTRIENT_PROFILE
+    }
+
+    fn entity_action_deleted_many_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_MANY_NUTRIENT_PROFILE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use sea_orm::{ActiveModelTrait, EntityTrait, QuerySelect};
+
+    use super::*;
+    use crate::{
+        entity::ingredient, entity_crud::IdColumn, migrator::tests::get_memory_database_migrated,
+    };
+
+    #[tokio::test]
+    async fn test_order_by_kcal() {
+        let db = get_memory_database_migrated().await;
+        for (name, kcal) in [("milk", Some(64.0)), ("salt", None), ("apple", Some(52.0))] {
+            let ingredient = ingredient::ActiveModel {
+                name: ActiveValue::Set(name.to_string()),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+            ActiveModel {
+                ingredient_id: ActiveValue::Set(ingredient.id),
+                kcal: ActiveValue::Set(kcal),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        let list_ids = |order: Order, after: Option<i64>| {
+            NutrientProfileCrud::paginate(
+                Entity::find().select_only().column_as(Column::Id, "id"),
+                vec![NutrientProfileOrderBy::Kcal(order)],
+                None,
+                None,
+                after,
+            )
+            .into_model::<IdColumn<i64>>()
+            .all(&db)
+        };
+        let ids = |id_columns: Vec<IdColumn<i64>>| -> Vec<i64> {
+            id_columns
+                .into_iter()
+                .map(|id_column| id_column.id)
+                .collect()
+        };
+        assert_eq!(
+            ids(list_ids(Order::Asc, None).await.unwrap()),
+            vec![3, 1, 2]
+        );
+        assert_eq!(
+            ids(list_ids(Order::Desc, None).await.unwrap()),
+            vec![1, 3, 2]
+        );
+        assert_eq!(
+            ids(list_ids(Order::Asc, Some(3)).await.unwrap()),
+            vec![1, 2]
+        );
+        assert_eq!(ids(list_ids(Order::Desc, Some(3)).await.unwrap()), vec![2]);
+        assert!(ids(list_ids(Order::Desc, Some(2)).await.unwrap()).is_empty());
+    }
+}
@@ -1,23 +1,62 @@
 //! This module implements [`EntityCrudTrait`] for [`crate::entity::recipe`].
 
+use std::collections::{BTreeSet, HashMap};
+
+use anyhow::Result;
+use async_trait::async_trait;
 use sea_orm::{
-    sea_query::IntoCondition, ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel,
-    IntoActiveModel, QueryOrder, Select,
+    sea_query::{Expr, Func, IntoCondition, Query, SelectStatement, SimpleExpr},
+    ActiveValue, ColumnTrait, Condition, ConnectionTrait, DatabaseTransaction,
+    DeriveIntoActiveModel, EntityTrait,
+    IntoActiveModel, IntoSimpleExpr, QueryFilter, QueryOrder, QuerySelect, QueryTrait,
+    TransactionTrait,
 };
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    entity::recipe::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
-    entity_crud::{EntityCrudTrait, Filter, Order, OrderBy},
+    database,
+    dietary::{allergen_recipe_ids, incompatible_recipe_ids, Diet},
+    entity::{
+        change_log, collection_recipe, cook_log, file,
+        ingredient_allergen::allergen::Allergen,
+        recipe::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
+        recipe_file, recipe_ingredient_draft, recipe_step, recipe_tag,
+    },
+    entity_crud::{
+        collection_recipe::CollectionRecipeCrud,
+        cook_log::CookLogCrud,
+        file::FileCrud,
+        recipe_file::{RecipeFileCreate, RecipeFileCrud},
+        recipe_ingredient_draft::{RecipeIngredientDraftCreate, RecipeIngredientDraftCrud},
+        recipe_step::{RecipeStepCreate, RecipeStepCrud},
+        recipe_step_file::{RecipeStepFileCreate, RecipeStepFileCrud},
+        recipe_step_ingredient::RecipeStepIngredientCrud,
+        recipe_step_ingredient_draft::{
+            RecipeStepIngredientDraftCreate, RecipeStepIngredientDraftCrud,
+        },
+        recipe_tag::RecipeTagCrud,
+        EntityCrudTrait, Filter, Order, OrderBy, TimestampCondition,
+    },
     event::channel::{
-        ENTITY_ACTION_CREATED_RECIPE, ENTITY_ACTION_DELETED_RECIPE, ENTITY_ACTION_UPDATED_RECIPE,
+        ENTITY_ACTION_CREATED_MANY_RECIPE, ENTITY_ACTION_CREATED_RECIPE,
+        ENTITY_ACTION_DELETED_MANY_RECIPE, ENTITY_ACTION_DELETED_RECIPE,
+        ENTITY_ACTION_UPDATED_MANY_RECIPE, ENTITY_ACTION_UPDATED_RECIPE,
     },
+    journal,
+    recipe_revision::{snapshot, RecipeSnapshot},
+    settings,
+    timestamp::unix_timestamp,
+    window::get_window,
 };
 
 #[derive(Debug, Deserialize, DeriveIntoActiveModel)]
 #[serde(rename_all = "camelCase")]
 pub struct RecipeCreate {
     pub name: String,
+    pub prep_time: Option<i64>,
+    pub cook_time: Option<i64>,
+    pub rest_time: Option<i64>,
+    pub total_time: Option<i64>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -25,6 +64,14 @@ pub struct RecipeCreate {
 pub struct RecipeUpdate {
     pub id: i64,
     pub name: Option<String>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub prep_time: Option<Option<i64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub cook_time: Option<Option<i64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub rest_time: Option<Option<i64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub total_time: Option<Option<i64>>,
 }
 
 impl IntoActiveModel<ActiveModel> for RecipeUpdate {
@@ -35,42 +82,712 @@ impl IntoActiveModel<ActiveModel> for RecipeUpdate {
                 Some(name) => ActiveValue::Set(name),
                 _ => ActiveValue::NotSet,
             },
+            deleted_at: ActiveValue::NotSet,
+            forked_from_id: ActiveValue::NotSet,
+            prep_time: match self.prep_time {
+                Some(prep_time) => ActiveValue::Set(prep_time),
+                _ => ActiveValue::NotSet,
+            },
+            cook_time: match self.cook_time {
+                Some(cook_time) => ActiveValue::Set(cook_time),
+                _ => ActiveValue::NotSet,
+            },
+            rest_time: match self.rest_time {
+                Some(rest_time) => ActiveValue::Set(rest_time),
+                _ => ActiveValue::NotSet,
+            },
+            total_time: match self.total_time {
+                Some(total_time) => ActiveValue::Set(total_time),
+                _ => ActiveValue::NotSet,
+            },
+            created_at: ActiveValue::NotSet,
+            updated_at: ActiveValue::NotSet,
         }
     }
 }
 
+/// This struct represents a recipe to import together with its children, see [`RecipeCrud::import`].
+#[derive(Debug)]
+pub struct RecipeImport {
+    pub recipe: RecipeCreate,
+    /// the texts of the recipe's ingredient drafts
+    pub ingredients: Vec<String>,
+    /// the files to attach to the recipe
+    pub files: Vec<RecipeImportFile>,
+    pub steps: Vec<RecipeStepImport>,
+}
+
+/// This struct represents a recipe step to import together with its children, see [`RecipeImport`].
+#[derive(Debug)]
+pub struct RecipeStepImport {
+    pub description: String,
+    /// the texts of the recipe step's ingredient drafts
+    pub ingredients: Vec<String>,
+    /// the files to attach to the recipe step
+    pub files: Vec<RecipeImportFile>,
+}
+
+/// This enum represents a file to attach to an imported recipe or recipe step, see [`RecipeImport`].
+#[derive(Debug)]
+pub enum RecipeImportFile {
+    /// the id of an already existing file
+    Existing(i64),
+    /// a file which is created with the recipe
+    Created(file::ActiveModel),
+}
+
+/// This struct represents an exported recipe.
+///
+/// The files attached to the recipe and its steps are included as entities.
+/// Their contents are not embedded but referenced by their path in the [`crate::file_storage`].
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeExport {
+    #[serde(flatten)]
+    pub snapshot: RecipeSnapshot,
+    pub files: Vec<file::Model>,
+}
+
 pub type RecipeFilter = Filter<RecipeCondition, RecipeOrderBy>;
 
-#[derive(Debug, Deserialize)]
+/// The condition to filter recipes by.
+///
+/// Only recipes in the trash are matched when `trashed` is true, otherwise only recipes not in the trash.
+/// The tag filters match recipes tagged with all, any or none of the given [tags](crate::entity::tag::Model).
+/// `collection_id` matches the recipes in a [collection](crate::entity::collection::Model).
+/// `cooked_since` and `not_cooked_since` are unix timestamps matching recipes with or without a [cook log](crate::entity::cook_log::Model) since then,
+/// so recipes which were never cooked are matched by `not_cooked_since`.
+/// `max_total_time` and `max_active_time` are seconds matching recipes whose total or preparation time is at most that long,
+/// recipes without the respective time are not matched.
+/// `exclude_allergens` matches recipes whose ingredients contain none of the given [allergens](crate::entity::ingredient_allergen::allergen::Allergen),
+/// `diets` matches recipes whose ingredients are all known to be compatible with each of the given [diets](Diet).
+#[derive(Debug, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RecipeCondition {
     pub name: Option<String>,
+    pub trashed: Option<bool>,
+    pub tags_all: Option<Vec<i64>>,
+    pub tags_any: Option<Vec<i64>>,
+    pub tags_none: Option<Vec<i64>>,
+    pub collection_id: Option<i64>,
+    pub cooked_since: Option<i64>,
+    pub not_cooked_since: Option<i64>,
+    pub max_total_time: Option<i64>,
+    pub max_active_time: Option<i64>,
+    pub exclude_allergens: Option<Vec<Allergen>>,
+    pub diets: Option<Vec<Diet>>,
+    #[serde(flatten)]
+    pub timestamps: TimestampCondition,
 }
 
 impl IntoCondition for RecipeCondition {
     fn into_condition(self) -> Condition {
-        Condition::all().add_option(self.name.map(|name| Column::Name.like(format!("%{name}%"))))
+        Condition::all()
+            .add(
+                self.timestamps
+                    .into_condition(Column::CreatedAt, Column::UpdatedAt),
+            )
+            .add_option(self.name.map(|name| Column::Name.like(format!("%{name}%"))))
+            .add(if self.trashed.unwrap_or_default() {
+                Column::DeletedAt.is_not_null()
+            } else {
+                Column::DeletedAt.is_null()
+            })
+            .add_option(self.tags_all.map(|tag_ids| {
+                tag_ids
+                    .into_iter()
+                    .fold(Condition::all(), |condition, tag_id| {
+                        condition.add(Column::Id.in_subquery(tagged_recipe_ids(vec![tag_id])))
+                    })
+            }))
+            .add_option(
+                self.tags_any
+                    .map(|tag_ids| Column::Id.in_subquery(tagged_recipe_ids(tag_ids))),
+            )
+            .add_option(
+                self.tags_none
+                    .map(|tag_ids| Column::Id.not_in_subquery(tagged_recipe_ids(tag_ids))),
+            )
+            .add_option(
+                self.cooked_since
+                    .map(|timestamp| Column::Id.in_subquery(cooked_recipe_ids(timestamp))),
+            )
+            .add_option(
+                self.not_cooked_since
+                    .map(|timestamp| Column::Id.not_in_subquery(cooked_recipe_ids(timestamp))),
+            )
+            .add_option(
+                self.max_total_time
+                    .map(|max_total_time| Column::TotalTime.lte(max_total_time)),
+            )
+            .add_option(
+                self.max_active_time
+                    .map(|max_active_time| Column::PrepTime.lte(max_active_time)),
+            )
+            .add_option(
+                self.exclude_allergens
+                    .map(|allergens| Column::Id.not_in_subquery(allergen_recipe_ids(allergens))),
+            )
+            .add_option(self.diets.map(|diets| {
+                diets.into_iter().fold(Condition::all(), |condition, diet| {
+                    condition.add(Column::Id.not_in_subquery(incompatible_recipe_ids(diet)))
+                })
+            }))
+            .add_option(self.collection_id.map(|collection_id| {
+                Column::Id.in_subquery(
+                    collection_recipe::Entity::find()
+                        .select_only()
+                        .column(collection_recipe::Column::RecipeId)
+                        .filter(collection_recipe::Column::CollectionId.eq(collection_id))
+                        .into_query(),
+                )
+            }))
     }
 }
 
+/// Select the ids of the recipes cooked at or after the given unix timestamp.
+fn cooked_recipe_ids(timestamp: i64) -> SelectStatement {
+    cook_log::Entity::find()
+        .select_only()
+        .column(cook_log::Column::RecipeId)
+        .filter(cook_log::Column::CookedAt.gte(timestamp))
+        .into_query()
+}
+
+/// Select the ids of the recipes tagged with any of the given tags.
+fn tagged_recipe_ids(tag_ids: Vec<i64>) -> SelectStatement {
+    recipe_tag::Entity::find()
+        .select_only()
+        .column(recipe_tag::Column::RecipeId)
+        .filter(recipe_tag::Column::TagId.is_in(tag_ids))
+        .into_query()
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum RecipeOrderBy {
     Name(Order),
+    LastCooked(Order),
+    TimesCooked(Order),
+    AverageRating(Order),
+    TotalTime(Order),
+    ActiveTime(Order),
+    CreatedAt(Order),
+    UpdatedAt(Order),
 }
 
 impl OrderBy for RecipeOrderBy {
     type Entity = Entity;
 
-    fn add(self, select: Select<Self::Entity>) -> Select<Self::Entity> {
+    fn order(self) -> (SimpleExpr, Order) {
         match self {
-            RecipeOrderBy::Name(order) => select.order_by(Column::Name, order.into()),
+            RecipeOrderBy::Name(order) => (Column::Name.into_simple_expr(), order),
+            RecipeOrderBy::LastCooked(order) => (
+                cook_log_aggregate(Func::max(Expr::col(cook_log::Column::CookedAt)).into()),
+                order,
+            ),
+            RecipeOrderBy::TimesCooked(order) => (
+                cook_log_aggregate(Func::count(Expr::col(cook_log::Column::Id)).into()),
+                order,
+            ),
+            RecipeOrderBy::AverageRating(order) => (
+                cook_log_aggregate(Func::avg(Expr::col(cook_log::Column::Rating)).into()),
+                order,
+            ),
+            RecipeOrderBy::TotalTime(order) => (
+                order.nulls_last(Column::TotalTime.into_simple_expr()),
+                order,
+            ),
+            RecipeOrderBy::ActiveTime(order) => {
+                (order.nulls_last(Column::PrepTime.into_simple_expr()), order)
+            }
+            RecipeOrderBy::CreatedAt(order) => (Column::CreatedAt.into_simple_expr(), order),
+            RecipeOrderBy::UpdatedAt(order) => (Column::UpdatedAt.into_simple_expr(), order),
         }
     }
 }
 
+/// Aggregate the [cook logs](crate::entity::cook_log::Model) of the recipe in the outer query.
+///
+/// Recipes without cook logs aggregate to zero instead of null, so that they can be compared by the keyset cursor.
+fn cook_log_aggregate(aggregate: SimpleExpr) -> SimpleExpr {
+    Func::coalesce([
+        SimpleExpr::SubQuery(
+            None,
+            Box::new(
+                Query::select()
+                    .expr(aggregate)
+                    .from(cook_log::Entity)
+                    .and_where(
+                        Expr::col((cook_log::Entity, cook_log::Column::RecipeId))
+                            .equals((Entity, Column::Id)),
+                    )
+                    .to_owned()
+                    .into_sub_query_statement(),
+            ),
+        ),
+        Expr::val(0).into(),
+    ])
+    .into()
+}
+
+/// Read a snapshot of a recipe together with its files, see [`RecipeExport`].
+pub async fn read_export<C>(id: i64, db: &C) -> Result<Option<RecipeExport>>
+where
+    C: ConnectionTrait,
+{
+    let Some(snapshot) = snapshot(id, db).await? else {
+        return Ok(None);
+    };
+    let file_ids: BTreeSet<i64> = snapshot
+        .recipe_step_files
+        .iter()
+        .map(|recipe_step_file| recipe_step_file.file_id)
+        .chain(
+            snapshot
+                .recipe_files
+                .iter()
+                .map(|recipe_file| recipe_file.file_id),
+        )
+        .collect();
+    let files = file::Entity::find()
+        .filter(file::Column::Id.is_in(file_ids))
+        .order_by_asc(file::Column::Id)
+        .all(db)
+        .await?;
+    Ok(Some(RecipeExport { snapshot, files }))
+}
+
 pub struct RecipeCrud {}
 
+impl RecipeCrud {
+    /// Export a recipe with its children and files, see [`RecipeExport`].
+    ///
+    /// Returns [`None`] when the recipe does not exist.
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database
+    pub async fn export(id: i64) -> Result<Option<RecipeExport>> {
+        let db = database::connect().await;
+        read_export(id, db).await
+    }
+
+    /// Move a recipe into the trash, hiding it from lists and counts.
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database, e.g. when the recipe does not exist
+    /// - when the updated recipe can't be serialized for the change log
+    /// - when the tauri window can't be messaged about the updated recipe
+    pub async fn trash(id: i64) -> Result<Model> {
+        Self::set_deleted_at(id, Some(unix_timestamp()?)).await
+    }
+
+    /// Move multiple recipes into the trash atomically, as one undoable journal action.
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database, e.g. when a recipe does not exist, no recipe is moved then
+    /// - when an updated recipe can't be serialized for the change log
+    /// - when the tauri window can't be messaged about the updated recipes
+    pub async fn trash_many(ids: Vec<i64>) -> Result<Vec<Model>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let deleted_at = unix_timestamp()?;
+        let db = database::connect_writing().await;
+        let txn = db.begin().await?;
+        let journal_action_id = journal::record_action(&txn).await?;
+        let mut models = Vec::with_capacity(ids.len());
+        let mut changes = Vec::with_capacity(ids.len());
+        for id in ids {
+            let (model, change) =
+                Self::set_deleted_at_logged(id, Some(deleted_at), journal_action_id, &txn).await?;
+            models.push(model);
+            changes.push(change);
+        }
+        txn.commit().await?;
+        get_window().emit(Self::entity_action_updated_many_channel(), changes)?;
+        Ok(models)
+    }
+
+    /// Restore a recipe from the trash.
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database, e.g. when the recipe does not exist
+    /// - when the updated recipe can't be serialized for the change log
+    /// - when the tauri window can't be messaged about the updated recipe
+    pub async fn restore(id: i64) -> Result<Model> {
+        Self::set_deleted_at(id, None).await
+    }
+
+    /// Purge recipes from the trash, deleting them and their children for good.
+    ///
+    /// Recipes which are not in the trash are skipped.
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::purge_all`] and [`FileCrud::collect_garbage`].
+    pub async fn purge(ids: Vec<i64>) -> Result<()> {
+        Self::purge_all(Column::Id.is_in(ids).into_condition()).await?;
+        FileCrud::collect_garbage().await
+    }
+
+    /// Purge all recipes from the trash, or only those which have been in it for more than the given number of days.
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::purge_all`] and [`FileCrud::collect_garbage`].
+    pub async fn purge_trash(older_than_days: Option<u64>) -> Result<()> {
+        let condition = match older_than_days {
+            Some(days) => {
+                let deleted_before =
+                    unix_timestamp()? - i64::try_from(days.saturating_mul(24 * 60 * 60))?;
+                Column::DeletedAt.lt(deleted_before).into_condition()
+            }
+            None => Condition::all(),
+        };
+        Self::purge_all(condition).await?;
+        FileCrud::collect_garbage().await
+    }
+
+    /// Purge the recipes which have been in the trash for longer than the retention of the settings.
+    ///
+    /// Without a retention, no recipe is purged, but the garbage of files is still collected.
+    ///
+    /// # Errors
+    ///
+    /// - when the settings can't be read
+    /// - see [`Self::purge_trash`]
+    pub async fn purge_expired() -> Result<()> {
+        match settings::get().await?.trash_retention_days {
+            Some(days) => Self::purge_trash(Some(days)).await,
+            None => FileCrud::collect_garbage().await,
+        }
+    }
+
+    /// Duplicate a recipe with its steps, step ingredients, drafts, file links and tags atomically.
+    ///
+    /// Files are shared by reference, not copied.
+    /// The copy gets the given name or the original one, and records the original recipe as its fork origin when `fork` is true.
+    /// Returns [`None`] when the recipe does not exist.
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database, nothing is duplicated then
+    /// - when a created entity can't be serialized for the change log
+    /// - when the tauri window can't be messaged about the created entities
+    pub async fn duplicate(id: i64, name: Option<String>, fork: bool) -> Result<Option<i64>> {
+        let db = database::connect_writing().await;
+        let txn = db.begin().await?;
+        let Some(original) = snapshot(id, &txn).await? else {
+            return Ok(None);
+        };
+        let journal_action_id = journal::record_action(&txn).await?;
+        let (id, changes) =
+            Self::duplicate_logged(original, name, fork, journal_action_id, &txn).await?;
+        txn.commit().await?;
+        journal::emit(&changes)?;
+        Ok(Some(id))
+    }
+
+    /// Create a copy of a recipe snapshot inside a transaction and log the changes.
+    ///
+    /// Returns the id of the copy and the changes, the copy's change first.
+    async fn duplicate_logged(
+        original: RecipeSnapshot,
+        name: Option<String>,
+        fork: bool,
+        journal_action_id: i64,
+        txn: &DatabaseTransaction,
+    ) -> Result<(i64, Vec<change_log::Model>)> {
+        let journal_action_id = Some(journal_action_id);
+        let mut changes = Vec::new();
+        let (recipe, change) = Self::create_logged(
+            ActiveModel {
+                id: ActiveValue::NotSet,
+                name: ActiveValue::Set(name.unwrap_or(original.recipe.name)),
+                deleted_at: ActiveValue::NotSet,
+                forked_from_id: ActiveValue::Set(fork.then_some(original.recipe.id)),
+                prep_time: ActiveValue::Set(original.recipe.prep_time),
+                cook_time: ActiveValue::Set(original.recipe.cook_time),
+                rest_time: ActiveValue::Set(original.recipe.rest_time),
+                total_time: ActiveValue::Set(original.recipe.total_time),
+                created_at: ActiveValue::NotSet,
+                updated_at: ActiveValue::NotSet,
+            },
+            journal_action_id,
+            txn,
+        )
+        .await?;
+        changes.push(change);
+        let mut recipe_step_ids = HashMap::new();
+        for model in original.recipe_steps {
+            let original_id = model.id;
+            let mut active_model = RecipeStepCrud::active_model_set(model);
+            active_model.id = ActiveValue::NotSet;
+            active_model.recipe_id = ActiveValue::Set(recipe.id);
+            let (recipe_step, change) =
+                RecipeStepCrud::create_logged(active_model, journal_action_id, txn).await?;
+            recipe_step_ids.insert(original_id, recipe_step.id);
+            changes.push(change);
+        }
+        for model in original.recipe_step_ingredients {
+            let recipe_step_id = recipe_step_ids[&model.recipe_step_id];
+            let mut active_model = RecipeStepIngredientCrud::active_model_set(model);
+            active_model.id = ActiveValue::NotSet;
+            active_model.recipe_step_id = ActiveValue::Set(recipe_step_id);
+            let (_, change) =
+                RecipeStepIngredientCrud::create_logged(active_model, journal_action_id, txn)
+                    .await?;
+            changes.push(change);
+        }
+        for model in original.recipe_step_ingredient_drafts {
+            let recipe_step_id = recipe_step_ids[&model.recipe_step_id];
+            let mut active_model = RecipeStepIngredientDraftCrud::active_model_set(model);
+            active_model.id = ActiveValue::NotSet;
+            active_model.recipe_step_id = ActiveValue::Set(recipe_step_id);
+            let (_, change) =
+                RecipeStepIngredientDraftCrud::create_logged(active_model, journal_action_id, txn)
+                    .await?;
+            changes.push(change);
+        }
+        for model in original.recipe_step_files {
+            let recipe_step_id = recipe_step_ids[&model.recipe_step_id];
+            let mut active_model = RecipeStepFileCrud::active_model_set(model);
+            active_model.id = ActiveValue::NotSet;
+            active_model.recipe_step_id = ActiveValue::Set(recipe_step_id);
+            let (_, change) =
+                RecipeStepFileCrud::create_logged(active_model, journal_action_id, txn).await?;
+            changes.push(change);
+        }
+        for model in original.recipe_files {
+            let mut active_model = RecipeFileCrud::active_model_set(model);
+            active_model.id = ActiveValue::NotSet;
+            active_model.recipe_id = ActiveValue::Set(recipe.id);
+            let (_, change) =
+                RecipeFileCrud::create_logged(active_model, journal_action_id, txn).await?;
+            changes.push(change);
+        }
+        for model in original.recipe_ingredient_drafts {
+            let mut active_model = RecipeIngredientDraftCrud::active_model_set(model);
+            active_model.id = ActiveValue::NotSet;
+            active_model.recipe_id = ActiveValue::Set(recipe.id);
+            let (_, change) =
+                RecipeIngredientDraftCrud::create_logged(active_model, journal_action_id, txn)
+                    .await?;
+            changes.push(change);
+        }
+        let recipe_tags = recipe_tag::Entity::find()
+            .filter(recipe_tag::Column::RecipeId.eq(original.recipe.id))
+            .order_by_asc(recipe_tag::Column::Id)
+            .all(txn)
+            .await?;
+        for model in recipe_tags {
+            let mut active_model = RecipeTagCrud::active_model_set(model);
+            active_model.id = ActiveValue::NotSet;
+            active_model.recipe_id = ActiveValue::Set(recipe.id);
+            let (_, change) =
+                RecipeTagCrud::create_logged(active_model, journal_action_id, txn).await?;
+            changes.push(change);
+        }
+        Ok((recipe.id, changes))
+    }
+
+    /// Import a recipe with its ingredient drafts, files and steps atomically, as one journal action.
+    ///
+    /// The children are ordered as given.
+    /// Returns the id of the new recipe.
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database, nothing is imported then
+    /// - when a created entity can't be serialized for the change log
+    /// - when the tauri window can't be messaged about the created entities
+    pub async fn import(import: RecipeImport) -> Result<i64> {
+        let db = database::connect_writing().await;
+        let txn = db.begin().await?;
+        let journal_action_id = journal::record_action(&txn).await?;
+        let (id, changes) = Self::import_logged(import, journal_action_id, &txn).await?;
+        txn.commit().await?;
+        journal::emit(&changes)?;
+        Ok(id)
+    }
+
+    /// Create an imported recipe inside a transaction and log the changes.
+    ///
+    /// Returns the id of the recipe and the changes, the recipe's change first.
+    async fn import_logged(
+        import: RecipeImport,
+        journal_action_id: i64,
+        txn: &DatabaseTransaction,
+    ) -> Result<(i64, Vec<change_log::Model>)> {
+        let journal_action_id = Some(journal_action_id);
+        let mut changes = Vec::new();
+        let (recipe, change) =
+            Self::create_logged(import.recipe.into_active_model(), journal_action_id, txn).await?;
+        changes.push(change);
+        for (order, text) in (1..).zip(import.ingredients) {
+            let active_model = RecipeIngredientDraftCreate {
+                order,
+                text,
+                recipe_id: recipe.id,
+            }
+            .into_active_model();
+            let (_, change) =
+                RecipeIngredientDraftCrud::create_logged(active_model, journal_action_id, txn)
+                    .await?;
+            changes.push(change);
+        }
+        for (order, file) in (1..).zip(import.files) {
+            let file_id =
+                Self::import_file_logged(file, journal_action_id, txn, &mut changes).await?;
+            let active_model = RecipeFileCreate {
+                order,
+                recipe_id: recipe.id,
+                file_id,
+            }
+            .into_active_model();
+            let (_, change) =
+                RecipeFileCrud::create_logged(active_model, journal_action_id, txn).await?;
+            changes.push(change);
+        }
+        for (order, step) in (1..).zip(import.steps) {
+            let active_model = RecipeStepCreate {
+                order,
+                description: step.description,
+                duration: None,
+                recipe_id: recipe.id,
+            }
+            .into_active_model();
+            let (recipe_step, change) =
+                RecipeStepCrud::create_logged(active_model, journal_action_id, txn).await?;
+            changes.push(change);
+            for (order, text) in (1..).zip(step.ingredients) {
+                let active_model = RecipeStepIngredientDraftCreate {
+                    order,
+                    text,
+                    recipe_step_id: recipe_step.id,
+                }
+                .into_active_model();
+                let (_, change) = RecipeStepIngredientDraftCrud::create_logged(
+                    active_model,
+                    journal_action_id,
+                    txn,
+                )
+                .await?;
+                changes.push(change);
+            }
+            for (order, file) in (1..).zip(step.files) {
+                let file_id =
+                    Self::import_file_logged(file, journal_action_id, txn, &mut changes).await?;
+                let active_model = RecipeStepFileCreate {
+                    order,
+                    recipe_step_id: recipe_step.id,
+                    file_id,
+                }
+                .into_active_model();
+                let (_, change) =
+                    RecipeStepFileCrud::create_logged(active_model, journal_action_id, txn).await?;
+                changes.push(change);
+            }
+        }
+        Ok((recipe.id, changes))
+    }
+
+    /// Get the id of a file to attach to an imported recipe, creating it inside the transaction if necessary.
+    async fn import_file_logged(
+        file: RecipeImportFile,
+        journal_action_id: Option<i64>,
+        txn: &DatabaseTransaction,
+        changes: &mut Vec<change_log::Model>,
+    ) -> Result<i64> {
+        match file {
+            RecipeImportFile::Existing(id) => Ok(id),
+            RecipeImportFile::Created(active_model) => {
+                let (file, change) =
+                    FileCrud::create_logged(active_model, journal_action_id, txn).await?;
+                changes.push(change);
+                Ok(file.id)
+            }
+        }
+    }
+
+    /// Set the deletion timestamp of a recipe, as an undoable journal action.
+    async fn set_deleted_at(id: i64, deleted_at: Option<i64>) -> Result<Model> {
+        let db = database::connect_writing().await;
+        let txn = db.begin().await?;
+        let journal_action_id = journal::record_action(&txn).await?;
+        let (model, change) =
+            Self::set_deleted_at_logged(id, deleted_at, journal_action_id, &txn).await?;
+        txn.commit().await?;
+        get_window().emit(Self::entity_action_updated_channel(), change)?;
+        Ok(model)
+    }
+
+    /// Set the deletion timestamp of a recipe inside a transaction and log the change.
+    async fn set_deleted_at_logged(
+        id: i64,
+        deleted_at: Option<i64>,
+        journal_action_id: i64,
+        txn: &DatabaseTransaction,
+    ) -> Result<(Model, change_log::Model)> {
+        let active_model = ActiveModel {
+            id: ActiveValue::Unchanged(id),
+            name: ActiveValue::NotSet,
+            deleted_at: ActiveValue::Set(deleted_at),
+            forked_from_id: ActiveValue::NotSet,
+            prep_time: ActiveValue::NotSet,
+            cook_time: ActiveValue::NotSet,
+            rest_time: ActiveValue::NotSet,
+            total_time: ActiveValue::NotSet,
+            created_at: ActiveValue::NotSet,
+            updated_at: ActiveValue::NotSet,
+        };
+        Self::update_logged(active_model, Some(journal_action_id), txn).await
+    }
+
+    /// Delete the recipes in the trash matching the condition and their children atomically.
+    ///
+    /// As that can't be undone, the deletions are not journaled and the journal actions which changed the deleted entities are dropped.
+    /// The files which are not used anymore are left to the garbage collection, see [`FileCrud::collect_garbage`].
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database, no recipe is purged then
+    /// - when a deleted entity can't be serialized for the change log
+    /// - when the tauri window can't be messaged about the deleted entities
+    async fn purge_all(condition: Condition) -> Result<()> {
+        let db = database::connect_writing().await;
+        let txn = db.begin().await?;
+        let models = Entity::find()
+            .filter(Column::DeletedAt.is_not_null())
+            .filter(condition)
+            .all(&txn)
+            .await?;
+        if models.is_empty() {
+            return Ok(());
+        }
+        let mut cascaded_changes = Vec::new();
+        let mut changes = Vec::with_capacity(models.len());
+        for model in models {
+            let mut entity_changes = Self::delete_model_logged(model, None, &txn).await?;
+            journal::forget(&entity_changes, &txn).await?;
+            if let Some(change) = entity_changes.pop() {
+                changes.push(change);
+            }
+            cascaded_changes.append(&mut entity_changes);
+        }
+        txn.commit().await?;
+        journal::emit(&cascaded_changes)?;
+        get_window().emit(Self::entity_action_deleted_many_channel(), changes)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
 impl EntityCrudTrait for RecipeCrud {
     type Entity = Entity;
     type Model = Model;
@@ -92,6 +809,80 @@ impl EntityCrudTrait for RecipeCrud {
         Column::Id
     }
 
+    fn condition(condition: Option<RecipeCondition>) -> Condition {
+        condition.unwrap_or_default().into_condition()
+    }
+
+    /// The database would set the fork origin of its forks to null, this is done explicitly instead.
+    async fn delete_children(
+        model: &Model,
+        journal_action_id: Option<i64>,
+        txn: &DatabaseTransaction,
+    ) -> Result<Vec<change_log::Model>> {
+        let mut changes = RecipeStepCrud::delete_all_logged(
+            recipe_step::Column::RecipeId.eq(model.id).into_condition(),
+            journal_action_id,
+            txn,
+        )
+        .await?;
+        changes.append(
+            &mut RecipeFileCrud::delete_all_logged(
+                recipe_file::Column::RecipeId.eq(model.id).into_condition(),
+                journal_action_id,
+                txn,
+            )
+            .await?,
+        );
+        changes.append(
+            &mut RecipeIngredientDraftCrud::delete_all_logged(
+                recipe_ingredient_draft::Column::RecipeId
+                    .eq(model.id)
+                    .into_condition(),
+                journal_action_id,
+                txn,
+            )
+            .await?,
+        );
+        changes.append(
+            &mut CollectionRecipeCrud::delete_all_logged(
+                collection_recipe::Column::RecipeId
+                    .eq(model.id)
+                    .into_condition(),
+                journal_action_id,
+                txn,
+            )
+            .await?,
+        );
+        changes.append(
+            &mut CookLogCrud::delete_all_logged(
+                cook_log::Column::RecipeId.eq(model.id).into_condition(),
+                journal_action_id,
+                txn,
+            )
+            .await?,
+        );
+        changes.append(
+            &mut RecipeTagCrud::delete_all_logged(
+                recipe_tag::Column::RecipeId.eq(model.id).into_condition(),
+                journal_action_id,
+                txn,
+            )
+            .await?,
+        );
+        let forks = Entity::find()
+            .filter(Column::ForkedFromId.eq(model.id))
+            .order_by_asc(Column::Id)
+            .all(txn)
+            .await?;
+        for fork in forks {
+            let mut active_model = fork.into_active_model();
+            active_model.forked_from_id = ActiveValue::Set(None);
+            let (_, change) = Self::update_logged(active_model, journal_action_id, txn).await?;
+            changes.push(change);
+        }
+        Ok(changes)
+    }
+
     fn entity_action_created_channel() -> &'static str {
         ENTITY_ACTION_CREATED_RECIPE
     }
@@ -103,4 +894,685 @@ impl EntityCrudTrait for RecipeCrud {
     fn entity_action_deleted_channel() -> &'static str {
         ENTITY_ACTION_DELETED_RECIPE
     }
+
+    fn entity_action_created_many_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_MANY_RECIPE
+    }
+
+    fn entity_action_updated_many_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_MANY_RECIPE
+    }
+
+    fn entity_action_deleted_many_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_MANY_RECIPE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use sea_orm::{ActiveModelTrait, DatabaseConnection};
+
+    use super::*;
+    use crate::{
+        entity::{
+            ingredient, ingredient_allergen, recipe_step_file, recipe_step_ingredient,
+            recipe_step_ingredient_draft, tag,
+        },
+        entity_crud::IdColumn,
+        migrator::tests::get_memory_database_migrated,
+    };
+
+    #[tokio::test]
+    async fn test_condition_trashed() {
+        let db = get_memory_database_migrated().await;
+        for (name, deleted_at) in [("kept", None), ("trashed", Some(1))] {
+            ActiveModel {
+                name: ActiveValue::Set(name.to_string()),
+                deleted_at: ActiveValue::Set(deleted_at),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        for (condition, expected_ids) in [
+            (None, vec![1]),
+            (
+                Some(RecipeCondition {
+                    trashed: Some(false),
+                    ..Default::default()
+                }),
+                vec![1],
+            ),
+            (
+                Some(RecipeCondition {
+                    trashed: Some(true),
+                    ..Default::default()
+                }),
+                vec![2],
+            ),
+        ] {
+            let ids: Vec<i64> = RecipeCrud::select_ids(condition)
+                .into_model::<IdColumn<i64>>()
+                .all(&db)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|id_column| id_column.id)
+                .collect();
+            assert_eq!(ids, expected_ids);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_condition_tags() {
+        let db = get_memory_database_migrated().await;
+        for name in ["soup", "stew", "salad"] {
+            ActiveModel {
+                name: ActiveValue::Set(name.to_string()),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        for name in ["warm", "vegetarian"] {
+            tag::ActiveModel {
+                name: ActiveValue::Set(name.to_string()),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        for (recipe_id, tag_id) in [(1, 1), (1, 2), (2, 1), (3, 2)] {
+            recipe_tag::ActiveModel {
+                recipe_id: ActiveValue::Set(recipe_id),
+                tag_id: ActiveValue::Set(tag_id),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        for (condition, expected_ids) in [
+            (
+                RecipeCondition {
+                    tags_all: Some(vec![1, 2]),
+                    ..Default::default()
+                },
+                vec![1],
+            ),
+            (
+                RecipeCondition {
+                    tags_any: Some(vec![1, 2]),
+                    ..Default::default()
+                },
+                vec![1, 2, 3],
+            ),
+            (
+                RecipeCondition {
+                    tags_none: Some(vec![2]),
+                    ..Default::default()
+                },
+                vec![2],
+            ),
+            (
+                RecipeCondition {
+                    tags_all: Some(vec![1]),
+                    tags_none: Some(vec![2]),
+                    ..Default::default()
+                },
+                vec![2],
+            ),
+        ] {
+            let ids: Vec<i64> = RecipeCrud::select_ids(Some(condition))
+                .into_model::<IdColumn<i64>>()
+                .all(&db)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|id_column| id_column.id)
+                .collect();
+            assert_eq!(ids, expected_ids);
+        }
+    }
+
+    async fn insert_cook_logs(db: &DatabaseConnection) {
+        for name in ["forgotten", "favourite", "tried once"] {
+            ActiveModel {
+                name: ActiveValue::Set(name.to_string()),
+                ..Default::default()
+            }
+            .insert(db)
+            .await
+            .unwrap();
+        }
+        for (recipe_id, cooked_at, rating) in [(2, 100, 4), (2, 300, 2), (3, 200, 5)] {
+            cook_log::ActiveModel {
+                recipe_id: ActiveValue::Set(recipe_id),
+                cooked_at: ActiveValue::Set(cooked_at),
+                servings: ActiveValue::Set(None),
+                rating: ActiveValue::Set(Some(rating)),
+                notes: ActiveValue::Set(None),
+                ..Default::default()
+            }
+            .insert(db)
+            .await
+            .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_condition_cooked() {
+        let db = get_memory_database_migrated().await;
+        insert_cook_logs(&db).await;
+        for (condition, expected_ids) in [
+            (
+                RecipeCondition {
+                    cooked_since: Some(150),
+                    ..Default::default()
+                },
+                vec![2, 3],
+            ),
+            (
+                RecipeCondition {
+                    cooked_since: Some(250),
+                    ..Default::default()
+                },
+                vec![2],
+            ),
+            (
+                RecipeCondition {
+                    not_cooked_since: Some(250),
+                    ..Default::default()
+                },
+                vec![1, 3],
+            ),
+        ] {
+            let ids: Vec<i64> = RecipeCrud::select_ids(Some(condition))
+                .into_model::<IdColumn<i64>>()
+                .all(&db)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|id_column| id_column.id)
+                .collect();
+            assert_eq!(ids, expected_ids);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_order_by_cooked() {
+        let db = get_memory_database_migrated().await;
+        insert_cook_logs(&db).await;
+        for (order_by, after, expected_ids) in [
+            (RecipeOrderBy::LastCooked(Order::Desc), None, vec![2, 3, 1]),
+            (RecipeOrderBy::LastCooked(Order::Desc), Some(2), vec![3, 1]),
+            (RecipeOrderBy::TimesCooked(Order::Asc), None, vec![1, 3, 2]),
+            (RecipeOrderBy::TimesCooked(Order::Asc), Some(3), vec![2]),
+            (
+                RecipeOrderBy::AverageRating(Order::Desc),
+                None,
+                vec![3, 2, 1],
+            ),
+            (RecipeOrderBy::AverageRating(Order::Desc), Some(2), vec![1]),
+        ] {
+            let ids: Vec<i64> = RecipeCrud::paginate(
+                RecipeCrud::select_ids(None),
+                vec![order_by],
+                None,
+                None,
+                after,
+            )
+            .into_model::<IdColumn<i64>>()
+            .all(&db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|id_column| id_column.id)
+            .collect();
+            assert_eq!(ids, expected_ids);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_time() {
+        let db = get_memory_database_migrated().await;
+        for (name, prep_time, total_time) in [
+            ("salad", Some(900), Some(900)),
+            ("stew", Some(1200), Some(7200)),
+            ("bread", None, None),
+        ] {
+            ActiveModel {
+                name: ActiveValue::Set(name.to_string()),
+                prep_time: ActiveValue::Set(prep_time),
+                total_time: ActiveValue::Set(total_time),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        for (condition, expected_ids) in [
+            (
+                RecipeCondition {
+                    max_total_time: Some(1800),
+                    ..Default::default()
+                },
+                vec![1],
+            ),
+            (
+                RecipeCondition {
+                    max_active_time: Some(1800),
+                    ..Default::default()
+                },
+                vec![1, 2],
+            ),
+        ] {
+            let ids: Vec<i64> = RecipeCrud::select_ids(Some(condition))
+                .into_model::<IdColumn<i64>>()
+                .all(&db)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|id_column| id_column.id)
+                .collect();
+            assert_eq!(ids, expected_ids);
+        }
+        for (order_by, after, expected_ids) in [
+            (RecipeOrderBy::TotalTime(Order::Asc), None, vec![1, 2, 3]),
+            (RecipeOrderBy::TotalTime(Order::Asc), Some(2), vec![3]),
+            (RecipeOrderBy::ActiveTime(Order::Desc), None, vec![2, 1, 3]),
+            (RecipeOrderBy::ActiveTime(Order::Desc), Some(1), vec![3]),
+        ] {
+            let ids: Vec<i64> = RecipeCrud::paginate(
+                RecipeCrud::select_ids(None),
+                vec![order_by],
+                None,
+                None,
+                after,
+            )
+            .into_model::<IdColumn<i64>>()
+            .all(&db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|id_column| id_column.id)
+            .collect();
+            assert_eq!(ids, expected_ids);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_condition_dietary() {
+        let db = get_memory_database_migrated().await;
+        for name in ["salad", "pancakes", "stew", "water"] {
+            ActiveModel {
+                name: ActiveValue::Set(name.to_string()),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        for recipe_id in 1..=3 {
+            recipe_step::ActiveModel {
+                order: ActiveValue::Set(1),
+                description: ActiveValue::Set(String::new()),
+                recipe_id: ActiveValue::Set(recipe_id),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        for (name, [vegetarian, vegan, gluten_free, lactose_free]) in [
+            ("lettuce", [None, Some(true), Some(true), None]),
+            ("flour", [Some(true), Some(true), Some(false), Some(true)]),
+            ("milk", [Some(true), Some(false), Some(true), Some(false)]),
+            ("mystery", [None, None, None, None]),
+        ] {
+            ingredient::ActiveModel {
+                name: ActiveValue::Set(name.to_string()),
+                vegetarian: ActiveValue::Set(vegetarian),
+                vegan: ActiveValue::Set(vegan),
+                gluten_free: ActiveValue::Set(gluten_free),
+                lactose_free: ActiveValue::Set(lactose_free),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        for (ingredient_id, allergen) in [(2, Allergen::Gluten), (3, Allergen::Milk)] {
+            ingredient_allergen::ActiveModel {
+                ingredient_id: ActiveValue::Set(ingredient_id),
+                allergen: ActiveValue::Set(allergen),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        for (order, recipe_step_id, ingredient_id) in [
+            (1, 1, 1),
+            (1, 2, 2),
+            (2, 2, 3),
+            (1, 3, 1),
+            (2, 3, 2),
+            (3, 3, 4),
+        ] {
+            recipe_step_ingredient::ActiveModel {
+                order: ActiveValue::Set(order),
+                recipe_step_id: ActiveValue::Set(recipe_step_id),
+                ingredient_id: ActiveValue::Set(ingredient_id),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        for (condition, expected_ids) in [
+            (
+                RecipeCondition {
+                    exclude_allergens: Some(vec![Allergen::Gluten]),
+                    ..Default::default()
+                },
+                vec![1, 4],
+            ),
+            (
+                RecipeCondition {
+                    exclude_allergens: Some(vec![Allergen::Milk]),
+                    ..Default::default()
+                },
+                vec![1, 3, 4],
+            ),
+            (
+                RecipeCondition {
+                    diets: Some(vec![Diet::Vegetarian]),
+                    ..Default::default()
+                },
+                vec![1, 2, 4],
+            ),
+            (
+                RecipeCondition {
+                    diets: Some(vec![Diet::Vegan]),
+                    ..Default::default()
+                },
+                vec![1, 4],
+            ),
+            (
+                RecipeCondition {
+                    diets: Some(vec![Diet::Vegetarian, Diet::LactoseFree]),
+                    ..Default::default()
+                },
+                vec![1, 4],
+            ),
+        ] {
+            let ids: Vec<i64> = RecipeCrud::select_ids(Some(condition))
+                .into_model::<IdColumn<i64>>()
+                .all(&db)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|id_column| id_column.id)
+                .collect();
+            assert_eq!(ids, expected_ids);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_logged() {
+        let db = get_memory_database_migrated().await;
+        ActiveModel {
+            name: ActiveValue::Set(String::from("bread")),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        for order in [1, 2] {
+            recipe_step::ActiveModel {
+                order: ActiveValue::Set(order),
+                description: ActiveValue::Set(format!("step {order}")),
+                recipe_id: ActiveValue::Set(1),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        recipe_step_ingredient_draft::ActiveModel {
+            order: ActiveValue::Set(1),
+            text: ActiveValue::Set(String::from("500 g flour")),
+            recipe_step_id: ActiveValue::Set(2),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+
+        let txn = db.begin().await.unwrap();
+        let journal_action_id = journal::record_action(&txn).await.unwrap();
+        let original = snapshot(1, &txn).await.unwrap().unwrap();
+        let (id, changes) = RecipeCrud::duplicate_logged(
+            original.clone(),
+            Some(String::from("gluten-free bread")),
+            true,
+            journal_action_id,
+            &txn,
+        )
+        .await
+        .unwrap();
+        txn.commit().await.unwrap();
+        assert_eq!(id, 2);
+        assert_eq!(changes.len(), 4);
+
+        let copy = snapshot(id, &db).await.unwrap().unwrap();
+        assert_eq!(copy.recipe.name, "gluten-free bread");
+        assert_eq!(copy.recipe.forked_from_id, Some(1));
+        assert_eq!(
+            copy.recipe_steps
+                .iter()
+                .map(|model| (model.id, model.order, model.recipe_id))
+                .collect::<Vec<_>>(),
+            vec![(3, 1, 2), (4, 2, 2)]
+        );
+        assert_eq!(
+            copy.recipe_step_ingredient_drafts
+                .iter()
+                .map(|model| (model.text.as_str(), model.recipe_step_id))
+                .collect::<Vec<_>>(),
+            vec![("500 g flour", 4)]
+        );
+        assert_eq!(snapshot(1, &db).await.unwrap().unwrap(), original);
+    }
+
+    #[tokio::test]
+    async fn test_delete_logged_fork() {
+        let db = get_memory_database_migrated().await;
+        for (name, forked_from_id) in [("bread", None), ("gluten-free bread", Some(1))] {
+            ActiveModel {
+                name: ActiveValue::Set(String::from(name)),
+                forked_from_id: ActiveValue::Set(forked_from_id),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        let fork = Entity::find_by_id(2).one(&db).await.unwrap().unwrap();
+
+        let txn = db.begin().await.unwrap();
+        let changes = RecipeCrud::delete_logged(1, None, &txn).await.unwrap();
+        txn.commit().await.unwrap();
+        let unlinked_fork = Entity::find_by_id(2).one(&db).await.unwrap().unwrap();
+        assert_eq!(unlinked_fork.forked_from_id, None);
+
+        let txn = db.begin().await.unwrap();
+        for change in changes.into_iter().rev() {
+            RecipeCrud::apply(change.new, change.old, None, &txn)
+                .await
+                .unwrap();
+        }
+        txn.commit().await.unwrap();
+        assert_eq!(Entity::find_by_id(2).one(&db).await.unwrap(), Some(fork));
+    }
+
+    #[tokio::test]
+    async fn test_read_export() {
+        let db = get_memory_database_migrated().await;
+        ActiveModel {
+            name: ActiveValue::Set(String::from("bread")),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        recipe_step::ActiveModel {
+            order: ActiveValue::Set(1),
+            description: ActiveValue::Set(String::from("bake")),
+            recipe_id: ActiveValue::Set(1),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        for name in ["step", "recipe", "unrelated"] {
+            file::ActiveModel {
+                name: ActiveValue::Set(String::from(name)),
+                mime: ActiveValue::Set(String::from("image/png")),
+                path: ActiveValue::Set(format!("{name}.png")),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        recipe_step_file::ActiveModel {
+            order: ActiveValue::Set(1),
+            recipe_step_id: ActiveValue::Set(1),
+            file_id: ActiveValue::Set(1),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        recipe_file::ActiveModel {
+            order: ActiveValue::Set(1),
+            recipe_id: ActiveValue::Set(1),
+            file_id: ActiveValue::Set(2),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        let export = read_export(1, &db).await.unwrap().unwrap();
+        assert_eq!(export.snapshot.recipe.name, "bread");
+        assert_eq!(
+            export
+                .files
+                .iter()
+                .map(|file| file.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["step", "recipe"]
+        );
+        let json = serde_json::to_value(&export).unwrap();
+        assert!(json.get("recipeSteps").is_some());
+        assert!(json.get("files").is_some());
+        assert_eq!(read_export(2, &db).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_import_logged() {
+        let db = get_memory_database_migrated().await;
+        let file = |name: &str| file::ActiveModel {
+            name: ActiveValue::Set(String::from(name)),
+            mime: ActiveValue::Set(String::from("application/pdf")),
+            path: ActiveValue::Set(format!("/{name}")),
+            ..Default::default()
+        };
+        file("bread.pdf").insert(&db).await.unwrap();
+        let import = RecipeImport {
+            recipe: RecipeCreate {
+                name: String::from("bread"),
+                prep_time: None,
+                cook_time: None,
+                rest_time: None,
+                total_time: None,
+            },
+            ingredients: vec![String::from("500 g flour"), String::from("1 tsp salt")],
+            files: vec![RecipeImportFile::Existing(1)],
+            steps: vec![
+                RecipeStepImport {
+                    description: String::from("Knead."),
+                    ingredients: vec![String::from("300 ml water")],
+                    files: vec![RecipeImportFile::Created(file("kneading.pdf"))],
+                },
+                RecipeStepImport {
+                    description: String::from("Bake."),
+                    ingredients: vec![],
+                    files: vec![],
+                },
+            ],
+        };
+
+        let txn = db.begin().await.unwrap();
+        let journal_action_id = journal::record_action(&txn).await.unwrap();
+        let (id, changes) = RecipeCrud::import_logged(import, journal_action_id, &txn)
+            .await
+            .unwrap();
+        txn.commit().await.unwrap();
+        assert_eq!(id, 1);
+        assert_eq!(changes.len(), 9);
+        assert!(
+            changes
+                .iter()
+                .all(|change| change.journal_action_id == Some(journal_action_id))
+        );
+
+        let imported = snapshot(id, &db).await.unwrap().unwrap();
+        assert_eq!(
+            imported
+                .recipe_ingredient_drafts
+                .iter()
+                .map(|model| (model.order, model.text.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(1, "500 g flour"), (2, "1 tsp salt")]
+        );
+        assert_eq!(
+            imported
+                .recipe_files
+                .iter()
+                .map(|model| (model.order, model.file_id))
+                .collect::<Vec<_>>(),
+            vec![(1, 1)]
+        );
+        assert_eq!(
+            imported
+                .recipe_steps
+                .iter()
+                .map(|model| (model.id, model.order, model.description.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(1, 1, "Knead."), (2, 2, "Bake.")]
+        );
+        assert_eq!(
+            imported
+                .recipe_step_ingredient_drafts
+                .iter()
+                .map(|model| (model.recipe_step_id, model.text.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(1, "300 ml water")]
+        );
+        assert_eq!(
+            imported
+                .recipe_step_files
+                .iter()
+                .map(|model| (model.recipe_step_id, model.file_id))
+                .collect::<Vec<_>>(),
+            vec![(1, 2)]
+        );
+    }
 }
@@ -2,17 +2,20 @@
 
 use async_trait::async_trait;
 use sea_orm::{
-    sea_query::IntoCondition, ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel,
-    IntoActiveModel, QueryOrder, Select,
+    sea_query::{IntoCondition, SimpleExpr},
+    ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel, IntoActiveModel, IntoSimpleExpr,
 };
 use serde::Deserialize;
 
 use crate::{
     entity::recipe_file::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
-    entity_crud::{EntityCrudTrait, Filter, Order, OrderBy},
+    entity_crud::{
+        EntityCrudTrait, Filter, Order, OrderBy, OrderedEntityCrudTrait, TimestampCondition,
+    },
     event::channel::{
-        ENTITY_ACTION_CREATED_RECIPE_FILE, ENTITY_ACTION_DELETED_RECIPE_FILE,
-        ENTITY_ACTION_UPDATED_RECIPE_FILE,
+        ENTITY_ACTION_CREATED_MANY_RECIPE_FILE, ENTITY_ACTION_CREATED_RECIPE_FILE,
+        ENTITY_ACTION_DELETED_MANY_RECIPE_FILE, ENTITY_ACTION_DELETED_RECIPE_FILE,
+        ENTITY_ACTION_UPDATED_MANY_RECIPE_FILE, ENTITY_ACTION_UPDATED_RECIPE_FILE,
     },
 };
 
@@ -41,6 +44,8 @@ impl IntoActiveModel<ActiveModel> for RecipeFileUpdate {
             },
             recipe_id: ActiveValue::NotSet,
             file_id: ActiveValue::NotSet,
+            created_at: ActiveValue::NotSet,
+            updated_at: ActiveValue::NotSet,
         }
     }
 }
@@ -51,14 +56,21 @@ pub type RecipeFileFilter = Filter<RecipeFileCondition, RecipeFileOrderBy>;
 #[serde(rename_all = "camelCase")]
 pub struct RecipeFileCondition {
     pub recipe_id: Option<i64>,
+    #[serde(flatten)]
+    pub timestamps: TimestampCondition,
 }
 
 impl IntoCondition for RecipeFileCondition {
     fn into_condition(self) -> Condition {
-        Condition::all().add_option(
-            self.recipe_id
-                .map(|recipe_id| Column::RecipeId.eq(recipe_id)),
-        )
+        Condition::all()
+            .add(
+                self.timestamps
+                    .into_condition(Column::CreatedAt, Column::UpdatedAt),
+            )
+            .add_option(
+                self.recipe_id
+                    .map(|recipe_id| Column::RecipeId.eq(recipe_id)),
+            )
     }
 }
 
@@ -66,14 +78,18 @@ impl IntoCondition for RecipeFileCondition {
 #[serde(rename_all = "camelCase")]
 pub enum RecipeFileOrderBy {
     Order(Order),
+    CreatedAt(Order),
+    UpdatedAt(Order),
 }
 
 impl OrderBy for RecipeFileOrderBy {
     type Entity = Entity;
 
-    fn add(self, select: Select<Self::Entity>) -> Select<Self::Entity> {
+    fn order(self) -> (SimpleExpr, Order) {
         match self {
-            RecipeFileOrderBy::Order(order) => select.order_by(Column::Order, order.into()),
+            RecipeFileOrderBy::Order(order) => (Column::Order.into_simple_expr(), order),
+            RecipeFileOrderBy::CreatedAt(order) => (Column::CreatedAt.into_simple_expr(), order),
+            RecipeFileOrderBy::UpdatedAt(order) => (Column::UpdatedAt.into_simple_expr(), order),
         }
     }
 }
@@ -102,6 +118,14 @@ impl EntityCrudTrait for RecipeFileCrud {
         Column::Id
     }
 
+    fn unique_order_column() -> Option<Column> {
+        Some(Column::Order)
+    }
+
+    fn parent_keys() -> &'static [&'static str] {
+        &["recipeId", "fileId"]
+    }
+
     fn entity_action_created_channel() -> &'static str {
         ENTITY_ACTION_CREATED_RECIPE_FILE
     }
@@ -113,4 +137,34 @@ impl EntityCrudTrait for RecipeFileCrud {
     fn entity_action_deleted_channel() -> &'static str {
         ENTITY_ACTION_DELETED_RECIPE_FILE
     }
+
+    fn entity_action_created_many_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_MANY_RECIPE_FILE
+    }
+
+    fn entity_action_updated_many_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_MANY_RECIPE_FILE
+    }
+
+    fn entity_action_deleted_many_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_MANY_RECIPE_FILE
+    }
+}
+
+impl OrderedEntityCrudTrait for RecipeFileCrud {
+    fn parent_id(model: &Model) -> i64 {
+        model.recipe_id
+    }
+
+    fn order(model: &Model) -> i64 {
+        model.order
+    }
+
+    fn parent_column() -> Column {
+        Column::RecipeId
+    }
+
+    fn order_column() -> Column {
+        Column::Order
+    }
 }
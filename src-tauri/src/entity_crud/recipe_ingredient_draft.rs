@@ -1,17 +1,22 @@
 //! This module implements [`EntityCrudTrait`] for [`crate::entity::recipe_ingredient_draft`].
 
 use sea_orm::{
-    sea_query::IntoCondition, ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel,
-    IntoActiveModel, QueryOrder, Select,
+    sea_query::{IntoCondition, SimpleExpr},
+    ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel, IntoActiveModel, IntoSimpleExpr,
 };
 use serde::Deserialize;
 
 use crate::{
     entity::recipe_ingredient_draft::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
-    entity_crud::{EntityCrudTrait, Filter, Order, OrderBy},
+    entity_crud::{
+        EntityCrudTrait, Filter, Order, OrderBy, OrderedEntityCrudTrait, TimestampCondition,
+    },
     event::channel::{
+        ENTITY_ACTION_CREATED_MANY_RECIPE_INGREDIENT_DRAFT,
         ENTITY_ACTION_CREATED_RECIPE_INGREDIENT_DRAFT,
+        ENTITY_ACTION_DELETED_MANY_RECIPE_INGREDIENT_DRAFT,
         ENTITY_ACTION_DELETED_RECIPE_INGREDIENT_DRAFT,
+        ENTITY_ACTION_UPDATED_MANY_RECIPE_INGREDIENT_DRAFT,
         ENTITY_ACTION_UPDATED_RECIPE_INGREDIENT_DRAFT,
     },
 };
@@ -45,6 +50,8 @@ impl IntoActiveModel<ActiveModel> for RecipeIngredientDraftUpdate {
                 _ => ActiveValue::NotSet,
             },
             recipe_id: ActiveValue::NotSet,
+            created_at: ActiveValue::NotSet,
+            updated_at: ActiveValue::NotSet,
         }
     }
 }
@@ -56,14 +63,21 @@ pub type RecipeIngredientDraftFilter =
 #[serde(rename_all = "camelCase")]
 pub struct RecipeIngredientDraftCondition {
     pub recipe_id: Option<i64>,
+    #[serde(flatten)]
+    pub timestamps: TimestampCondition,
 }
 
 impl IntoCondition for RecipeIngredientDraftCondition {
     fn into_condition(self) -> Condition {
-        Condition::all().add_option(
-            self.recipe_id
-                .map(|recipe_id| Column::RecipeId.eq(recipe_id)),
-        )
+        Condition::all()
+            .add(
+                self.timestamps
+                    .into_condition(Column::CreatedAt, Column::UpdatedAt),
+            )
+            .add_option(
+                self.recipe_id
+                    .map(|recipe_id| Column::RecipeId.eq(recipe_id)),
+            )
     }
 }
 
@@ -71,15 +85,21 @@ impl IntoCondition for RecipeIngredientDraftCondition {
 #[serde(rename_all = "camelCase")]
 pub enum RecipeIngredientDraftOrderBy {
     Order(Order),
+    CreatedAt(Order),
+    UpdatedAt(Order),
 }
 
 impl OrderBy for RecipeIngredientDraftOrderBy {
     type Entity = Entity;
 
-    fn add(self, select: Select<Self::Entity>) -> Select<Self::Entity> {
+    fn order(self) -> (SimpleExpr, Order) {
         match self {
-            RecipeIngredientDraftOrderBy::Order(order) => {
-                select.order_by(Column::Order, order.into())
+            RecipeIngredientDraftOrderBy::Order(order) => (Column::Order.into_simple_expr(), order),
+            RecipeIngredientDraftOrderBy::CreatedAt(order) => {
+                (Column::CreatedAt.into_simple_expr(), order)
+            }
+            RecipeIngredientDraftOrderBy::UpdatedAt(order) => {
+                (Column::UpdatedAt.into_simple_expr(), order)
             }
         }
     }
@@ -108,6 +128,14 @@ impl EntityCrudTrait for RecipeIngredientDraftCrud {
         Column::Id
     }
 
+    fn unique_order_column() -> Option<Column> {
+        Some(Column::Order)
+    }
+
+    fn parent_keys() -> &'static [&'static str] {
+        &["recipeId"]
+    }
+
     fn entity_action_created_channel() -> &'static str {
         ENTITY_ACTION_CREATED_RECIPE_INGREDIENT_DRAFT
     }
@@ -119,4 +147,34 @@ impl EntityCrudTrait for RecipeIngredientDraftCrud {
     fn entity_action_deleted_channel() -> &'static str {
         ENTITY_ACTION_DELETED_RECIPE_INGREDIENT_DRAFT
     }
+
+    fn entity_action_created_many_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_MANY_RECIPE_INGREDIENT_DRAFT
+    }
+
+    fn entity_action_updated_many_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_MANY_RECIPE_INGREDIENT_DRAFT
+    }
+
+    fn entity_action_deleted_many_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_MANY_RECIPE_INGREDIENT_DRAFT
+    }
+}
+
+impl OrderedEntityCrudTrait for RecipeIngredientDraftCrud {
+    fn parent_id(model: &Model) -> i64 {
+        model.recipe_id
+    }
+
+    fn order(model: &Model) -> i64 {
+        model.order
+    }
+
+    fn parent_column() -> Column {
+        Column::RecipeId
+    }
+
+    fn order_column() -> Column {
+        Column::Order
+    }
 }
@@ -1,17 +1,29 @@
 //! This module implements [`EntityCrudTrait`] for [`crate::entity::recipe_step`].
 
+use anyhow::Result;
+use async_trait::async_trait;
 use sea_orm::{
-    sea_query::IntoCondition, ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel,
-    IntoActiveModel, QueryOrder, Select,
+    sea_query::{IntoCondition, SimpleExpr},
+    ActiveValue, ColumnTrait, Condition, DatabaseTransaction, DeriveIntoActiveModel,
+    IntoActiveModel, IntoSimpleExpr,
 };
 use serde::Deserialize;
 
 use crate::{
-    entity::recipe_step::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
-    entity_crud::{EntityCrudTrait, Filter, Order, OrderBy},
+    entity::{
+        change_log,
+        recipe_step::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
+        recipe_step_file, recipe_step_ingredient, recipe_step_ingredient_draft,
+    },
+    entity_crud::{
+        recipe_step_file::RecipeStepFileCrud, recipe_step_ingredient::RecipeStepIngredientCrud,
+        recipe_step_ingredient_draft::RecipeStepIngredientDraftCrud, EntityCrudTrait, Filter,
+        Order, OrderBy, OrderedEntityCrudTrait, TimestampCondition,
+    },
     event::channel::{
-        ENTITY_ACTION_CREATED_RECIPE_STEP, ENTITY_ACTION_DELETED_RECIPE_STEP,
-        ENTITY_ACTION_UPDATED_RECIPE_STEP,
+        ENTITY_ACTION_CREATED_MANY_RECIPE_STEP, ENTITY_ACTION_CREATED_RECIPE_STEP,
+        ENTITY_ACTION_DELETED_MANY_RECIPE_STEP, ENTITY_ACTION_DELETED_RECIPE_STEP,
+        ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP, ENTITY_ACTION_UPDATED_RECIPE_STEP,
     },
 };
 
@@ -20,6 +32,7 @@ use crate::{
 pub struct RecipeStepCreate {
     pub order: i64,
     pub description: String,
+    pub duration: Option<i64>,
     pub recipe_id: i64,
 }
 
@@ -29,6 +42,8 @@ pub struct RecipeStepUpdate {
     pub id: i64,
     pub order: Option<i64>,
     pub description: Option<String>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub duration: Option<Option<i64>>,
 }
 
 impl IntoActiveModel<ActiveModel> for RecipeStepUpdate {
@@ -43,7 +58,13 @@ impl IntoActiveModel<ActiveModel> for RecipeStepUpdate {
                 Some(description) => ActiveValue::Set(description),
                 _ => ActiveValue::NotSet,
             },
+            duration: match self.duration {
+                Some(duration) => ActiveValue::Set(duration),
+                _ => ActiveValue::NotSet,
+            },
             recipe_id: ActiveValue::NotSet,
+            created_at: ActiveValue::NotSet,
+            updated_at: ActiveValue::NotSet,
         }
     }
 }
@@ -54,14 +75,21 @@ pub type RecipeStepFilter = Filter<RecipeStepCondition, RecipeStepOrderBy>;
 #[serde(rename_all = "camelCase")]
 pub struct RecipeStepCondition {
     pub recipe_id: Option<i64>,
+    #[serde(flatten)]
+    pub timestamps: TimestampCondition,
 }
 
 impl IntoCondition for RecipeStepCondition {
     fn into_condition(self) -> Condition {
-        Condition::all().add_option(
-            self.recipe_id
-                .map(|recipe_id| Column::RecipeId.eq(recipe_id)),
-        )
+        Condition::all()
+            .add(
+                self.timestamps
+                    .into_condition(Column::CreatedAt, Column::UpdatedAt),
+            )
+            .add_option(
+                self.recipe_id
+                    .map(|recipe_id| Column::RecipeId.eq(recipe_id)),
+            )
     }
 }
 
@@ -69,20 +97,25 @@ impl IntoCondition for RecipeStepCondition {
 #[serde(rename_all = "camelCase")]
 pub enum RecipeStepOrderBy {
     Order(Order),
+    CreatedAt(Order),
+    UpdatedAt(Order),
 }
 
 impl OrderBy for RecipeStepOrderBy {
     type Entity = Entity;
 
-    fn add(self, select: Select<Self::Entity>) -> Select<Self::Entity> {
+    fn order(self) -> (SimpleExpr, Order) {
         match self {
-            RecipeStepOrderBy::Order(order) => select.order_by(Column::Order, order.into()),
+            RecipeStepOrderBy::Order(order) => (Column::Order.into_simple_expr(), order),
+            RecipeStepOrderBy::CreatedAt(order) => (Column::CreatedAt.into_simple_expr(), order),
+            RecipeStepOrderBy::UpdatedAt(order) => (Column::UpdatedAt.into_simple_expr(), order),
         }
     }
 }
 
 pub struct RecipeStepCrud {}
 
+#[async_trait]
 impl EntityCrudTrait for RecipeStepCrud {
     type Entity = Entity;
     type Model = Model;
@@ -104,6 +137,50 @@ impl EntityCrudTrait for RecipeStepCrud {
         Column::Id
     }
 
+    fn unique_order_column() -> Option<Column> {
+        Some(Column::Order)
+    }
+
+    fn parent_keys() -> &'static [&'static str] {
+        &["recipeId"]
+    }
+
+    async fn delete_children(
+        model: &Model,
+        journal_action_id: Option<i64>,
+        txn: &DatabaseTransaction,
+    ) -> Result<Vec<change_log::Model>> {
+        let mut changes = RecipeStepIngredientCrud::delete_all_logged(
+            recipe_step_ingredient::Column::RecipeStepId
+                .eq(model.id)
+                .into_condition(),
+            journal_action_id,
+            txn,
+        )
+        .await?;
+        changes.append(
+            &mut RecipeStepFileCrud::delete_all_logged(
+                recipe_step_file::Column::RecipeStepId
+                    .eq(model.id)
+                    .into_condition(),
+                journal_action_id,
+                txn,
+            )
+            .await?,
+        );
+        changes.append(
+            &mut RecipeStepIngredientDraftCrud::delete_all_logged(
+                recipe_step_ingredient_draft::Column::RecipeStepId
+                    .eq(model.id)
+                    .into_condition(),
+                journal_action_id,
+                txn,
+            )
+            .await?,
+        );
+        Ok(changes)
+    }
+
     fn entity_action_created_channel() -> &'static str {
         ENTITY_ACTION_CREATED_RECIPE_STEP
     }
@@ -115,4 +192,34 @@ impl EntityCrudTrait for RecipeStepCrud {
     fn entity_action_deleted_channel() -> &'static str {
         ENTITY_ACTION_DELETED_RECIPE_STEP
     }
+
+    fn entity_action_created_many_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_MANY_RECIPE_STEP
+    }
+
+    fn entity_action_updated_many_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP
+    }
+
+    fn entity_action_deleted_many_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_MANY_RECIPE_STEP
+    }
+}
+
+impl OrderedEntityCrudTrait for RecipeStepCrud {
+    fn parent_id(model: &Model) -> i64 {
+        model.recipe_id
+    }
+
+    fn order(model: &Model) -> i64 {
+        model.order
+    }
+
+    fn parent_column() -> Column {
+        Column::RecipeId
+    }
+
+    fn order_column() -> Column {
+        Column::Order
+    }
 }
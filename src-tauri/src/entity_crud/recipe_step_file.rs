@@ -1,19 +1,31 @@
 //! This module implements [`EntityCrudTrait`] for [`crate::entity::recipe_step_file`].
 
+use anyhow::Result;
 use async_trait::async_trait;
 use sea_orm::{
-    sea_query::IntoCondition, ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel,
-    IntoActiveModel, QueryOrder, Select,
+    sea_query::{IntoCondition, SimpleExpr},
+    ActiveValue, ColumnTrait, Condition, DatabaseTransaction, DeriveIntoActiveModel,
+    IntoActiveModel, IntoSimpleExpr, TransactionTrait,
 };
 use serde::Deserialize;
 
 use crate::{
-    entity::recipe_step_file::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
-    entity_crud::{EntityCrudTrait, Filter, Order, OrderBy},
+    database,
+    entity::{
+        change_log, file,
+        recipe_step_file::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
+    },
+    entity_crud::{
+        file::{FileCreate, FileCrud},
+        EntityCrudTrait, Filter, Order, OrderBy, OrderedEntityCrudTrait, TimestampCondition,
+        TryIntoActiveModel,
+    },
     event::channel::{
-        ENTITY_ACTION_CREATED_RECIPE_STEP_FILE, ENTITY_ACTION_DELETED_RECIPE_STEP_FILE,
-        ENTITY_ACTION_UPDATED_RECIPE_STEP_FILE,
+        ENTITY_ACTION_CREATED_MANY_RECIPE_STEP_FILE, ENTITY_ACTION_CREATED_RECIPE_STEP_FILE,
+        ENTITY_ACTION_DELETED_MANY_RECIPE_STEP_FILE, ENTITY_ACTION_DELETED_RECIPE_STEP_FILE,
+        ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP_FILE, ENTITY_ACTION_UPDATED_RECIPE_STEP_FILE,
     },
+    journal,
 };
 
 #[derive(Debug, Deserialize, DeriveIntoActiveModel)]
@@ -41,6 +53,8 @@ impl IntoActiveModel<ActiveModel> for RecipeStepFileUpdate {
             },
             recipe_step_id: ActiveValue::NotSet,
             file_id: ActiveValue::NotSet,
+            created_at: ActiveValue::NotSet,
+            updated_at: ActiveValue::NotSet,
         }
     }
 }
@@ -51,14 +65,21 @@ pub type RecipeStepFileFilter = Filter<RecipeStepFileCondition, RecipeStepFileOr
 #[serde(rename_all = "camelCase")]
 pub struct RecipeStepFileCondition {
     pub recipe_step_id: Option<i64>,
+    #[serde(flatten)]
+    pub timestamps: TimestampCondition,
 }
 
 impl IntoCondition for RecipeStepFileCondition {
     fn into_condition(self) -> Condition {
-        Condition::all().add_option(
-            self.recipe_step_id
-                .map(|recipe_step_id| Column::RecipeStepId.eq(recipe_step_id)),
-        )
+        Condition::all()
+            .add(
+                self.timestamps
+                    .into_condition(Column::CreatedAt, Column::UpdatedAt),
+            )
+            .add_option(
+                self.recipe_step_id
+                    .map(|recipe_step_id| Column::RecipeStepId.eq(recipe_step_id)),
+            )
     }
 }
 
@@ -66,20 +87,87 @@ impl IntoCondition for RecipeStepFileCondition {
 #[serde(rename_all = "camelCase")]
 pub enum RecipeStepFileOrderBy {
     Order(Order),
+    CreatedAt(Order),
+    UpdatedAt(Order),
 }
 
 impl OrderBy for RecipeStepFileOrderBy {
     type Entity = Entity;
 
-    fn add(self, select: Select<Self::Entity>) -> Select<Self::Entity> {
+    fn order(self) -> (SimpleExpr, Order) {
         match self {
-            RecipeStepFileOrderBy::Order(order) => select.order_by(Column::Order, order.into()),
+            RecipeStepFileOrderBy::Order(order) => (Column::Order.into_simple_expr(), order),
+            RecipeStepFileOrderBy::CreatedAt(order) => {
+                (Column::CreatedAt.into_simple_expr(), order)
+            }
+            RecipeStepFileOrderBy::UpdatedAt(order) => {
+                (Column::UpdatedAt.into_simple_expr(), order)
+            }
         }
     }
 }
 
 pub struct RecipeStepFileCrud {}
 
+impl RecipeStepFileCrud {
+    /// Create a file and attach it after the other files of a recipe step atomically.
+    ///
+    /// Returns the id of the created file.
+    ///
+    /// # Errors
+    ///
+    /// - when the file can't be created, see [`FileCreate`]
+    /// - when there is any problem with the database, nothing is created then
+    /// - when a created entity can't be serialized for the change log
+    /// - when the tauri window can't be messaged about the created entities
+    pub async fn create_with_file(recipe_step_id: i64, file_create: FileCreate) -> Result<i64> {
+        let file_active_model = file_create.try_into_active_model().await?;
+        let db = database::connect_writing().await;
+        let txn = db.begin().await?;
+        let journal_action_id = journal::record_action(&txn).await?;
+        let (file_id, changes) = Self::create_with_file_logged(
+            recipe_step_id,
+            file_active_model,
+            Some(journal_action_id),
+            &txn,
+        )
+        .await?;
+        txn.commit().await?;
+        journal::emit(&changes)?;
+        Ok(file_id)
+    }
+
+    /// Create a file and attach it after the other files of a recipe step inside a transaction and log the changes.
+    ///
+    /// Returns the id of the created file.
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database
+    /// - when a created entity can't be serialized for the change log
+    async fn create_with_file_logged(
+        recipe_step_id: i64,
+        file_active_model: file::ActiveModel,
+        journal_action_id: Option<i64>,
+        txn: &DatabaseTransaction,
+    ) -> Result<(i64, Vec<change_log::Model>)> {
+        let (file, file_change) =
+            FileCrud::create_logged(file_active_model, journal_action_id, txn).await?;
+        let order = Self::siblings(recipe_step_id, txn)
+            .await?
+            .last()
+            .map_or(1, |sibling| sibling.order + 1);
+        let active_model = RecipeStepFileCreate {
+            order,
+            recipe_step_id,
+            file_id: file.id,
+        }
+        .into_active_model();
+        let (_, change) = Self::create_logged(active_model, journal_action_id, txn).await?;
+        Ok((file.id, vec![file_change, change]))
+    }
+}
+
 #[async_trait]
 impl EntityCrudTrait for RecipeStepFileCrud {
     type Entity = Entity;
@@ -102,6 +190,14 @@ impl EntityCrudTrait for RecipeStepFileCrud {
         Column::Id
     }
 
+    fn unique_order_column() -> Option<Column> {
+        Some(Column::Order)
+    }
+
+    fn parent_keys() -> &'static [&'static str] {
+        &["recipeStepId", "fileId"]
+    }
+
     fn entity_action_created_channel() -> &'static str {
         ENTITY_ACTION_CREATED_RECIPE_STEP_FILE
     }
@@ -113,4 +209,93 @@ impl EntityCrudTrait for RecipeStepFileCrud {
     fn entity_action_deleted_channel() -> &'static str {
         ENTITY_ACTION_DELETED_RECIPE_STEP_FILE
     }
+
+    fn entity_action_created_many_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_MANY_RECIPE_STEP_FILE
+    }
+
+    fn entity_action_updated_many_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP_FILE
+    }
+
+    fn entity_action_deleted_many_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_MANY_RECIPE_STEP_FILE
+    }
+}
+
+impl OrderedEntityCrudTrait for RecipeStepFileCrud {
+    fn parent_id(model: &Model) -> i64 {
+        model.recipe_step_id
+    }
+
+    fn order(model: &Model) -> i64 {
+        model.order
+    }
+
+    fn parent_column() -> Column {
+        Column::RecipeStepId
+    }
+
+    fn order_column() -> Column {
+        Column::Order
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use sea_orm::{ActiveModelTrait, EntityTrait};
+
+    use super::*;
+    use crate::{
+        entity::{recipe, recipe_step},
+        migrator::tests::get_memory_database_migrated,
+    };
+
+    #[tokio::test]
+    async fn test_create_with_file_logged() {
+        let db = get_memory_database_migrated().await;
+        recipe::ActiveModel {
+            name: ActiveValue::Set(String::from("a")),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        recipe_step::ActiveModel {
+            order: ActiveValue::Set(1),
+            description: ActiveValue::Set(String::from("a")),
+            recipe_id: ActiveValue::Set(1),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        let txn = db.begin().await.unwrap();
+        for name in ["a", "b"] {
+            let (_, changes) = RecipeStepFileCrud::create_with_file_logged(
+                1,
+                file::ActiveModel {
+                    name: ActiveValue::Set(String::from(name)),
+                    mime: ActiveValue::Set(String::from("image/png")),
+                    path: ActiveValue::Set(format!("{name}.png")),
+                    ..Default::default()
+                },
+                None,
+                &txn,
+            )
+            .await
+            .unwrap();
+            assert_eq!(changes.len(), 2);
+        }
+        txn.commit().await.unwrap();
+        let recipe_step_files: Vec<(i64, i64)> = Entity::find()
+            .all(&db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|model| (model.order, model.file_id))
+            .collect();
+        assert_eq!(recipe_step_files, vec![(1, 1), (2, 2)]);
+    }
 }
@@ -1,16 +1,22 @@
 //! This module implements [`EntityCrudTrait`] for [`crate::entity::recipe_step_ingredient`].
 
 use sea_orm::{
-    sea_query::IntoCondition, ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel,
-    IntoActiveModel, QueryOrder, Select,
+    sea_query::{IntoCondition, SimpleExpr},
+    ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel, IntoActiveModel, IntoSimpleExpr,
 };
 use serde::Deserialize;
 
 use crate::{
     entity::recipe_step_ingredient::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
-    entity_crud::{EntityCrudTrait, Filter, Order, OrderBy},
+    entity_crud::{
+        EntityCrudTrait, Filter, Order, OrderBy, OrderedEntityCrudTrait, TimestampCondition,
+    },
     event::channel::{
-        ENTITY_ACTION_CREATED_RECIPE_STEP_INGREDIENT, ENTITY_ACTION_DELETED_RECIPE_STEP_INGREDIENT,
+        ENTITY_ACTION_CREATED_MANY_RECIPE_STEP_INGREDIENT,
+        ENTITY_ACTION_CREATED_RECIPE_STEP_INGREDIENT,
+        ENTITY_ACTION_DELETED_MANY_RECIPE_STEP_INGREDIENT,
+        ENTITY_ACTION_DELETED_RECIPE_STEP_INGREDIENT,
+        ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP_INGREDIENT,
         ENTITY_ACTION_UPDATED_RECIPE_STEP_INGREDIENT,
     },
 };
@@ -65,6 +71,8 @@ impl IntoActiveModel<ActiveModel> for RecipeStepIngredientUpdate {
                 Some(ingredient_id) => ActiveValue::Set(ingredient_id),
                 _ => ActiveValue::NotSet,
             },
+            created_at: ActiveValue::NotSet,
+            updated_at: ActiveValue::NotSet,
         }
     }
 }
@@ -77,11 +85,17 @@ pub type RecipeStepIngredientFilter =
 pub struct RecipeStepIngredientCondition {
     pub recipe_step_id: Option<i64>,
     pub ingredient_id: Option<i64>,
+    #[serde(flatten)]
+    pub timestamps: TimestampCondition,
 }
 
 impl IntoCondition for RecipeStepIngredientCondition {
     fn into_condition(self) -> Condition {
         Condition::all()
+            .add(
+                self.timestamps
+                    .into_condition(Column::CreatedAt, Column::UpdatedAt),
+            )
             .add_option(
                 self.recipe_step_id
                     .map(|recipe_step_id| Column::RecipeStepId.eq(recipe_step_id)),
@@ -97,15 +111,21 @@ impl IntoCondition for RecipeStepIngredientCondition {
 #[serde(rename_all = "camelCase")]
 pub enum RecipeStepIngredientOrderBy {
     Order(Order),
+    CreatedAt(Order),
+    UpdatedAt(Order),
 }
 
 impl OrderBy for RecipeStepIngredientOrderBy {
     type Entity = Entity;
 
-    fn add(self, select: Select<Self::Entity>) -> Select<Self::Entity> {
+    fn order(self) -> (SimpleExpr, Order) {
         match self {
-            RecipeStepIngredientOrderBy::Order(order) => {
-                select.order_by(Column::Order, order.into())
+            RecipeStepIngredientOrderBy::Order(order) => (Column::Order.into_simple_expr(), order),
+            RecipeStepIngredientOrderBy::CreatedAt(order) => {
+                (Column::CreatedAt.into_simple_expr(), order)
+            }
+            RecipeStepIngredientOrderBy::UpdatedAt(order) => {
+                (Column::UpdatedAt.into_simple_expr(), order)
             }
         }
     }
@@ -134,6 +154,14 @@ impl EntityCrudTrait for RecipeStepIngredientCrud {
         Column::Id
     }
 
+    fn unique_order_column() -> Option<Column> {
+        Some(Column::Order)
+    }
+
+    fn parent_keys() -> &'static [&'static str] {
+        &["recipeStepId", "ingredientId"]
+    }
+
     fn entity_action_created_channel() -> &'static str {
         ENTITY_ACTION_CREATED_RECIPE_STEP_INGREDIENT
     }
@@ -145,4 +173,34 @@ impl EntityCrudTrait for RecipeStepIngredientCrud {
     fn entity_action_deleted_channel() -> &'static str {
         ENTITY_ACTION_DELETED_RECIPE_STEP_INGREDIENT
     }
+
+    fn entity_action_created_many_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_MANY_RECIPE_STEP_INGREDIENT
+    }
+
+    fn entity_action_updated_many_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP_INGREDIENT
+    }
+
+    fn entity_action_deleted_many_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_MANY_RECIPE_STEP_INGREDIENT
+    }
+}
+
+impl OrderedEntityCrudTrait for RecipeStepIngredientCrud {
+    fn parent_id(model: &Model) -> i64 {
+        model.recipe_step_id
+    }
+
+    fn order(model: &Model) -> i64 {
+        model.order
+    }
+
+    fn parent_column() -> Column {
+        Column::RecipeStepId
+    }
+
+    fn order_column() -> Column {
+        Column::Order
+    }
 }
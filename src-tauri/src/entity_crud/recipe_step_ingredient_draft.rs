@@ -1,8 +1,8 @@
 //! This module implements [`EntityCrudTrait`] for [`crate::entity::recipe_step_ingredient_draft`].
 
 use sea_orm::{
-    sea_query::IntoCondition, ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel,
-    IntoActiveModel, QueryOrder, Select,
+    sea_query::{IntoCondition, SimpleExpr},
+    ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel, IntoActiveModel, IntoSimpleExpr,
 };
 use serde::Deserialize;
 
@@ -10,10 +10,15 @@ use crate::{
     entity::recipe_step_ingredient_draft::{
         ActiveModel, Column, Entity, Model, PrimaryKey, Relation,
     },
-    entity_crud::{EntityCrudTrait, Filter, Order, OrderBy},
+    entity_crud::{
+        EntityCrudTrait, Filter, Order, OrderBy, OrderedEntityCrudTrait, TimestampCondition,
+    },
     event::channel::{
+        ENTITY_ACTION_CREATED_MANY_RECIPE_STEP_INGREDIENT_DRAFT,
         ENTITY_ACTION_CREATED_RECIPE_STEP_INGREDIENT_DRAFT,
+        ENTITY_ACTION_DELETED_MANY_RECIPE_STEP_INGREDIENT_DRAFT,
         ENTITY_ACTION_DELETED_RECIPE_STEP_INGREDIENT_DRAFT,
+        ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP_INGREDIENT_DRAFT,
         ENTITY_ACTION_UPDATED_RECIPE_STEP_INGREDIENT_DRAFT,
     },
 };
@@ -47,6 +52,8 @@ impl IntoActiveModel<ActiveModel> for RecipeStepIngredientDraftUpdate {
                 _ => ActiveValue::NotSet,
             },
             recipe_step_id: ActiveValue::NotSet,
+            created_at: ActiveValue::NotSet,
+            updated_at: ActiveValue::NotSet,
         }
     }
 }
@@ -58,14 +65,21 @@ pub type RecipeStepIngredientDraftFilter =
 #[serde(rename_all = "camelCase")]
 pub struct RecipeStepIngredientDraftCondition {
     pub recipe_step_id: Option<i64>,
+    #[serde(flatten)]
+    pub timestamps: TimestampCondition,
 }
 
 impl IntoCondition for RecipeStepIngredientDraftCondition {
     fn into_condition(self) -> Condition {
-        Condition::all().add_option(
-            self.recipe_step_id
-                .map(|recipe_step_id| Column::RecipeStepId.eq(recipe_step_id)),
-        )
+        Condition::all()
+            .add(
+                self.timestamps
+                    .into_condition(Column::CreatedAt, Column::UpdatedAt),
+            )
+            .add_option(
+                self.recipe_step_id
+                    .map(|recipe_step_id| Column::RecipeStepId.eq(recipe_step_id)),
+            )
     }
 }
 
@@ -73,15 +87,23 @@ impl IntoCondition for RecipeStepIngredientDraftCondition {
 #[serde(rename_all = "camelCase")]
 pub enum RecipeStepIngredientDraftOrderBy {
     Order(Order),
+    CreatedAt(Order),
+    UpdatedAt(Order),
 }
 
 impl OrderBy for RecipeStepIngredientDraftOrderBy {
     type Entity = Entity;
 
-    fn add(self, select: Select<Self::Entity>) -> Select<Self::Entity> {
+    fn order(self) -> (SimpleExpr, Order) {
         match self {
             RecipeStepIngredientDraftOrderBy::Order(order) => {
-                select.order_by(Column::Order, order.into())
+                (Column::Order.into_simple_expr(), order)
+            }
+            RecipeStepIngredientDraftOrderBy::CreatedAt(order) => {
+                (Column::CreatedAt.into_simple_expr(), order)
+            }
+            RecipeStepIngredientDraftOrderBy::UpdatedAt(order) => {
+                (Column::UpdatedAt.into_simple_expr(), order)
             }
         }
     }
@@ -110,6 +132,14 @@ impl EntityCrudTrait for RecipeStepIngredientDraftCrud {
         Column::Id
     }
 
+    fn unique_order_column() -> Option<Column> {
+        Some(Column::Order)
+    }
+
+    fn parent_keys() -> &'static [&'static str] {
+        &["recipeStepId"]
+    }
+
     fn entity_action_created_channel() -> &'static str {
         ENTITY_ACTION_CREATED_RECIPE_STEP_INGREDIENT_DRAFT
     }
@@ -121,4 +151,34 @@ impl EntityCrudTrait for RecipeStepIngredientDraftCrud {
     fn entity_action_deleted_channel() -> &'static str {
         ENTITY_ACTION_DELETED_RECIPE_STEP_INGREDIENT_DRAFT
     }
+
+    fn entity_action_created_many_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_MANY_RECIPE_STEP_INGREDIENT_DRAFT
+    }
+
+    fn entity_action_updated_many_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP_INGREDIENT_DRAFT
+    }
+
+    fn entity_action_deleted_many_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_MANY_RECIPE_STEP_INGREDIENT_DRAFT
+    }
+}
+
+impl OrderedEntityCrudTrait for RecipeStepIngredientDraftCrud {
+    fn parent_id(model: &Model) -> i64 {
+        model.recipe_step_id
+    }
+
+    fn order(model: &Model) -> i64 {
+        model.order
+    }
+
+    fn parent_column() -> Column {
+        Column::RecipeStepId
+    }
+
+    fn order_column() -> Column {
+        Column::Order
+    }
 }
@@ -0,0 +1,163 @@
+//! This module implements [`EntityCrudTrait`] for [`crate::entity::recipe_tag`].
+
+use sea_orm::{
+    sea_query::{Expr, IntoCondition, Query, SimpleExpr},
+    ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel, IntoActiveModel, IntoSimpleExpr,
+};
+use serde::Deserialize;
+
+use crate::{
+    entity::{
+        recipe_tag::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
+        tag,
+    },
+    entity_crud::{EntityCrudTrait, Filter, Order, OrderBy, TimestampCondition},
+    event::channel::{
+        ENTITY_ACTION_CREATED_MANY_RECIPE_TAG, ENTITY_ACTION_CREATED_RECIPE_TAG,
+        ENTITY_ACTION_DELETED_MANY_RECIPE_TAG, ENTITY_ACTION_DELETED_RECIPE_TAG,
+        ENTITY_ACTION_UPDATED_MANY_RECIPE_TAG, ENTITY_ACTION_UPDATED_RECIPE_TAG,
+    },
+};
+
+#[derive(Debug, Deserialize, DeriveIntoActiveModel)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeTagCreate {
+    pub recipe_id: i64,
+    pub tag_id: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeTagUpdate {
+    pub id: i64,
+    pub tag_id: Option<i64>,
+}
+
+impl IntoActiveModel<ActiveModel> for RecipeTagUpdate {
+    fn into_active_model(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Unchanged(self.id),
+            recipe_id: ActiveValue::NotSet,
+            tag_id: match self.tag_id {
+                Some(tag_id) => ActiveValue::Set(tag_id),
+                _ => ActiveValue::NotSet,
+            },
+            created_at: ActiveValue::NotSet,
+            updated_at: ActiveValue::NotSet,
+        }
+    }
+}
+
+pub type RecipeTagFilter = Filter<RecipeTagCondition, RecipeTagOrderBy>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeTagCondition {
+    pub recipe_id: Option<i64>,
+    pub tag_id: Option<i64>,
+    #[serde(flatten)]
+    pub timestamps: TimestampCondition,
+}
+
+impl IntoCondition for RecipeTagCondition {
+    fn into_condition(self) -> Condition {
+        Condition::all()
+            .add(
+                self.timestamps
+                    .into_condition(Column::CreatedAt, Column::UpdatedAt),
+            )
+            .add_option(
+                self.recipe_id
+                    .map(|recipe_id| Column::RecipeId.eq(recipe_id)),
+            )
+            .add_option(self.tag_id.map(|tag_id| Column::TagId.eq(tag_id)))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RecipeTagOrderBy {
+    TagName(Order),
+    CreatedAt(Order),
+    UpdatedAt(Order),
+}
+
+impl OrderBy for RecipeTagOrderBy {
+    type Entity = Entity;
+
+    fn order(self) -> (SimpleExpr, Order) {
+        match self {
+            RecipeTagOrderBy::TagName(order) => (
+                SimpleExpr::SubQuery(
+                    None,
+                    Box::new(
+                        Query::select()
+                            .column(tag::Column::Name)
+                            .from(tag::Entity)
+                            .and_where(
+                                Expr::col((tag::Entity, tag::Column::Id))
+                                    .equals((Entity, Column::TagId)),
+                            )
+                            .to_owned()
+                            .into_sub_query_statement(),
+                    ),
+                ),
+                order,
+            ),
+            RecipeTagOrderBy::CreatedAt(order) => (Column::CreatedAt.into_simple_expr(), order),
+            RecipeTagOrderBy::UpdatedAt(order) => (Column::UpdatedAt.into_simple_expr(), order),
+        }
+    }
+}
+
+pub struct RecipeTagCrud {}
+
+impl EntityCrudTrait for RecipeTagCrud {
+    type Entity = Entity;
+    type Model = Model;
+    type ActiveModel = ActiveModel;
+    type Column = Column;
+    type Relation = Relation;
+    type PrimaryKey = PrimaryKey;
+    type PrimaryKeyValue = i64;
+    type EntityCreate = RecipeTagCreate;
+    type EntityUpdate = RecipeTagUpdate;
+    type EntityCondition = RecipeTagCondition;
+    type EntityOrderBy = RecipeTagOrderBy;
+
+    fn primary_key_value(model: &Model) -> i64 {
+        model.id
+    }
+
+    fn primary_key_colum() -> Column {
+        Column::Id
+    }
+
+    fn parent_keys() -> &'static [&'static str] {
+        &["recipeId", "tagId"]
+    }
+
+    fn entity_action_created_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_RECIPE_TAG
+    }
+
+    fn entity_action_updated_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_RECIPE_TAG
+    }
+
+    fn entity_action_deleted_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_RECIPE_TAG
+    }
+
+    fn entity_action_created_many_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_MANY_RECIPE_TAG
+    }
+
+    fn entity_action_updated_many_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_MANY_RECIPE_TAG
+    }
+
+    fn entity_action_deleted_many_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_MANY_RECIPE_TAG
+    }
+}
@@ -0,0 +1,377 @@
+//! This module implements [`EntityCrudTrait`] for [`crate::entity::tag`].
+
+use std::collections::HashSet;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use sea_orm::{
+    sea_query::{Expr, IntoCondition, SimpleExpr},
+    ActiveValue, ColumnTrait, Condition, DatabaseTransaction, DeriveIntoActiveModel, EntityTrait,
+    FromQueryResult, IntoActiveModel, IntoSimpleExpr, QueryFilter, QueryOrder, QuerySelect,
+    QueryTrait, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    database,
+    entity::{
+        change_log, recipe, recipe_tag,
+        tag::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
+    },
+    entity_crud::{
+        recipe_tag::RecipeTagCrud, EntityCrudTrait, Filter, Order, OrderBy, TimestampCondition,
+    },
+    event::channel::{
+        ENTITY_ACTION_CREATED_MANY_TAG, ENTITY_ACTION_CREATED_TAG, ENTITY_ACTION_DELETED_MANY_TAG,
+        ENTITY_ACTION_DELETED_TAG, ENTITY_ACTION_UPDATED_MANY_TAG, ENTITY_ACTION_UPDATED_TAG,
+    },
+    journal,
+};
+
+#[derive(Debug, Deserialize, DeriveIntoActiveModel)]
+#[serde(rename_all = "camelCase")]
+pub struct TagCreate {
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagUpdate {
+    pub id: i64,
+    pub name: Option<String>,
+}
+
+impl IntoActiveModel<ActiveModel> for TagUpdate {
+    fn into_active_model(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Unchanged(self.id),
+            name: match self.name {
+                Some(name) => ActiveValue::Set(name),
+                _ => ActiveValue::NotSet,
+            },
+            created_at: ActiveValue::NotSet,
+            updated_at: ActiveValue::NotSet,
+        }
+    }
+}
+
+pub type TagFilter = Filter<TagCondition, TagOrderBy>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagCondition {
+    pub name: Option<String>,
+    pub name_exact: Option<String>,
+    pub recipe_id: Option<i64>,
+    #[serde(flatten)]
+    pub timestamps: TimestampCondition,
+}
+
+impl IntoCondition for TagCondition {
+    fn into_condition(self) -> Condition {
+        Condition::all()
+            .add(
+                self.timestamps
+                    .into_condition(Column::CreatedAt, Column::UpdatedAt),
+            )
+            .add_option(self.name.map(|name| Column::Name.like(format!("%{name}%"))))
+            .add_option(self.name_exact.map(|name| Column::Name.eq(name)))
+            .add_option(self.recipe_id.map(|recipe_id| {
+                Column::Id.in_subquery(
+                    recipe_tag::Entity::find()
+                        .select_only()
+                        .column(recipe_tag::Column::TagId)
+                        .filter(recipe_tag::Column::RecipeId.eq(recipe_id))
+                        .into_query(),
+                )
+            }))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TagOrderBy {
+    Name(Order),
+    CreatedAt(Order),
+    UpdatedAt(Order),
+}
+
+impl OrderBy for TagOrderBy {
+    type Entity = Entity;
+
+    fn order(self) -> (SimpleExpr, Order) {
+        match self {
+            TagOrderBy::Name(order) => (Column::Name.into_simple_expr(), order),
+            TagOrderBy::CreatedAt(order) => (Column::CreatedAt.into_simple_expr(), order),
+            TagOrderBy::UpdatedAt(order) => (Column::UpdatedAt.into_simple_expr(), order),
+        }
+    }
+}
+
+/// This struct represents a tag with the number of recipes it is used by, for the tag cloud.
+///
+/// Recipes in the trash are not counted.
+#[derive(Debug, PartialEq, Eq, FromQueryResult, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagUsage {
+    pub id: i64,
+    pub name: String,
+    pub count: i64,
+}
+
+pub struct TagCrud {}
+
+impl TagCrud {
+    /// Rename a tag atomically.
+    ///
+    /// When another tag already has the new name, the tag is merged into that one instead.
+    /// Returns the id of the renamed or merged into tag, or [`None`] when the tag does not exist.
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database, nothing is renamed then
+    /// - when a changed entity can't be serialized for the change log
+    /// - when the tauri window can't be messaged about the changed entities
+    pub async fn rename(id: i64, name: String) -> Result<Option<i64>> {
+        let db = database::connect_writing().await;
+        let txn = db.begin().await?;
+        let Some(model) = Entity::find_by_id(id).one(&txn).await? else {
+            return Ok(None);
+        };
+        let journal_action_id = journal::record_action(&txn).await?;
+        let (id, changes) = Self::rename_logged(model, name, Some(journal_action_id), &txn).await?;
+        txn.commit().await?;
+        journal::emit(&changes)?;
+        Ok(Some(id))
+    }
+
+    /// Rename a tag inside a transaction and log the changes.
+    ///
+    /// When another tag already has the new name, the recipe tags of the tag are re-pointed to that one and the tag is deleted.
+    /// As a tag can only be used once per recipe, a recipe tag is deleted instead when its recipe already has the other tag.
+    /// Returns the id of the renamed or merged into tag.
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database
+    /// - when a changed entity can't be serialized for the change log
+    pub async fn rename_logged(
+        model: Model,
+        name: String,
+        journal_action_id: Option<i64>,
+        txn: &DatabaseTransaction,
+    ) -> Result<(i64, Vec<change_log::Model>)> {
+        let target_option = Entity::find()
+            .filter(Column::Name.eq(&name))
+            .filter(Column::Id.ne(model.id))
+            .one(txn)
+            .await?;
+        let Some(target) = target_option else {
+            let id = model.id;
+            let mut active_model = model.into_active_model();
+            active_model.name = ActiveValue::Set(name);
+            let (_, change) = Self::update_logged(active_model, journal_action_id, txn).await?;
+            return Ok((id, vec![change]));
+        };
+        let mut target_recipe_ids: HashSet<i64> = recipe_tag::Entity::find()
+            .filter(recipe_tag::Column::TagId.eq(target.id))
+            .all(txn)
+            .await?
+            .into_iter()
+            .map(|recipe_tag| recipe_tag.recipe_id)
+            .collect();
+        let recipe_tags = recipe_tag::Entity::find()
+            .filter(recipe_tag::Column::TagId.eq(model.id))
+            .order_by_asc(recipe_tag::Column::Id)
+            .all(txn)
+            .await?;
+        let mut changes = Vec::new();
+        for recipe_tag in recipe_tags {
+            if target_recipe_ids.insert(recipe_tag.recipe_id) {
+                let mut active_model = recipe_tag.into_active_model();
+                active_model.tag_id = ActiveValue::Set(target.id);
+                let (_, change) =
+                    RecipeTagCrud::update_logged(active_model, journal_action_id, txn).await?;
+                changes.push(change);
+            } else {
+                changes.append(
+                    &mut RecipeTagCrud::delete_model_logged(recipe_tag, journal_action_id, txn)
+                        .await?,
+                );
+            }
+        }
+        changes.append(&mut Self::delete_model_logged(model, journal_action_id, txn).await?);
+        Ok((target.id, changes))
+    }
+
+    /// Get all tags with the number of recipes they are used by, ordered by name.
+    ///
+    /// # Errors
+    ///
+    /// - when there is any problem with the database
+    pub async fn cloud() -> Result<Vec<TagUsage>> {
+        let db = database::connect().await;
+        let count = recipe_tag::Entity::find()
+            .select_only()
+            .expr(Expr::col((recipe_tag::Entity, recipe_tag::Column::Id)).count())
+            .inner_join(recipe::Entity)
+            .filter(
+                Expr::col((recipe_tag::Entity, recipe_tag::Column::TagId))
+                    .equals((Entity, Column::Id)),
+            )
+            .filter(recipe::Column::DeletedAt.is_null())
+            .into_query();
+        let tag_usages = Entity::find()
+            .select_only()
+            .column(Column::Id)
+            .column(Column::Name)
+            .column_as(
+                SimpleExpr::SubQuery(None, Box::new(count.into_sub_query_statement())),
+                "count",
+            )
+            .order_by_asc(Column::Name)
+            .into_model::<TagUsage>()
+            .all(db)
+            .await?;
+        Ok(tag_usages)
+    }
+}
+
+#[async_trait]
+impl EntityCrudTrait for TagCrud {
+    type Entity = Entity;
+    type Model = Model;
+    type ActiveModel = ActiveModel;
+    type Column = Column;
+    type Relation = Relation;
+    type PrimaryKey = PrimaryKey;
+    type PrimaryKeyValue = i64;
+    type EntityCreate = TagCreate;
+    type EntityUpdate = TagUpdate;
+    type EntityCondition = TagCondition;
+    type EntityOrderBy = TagOrderBy;
+
+    fn primary_key_value(model: &Model) -> i64 {
+        model.id
+    }
+
+    fn primary_key_colum() -> Column {
+        Column::Id
+    }
+
+    async fn delete_children(
+        model: &Model,
+        journal_action_id: Option<i64>,
+        txn: &DatabaseTransaction,
+    ) -> Result<Vec<change_log::Model>> {
+        RecipeTagCrud::delete_all_logged(
+            recipe_tag::Column::TagId.eq(model.id).into_condition(),
+            journal_action_id,
+            txn,
+        )
+        .await
+    }
+
+    fn entity_action_created_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_TAG
+    }
+
+    fn entity_action_updated_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_TAG
+    }
+
+    fn entity_action_deleted_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_TAG
+    }
+
+    fn entity_action_created_many_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_MANY_TAG
+    }
+
+    fn entity_action_updated_many_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_MANY_TAG
+    }
+
+    fn entity_action_deleted_many_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_MANY_TAG
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use sea_orm::ActiveModelTrait;
+
+    use super::*;
+    use crate::migrator::tests::get_memory_database_migrated;
+
+    #[tokio::test]
+    async fn test_rename_logged() {
+        let db = get_memory_database_migrated().await;
+        for name in ["soup", "stew"] {
+            recipe::ActiveModel {
+                name: ActiveValue::Set(name.to_string()),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        for name in ["veggie", "vegetarian"] {
+            ActiveModel {
+                name: ActiveValue::Set(name.to_string()),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        for (recipe_id, tag_id) in [(1, 1), (2, 1), (2, 2)] {
+            recipe_tag::ActiveModel {
+                recipe_id: ActiveValue::Set(recipe_id),
+                tag_id: ActiveValue::Set(tag_id),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+
+        let txn = db.begin().await.unwrap();
+        let model = Entity::find_by_id(2).one(&txn).await.unwrap().unwrap();
+        let (id, _) = TagCrud::rename_logged(model, String::from("vegetable"), None, &txn)
+            .await
+            .unwrap();
+        txn.commit().await.unwrap();
+        assert_eq!(id, 2);
+        assert_eq!(
+            Entity::find_by_id(2).one(&db).await.unwrap().unwrap().name,
+            "vegetable"
+        );
+
+        let txn = db.begin().await.unwrap();
+        let model = Entity::find_by_id(1).one(&txn).await.unwrap().unwrap();
+        let (id, changes) = TagCrud::rename_logged(model, String::from("vegetable"), None, &txn)
+            .await
+            .unwrap();
+        txn.commit().await.unwrap();
+        assert_eq!(id, 2);
+        assert_eq!(
+            changes
+                .iter()
+                .map(|change| change.entity.as_str())
+                .collect::<Vec<_>>(),
+            vec!["recipe_tag", "recipe_tag", "tag"]
+        );
+        let recipe_tags: Vec<(i64, i64)> = recipe_tag::Entity::find()
+            .order_by_asc(recipe_tag::Column::RecipeId)
+            .all(&db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|model| (model.recipe_id, model.tag_id))
+            .collect();
+        assert_eq!(recipe_tags, vec![(1, 2), (2, 2)]);
+        assert_eq!(Entity::find_by_id(1).one(&db).await.unwrap(), None);
+    }
+}
@@ -1,16 +1,18 @@
 //! This module implements [`EntityCrudTrait`] for [`crate::entity::unit_name`].
 
 use sea_orm::{
-    sea_query::IntoCondition, ActiveValue, Condition, IntoActiveModel, IntoActiveValue, Select,
+    sea_query::{IntoCondition, SimpleExpr},
+    ActiveValue, Condition, IntoActiveModel, IntoActiveValue, IntoSimpleExpr,
 };
 use serde::Deserialize;
 
 use crate::{
     entity::unit_name::{unit::Unit, ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
-    entity_crud::{EntityCrudTrait, Filter, OrderBy},
+    entity_crud::{EntityCrudTrait, Filter, Order, OrderBy, TimestampCondition},
     event::channel::{
-        ENTITY_ACTION_CREATED_UNIT_NAME, ENTITY_ACTION_DELETED_UNIT_NAME,
-        ENTITY_ACTION_UPDATED_UNIT_NAME,
+        ENTITY_ACTION_CREATED_MANY_UNIT_NAME, ENTITY_ACTION_CREATED_UNIT_NAME,
+        ENTITY_ACTION_DELETED_MANY_UNIT_NAME, ENTITY_ACTION_DELETED_UNIT_NAME,
+        ENTITY_ACTION_UPDATED_MANY_UNIT_NAME, ENTITY_ACTION_UPDATED_UNIT_NAME,
     },
 };
 
@@ -26,6 +28,8 @@ impl IntoActiveModel<ActiveModel> for UnitNameCreate {
         ActiveModel {
             name: self.name.into_active_value(),
             unit: self.unit.into_active_value(),
+            created_at: ActiveValue::NotSet,
+            updated_at: ActiveValue::NotSet,
         }
     }
 }
@@ -45,31 +49,45 @@ impl IntoActiveModel<ActiveModel> for UnitNameUpdate {
                 Some(unit) => ActiveValue::Set(unit),
                 _ => ActiveValue::NotSet,
             },
+            created_at: ActiveValue::NotSet,
+            updated_at: ActiveValue::NotSet,
         }
     }
 }
 
-pub type UnitNameFilter = Filter<UnitNameCondition, UnitNameOrderBy>;
+pub type UnitNameFilter = Filter<UnitNameCondition, UnitNameOrderBy, String>;
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct UnitNameCondition {}
+pub struct UnitNameCondition {
+    #[serde(flatten)]
+    pub timestamps: TimestampCondition,
+}
 
 impl IntoCondition for UnitNameCondition {
     fn into_condition(self) -> Condition {
-        Condition::all()
+        Condition::all().add(
+            self.timestamps
+                .into_condition(Column::CreatedAt, Column::UpdatedAt),
+        )
     }
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub enum UnitNameOrderBy {}
+pub enum UnitNameOrderBy {
+    CreatedAt(Order),
+    UpdatedAt(Order),
+}
 
 impl OrderBy for UnitNameOrderBy {
     type Entity = Entity;
 
-    fn add(self, select: Select<Self::Entity>) -> Select<Self::Entity> {
-        select
+    fn order(self) -> (SimpleExpr, Order) {
+        match self {
+            UnitNameOrderBy::CreatedAt(order) => (Column::CreatedAt.into_simple_expr(), order),
+            UnitNameOrderBy::UpdatedAt(order) => (Column::UpdatedAt.into_simple_expr(), order),
+        }
     }
 }
 
@@ -107,4 +125,16 @@ impl EntityCrudTrait for UnitNameCrud {
     fn entity_action_deleted_channel() -> &'static str {
         ENTITY_ACTION_DELETED_UNIT_NAME
     }
+
+    fn entity_action_created_many_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_MANY_UNIT_NAME
+    }
+
+    fn entity_action_updated_many_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_MANY_UNIT_NAME
+    }
+
+    fn entity_action_deleted_many_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_MANY_UNIT_NAME
+    }
 }
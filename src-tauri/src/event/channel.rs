@@ -3,18 +3,76 @@
 pub const ENTITY_ACTION_CREATED_FILE: &str = "ENTITY_ACTION_CREATED_FILE";
 pub const ENTITY_ACTION_UPDATED_FILE: &str = "ENTITY_ACTION_UPDATED_FILE";
 pub const ENTITY_ACTION_DELETED_FILE: &str = "ENTITY_ACTION_DELETED_FILE";
+pub const ENTITY_ACTION_CREATED_MANY_FILE: &str = "ENTITY_ACTION_CREATED_MANY_FILE";
+pub const ENTITY_ACTION_UPDATED_MANY_FILE: &str = "ENTITY_ACTION_UPDATED_MANY_FILE";
+pub const ENTITY_ACTION_DELETED_MANY_FILE: &str = "ENTITY_ACTION_DELETED_MANY_FILE";
 
 pub const ENTITY_ACTION_CREATED_INGREDIENT: &str = "ENTITY_ACTION_CREATED_INGREDIENT";
 pub const ENTITY_ACTION_UPDATED_INGREDIENT: &str = "ENTITY_ACTION_UPDATED_INGREDIENT";
 pub const ENTITY_ACTION_DELETED_INGREDIENT: &str = "ENTITY_ACTION_DELETED_INGREDIENT";
+pub const ENTITY_ACTION_CREATED_MANY_INGREDIENT: &str = "ENTITY_ACTION_CREATED_MANY_INGREDIENT";
+pub const ENTITY_ACTION_UPDATED_MANY_INGREDIENT: &str = "ENTITY_ACTION_UPDATED_MANY_INGREDIENT";
+pub const ENTITY_ACTION_DELETED_MANY_INGREDIENT: &str = "ENTITY_ACTION_DELETED_MANY_INGREDIENT";
+
+pub const ENTITY_ACTION_CREATED_INGREDIENT_ALIAS: &str = "ENTITY_ACTION_CREATED_INGREDIENT_ALIAS";
+pub const ENTITY_ACTION_UPDATED_INGREDIENT_ALIAS: &str = "ENTITY_ACTION_UPDATED_INGREDIENT_ALIAS";
+pub const ENTITY_ACTION_DELETED_INGREDIENT_ALIAS: &str = "ENTITY_ACTION_DELETED_INGREDIENT_ALIAS";
+pub const ENTITY_ACTION_CREATED_MANY_INGREDIENT_ALIAS: &str =
+    "ENTITY_ACTION_CREATED_MANY_INGREDIENT_ALIAS";
+pub const ENTITY_ACTION_UPDATED_MANY_INGREDIENT_ALIAS: &str =
+    "ENTITY_ACTION_UPDATED_MANY_INGREDIENT_ALIAS";
+pub const ENTITY_ACTION_DELETED_MANY_INGREDIENT_ALIAS: &str =
+    "ENTITY_ACTION_DELETED_MANY_INGREDIENT_ALIAS";
+
+pub const ENTITY_ACTION_CREATED_INGREDIENT_ALLERGEN: &str =
+    "ENTITY_ACTION_CREATED_INGREDIENT_ALLERGEN";
+pub const ENTITY_ACTION_UPDATED_INGREDIENT_ALLERGEN: &str =
+    "ENTITY_ACTION_UPDATED_INGREDIENT_ALLERGEN";
+pub const ENTITY_ACTION_DELETED_INGREDIENT_ALLERGEN: &str =
+    "ENTITY_ACTION_DELETED_INGREDIENT_ALLERGEN";
+pub const ENTITY_ACTION_CREATED_MANY_INGREDIENT_ALLERGEN: &str =
+    "ENTITY_ACTION_CREATED_MANY_INGREDIENT_ALLERGEN";
+pub const ENTITY_ACTION_UPDATED_MANY_INGREDIENT_ALLERGEN: &str =
+    "ENTITY_ACTION_UPDATED_MANY_INGREDIENT_ALLERGEN";
+pub const ENTITY_ACTION_DELETED_MANY_INGREDIENT_ALLERGEN: &str =
+    "ENTITY_ACTION_DELETED_MANY_INGREDIENT_ALLERGEN";
+
+pub const ENTITY_ACTION_CREATED_INGREDIENT_CATEGORY: &str =
+    "ENTITY_ACTION_CREATED_INGREDIENT_CATEGORY";
+pub const ENTITY_ACTION_UPDATED_INGREDIENT_CATEGORY: &str =
+    "ENTITY_ACTION_UPDATED_INGREDIENT_CATEGORY";
+pub const ENTITY_ACTION_DELETED_INGREDIENT_CATEGORY: &str =
+    "ENTITY_ACTION_DELETED_INGREDIENT_CATEGORY";
+pub const ENTITY_ACTION_CREATED_MANY_INGREDIENT_CATEGORY: &str =
+    "ENTITY_ACTION_CREATED_MANY_INGREDIENT_CATEGORY";
+pub const ENTITY_ACTION_UPDATED_MANY_INGREDIENT_CATEGORY: &str =
+    "ENTITY_ACTION_UPDATED_MANY_INGREDIENT_CATEGORY";
+pub const ENTITY_ACTION_DELETED_MANY_INGREDIENT_CATEGORY: &str =
+    "ENTITY_ACTION_DELETED_MANY_INGREDIENT_CATEGORY";
+
+pub const ENTITY_ACTION_CREATED_NUTRIENT_PROFILE: &str = "ENTITY_ACTION_CREATED_NUTRIENT_PROFILE";
+pub const ENTITY_ACTION_UPDATED_NUTRIENT_PROFILE: &str = "ENTITY_ACTION_UPDATED_NUTRIENT_PROFILE";
+pub const ENTITY_ACTION_DELETED_NUTRIENT_PROFILE: &str = "ENTITY_ACTION_DELETED_NUTRIENT_PROFILE";
+pub const ENTITY_ACTION_CREATED_MANY_NUTRIENT_PROFILE: &str =
+    "ENTITY_ACTION_CREATED_MANY_NUTRIENT_PROFILE";
+pub const ENTITY_ACTION_UPDATED_MANY_NUTRIENT_PROFILE: &str =
+    "ENTITY_ACTION_UPDATED_MANY_NUTRIENT_PROFILE";
+pub const ENTITY_ACTION_DELETED_MANY_NUTRIENT_PROFILE: &str =
+    "ENTITY_ACTION_DELETED_MANY_NUTRIENT_PROFILE";
 
 pub const ENTITY_ACTION_CREATED_RECIPE: &str = "ENTITY_ACTION_CREATED_RECIPE";
 pub const ENTITY_ACTION_UPDATED_RECIPE: &str = "ENTITY_ACTION_UPDATED_RECIPE";
 pub const ENTITY_ACTION_DELETED_RECIPE: &str = "ENTITY_ACTION_DELETED_RECIPE";
+pub const ENTITY_ACTION_CREATED_MANY_RECIPE: &str = "ENTITY_ACTION_CREATED_MANY_RECIPE";
+pub const ENTITY_ACTION_UPDATED_MANY_RECIPE: &str = "ENTITY_ACTION_UPDATED_MANY_RECIPE";
+pub const ENTITY_ACTION_DELETED_MANY_RECIPE: &str = "ENTITY_ACTION_DELETED_MANY_RECIPE";
 
 pub const ENTITY_ACTION_CREATED_RECIPE_FILE: &str = "ENTITY_ACTION_CREATED_RECIPE_FILE";
 pub const ENTITY_ACTION_UPDATED_RECIPE_FILE: &str = "ENTITY_ACTION_UPDATED_RECIPE_FILE";
 pub const ENTITY_ACTION_DELETED_RECIPE_FILE: &str = "ENTITY_ACTION_DELETED_RECIPE_FILE";
+pub const ENTITY_ACTION_CREATED_MANY_RECIPE_FILE: &str = "ENTITY_ACTION_CREATED_MANY_RECIPE_FILE";
+pub const ENTITY_ACTION_UPDATED_MANY_RECIPE_FILE: &str = "ENTITY_ACTION_UPDATED_MANY_RECIPE_FILE";
+pub const ENTITY_ACTION_DELETED_MANY_RECIPE_FILE: &str = "ENTITY_ACTION_DELETED_MANY_RECIPE_FILE";
 
 pub const ENTITY_ACTION_CREATED_RECIPE_INGREDIENT_DRAFT: &str =
     "ENTITY_ACTION_CREATED_RECIPE_INGREDIENT_DRAFT";
@@ -22,10 +80,22 @@ pub const ENTITY_ACTION_UPDATED_RECIPE_INGREDIENT_DRAFT: &str =
     "ENTITY_ACTION_UPDATED_RECIPE_INGREDIENT_DRAFT";
 pub const ENTITY_ACTION_DELETED_RECIPE_INGREDIENT_DRAFT: &str =
     "ENTITY_ACTION_DELETED_RECIPE_INGREDIENT_DRAFT";
+pub const ENTITY_ACTION_CREATED_MANY_RECIPE_INGREDIENT_DRAFT: &str =
+    "ENTITY_ACTION_CREATED_MANY_RECIPE_INGREDIENT_DRAFT";
+pub const ENTITY_ACTION_UPDATED_MANY_RECIPE_INGREDIENT_DRAFT: &str =
+    "ENTITY_ACTION_UPDATED_MANY_RECIPE_INGREDIENT_DRAFT";
+pub const ENTITY_ACTION_DELETED_MANY_RECIPE_INGREDIENT_DRAFT: &str =
+    "ENTITY_ACTION_DELETED_MANY_RECIPE_INGREDIENT_DRAFT";
 
 pub const ENTITY_ACTION_CREATED_RECIPE_STEP_FILE: &str = "ENTITY_ACTION_CREATED_RECIPE_STEP_FILE";
 pub const ENTITY_ACTION_UPDATED_RECIPE_STEP_FILE: &str = "ENTITY_ACTION_UPDATED_RECIPE_STEP_FILE";
 pub const ENTITY_ACTION_DELETED_RECIPE_STEP_FILE: &str = "ENTITY_ACTION_DELETED_RECIPE_STEP_FILE";
+pub const ENTITY_ACTION_CREATED_MANY_RECIPE_STEP_FILE: &str =
+    "ENTITY_ACTION_CREATED_MANY_RECIPE_STEP_FILE";
+pub const ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP_FILE: &str =
+    "ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP_FILE";
+pub const ENTITY_ACTION_DELETED_MANY_RECIPE_STEP_FILE: &str =
+    "ENTITY_ACTION_DELETED_MANY_RECIPE_STEP_FILE";
 
 pub const ENTITY_ACTION_CREATED_RECIPE_STEP_INGREDIENT: &str =
     "ENTITY_ACTION_CREATED_RECIPE_STEP_INGREDIENT";
@@ -33,6 +103,12 @@ pub const ENTITY_ACTION_UPDATED_RECIPE_STEP_INGREDIENT: &str =
     "ENTITY_ACTION_UPDATED_RECIPE_STEP_INGREDIENT";
 pub const ENTITY_ACTION_DELETED_RECIPE_STEP_INGREDIENT: &str =
     "ENTITY_ACTION_DELETED_RECIPE_STEP_INGREDIENT";
+pub const ENTITY_ACTION_CREATED_MANY_RECIPE_STEP_INGREDIENT: &str =
+    "ENTITY_ACTION_CREATED_MANY_RECIPE_STEP_INGREDIENT";
+pub const ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP_INGREDIENT: &str =
+    "ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP_INGREDIENT";
+pub const ENTITY_ACTION_DELETED_MANY_RECIPE_STEP_INGREDIENT: &str =
+    "ENTITY_ACTION_DELETED_MANY_RECIPE_STEP_INGREDIENT";
 
 pub const ENTITY_ACTION_CREATED_RECIPE_STEP_INGREDIENT_DRAFT: &str =
     "ENTITY_ACTION_CREATED_RECIPE_STEP_INGREDIENT_DRAFT";
@@ -40,14 +116,78 @@ pub const ENTITY_ACTION_UPDATED_RECIPE_STEP_INGREDIENT_DRAFT: &str =
     "ENTITY_ACTION_UPDATED_RECIPE_STEP_INGREDIENT_DRAFT";
 pub const ENTITY_ACTION_DELETED_RECIPE_STEP_INGREDIENT_DRAFT: &str =
     "ENTITY_ACTION_DELETED_RECIPE_STEP_INGREDIENT_DRAFT";
+pub const ENTITY_ACTION_CREATED_MANY_RECIPE_STEP_INGREDIENT_DRAFT: &str =
+    "ENTITY_ACTION_CREATED_MANY_RECIPE_STEP_INGREDIENT_DRAFT";
+pub const ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP_INGREDIENT_DRAFT: &str =
+    "ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP_INGREDIENT_DRAFT";
+pub const ENTITY_ACTION_DELETED_MANY_RECIPE_STEP_INGREDIENT_DRAFT: &str =
+    "ENTITY_ACTION_DELETED_MANY_RECIPE_STEP_INGREDIENT_DRAFT";
 
 pub const ENTITY_ACTION_CREATED_RECIPE_STEP: &str = "ENTITY_ACTION_CREATED_RECIPE_STEP";
 pub const ENTITY_ACTION_UPDATED_RECIPE_STEP: &str = "ENTITY_ACTION_UPDATED_RECIPE_STEP";
 pub const ENTITY_ACTION_DELETED_RECIPE_STEP: &str = "ENTITY_ACTION_DELETED_RECIPE_STEP";
+pub const ENTITY_ACTION_CREATED_MANY_RECIPE_STEP: &str = "ENTITY_ACTION_CREATED_MANY_RECIPE_STEP";
+pub const ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP: &str = "ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP";
+pub const ENTITY_ACTION_DELETED_MANY_RECIPE_STEP: &str = "ENTITY_ACTION_DELETED_MANY_RECIPE_STEP";
+
+pub const ENTITY_ACTION_CREATED_RECIPE_TAG: &str = "ENTITY_ACTION_CREATED_RECIPE_TAG";
+pub const ENTITY_ACTION_UPDATED_RECIPE_TAG: &str = "ENTITY_ACTION_UPDATED_RECIPE_TAG";
+pub const ENTITY_ACTION_DELETED_RECIPE_TAG: &str = "ENTITY_ACTION_DELETED_RECIPE_TAG";
+pub const ENTITY_ACTION_CREATED_MANY_RECIPE_TAG: &str = "ENTITY_ACTION_CREATED_MANY_RECIPE_TAG";
+pub const ENTITY_ACTION_UPDATED_MANY_RECIPE_TAG: &str = "ENTITY_ACTION_UPDATED_MANY_RECIPE_TAG";
+pub const ENTITY_ACTION_DELETED_MANY_RECIPE_TAG: &str = "ENTITY_ACTION_DELETED_MANY_RECIPE_TAG";
+
+pub const ENTITY_ACTION_CREATED_TAG: &str = "ENTITY_ACTION_CREATED_TAG";
+pub const ENTITY_ACTION_UPDATED_TAG: &str = "ENTITY_ACTION_UPDATED_TAG";
+pub const ENTITY_ACTION_DELETED_TAG: &str = "ENTITY_ACTION_DELETED_TAG";
+pub const ENTITY_ACTION_CREATED_MANY_TAG: &str = "ENTITY_ACTION_CREATED_MANY_TAG";
+pub const ENTITY_ACTION_UPDATED_MANY_TAG: &str = "ENTITY_ACTION_UPDATED_MANY_TAG";
+pub const ENTITY_ACTION_DELETED_MANY_TAG: &str = "ENTITY_ACTION_DELETED_MANY_TAG";
+
+pub const ENTITY_ACTION_CREATED_COLLECTION: &str = "ENTITY_ACTION_CREATED_COLLECTION";
+pub const ENTITY_ACTION_UPDATED_COLLECTION: &str = "ENTITY_ACTION_UPDATED_COLLECTION";
+pub const ENTITY_ACTION_DELETED_COLLECTION: &str = "ENTITY_ACTION_DELETED_COLLECTION";
+pub const ENTITY_ACTION_CREATED_MANY_COLLECTION: &str = "ENTITY_ACTION_CREATED_MANY_COLLECTION";
+pub const ENTITY_ACTION_UPDATED_MANY_COLLECTION: &str = "ENTITY_ACTION_UPDATED_MANY_COLLECTION";
+pub const ENTITY_ACTION_DELETED_MANY_COLLECTION: &str = "ENTITY_ACTION_DELETED_MANY_COLLECTION";
+
+pub const ENTITY_ACTION_CREATED_COLLECTION_RECIPE: &str = "ENTITY_ACTION_CREATED_COLLECTION_RECIPE";
+pub const ENTITY_ACTION_UPDATED_COLLECTION_RECIPE: &str = "ENTITY_ACTION_UPDATED_COLLECTION_RECIPE";
+pub const ENTITY_ACTION_DELETED_COLLECTION_RECIPE: &str = "ENTITY_ACTION_DELETED_COLLECTION_RECIPE";
+pub const ENTITY_ACTION_CREATED_MANY_COLLECTION_RECIPE: &str =
+    "ENTITY_ACTION_CREATED_MANY_COLLECTION_RECIPE";
+pub const ENTITY_ACTION_UPDATED_MANY_COLLECTION_RECIPE: &str =
+    "ENTITY_ACTION_UPDATED_MANY_COLLECTION_RECIPE";
+pub const ENTITY_ACTION_DELETED_MANY_COLLECTION_RECIPE: &str =
+    "ENTITY_ACTION_DELETED_MANY_COLLECTION_RECIPE";
+
+pub const ENTITY_ACTION_CREATED_COOK_LOG: &str = "ENTITY_ACTION_CREATED_COOK_LOG";
+pub const ENTITY_ACTION_UPDATED_COOK_LOG: &str = "ENTITY_ACTION_UPDATED_COOK_LOG";
+pub const ENTITY_ACTION_DELETED_COOK_LOG: &str = "ENTITY_ACTION_DELETED_COOK_LOG";
+pub const ENTITY_ACTION_CREATED_MANY_COOK_LOG: &str = "ENTITY_ACTION_CREATED_MANY_COOK_LOG";
+pub const ENTITY_ACTION_UPDATED_MANY_COOK_LOG: &str = "ENTITY_ACTION_UPDATED_MANY_COOK_LOG";
+pub const ENTITY_ACTION_DELETED_MANY_COOK_LOG: &str = "ENTITY_ACTION_DELETED_MANY_COOK_LOG";
+
+pub const ENTITY_ACTION_CREATED_COOK_LOG_FILE: &str = "ENTITY_ACTION_CREATED_COOK_LOG_FILE";
+pub const ENTITY_ACTION_UPDATED_COOK_LOG_FILE: &str = "ENTITY_ACTION_UPDATED_COOK_LOG_FILE";
+pub const ENTITY_ACTION_DELETED_COOK_LOG_FILE: &str = "ENTITY_ACTION_DELETED_COOK_LOG_FILE";
+pub const ENTITY_ACTION_CREATED_MANY_COOK_LOG_FILE: &str =
+    "ENTITY_ACTION_CREATED_MANY_COOK_LOG_FILE";
+pub const ENTITY_ACTION_UPDATED_MANY_COOK_LOG_FILE: &str =
+    "ENTITY_ACTION_UPDATED_MANY_COOK_LOG_FILE";
+pub const ENTITY_ACTION_DELETED_MANY_COOK_LOG_FILE: &str =
+    "ENTITY_ACTION_DELETED_MANY_COOK_LOG_FILE";
 
 pub const ENTITY_ACTION_CREATED_UNIT_NAME: &str = "ENTITY_ACTION_CREATED_UNIT_NAME";
 pub const ENTITY_ACTION_UPDATED_UNIT_NAME: &str = "ENTITY_ACTION_UPDATED_UNIT_NAME";
 pub const ENTITY_ACTION_DELETED_UNIT_NAME: &str = "ENTITY_ACTION_DELETED_UNIT_NAME";
+pub const ENTITY_ACTION_CREATED_MANY_UNIT_NAME: &str = "ENTITY_ACTION_CREATED_MANY_UNIT_NAME";
+pub const ENTITY_ACTION_UPDATED_MANY_UNIT_NAME: &str = "ENTITY_ACTION_UPDATED_MANY_UNIT_NAME";
+pub const ENTITY_ACTION_DELETED_MANY_UNIT_NAME: &str = "ENTITY_ACTION_DELETED_MANY_UNIT_NAME";
 
 pub const SCRAPER_DOM_DROP: &str = "SCRAPER_DOM_DROP";
 pub const SCRAPER_ELEMENT_DROP: &str = "SCRAPER_ELEMENT_DROP";
+
+pub const TIMER_TICK: &str = "TIMER_TICK";
+pub const TIMER_FINISH: &str = "TIMER_FINISH";
+pub const TIMER_CANCEL: &str = "TIMER_CANCEL";
@@ -8,15 +8,19 @@ use schema_org_constants::{
     HOW_TO_SECTION_IRI_HTTP, HOW_TO_SECTION_IRI_HTTPS, HOW_TO_STEP_IRI_HTTP, HOW_TO_STEP_IRI_HTTPS,
 };
 use schema_org_traits::{
-    json_ld_0_15::JsonLdStore, FindRecipeIds, GetContentUrlProperty, GetImageProperty,
-    GetItemListElementProperty, GetNameProperty, GetRecipeIngredientProperty,
-    GetRecipeInstructionsProperty, GetTextProperty, GetVideoProperty,
+    json_ld_0_15::JsonLdStore, FindRecipeIds, GetContentUrlProperty, GetCookTimeProperty,
+    GetImageProperty, GetItemListElementProperty, GetNameProperty, GetPrepTimeProperty,
+    GetRecipeIngredientProperty, GetRecipeInstructionsProperty, GetTextProperty,
+    GetTotalTimeProperty, GetVideoProperty,
 };
 use url::Url;
 
-use crate::external_recipe::{
-    error::ExternalRecipeError, generic::generic_external_recipe_getters,
-    specific::specific_external_recipe_getters,
+use crate::{
+    external_recipe::{
+        error::ExternalRecipeError, generic::generic_external_recipe_getters,
+        specific::specific_external_recipe_getters,
+    },
+    recipe_time::parse_iso_8601_duration,
 };
 
 pub mod error;
@@ -41,6 +45,12 @@ pub struct ExternalRecipe {
     pub ingredients: Vec<String>,
     pub files: Vec<String>,
     pub steps: Vec<ExternalRecipeStep>,
+    /// the preparation time in seconds
+    pub prep_time: Option<i64>,
+    /// the cooking time in seconds
+    pub cook_time: Option<i64>,
+    /// the total time in seconds
+    pub total_time: Option<i64>,
 }
 
 impl ExternalRecipe {
@@ -114,12 +124,24 @@ impl ExternalRecipe {
                 },
             )
             .collect();
+        let get_duration = |objects: Vec<&Object>| {
+            objects
+                .into_iter()
+                .filter_map(|object| object.as_literal())
+                .find_map(|literal| parse_iso_8601_duration(literal.as_str()))
+        };
+        let prep_time = get_duration(json_ld_store.get_prep_time_property(recipe_id));
+        let cook_time = get_duration(json_ld_store.get_cook_time_property(recipe_id));
+        let total_time = get_duration(json_ld_store.get_total_time_property(recipe_id));
 
         Self {
             name,
             ingredients,
             files,
             steps,
+            prep_time,
+            cook_time,
+            total_time,
         }
     }
 }
@@ -172,6 +172,9 @@ mod tests {
                             files: vec![],
                         },
                     ],
+                    prep_time: None,
+                    cook_time: None,
+                    total_time: None,
                 },
             },
             ExpectedGet {
@@ -217,6 +220,9 @@ mod tests {
                             files: vec![],
                         },
                     ],
+                    prep_time: None,
+                    cook_time: None,
+                    total_time: None,
                 },
             },
         ]
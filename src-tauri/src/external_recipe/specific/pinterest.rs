@@ -343,6 +343,9 @@ mod tests {
                             files: vec![],
                         },
                     ],
+                    prep_time: None,
+                    cook_time: None,
+                    total_time: None,
                 },
             },
             ExpectedGet {
@@ -459,6 +462,9 @@ mod tests {
                             files: vec![],
                         },
                     ],
+                    prep_time: None,
+                    cook_time: None,
+                    total_time: None,
                 },
             },
         ]
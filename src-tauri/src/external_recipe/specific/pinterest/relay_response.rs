@@ -1,9 +1,9 @@
 use std::{fmt, fmt::Formatter};
 
 use serde::{
+    __private::de::{ContentDeserializer, TaggedContentVisitor},
     de::Visitor,
     Deserialize, Deserializer,
-    __private::de::{ContentDeserializer, TaggedContentVisitor},
 };
 
 #[derive(Debug, Clone, Deserialize)]
@@ -132,6 +132,9 @@ mod tests {
                 "https://sallys-blog.de/_next/image?url=https%3A%2F%2Fimg2.storyblok.com%2F950x650%2Ff%2F130848%2F799x533%2F2db64f46e1%2F1292_19675_ofenkebab_kartoffel_2-jpg.jpg&w=3840&q=75".to_string(),
                     ],
                 }],
+                prep_time: None,
+                cook_time: None,
+                total_time: None,
             },
         }]
     }
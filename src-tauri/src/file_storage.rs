@@ -1,6 +1,6 @@
 //! This module handles storage of binary files like images.
 
-use std::path::PathBuf;
+use std::{io::ErrorKind, path::PathBuf, time::SystemTime};
 
 use tokio::fs;
 use uuid::Uuid;
@@ -55,6 +55,32 @@ pub async fn delete(path: &str) -> Result<(), FileStorageError> {
     Ok(())
 }
 
+/// Lists the paths of all stored files which were last modified before a time.
+///
+/// # Errors
+///
+/// - [`FileStorageError::Io`] when there is an I/O error while reading a directory
+pub async fn list(modified_before: SystemTime) -> Result<Vec<PathBuf>, FileStorageError> {
+    let mut paths = vec![];
+    let mut dirs = vec![root_dir()];
+    while let Some(dir) = dirs.pop() {
+        let mut entries = match fs::read_dir(&dir).await {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == ErrorKind::NotFound => continue,
+            Err(err) => return Err(err.into()),
+        };
+        while let Some(entry) = entries.next_entry().await? {
+            let metadata = entry.metadata().await?;
+            if metadata.is_dir() {
+                dirs.push(entry.path());
+            } else if metadata.modified()? < modified_before {
+                paths.push(entry.path());
+            }
+        }
+    }
+    Ok(paths)
+}
+
 /// Get the file storage root directory.
 fn root_dir() -> PathBuf {
     let mut dir = app_data_dir();
@@ -80,6 +106,8 @@ fn generate_file_path(mime: &str) -> PathBuf {
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use mime_guess::mime::APPLICATION_OCTET_STREAM;
 
     use super::*;
@@ -123,6 +151,27 @@ mod tests {
         TEST_NAME.set(None);
     }
 
+    #[tokio::test]
+    async fn test_list() {
+        TEST_NAME.set(Some("file_storage__test_list".to_string()));
+        crate::tests::run();
+
+        let temp_path = create_temp_file("file_storage__test_list.bin", "");
+        let path = create(
+            &temp_path.to_string_lossy(),
+            APPLICATION_OCTET_STREAM.essence_str(),
+        )
+        .await
+        .unwrap();
+        assert!(list(SystemTime::UNIX_EPOCH).await.unwrap().is_empty());
+        let paths = list(SystemTime::now() + Duration::from_secs(1))
+            .await
+            .unwrap();
+        assert_eq!(paths, vec![path]);
+
+        TEST_NAME.set(None);
+    }
+
     #[tokio::test]
     async fn test_delete_invalid() {
         crate::tests::run();
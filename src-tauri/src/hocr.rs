@@ -0,0 +1,484 @@
+//! This module implements parsing [hOCR](https://kba.github.io/hocr-spec/1.2/) as produced by Tesseract.
+//!
+//! See [`parse`] for more information.
+
+use quick_xml::{
+    events::{BytesStart, Event},
+    Reader,
+};
+use serde::Serialize;
+
+use crate::hocr::error::HocrError;
+
+pub mod error;
+
+/// The bounding box of an element in image pixels, with the origin in the top left corner.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoundingBox {
+    pub x0: i32,
+    pub y0: i32,
+    pub x1: i32,
+    pub y1: i32,
+}
+
+impl BoundingBox {
+    pub fn height(&self) -> i32 {
+        self.y1 - self.y0
+    }
+}
+
+/// This struct represents an `ocr_page`.
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HocrPage {
+    pub id: Option<String>,
+    pub bbox: BoundingBox,
+    pub blocks: Vec<HocrBlock>,
+}
+
+/// This struct represents an `ocr_carea`, a block of text.
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HocrBlock {
+    pub id: Option<String>,
+    pub bbox: BoundingBox,
+    pub paragraphs: Vec<HocrParagraph>,
+}
+
+/// This struct represents an `ocr_par`.
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HocrParagraph {
+    pub id: Option<String>,
+    pub bbox: BoundingBox,
+    pub lang: Option<String>,
+    pub lines: Vec<HocrLine>,
+}
+
+/// This struct represents an `ocr_line` or one of its variants like `ocr_header` or `ocr_caption`.
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HocrLine {
+    pub id: Option<String>,
+    pub bbox: BoundingBox,
+    pub words: Vec<HocrWord>,
+}
+
+impl HocrLine {
+    /// Get the text of the line with its words separated by spaces.
+    pub fn text(&self) -> String {
+        self.words
+            .iter()
+            .map(|word| word.text.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// This struct represents an `ocrx_word`.
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HocrWord {
+    pub id: Option<String>,
+    pub bbox: BoundingBox,
+    /// The confidence between 0 and 100, see `x_wconf`.
+    pub confidence: Option<f32>,
+    pub text: String,
+}
+
+/// The hOCR classes this parser cares about, ordered by depth.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Class {
+    Page,
+    Block,
+    Paragraph,
+    Line,
+    Word,
+}
+
+impl Class {
+    fn from_class_attribute(class: &str) -> Option<Self> {
+        class.split_whitespace().find_map(|class| match class {
+            "ocr_page" => Some(Self::Page),
+            "ocr_carea" => Some(Self::Block),
+            "ocr_par" => Some(Self::Paragraph),
+            "ocr_line" | "ocr_header" | "ocr_caption" | "ocr_textfloat" => Some(Self::Line),
+            "ocrx_word" => Some(Self::Word),
+            _ => None,
+        })
+    }
+}
+
+/// The attributes of an element with a known [`Class`].
+#[derive(Debug, Default)]
+struct Element {
+    id: Option<String>,
+    lang: Option<String>,
+    bbox: BoundingBox,
+    confidence: Option<f32>,
+}
+
+/// Parse the hOCR output of Tesseract into its pages.
+///
+/// Elements are attached to their nearest known ancestor, so e.g. a word is always inside a line.
+/// Missing ancestors are created with the bounding box of their first child.
+///
+/// # Errors
+///
+/// - [`HocrError::Xml`] when the input is no well-formed XML
+pub fn parse(hocr: &str) -> Result<Vec<HocrPage>, HocrError> {
+    let mut reader = Reader::from_str(hocr);
+    // The class of every open element, to know what to close on its end.
+    let mut open_classes: Vec<Option<Class>> = vec![];
+    let mut pages: Vec<HocrPage> = vec![];
+    // The class of the deepest element which is currently open, to attach children and text.
+    let mut current: Option<Class> = None;
+    loop {
+        match reader.read_event()? {
+            Event::Start(start) => {
+                let class = open_element(&start, &mut pages, current)?;
+                if class.is_some() {
+                    current = class;
+                }
+                open_classes.push(class);
+            }
+            Event::Empty(start) => {
+                open_element(&start, &mut pages, current)?;
+            }
+            Event::End(_) => {
+                if let Some(Some(class)) = open_classes.pop() {
+                    current = open_classes
+                        .iter()
+                        .rev()
+                        .find_map(|class| *class)
+                        .filter(|parent| *parent < class);
+                }
+            }
+            Event::Text(text) if current == Some(Class::Word) => {
+                if let Some(word) = last_word(&mut pages) {
+                    word.text.push_str(&text.unescape()?);
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    for page in &mut pages {
+        for block in &mut page.blocks {
+            for paragraph in &mut block.paragraphs {
+                for line in &mut paragraph.lines {
+                    for word in &mut line.words {
+                        word.text = word.text.trim().to_string();
+                    }
+                    line.words.retain(|word| !word.text.is_empty());
+                }
+                paragraph.lines.retain(|line| !line.words.is_empty());
+            }
+        }
+    }
+    Ok(pages)
+}
+
+/// Open an element if it has a known [`Class`] and attach it to the tree.
+fn open_element(
+    start: &BytesStart,
+    pages: &mut Vec<HocrPage>,
+    current: Option<Class>,
+) -> Result<Option<Class>, HocrError> {
+    let mut class = None;
+    let mut element = Element::default();
+    for attribute in start.attributes() {
+        let attribute = attribute?;
+        let value = attribute.unescape_value()?;
+        match attribute.key.as_ref() {
+            b"class" => class = Class::from_class_attribute(&value),
+            b"id" => element.id = Some(value.to_string()),
+            b"lang" => element.lang = Some(value.to_string()),
+            b"title" => parse_title(&value, &mut element),
+            _ => {}
+        }
+    }
+    let Some(class) = class else {
+        return Ok(None);
+    };
+    // Text inside a word is never a new element, e.g. `<strong>`.
+    if current == Some(Class::Word) {
+        return Ok(None);
+    }
+    attach(pages, class, element);
+    Ok(Some(class))
+}
+
+/// Parse the properties of the `title` attribute, e.g. `bbox 36 92 96 116; x_wconf 95`.
+fn parse_title(title: &str, element: &mut Element) {
+    for property in title.split(';') {
+        let mut parts = property.split_whitespace();
+        match parts.next() {
+            Some("bbox") => {
+                let coordinates: Vec<i32> = parts.filter_map(|part| part.parse().ok()).collect();
+                if let [x0, y0, x1, y1] = coordinates[..] {
+                    element.bbox = BoundingBox { x0, y0, x1, y1 };
+                }
+            }
+            Some("x_wconf") => {
+                element.confidence = parts.next().and_then(|part| part.parse().ok());
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Attach a new element as the last child of the last element one level above it.
+fn attach(pages: &mut Vec<HocrPage>, class: Class, element: Element) {
+    let bbox = element.bbox;
+    if class == Class::Page {
+        pages.push(HocrPage {
+            id: element.id,
+            bbox,
+            blocks: vec![],
+        });
+        return;
+    }
+    if pages.is_empty() {
+        pages.push(HocrPage {
+            bbox,
+            ..Default::default()
+        });
+    }
+    let page = pages.last_mut().unwrap();
+    if class == Class::Block || page.blocks.is_empty() {
+        page.blocks.push(HocrBlock {
+            bbox,
+            ..Default::default()
+        });
+    }
+    let block = page.blocks.last_mut().unwrap();
+    if class == Class::Block {
+        block.id = element.id;
+        return;
+    }
+    if class == Class::Paragraph || block.paragraphs.is_empty() {
+        block.paragraphs.push(HocrParagraph {
+            bbox,
+            ..Default::default()
+        });
+    }
+    let paragraph = block.paragraphs.last_mut().unwrap();
+    if class == Class::Paragraph {
+        paragraph.id = element.id;
+        paragraph.lang = element.lang;
+        return;
+    }
+    if class == Class::Line || paragraph.lines.is_empty() {
+        paragraph.lines.push(HocrLine {
+            bbox,
+            ..Default::default()
+        });
+    }
+    let line = paragraph.lines.last_mut().unwrap();
+    if class == Class::Line {
+        line.id = element.id;
+        return;
+    }
+    line.words.push(HocrWord {
+        id: element.id,
+        bbox,
+        confidence: element.confidence,
+        text: String::new(),
+    });
+}
+
+fn last_word(pages: &mut [HocrPage]) -> Option<&mut HocrWord> {
+    pages
+        .last_mut()?
+        .blocks
+        .last_mut()?
+        .paragraphs
+        .last_mut()?
+        .lines
+        .last_mut()?
+        .words
+        .last_mut()
+}
+
+/// Remove all words with a confidence below the minimum and all elements which are empty afterwards.
+///
+/// Words without a confidence are kept.
+pub fn retain_confident(pages: &mut [HocrPage], min_confidence: f32) {
+    for page in pages.iter_mut() {
+        for block in &mut page.blocks {
+            for paragraph in &mut block.paragraphs {
+                for line in &mut paragraph.lines {
+                    line.words.retain(|word| {
+                        word.confidence
+                            .map_or(true, |confidence| confidence >= min_confidence)
+                    });
+                }
+                paragraph.lines.retain(|line| !line.words.is_empty());
+            }
+            block
+                .paragraphs
+                .retain(|paragraph| !paragraph.lines.is_empty());
+        }
+        page.blocks.retain(|block| !block.paragraphs.is_empty());
+    }
+}
+
+/// Get the text of the pages in reading order.
+///
+/// Lines are separated by a line break, paragraphs, blocks and pages by an empty line.
+pub fn text(pages: &[HocrPage]) -> String {
+    pages
+        .iter()
+        .flat_map(|page| &page.blocks)
+        .flat_map(|block| &block.paragraphs)
+        .map(|paragraph| {
+            paragraph
+                .lines
+                .iter()
+                .map(HocrLine::text)
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .filter(|paragraph| !paragraph.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const HOCR: &str = "  <div class='ocr_page' id='page_1' title='image \"\"; bbox 0 0 800 600; ppageno 0; scan_res 70 70'>
+   <div class='ocr_carea' id='block_1_1' title=\"bbox 36 92 580 190\">
+    <p class='ocr_par' id='par_1_1' lang='deu' title=\"bbox 36 92 580 190\">
+     <span class='ocr_line' id='line_1_1' title=\"bbox 36 92 580 116; baseline 0 -6; x_size 24; x_descenders 6; x_ascenders 6\">
+      <span class='ocrx_word' id='word_1_1' title='bbox 36 92 96 116; x_wconf 95'>250</span>
+      <span class='ocrx_word' id='word_1_2' title='bbox 109 92 130 116; x_wconf 91'>g</span>
+      <span class='ocrx_word' id='word_1_3' title='bbox 144 92 240 116; x_wconf 42'><strong>M&amp;ehl</strong></span>
+     </span>
+     <span class='ocr_header' id='line_1_2' title=\"bbox 36 160 580 190\">
+      <span class='ocrx_word' id='word_1_4' title='bbox 36 160 100 190; x_wconf 12'>~</span>
+     </span>
+    </p>
+   </div>
+  </div>
+";
+
+    fn expected_pages() -> Vec<HocrPage> {
+        vec![HocrPage {
+            id: Some(String::from("page_1")),
+            bbox: BoundingBox {
+                x0: 0,
+                y0: 0,
+                x1: 800,
+                y1: 600,
+            },
+            blocks: vec![HocrBlock {
+                id: Some(String::from("block_1_1")),
+                bbox: BoundingBox {
+                    x0: 36,
+                    y0: 92,
+                    x1: 580,
+                    y1: 190,
+                },
+                paragraphs: vec![HocrParagraph {
+                    id: Some(String::from("par_1_1")),
+                    bbox: BoundingBox {
+                        x0: 36,
+                        y0: 92,
+                        x1: 580,
+                        y1: 190,
+                    },
+                    lang: Some(String::from("deu")),
+                    lines: vec![
+                        HocrLine {
+                            id: Some(String::from("line_1_1")),
+                            bbox: BoundingBox {
+                                x0: 36,
+                                y0: 92,
+                                x1: 580,
+                                y1: 116,
+                            },
+                            words: vec![
+                                HocrWord {
+                                    id: Some(String::from("word_1_1")),
+                                    bbox: BoundingBox {
+                                        x0: 36,
+                                        y0: 92,
+                                        x1: 96,
+                                        y1: 116,
+                                    },
+                                    confidence: Some(95.0),
+                                    text: String::from("250"),
+                                },
+                                HocrWord {
+                                    id: Some(String::from("word_1_2")),
+                                    bbox: BoundingBox {
+                                        x0: 109,
+                                        y0: 92,
+                                        x1: 130,
+                                        y1: 116,
+                                    },
+                                    confidence: Some(91.0),
+                                    text: String::from("g"),
+                                },
+                                HocrWord {
+                                    id: Some(String::from("word_1_3")),
+                                    bbox: BoundingBox {
+                                        x0: 144,
+                                        y0: 92,
+                                        x1: 240,
+                                        y1: 116,
+                                    },
+                                    confidence: Some(42.0),
+                                    text: String::from("M&ehl"),
+                                },
+                            ],
+                        },
+                        HocrLine {
+                            id: Some(String::from("line_1_2")),
+                            bbox: BoundingBox {
+                                x0: 36,
+                                y0: 160,
+                                x1: 580,
+                                y1: 190,
+                            },
+                            words: vec![HocrWord {
+                                id: Some(String::from("word_1_4")),
+                                bbox: BoundingBox {
+                                    x0: 36,
+                                    y0: 160,
+                                    x1: 100,
+                                    y1: 190,
+                                },
+                                confidence: Some(12.0),
+                                text: String::from("~"),
+                            }],
+                        },
+                    ],
+                }],
+            }],
+        }]
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse(HOCR).unwrap(), expected_pages());
+    }
+
+    #[test]
+    fn test_retain_confident() {
+        let mut pages = parse(HOCR).unwrap();
+        retain_confident(&mut pages, 50.0);
+        assert_eq!(text(&pages), "250 g");
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(text(&expected_pages()), "250 g M&ehl\n~");
+    }
+}
@@ -0,0 +1,11 @@
+//! This module contains the [`std::error::Error`] for the [`crate::hocr`] module.
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum HocrError {
+    #[error(transparent)]
+    Xml(#[from] quick_xml::Error),
+    #[error(transparent)]
+    XmlAttribute(#[from] quick_xml::events::attributes::AttrError),
+}
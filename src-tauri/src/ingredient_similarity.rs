@@ -0,0 +1,173 @@
+//! This module finds ingredients which are likely duplicates of each other, e.g. _Zwiebel_, _Zwiebeln_ and _zwiebel_.
+//!
+//! Ingredient names are compared by a key which is case folded and stripped of a plural suffix.
+//! Keys which are equal, or close enough by edit distance, make their ingredients duplicates.
+
+use crate::entity::ingredient::Model;
+
+/// The suffixes which are stripped from the last word of a name to get its singular, with their replacement.
+///
+/// The first matching suffix is stripped.
+const PLURAL_SUFFIXES: [(&str, &str); 6] = [
+    ("ies", "y"),
+    ("es", ""),
+    ("en", ""),
+    ("s", ""),
+    ("n", ""),
+    ("e", ""),
+];
+
+/// A name keeps at least this many characters when stripping a plural suffix.
+const MIN_STEM_CHARS: usize = 3;
+
+/// Keys need this many characters per allowed edit to be similar.
+const MIN_CHARS_PER_EDIT: usize = 5;
+
+/// Group the ingredients which are likely duplicates of each other.
+///
+/// Each group contains at least two ingredient ids, sorted ascending, and the groups are sorted by their first id.
+pub fn duplicate_groups(ingredients: &[Model]) -> Vec<Vec<i64>> {
+    let keys: Vec<String> = ingredients
+        .iter()
+        .map(|ingredient| key(&ingredient.name))
+        .collect();
+    let mut roots: Vec<usize> = (0..ingredients.len()).collect();
+    for i in 0..keys.len() {
+        for j in (i + 1)..keys.len() {
+            if is_similar(&keys[i], &keys[j]) {
+                let root_i = find_root(&mut roots, i);
+                let root_j = find_root(&mut roots, j);
+                roots[root_i.max(root_j)] = root_i.min(root_j);
+            }
+        }
+    }
+    let mut groups: Vec<Vec<i64>> = vec![vec![]; ingredients.len()];
+    for (i, ingredient) in ingredients.iter().enumerate() {
+        let root = find_root(&mut roots, i);
+        groups[root].push(ingredient.id);
+    }
+    let mut groups: Vec<Vec<i64>> = groups
+        .into_iter()
+        .filter(|group| group.len() > 1)
+        .map(|mut group| {
+            group.sort_unstable();
+            group
+        })
+        .collect();
+    groups.sort_unstable();
+    groups
+}
+
+/// Get the key of an ingredient name, which is case folded and stripped of a plural suffix.
+fn key(name: &str) -> String {
+    let folded = name
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+    for (suffix, replacement) in PLURAL_SUFFIXES {
+        if let Some(stem) = folded.strip_suffix(suffix) {
+            if stem.chars().count() >= MIN_STEM_CHARS && !stem.ends_with(' ') {
+                return format!("{stem}{replacement}");
+            }
+        }
+    }
+    folded
+}
+
+/// Check whether two keys are equal, or close enough by edit distance.
+fn is_similar(a: &str, b: &str) -> bool {
+    let max_distance = a.chars().count().min(b.chars().count()) / MIN_CHARS_PER_EDIT;
+    edit_distance(a, b) <= max_distance
+}
+
+/// Get the Levenshtein distance between two strings, counting characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous_row: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut row = Vec::with_capacity(b.len() + 1);
+        row.push(i + 1);
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous_row[j] + usize::from(a_char != *b_char);
+            row.push(substitution.min(previous_row[j + 1] + 1).min(row[j] + 1));
+        }
+        previous_row = row;
+    }
+    previous_row[b.len()]
+}
+
+/// Find the root of an element in the union-find forest, compressing the path.
+fn find_root(roots: &mut [usize], element: usize) -> usize {
+    let mut root = element;
+    while roots[root] != root {
+        root = roots[root];
+    }
+    let mut current = element;
+    while roots[current] != root {
+        let next = roots[current];
+        roots[current] = root;
+        current = next;
+    }
+    root
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn ingredients(names: &[&str]) -> Vec<Model> {
+        names
+            .iter()
+            .zip(1..)
+            .map(|(name, id)| Model {
+                id,
+                name: name.to_string(),
+                ingredient_category_id: None,
+                vegetarian: None,
+                vegan: None,
+                gluten_free: None,
+                lactose_free: None,
+                created_at: 0,
+                updated_at: 0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_key() {
+        assert_eq!(key("Zwiebeln"), "zwiebel");
+        assert_eq!(key(" zwiebel "), "zwiebel");
+        assert_eq!(key("Tomaten"), key("Tomate"));
+        assert_eq!(key("Cherries"), "cherry");
+        assert_eq!(key("red  Onions"), "red onion");
+        assert_eq!(key("Eis"), "eis");
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("knoblauch", "knobluach"), 2);
+        assert_eq!(edit_distance("möhre", "mohre"), 1);
+        assert_eq!(edit_distance("salz", "salz"), 0);
+    }
+
+    #[test]
+    fn test_duplicate_groups() {
+        assert_eq!(
+            duplicate_groups(&ingredients(&[
+                "Zwiebel",
+                "Salz",
+                "Zwiebeln",
+                "Malz",
+                "zwiebel",
+                "Petersilie",
+                "Petersillie",
+            ])),
+            vec![vec![1, 3, 5], vec![6, 7]]
+        );
+        assert!(duplicate_groups(&ingredients(&["Mehl"])).is_empty());
+    }
+}
@@ -0,0 +1,529 @@
+//! This module implements the undo/redo journal of entity mutations.
+//!
+//! Every mutation via [`EntityCrudTrait`] records a [journal action](journal_action::Model), to which all its [changes](change_log::Model) belong.
+//! Undoing an action applies the inverse of its changes in reverse order, redoing it applies its changes again.
+//! Both happen in one transaction and emit the usual entity action events.
+//!
+//! Files stay in the storage as long as the journal can restore an entity referencing them, see [`FileCrud::collect_garbage`].
+//! Purging recipes from the trash is not journaled and drops the journal actions which changed the purged entities, see [`forget`].
+//!
+//! Only the latest actions are kept, and so are the latest changes which no action references anymore, see [`prune`].
+
+use std::collections::HashSet;
+
+use anyhow::{bail, Result};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseTransaction, DbErr, EntityTrait, JsonValue,
+    ModelTrait, QueryFilter, QueryOrder, QuerySelect, TransactionTrait,
+};
+
+use crate::{
+    database,
+    entity::{change_log, journal_action},
+    entity_crud::{
+        collection::CollectionCrud, collection_recipe::CollectionRecipeCrud, cook_log::CookLogCrud,
+        cook_log_file::CookLogFileCrud, file::FileCrud, ingredient::IngredientCrud,
+        ingredient_alias::IngredientAliasCrud, ingredient_allergen::IngredientAllergenCrud,
+        ingredient_category::IngredientCategoryCrud, nutrient_profile::NutrientProfileCrud,
+        recipe::RecipeCrud, recipe_file::RecipeFileCrud,
+        recipe_ingredient_draft::RecipeIngredientDraftCrud, recipe_step::RecipeStepCrud,
+        recipe_step_file::RecipeStepFileCrud, recipe_step_ingredient::RecipeStepIngredientCrud,
+        recipe_step_ingredient_draft::RecipeStepIngredientDraftCrud, recipe_tag::RecipeTagCrud,
+        tag::TagCrud, unit_name::UnitNameCrud, EntityCrudTrait,
+    },
+};
+
+/// the number of latest journal actions which can be undone
+const JOURNAL_LENGTH: u64 = 100;
+
+/// the number of latest changes which are kept without belonging to a journal action,
+/// so windows can still catch up on them, see [`crate::command::change_log::changes_since`]
+const CHANGE_LOG_LENGTH: u64 = 1000;
+
+/// Record a new journal action, which clears the redo stack and prunes the journal.
+///
+/// # Errors
+///
+/// - when there is any problem with the database
+pub async fn record_action(txn: &DatabaseTransaction) -> Result<i64, DbErr> {
+    journal_action::Entity::delete_many()
+        .filter(journal_action::Column::Undone.eq(true))
+        .exec(txn)
+        .await?;
+    prune(JOURNAL_LENGTH - 1, CHANGE_LOG_LENGTH, txn).await?;
+    let journal_action = journal_action::ActiveModel {
+        id: ActiveValue::NotSet,
+        undone: ActiveValue::Set(false),
+    }
+    .insert(txn)
+    .await?;
+    Ok(journal_action.id)
+}
+
+/// Delete the journal actions which changed the entities of the given changes, so they can't be undone or redone anymore.
+///
+/// This is needed when changes are made which the journal can't undo, like purging entities.
+/// The logged changes are kept, they just don't belong to a journal action anymore.
+///
+/// # Errors
+///
+/// - when there is any problem with the database
+pub async fn forget(changes: &[change_log::Model], txn: &DatabaseTransaction) -> Result<(), DbErr> {
+    let entities: HashSet<&str> = changes
+        .iter()
+        .map(|change| change.entity.as_str())
+        .collect();
+    let journal_changes = change_log::Entity::find()
+        .filter(change_log::Column::JournalActionId.is_not_null())
+        .filter(change_log::Column::Entity.is_in(entities))
+        .all(txn)
+        .await?;
+    let journal_action_ids: HashSet<i64> = journal_changes
+        .into_iter()
+        .filter(|journal_change| {
+            changes.iter().any(|change| {
+                change.entity == journal_change.entity
+                    && change.entity_id == journal_change.entity_id
+            })
+        })
+        .filter_map(|journal_change| journal_change.journal_action_id)
+        .collect();
+    journal_action::Entity::delete_many()
+        .filter(journal_action::Column::Id.is_in(journal_action_ids))
+        .exec(txn)
+        .await?;
+    Ok(())
+}
+
+/// Delete all but the latest `journal_length` journal actions,
+/// and all but the latest `change_log_length` changes which don't belong to a journal action.
+///
+/// Without this, the change log would grow with every mutation.
+///
+/// # Errors
+///
+/// - when there is any problem with the database
+async fn prune(
+    journal_length: u64,
+    change_log_length: u64,
+    txn: &DatabaseTransaction,
+) -> Result<(), DbErr> {
+    let last_pruned_action = journal_action::Entity::find()
+        .order_by_desc(journal_action::Column::Id)
+        .offset(journal_length)
+        .one(txn)
+        .await?;
+    if let Some(last_pruned_action) = last_pruned_action {
+        journal_action::Entity::delete_many()
+            .filter(journal_action::Column::Id.lte(last_pruned_action.id))
+            .exec(txn)
+            .await?;
+    }
+    let last_pruned_change = change_log::Entity::find()
+        .order_by_desc(change_log::Column::Seq)
+        .offset(change_log_length)
+        .one(txn)
+        .await?;
+    if let Some(last_pruned_change) = last_pruned_change {
+        change_log::Entity::delete_many()
+            .filter(change_log::Column::Seq.lte(last_pruned_change.seq))
+            .filter(change_log::Column::JournalActionId.is_null())
+            .exec(txn)
+            .await?;
+    }
+    Ok(())
+}
+
+/// Undo the last journal action which is not undone yet.
+///
+/// Returns whether there was an action to undo.
+///
+/// # Errors
+///
+/// - when there is any problem with the database, e.g. when an entity to restore references a deleted one
+/// - when the tauri window can't be messaged about the changes
+pub async fn undo() -> Result<bool> {
+    let db = database::connect_writing().await;
+    let txn = db.begin().await?;
+    let journal_action_option = journal_action::Entity::find()
+        .filter(journal_action::Column::Undone.eq(false))
+        .order_by_desc(journal_action::Column::Id)
+        .one(&txn)
+        .await?;
+    let Some(journal_action) = journal_action_option else {
+        return Ok(false);
+    };
+    let changes = journal_action
+        .find_related(change_log::Entity)
+        .order_by_desc(change_log::Column::Seq)
+        .all(&txn)
+        .await?;
+    let mut applied_changes = Vec::with_capacity(changes.len());
+    for change in changes {
+        applied_changes.push(apply(&change.entity, change.new, change.old, &txn).await?);
+    }
+    set_undone(journal_action, true, &txn).await?;
+    txn.commit().await?;
+    emit(&applied_changes)?;
+    Ok(true)
+}
+
+/// Redo the first undone journal action.
+///
+/// Returns whether there was an action to redo.
+///
+/// # Errors
+///
+/// - when there is any problem with the database, e.g. when an entity to restore references a deleted one
+/// - when the tauri window can't be messaged about the changes
+pub async fn redo() -> Result<bool> {
+    let db = database::connect_writing().await;
+    let txn = db.begin().await?;
+    let journal_action_option = journal_action::Entity::find()
+        .filter(journal_action::Column::Undone.eq(true))
+        .order_by_asc(journal_action::Column::Id)
+        .one(&txn)
+        .await?;
+    let Some(journal_action) = journal_action_option else {
+        return Ok(false);
+    };
+    let changes = journal_action
+        .find_related(change_log::Entity)
+        .order_by_asc(change_log::Column::Seq)
+        .all(&txn)
+        .await?;
+    let mut applied_changes = Vec::with_capacity(changes.len());
+    for change in changes {
+        applied_changes.push(apply(&change.entity, change.old, change.new, &txn).await?);
+    }
+    set_undone(journal_action, false, &txn).await?;
+    txn.commit().await?;
+    emit(&applied_changes)?;
+    Ok(true)
+}
+
+/// Emit logged changes of any entity on the tauri event channels of their entity and action.
+///
+/// # Errors
+///
+/// - when a change belongs to an unknown entity
+/// - when the tauri window can't be messaged about a change
+pub fn emit(changes: &[change_log::Model]) -> Result<()> {
+    for change in changes {
+        match change.entity.as_str() {
+            "collection" => CollectionCrud::emit_change(change)?,
+            "collection_recipe" => CollectionRecipeCrud::emit_change(change)?,
+            "cook_log" => CookLogCrud::emit_change(change)?,
+            "cook_log_file" => CookLogFileCrud::emit_change(change)?,
+            "file" => FileCrud::emit_change(change)?,
+            "ingredient" => IngredientCrud::emit_change(change)?,
+            "ingredient_alias" => IngredientAliasCrud::emit_change(change)?,
+            "ingredient_allergen" => IngredientAllergenCrud::emit_change(change)?,
+            "ingredient_category" => IngredientCategoryCrud::emit_change(change)?,
+            "nutrient_profile" => NutrientProfileCrud::emit_change(change)?,
+            "recipe" => RecipeCrud::emit_change(change)?,
+            "recipe_file" => RecipeFileCrud::emit_change(change)?,
+            "recipe_ingredient_draft" => RecipeIngredientDraftCrud::emit_change(change)?,
+            "recipe_step" => RecipeStepCrud::emit_change(change)?,
+            "recipe_step_file" => RecipeStepFileCrud::emit_change(change)?,
+            "recipe_step_ingredient" => RecipeStepIngredientCrud::emit_change(change)?,
+            "recipe_step_ingredient_draft" => RecipeStepIngredientDraftCrud::emit_change(change)?,
+            "recipe_tag" => RecipeTagCrud::emit_change(change)?,
+            "tag" => TagCrud::emit_change(change)?,
+            "unit_name" => UnitNameCrud::emit_change(change)?,
+            entity => bail!("Unknown entity {entity}"),
+        }
+    }
+    Ok(())
+}
+
+/// Apply a change of any entity, see [`EntityCrudTrait::apply`].
+async fn apply(
+    entity: &str,
+    from: Option<JsonValue>,
+    to: Option<JsonValue>,
+    txn: &DatabaseTransaction,
+) -> Result<change_log::Model> {
+    match entity {
+        "collection" => CollectionCrud::apply(from, to, None, txn).await,
+        "collection_recipe" => CollectionRecipeCrud::apply(from, to, None, txn).await,
+        "cook_log" => CookLogCrud::apply(from, to, None, txn).await,
+        "cook_log_file" => CookLogFileCrud::apply(from, to, None, txn).await,
+        "file" => FileCrud::apply(from, to, None, txn).await,
+        "ingredient" => IngredientCrud::apply(from, to, None, txn).await,
+        "ingredient_alias" => IngredientAliasCrud::apply(from, to, None, txn).await,
+        "ingredient_allergen" => IngredientAllergenCrud::apply(from, to, None, txn).await,
+        "ingredient_category" => IngredientCategoryCrud::apply(from, to, None, txn).await,
+        "nutrient_profile" => NutrientProfileCrud::apply(from, to, None, txn).await,
+        "recipe" => RecipeCrud::apply(from, to, None, txn).await,
+        "recipe_file" => RecipeFileCrud::apply(from, to, None, txn).await,
+        "recipe_ingredient_draft" => RecipeIngredientDraftCrud::apply(from, to, None, txn).await,
+        "recipe_step" => RecipeStepCrud::apply(from, to, None, txn).await,
+        "recipe_step_file" => RecipeStepFileCrud::apply(from, to, None, txn).await,
+        "recipe_step_ingredient" => RecipeStepIngredientCrud::apply(from, to, None, txn).await,
+        "recipe_step_ingredient_draft" => {
+            RecipeStepIngredientDraftCrud::apply(from, to, None, txn).await
+        }
+        "recipe_tag" => RecipeTagCrud::apply(from, to, None, txn).await,
+        "tag" => TagCrud::apply(from, to, None, txn).await,
+        "unit_name" => UnitNameCrud::apply(from, to, None, txn).await,
+        entity => bail!("Unknown entity {entity}"),
+    }
+}
+
+async fn set_undone(
+    journal_action: journal_action::Model,
+    undone: bool,
+    txn: &DatabaseTransaction,
+) -> Result<(), DbErr> {
+    let mut journal_action: journal_action::ActiveModel = journal_action.into();
+    journal_action.undone = ActiveValue::Set(undone);
+    journal_action.update(txn).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use sea_orm::PaginatorTrait;
+
+    use super::*;
+    use crate::{
+        entity::{file, ingredient, recipe, recipe_step, recipe_step_file, recipe_step_ingredient},
+        migrator::tests::get_memory_database_migrated,
+    };
+
+    #[tokio::test]
+    async fn test_undo_cascaded_delete() {
+        let db = get_memory_database_migrated().await;
+        recipe::ActiveModel {
+            name: ActiveValue::Set(String::from("soup")),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        recipe_step::ActiveModel {
+            order: ActiveValue::Set(1),
+            description: ActiveValue::Set(String::from("stir")),
+            recipe_id: ActiveValue::Set(1),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        ingredient::ActiveModel {
+            name: ActiveValue::Set(String::from("salt")),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        let step_ingredient = recipe_step_ingredient::ActiveModel {
+            order: ActiveValue::Set(1),
+            quantity: ActiveValue::Set(Some(1.0)),
+            unit: ActiveValue::Set(None),
+            quality: ActiveValue::Set(None),
+            recipe_step_id: ActiveValue::Set(1),
+            ingredient_id: ActiveValue::Set(1),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+
+        let txn = db.begin().await.unwrap();
+        let journal_action_id = record_action(&txn).await.unwrap();
+        let changes = RecipeCrud::delete_logged(1, Some(journal_action_id), &txn)
+            .await
+            .unwrap();
+        txn.commit().await.unwrap();
+        assert_eq!(
+            changes
+                .iter()
+                .map(|change| change.entity.as_str())
+                .collect::<Vec<_>>(),
+            vec!["recipe_step_ingredient", "recipe_step", "recipe"]
+        );
+        assert_eq!(recipe_step::Entity::find().count(&db).await.unwrap(), 0);
+
+        let txn = db.begin().await.unwrap();
+        for change in changes.into_iter().rev() {
+            apply(&change.entity, change.new, change.old, &txn)
+                .await
+                .unwrap();
+        }
+        txn.commit().await.unwrap();
+        assert_eq!(
+            recipe_step_ingredient::Entity::find_by_id(step_ingredient.id)
+                .one(&db)
+                .await
+                .unwrap(),
+            Some(step_ingredient)
+        );
+        assert_eq!(recipe::Entity::find().count(&db).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_undo_cascaded_delete_file() {
+        let db = get_memory_database_migrated().await;
+        recipe::ActiveModel {
+            name: ActiveValue::Set(String::from("soup")),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        recipe_step::ActiveModel {
+            order: ActiveValue::Set(1),
+            description: ActiveValue::Set(String::from("stir")),
+            recipe_id: ActiveValue::Set(1),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        file::ActiveModel {
+            name: ActiveValue::Set(String::from("photo")),
+            mime: ActiveValue::Set(String::from("image/jpeg")),
+            path: ActiveValue::Set(String::from("photo.jpg")),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        let step_file = recipe_step_file::ActiveModel {
+            order: ActiveValue::Set(1),
+            recipe_step_id: ActiveValue::Set(1),
+            file_id: ActiveValue::Set(1),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+
+        let txn = db.begin().await.unwrap();
+        let journal_action_id = record_action(&txn).await.unwrap();
+        let changes = RecipeStepCrud::delete_logged(1, Some(journal_action_id), &txn)
+            .await
+            .unwrap();
+        txn.commit().await.unwrap();
+        file::remove_orphans(i64::MAX, &db).await.unwrap();
+        assert_eq!(file::Entity::find().count(&db).await.unwrap(), 1);
+
+        let txn = db.begin().await.unwrap();
+        for change in changes.into_iter().rev() {
+            apply(&change.entity, change.new, change.old, &txn)
+                .await
+                .unwrap();
+        }
+        txn.commit().await.unwrap();
+        assert_eq!(
+            recipe_step_file::Entity::find_by_id(step_file.id)
+                .one(&db)
+                .await
+                .unwrap(),
+            Some(step_file)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_forget() {
+        let db = get_memory_database_migrated().await;
+        let txn = db.begin().await.unwrap();
+        for _ in 0..2 {
+            let journal_action_id = record_action(&txn).await.unwrap();
+            RecipeCrud::create_logged(
+                recipe::ActiveModel {
+                    name: ActiveValue::Set(String::from("soup")),
+                    ..Default::default()
+                },
+                Some(journal_action_id),
+                &txn,
+            )
+            .await
+            .unwrap();
+        }
+        let journal_action_id = record_action(&txn).await.unwrap();
+        RecipeCrud::update_logged(
+            recipe::ActiveModel {
+                id: ActiveValue::Unchanged(1),
+                name: ActiveValue::Set(String::from("stew")),
+                ..Default::default()
+            },
+            Some(journal_action_id),
+            &txn,
+        )
+        .await
+        .unwrap();
+        let changes = RecipeCrud::delete_logged(1, None, &txn).await.unwrap();
+        forget(&changes, &txn).await.unwrap();
+        txn.commit().await.unwrap();
+
+        let journal_action_ids: Vec<i64> = journal_action::Entity::find()
+            .all(&db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|journal_action| journal_action.id)
+            .collect();
+        assert_eq!(journal_action_ids, vec![2]);
+    }
+
+    #[tokio::test]
+    async fn test_prune() {
+        let db = get_memory_database_migrated().await;
+        let txn = db.begin().await.unwrap();
+        for _ in 0..3 {
+            let journal_action_id = record_action(&txn).await.unwrap();
+            RecipeCrud::create_logged(
+                recipe::ActiveModel {
+                    name: ActiveValue::Set(String::from("soup")),
+                    ..Default::default()
+                },
+                Some(journal_action_id),
+                &txn,
+            )
+            .await
+            .unwrap();
+        }
+        journal_action::Entity::delete_many()
+            .exec(&txn)
+            .await
+            .unwrap();
+        for _ in 0..3 {
+            let journal_action_id = record_action(&txn).await.unwrap();
+            RecipeCrud::create_logged(
+                recipe::ActiveModel {
+                    name: ActiveValue::Set(String::from("soup")),
+                    ..Default::default()
+                },
+                Some(journal_action_id),
+                &txn,
+            )
+            .await
+            .unwrap();
+        }
+        prune(2, 1, &txn).await.unwrap();
+        txn.commit().await.unwrap();
+
+        let journal_action_ids: Vec<i64> = journal_action::Entity::find()
+            .order_by_asc(journal_action::Column::Id)
+            .all(&db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|journal_action| journal_action.id)
+            .collect();
+        assert_eq!(journal_action_ids, vec![5, 6]);
+        let changes: Vec<(i64, Option<i64>)> = change_log::Entity::find()
+            .order_by_asc(change_log::Column::Seq)
+            .all(&db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|change| (change.seq, change.journal_action_id))
+            .collect();
+        assert_eq!(changes, vec![(5, Some(5)), (6, Some(6))]);
+    }
+}
@@ -5,62 +5,178 @@
 
 use tauri::Wry;
 
-use crate::command::{
-    entity::{
-        file::{
-            entity_count_file, entity_create_file, entity_delete_file, entity_list_file,
-            entity_read_file, entity_update_file,
+use crate::{
+    command::{
+        change_log::changes_since,
+        dietary::dietary_summary,
+        entity::{
+            collection::{
+                collection_export, entity_count_collection, entity_create_collection,
+                entity_create_many_collection, entity_delete_collection,
+                entity_delete_many_collection, entity_list_collection, entity_list_page_collection,
+                entity_read_collection, entity_update_collection, entity_update_many_collection,
+            },
+            collection_recipe::{
+                entity_count_collection_recipe, entity_create_collection_recipe,
+                entity_create_many_collection_recipe, entity_delete_collection_recipe,
+                entity_delete_many_collection_recipe, entity_list_collection_recipe,
+                entity_list_page_collection_recipe, entity_move_collection_recipe,
+                entity_read_collection_recipe, entity_reorder_collection_recipe,
+                entity_update_collection_recipe, entity_update_many_collection_recipe,
+            },
+            cook_log::{
+                entity_count_cook_log, entity_create_cook_log, entity_create_many_cook_log,
+                entity_delete_cook_log, entity_delete_many_cook_log, entity_list_cook_log,
+                entity_list_page_cook_log, entity_read_cook_log, entity_update_cook_log,
+                entity_update_many_cook_log,
+            },
+            cook_log_file::{
+                entity_count_cook_log_file, entity_create_cook_log_file,
+                entity_create_many_cook_log_file, entity_delete_cook_log_file,
+                entity_delete_many_cook_log_file, entity_list_cook_log_file,
+                entity_list_page_cook_log_file, entity_move_cook_log_file,
+                entity_read_cook_log_file, entity_reorder_cook_log_file,
+                entity_update_cook_log_file, entity_update_many_cook_log_file,
+            },
+            file::{
+                entity_count_file, entity_create_file, entity_create_many_file, entity_delete_file,
+                entity_delete_many_file, entity_list_file, entity_list_page_file, entity_read_file,
+                entity_update_file, entity_update_many_file,
+            },
+            ingredient::{
+                entity_count_ingredient, entity_create_ingredient, entity_create_many_ingredient,
+                entity_delete_ingredient, entity_delete_many_ingredient, entity_list_ingredient,
+                entity_list_page_ingredient, entity_read_ingredient, entity_update_ingredient,
+                entity_update_many_ingredient, ingredient_merge, ingredient_merge_suggestions,
+            },
+            ingredient_alias::{
+                entity_count_ingredient_alias, entity_create_ingredient_alias,
+                entity_create_many_ingredient_alias, entity_delete_ingredient_alias,
+                entity_delete_many_ingredient_alias, entity_list_ingredient_alias,
+                entity_list_page_ingredient_alias, entity_read_ingredient_alias,
+                entity_update_ingredient_alias, entity_update_many_ingredient_alias,
+            },
+            ingredient_allergen::{
+                entity_count_ingredient_allergen, entity_create_ingredient_allergen,
+                entity_create_many_ingredient_allergen, entity_delete_ingredient_allergen,
+                entity_delete_many_ingredient_allergen, entity_list_ingredient_allergen,
+                entity_list_page_ingredient_allergen, entity_read_ingredient_allergen,
+                entity_update_ingredient_allergen, entity_update_many_ingredient_allergen,
+            },
+            ingredient_category::{
+                entity_count_ingredient_category, entity_create_ingredient_category,
+                entity_create_many_ingredient_category, entity_delete_ingredient_category,
+                entity_delete_many_ingredient_category, entity_list_ingredient_category,
+                entity_list_page_ingredient_category, entity_read_ingredient_category,
+                entity_update_ingredient_category, entity_update_many_ingredient_category,
+            },
+            nutrient_profile::{
+                entity_count_nutrient_profile, entity_create_many_nutrient_profile,
+                entity_create_nutrient_profile, entity_delete_many_nutrient_profile,
+                entity_delete_nutrient_profile, entity_list_nutrient_profile,
+                entity_list_page_nutrient_profile, entity_read_nutrient_profile,
+                entity_update_many_nutrient_profile, entity_update_nutrient_profile,
+            },
+            recipe::{
+                entity_count_recipe, entity_create_many_recipe, entity_create_recipe,
+                entity_delete_many_recipe, entity_delete_recipe, entity_list_page_recipe,
+                entity_list_recipe, entity_purge_recipe, entity_purge_trash_recipe,
+                entity_read_recipe, entity_restore_recipe, entity_trash_recipe,
+                entity_update_many_recipe, entity_update_recipe, recipe_duplicate, recipe_export,
+            },
+            recipe_file::{
+                entity_count_recipe_file, entity_create_many_recipe_file,
+                entity_create_recipe_file, entity_delete_many_recipe_file,
+                entity_delete_recipe_file, entity_list_page_recipe_file, entity_list_recipe_file,
+                entity_move_recipe_file, entity_read_recipe_file, entity_reorder_recipe_file,
+                entity_update_many_recipe_file, entity_update_recipe_file,
+            },
+            recipe_ingredient_draft::{
+                entity_count_recipe_ingredient_draft, entity_create_many_recipe_ingredient_draft,
+                entity_create_recipe_ingredient_draft, entity_delete_many_recipe_ingredient_draft,
+                entity_delete_recipe_ingredient_draft, entity_list_page_recipe_ingredient_draft,
+                entity_list_recipe_ingredient_draft, entity_move_recipe_ingredient_draft,
+                entity_read_recipe_ingredient_draft, entity_reorder_recipe_ingredient_draft,
+                entity_update_many_recipe_ingredient_draft, entity_update_recipe_ingredient_draft,
+            },
+            recipe_step::{
+                entity_count_recipe_step, entity_create_many_recipe_step,
+                entity_create_recipe_step, entity_delete_many_recipe_step,
+                entity_delete_recipe_step, entity_list_page_recipe_step, entity_list_recipe_step,
+                entity_move_recipe_step, entity_read_recipe_step, entity_reorder_recipe_step,
+                entity_update_many_recipe_step, entity_update_recipe_step,
+            },
+            recipe_step_file::{
+                entity_count_recipe_step_file, entity_create_many_recipe_step_file,
+                entity_create_recipe_step_file, entity_delete_many_recipe_step_file,
+                entity_delete_recipe_step_file, entity_list_page_recipe_step_file,
+                entity_list_recipe_step_file, entity_move_recipe_step_file,
+                entity_read_recipe_step_file, entity_reorder_recipe_step_file,
+                entity_update_many_recipe_step_file, entity_update_recipe_step_file,
+            },
+            recipe_step_ingredient::{
+                entity_count_recipe_step_ingredient, entity_create_many_recipe_step_ingredient,
+                entity_create_recipe_step_ingredient, entity_delete_many_recipe_step_ingredient,
+                entity_delete_recipe_step_ingredient, entity_list_page_recipe_step_ingredient,
+                entity_list_recipe_step_ingredient, entity_move_recipe_step_ingredient,
+                entity_read_recipe_step_ingredient, entity_reorder_recipe_step_ingredient,
+                entity_update_many_recipe_step_ingredient, entity_update_recipe_step_ingredient,
+            },
+            recipe_step_ingredient_draft::{
+                entity_count_recipe_step_ingredient_draft,
+                entity_create_many_recipe_step_ingredient_draft,
+                entity_create_recipe_step_ingredient_draft,
+                entity_delete_many_recipe_step_ingredient_draft,
+                entity_delete_recipe_step_ingredient_draft,
+                entity_list_page_recipe_step_ingredient_draft,
+                entity_list_recipe_step_ingredient_draft, entity_move_recipe_step_ingredient_draft,
+                entity_read_recipe_step_ingredient_draft,
+                entity_reorder_recipe_step_ingredient_draft,
+                entity_update_many_recipe_step_ingredient_draft,
+                entity_update_recipe_step_ingredient_draft,
+            },
+            recipe_tag::{
+                entity_count_recipe_tag, entity_create_many_recipe_tag, entity_create_recipe_tag,
+                entity_delete_many_recipe_tag, entity_delete_recipe_tag,
+                entity_list_page_recipe_tag, entity_list_recipe_tag, entity_read_recipe_tag,
+                entity_update_many_recipe_tag, entity_update_recipe_tag,
+            },
+            tag::{
+                entity_count_tag, entity_create_many_tag, entity_create_tag,
+                entity_delete_many_tag, entity_delete_tag, entity_list_page_tag, entity_list_tag,
+                entity_read_tag, entity_update_many_tag, entity_update_tag, tag_cloud, tag_rename,
+            },
+            unit_name::{
+                entity_count_unit_name, entity_create_many_unit_name, entity_create_unit_name,
+                entity_delete_many_unit_name, entity_delete_unit_name, entity_list_page_unit_name,
+                entity_list_unit_name, entity_read_unit_name, entity_update_many_unit_name,
+                entity_update_unit_name,
+            },
         },
-        ingredient::{
-            entity_count_ingredient, entity_create_ingredient, entity_delete_ingredient,
-            entity_list_ingredient, entity_read_ingredient, entity_update_ingredient,
-        },
-        recipe::{
-            entity_count_recipe, entity_create_recipe, entity_delete_recipe, entity_list_recipe,
-            entity_read_recipe, entity_update_recipe,
-        },
-        recipe_file::{
-            entity_count_recipe_file, entity_create_recipe_file, entity_delete_recipe_file,
-            entity_list_recipe_file, entity_read_recipe_file, entity_update_recipe_file,
-        },
-        recipe_ingredient_draft::{
-            entity_count_recipe_ingredient_draft, entity_create_recipe_ingredient_draft,
-            entity_delete_recipe_ingredient_draft, entity_list_recipe_ingredient_draft,
-            entity_read_recipe_ingredient_draft, entity_update_recipe_ingredient_draft,
-        },
-        recipe_step::{
-            entity_count_recipe_step, entity_create_recipe_step, entity_delete_recipe_step,
-            entity_list_recipe_step, entity_read_recipe_step, entity_update_recipe_step,
-        },
-        recipe_step_file::{
-            entity_count_recipe_step_file, entity_create_recipe_step_file,
-            entity_delete_recipe_step_file, entity_list_recipe_step_file,
-            entity_read_recipe_step_file, entity_update_recipe_step_file,
-        },
-        recipe_step_ingredient::{
-            entity_count_recipe_step_ingredient, entity_create_recipe_step_ingredient,
-            entity_delete_recipe_step_ingredient, entity_list_recipe_step_ingredient,
-            entity_read_recipe_step_ingredient, entity_update_recipe_step_ingredient,
-        },
-        recipe_step_ingredient_draft::{
-            entity_count_recipe_step_ingredient_draft, entity_create_recipe_step_ingredient_draft,
-            entity_delete_recipe_step_ingredient_draft, entity_list_recipe_step_ingredient_draft,
-            entity_read_recipe_step_ingredient_draft, entity_update_recipe_step_ingredient_draft,
-        },
-        unit_name::{
-            entity_count_unit_name, entity_create_unit_name, entity_delete_unit_name,
-            entity_list_unit_name, entity_read_unit_name, entity_update_unit_name,
+        external_recipe::external_recipe,
+        journal::{redo, undo},
+        nutrition::{nutrition_calculate, nutrition_import},
+        ocr::{ocr, ocr_language_list, ocr_preprocess, ocr_structured},
+        ocr_recipe::ocr_recipe,
+        pdf::pdf_text,
+        recipe_revision::{
+            recipe_revision_diff, recipe_revision_list, recipe_revision_revert,
+            recipe_revision_save,
         },
+        recipe_time::recipe_time_fill,
+        settings::{settings_get, settings_set},
+        step_duration::{step_duration_list, step_duration_parse},
+        timer::{timer_cancel, timer_list, timer_start},
+        unit_conversion::unit_convert,
+        unit_list::unit_list_get,
     },
-    external_recipe::external_recipe,
-    ocr::ocr,
-    unit_conversion::unit_convert,
-    unit_list::unit_list_get,
+    entity_crud::recipe::RecipeCrud,
 };
 
 mod app_handle;
 mod command;
 mod database;
+mod dietary;
 mod dom_content_loaded;
 mod entity;
 mod entity_crud;
@@ -68,10 +184,23 @@ mod event;
 mod external_recipe;
 mod file_storage;
 mod fs;
+mod hocr;
+mod ingredient_similarity;
+mod journal;
 mod log;
 mod migrator;
+mod nutrition;
+mod ocr;
 mod path;
+mod pdf;
+mod recipe_revision;
+mod recipe_text;
+mod recipe_time;
 mod scraper;
+mod settings;
+mod step_duration;
+mod timer;
+mod timestamp;
 mod unit_conversion;
 mod window;
 
@@ -88,73 +217,271 @@ fn setup() -> tauri::Builder<Wry> {
             app_handle::setup(app);
             log::init();
             dom_content_loaded::setup(app);
+            // the tests run the app, but must not purge in the background
+            if !cfg!(test) {
+                tauri::async_runtime::spawn(async {
+                    if let Err(err) = RecipeCrud::purge_expired().await {
+                        ::log::error!("Could not purge the recipe trash: {err}");
+                    }
+                });
+            }
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
+            entity_create_collection,
+            entity_read_collection,
+            entity_update_collection,
+            entity_delete_collection,
+            entity_create_many_collection,
+            entity_update_many_collection,
+            entity_delete_many_collection,
+            entity_list_collection,
+            entity_list_page_collection,
+            entity_count_collection,
+            collection_export,
+            entity_create_collection_recipe,
+            entity_read_collection_recipe,
+            entity_update_collection_recipe,
+            entity_delete_collection_recipe,
+            entity_create_many_collection_recipe,
+            entity_update_many_collection_recipe,
+            entity_delete_many_collection_recipe,
+            entity_list_collection_recipe,
+            entity_list_page_collection_recipe,
+            entity_count_collection_recipe,
+            entity_move_collection_recipe,
+            entity_reorder_collection_recipe,
+            entity_create_cook_log,
+            entity_read_cook_log,
+            entity_update_cook_log,
+            entity_delete_cook_log,
+            entity_create_many_cook_log,
+            entity_update_many_cook_log,
+            entity_delete_many_cook_log,
+            entity_list_cook_log,
+            entity_list_page_cook_log,
+            entity_count_cook_log,
+            entity_create_cook_log_file,
+            entity_read_cook_log_file,
+            entity_update_cook_log_file,
+            entity_delete_cook_log_file,
+            entity_create_many_cook_log_file,
+            entity_update_many_cook_log_file,
+            entity_delete_many_cook_log_file,
+            entity_list_cook_log_file,
+            entity_list_page_cook_log_file,
+            entity_count_cook_log_file,
+            entity_move_cook_log_file,
+            entity_reorder_cook_log_file,
             entity_create_file,
             entity_read_file,
             entity_update_file,
             entity_delete_file,
+            entity_create_many_file,
+            entity_update_many_file,
+            entity_delete_many_file,
             entity_list_file,
+            entity_list_page_file,
             entity_count_file,
             entity_create_ingredient,
             entity_read_ingredient,
             entity_update_ingredient,
             entity_delete_ingredient,
+            entity_create_many_ingredient,
+            entity_update_many_ingredient,
+            entity_delete_many_ingredient,
             entity_list_ingredient,
+            entity_list_page_ingredient,
             entity_count_ingredient,
+            ingredient_merge,
+            ingredient_merge_suggestions,
+            entity_create_ingredient_alias,
+            entity_read_ingredient_alias,
+            entity_update_ingredient_alias,
+            entity_delete_ingredient_alias,
+            entity_create_many_ingredient_alias,
+            entity_update_many_ingredient_alias,
+            entity_delete_many_ingredient_alias,
+            entity_list_ingredient_alias,
+            entity_list_page_ingredient_alias,
+            entity_count_ingredient_alias,
+            entity_create_ingredient_allergen,
+            entity_read_ingredient_allergen,
+            entity_update_ingredient_allergen,
+            entity_delete_ingredient_allergen,
+            entity_create_many_ingredient_allergen,
+            entity_update_many_ingredient_allergen,
+            entity_delete_many_ingredient_allergen,
+            entity_list_ingredient_allergen,
+            entity_list_page_ingredient_allergen,
+            entity_count_ingredient_allergen,
+            entity_create_ingredient_category,
+            entity_read_ingredient_category,
+            entity_update_ingredient_category,
+            entity_delete_ingredient_category,
+            entity_create_many_ingredient_category,
+            entity_update_many_ingredient_category,
+            entity_delete_many_ingredient_category,
+            entity_list_ingredient_category,
+            entity_list_page_ingredient_category,
+            entity_count_ingredient_category,
+            entity_create_nutrient_profile,
+            entity_read_nutrient_profile,
+            entity_update_nutrient_profile,
+            entity_delete_nutrient_profile,
+            entity_create_many_nutrient_profile,
+            entity_update_many_nutrient_profile,
+            entity_delete_many_nutrient_profile,
+            entity_list_nutrient_profile,
+            entity_list_page_nutrient_profile,
+            entity_count_nutrient_profile,
             entity_create_recipe,
             entity_read_recipe,
             entity_update_recipe,
             entity_delete_recipe,
+            entity_create_many_recipe,
+            entity_update_many_recipe,
+            entity_delete_many_recipe,
             entity_list_recipe,
+            entity_list_page_recipe,
             entity_count_recipe,
+            entity_trash_recipe,
+            entity_restore_recipe,
+            entity_purge_recipe,
+            entity_purge_trash_recipe,
+            recipe_duplicate,
+            recipe_export,
             entity_create_recipe_file,
             entity_read_recipe_file,
             entity_update_recipe_file,
             entity_delete_recipe_file,
+            entity_create_many_recipe_file,
+            entity_update_many_recipe_file,
+            entity_delete_many_recipe_file,
             entity_list_recipe_file,
+            entity_list_page_recipe_file,
             entity_count_recipe_file,
+            entity_move_recipe_file,
+            entity_reorder_recipe_file,
             entity_create_recipe_ingredient_draft,
             entity_read_recipe_ingredient_draft,
             entity_update_recipe_ingredient_draft,
             entity_delete_recipe_ingredient_draft,
+            entity_create_many_recipe_ingredient_draft,
+            entity_update_many_recipe_ingredient_draft,
+            entity_delete_many_recipe_ingredient_draft,
             entity_list_recipe_ingredient_draft,
+            entity_list_page_recipe_ingredient_draft,
             entity_count_recipe_ingredient_draft,
+            entity_move_recipe_ingredient_draft,
+            entity_reorder_recipe_ingredient_draft,
             entity_create_recipe_step_file,
             entity_read_recipe_step_file,
             entity_update_recipe_step_file,
             entity_delete_recipe_step_file,
+            entity_create_many_recipe_step_file,
+            entity_update_many_recipe_step_file,
+            entity_delete_many_recipe_step_file,
             entity_list_recipe_step_file,
+            entity_list_page_recipe_step_file,
             entity_count_recipe_step_file,
+            entity_move_recipe_step_file,
+            entity_reorder_recipe_step_file,
             entity_create_recipe_step_ingredient,
             entity_read_recipe_step_ingredient,
             entity_update_recipe_step_ingredient,
             entity_delete_recipe_step_ingredient,
+            entity_create_many_recipe_step_ingredient,
+            entity_update_many_recipe_step_ingredient,
+            entity_delete_many_recipe_step_ingredient,
             entity_list_recipe_step_ingredient,
+            entity_list_page_recipe_step_ingredient,
             entity_count_recipe_step_ingredient,
+            entity_move_recipe_step_ingredient,
+            entity_reorder_recipe_step_ingredient,
             entity_create_recipe_step_ingredient_draft,
             entity_read_recipe_step_ingredient_draft,
             entity_update_recipe_step_ingredient_draft,
             entity_delete_recipe_step_ingredient_draft,
+            entity_create_many_recipe_step_ingredient_draft,
+            entity_update_many_recipe_step_ingredient_draft,
+            entity_delete_many_recipe_step_ingredient_draft,
             entity_list_recipe_step_ingredient_draft,
+            entity_list_page_recipe_step_ingredient_draft,
             entity_count_recipe_step_ingredient_draft,
+            entity_move_recipe_step_ingredient_draft,
+            entity_reorder_recipe_step_ingredient_draft,
             entity_create_recipe_step,
             entity_read_recipe_step,
             entity_update_recipe_step,
             entity_delete_recipe_step,
+            entity_create_many_recipe_step,
+            entity_update_many_recipe_step,
+            entity_delete_many_recipe_step,
             entity_list_recipe_step,
+            entity_list_page_recipe_step,
             entity_count_recipe_step,
+            entity_move_recipe_step,
+            entity_reorder_recipe_step,
+            entity_create_recipe_tag,
+            entity_read_recipe_tag,
+            entity_update_recipe_tag,
+            entity_delete_recipe_tag,
+            entity_create_many_recipe_tag,
+            entity_update_many_recipe_tag,
+            entity_delete_many_recipe_tag,
+            entity_list_recipe_tag,
+            entity_list_page_recipe_tag,
+            entity_count_recipe_tag,
+            entity_create_tag,
+            entity_read_tag,
+            entity_update_tag,
+            entity_delete_tag,
+            entity_create_many_tag,
+            entity_update_many_tag,
+            entity_delete_many_tag,
+            entity_list_tag,
+            entity_list_page_tag,
+            entity_count_tag,
+            tag_rename,
+            tag_cloud,
             entity_create_unit_name,
             entity_read_unit_name,
             entity_update_unit_name,
             entity_delete_unit_name,
+            entity_create_many_unit_name,
+            entity_update_many_unit_name,
+            entity_delete_many_unit_name,
             entity_list_unit_name,
+            entity_list_page_unit_name,
             entity_count_unit_name,
             external_recipe,
             ocr,
+            ocr_structured,
+            ocr_recipe,
+            ocr_language_list,
+            ocr_preprocess,
+            pdf_text,
             unit_convert,
             unit_list_get,
+            changes_since,
+            undo,
+            redo,
+            recipe_revision_save,
+            recipe_revision_list,
+            recipe_revision_diff,
+            recipe_revision_revert,
+            recipe_time_fill,
+            nutrition_import,
+            nutrition_calculate,
+            dietary_summary,
+            step_duration_list,
+            step_duration_parse,
+            timer_start,
+            timer_cancel,
+            timer_list,
+            settings_get,
+            settings_set,
         ])
 }
 
@@ -5,13 +5,51 @@
 use sea_orm_migration::prelude::*;
 
 mod m20230306_214922_1_0_0;
+mod m20261019_100000_ocr_result;
+mod m20261019_103000_change_log;
+mod m20261019_104000_journal;
+mod m20261019_105000_recipe_trash;
+mod m20261019_110000_recipe_revision;
+mod m20261019_113000_recipe_fork;
+mod m20261019_120000_order_repair;
+mod m20261019_121000_ingredient_alias;
+mod m20261019_122000_ingredient_category;
+mod m20261019_123000_tag;
+mod m20261019_124000_collection;
+mod m20261019_125000_cook_log;
+mod m20261019_130000_timestamps;
+mod m20261019_140000_step_duration;
+mod m20261019_141000_recipe_time;
+mod m20261019_142000_nutrient_profile;
+mod m20261019_143000_dietary;
+mod m20261019_150000_setting;
 
 /// This struct implements [`MigratorTrait`] to run the migrations via [`<Self as MigrationTrait>::up`].
 pub struct Migrator;
 
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(m20230306_214922_1_0_0::Migration)]
+        vec![
+            Box::new(m20230306_214922_1_0_0::Migration),
+            Box::new(m20261019_100000_ocr_result::Migration),
+            Box::new(m20261019_103000_change_log::Migration),
+            Box::new(m20261019_104000_journal::Migration),
+            Box::new(m20261019_105000_recipe_trash::Migration),
+            Box::new(m20261019_110000_recipe_revision::Migration),
+            Box::new(m20261019_113000_recipe_fork::Migration),
+            Box::new(m20261019_120000_order_repair::Migration),
+            Box::new(m20261019_121000_ingredient_alias::Migration),
+            Box::new(m20261019_122000_ingredient_category::Migration),
+            Box::new(m20261019_123000_tag::Migration),
+            Box::new(m20261019_124000_collection::Migration),
+            Box::new(m20261019_125000_cook_log::Migration),
+            Box::new(m20261019_130000_timestamps::Migration),
+            Box::new(m20261019_140000_step_duration::Migration),
+            Box::new(m20261019_141000_recipe_time::Migration),
+            Box::new(m20261019_142000_nutrient_profile::Migration),
+            Box::new(m20261019_143000_dietary::Migration),
+            Box::new(m20261019_150000_setting::Migration),
+        ]
     }
 }
 
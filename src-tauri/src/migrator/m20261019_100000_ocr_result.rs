@@ -0,0 +1,106 @@
+//! This module implements the database migration which creates [`crate::entity::ocr_result`].
+
+use sea_orm_migration::prelude::*;
+
+use crate::migrator::index_name;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(OcrResult::Table)
+                    .col(
+                        ColumnDef::new(OcrResult::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(OcrResult::FileId).integer().not_null())
+                    .col(ColumnDef::new(OcrResult::Language).string().not_null())
+                    .col(ColumnDef::new(OcrResult::Settings).string().not_null())
+                    .col(ColumnDef::new(OcrResult::Hocr).text().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(OcrResult::Table, OcrResult::FileId)
+                            .to(File::Table, File::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .col(OcrResult::FileId)
+                            .col(OcrResult::Language)
+                            .col(OcrResult::Settings)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(index_name(&OcrResult::Table, &OcrResult::FileId))
+                    .table(OcrResult::Table)
+                    .col(OcrResult::FileId)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum OcrResult {
+    Table,
+    Id,
+    FileId,
+    Language,
+    Settings,
+    Hocr,
+}
+
+#[derive(Iden)]
+enum File {
+    Table,
+    Id,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::*;
+    use crate::{
+        database::tests::{get_memory_database, get_table_indices, get_table_schema},
+        migrator::Migrator,
+    };
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database().await;
+        Migrator::up(&db, Some(2)).await.unwrap();
+        assert_str_eq!(
+            get_table_schema("ocr_result", &db).await,
+            "CREATE TABLE \"ocr_result\" ( \
+            \"id\" integer NOT NULL PRIMARY KEY AUTOINCREMENT, \
+            \"file_id\" integer NOT NULL, \
+            \"language\" text NOT NULL, \
+            \"settings\" text NOT NULL, \
+            \"hocr\" text NOT NULL, \
+            UNIQUE (\"file_id\", \"language\", \"settings\"), \
+            FOREIGN KEY (\"file_id\") REFERENCES \"file\" (\"id\") ON DELETE CASCADE \
+            )"
+        );
+        assert_eq!(
+            get_table_indices("ocr_result", &db).await,
+            vec![String::from(
+                "CREATE INDEX \"idx-ocr_result-file_id\" ON \"ocr_result\" (\"file_id\")"
+            )]
+        );
+    }
+}
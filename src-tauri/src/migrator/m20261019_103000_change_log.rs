@@ -0,0 +1,75 @@
+//! This module implements the database migration which creates [`crate::entity::change_log`].
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ChangeLog::Table)
+                    .col(
+                        ColumnDef::new(ChangeLog::Seq)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(ChangeLog::Entity).string().not_null())
+                    .col(ColumnDef::new(ChangeLog::Action).text().not_null())
+                    .col(ColumnDef::new(ChangeLog::EntityId).json().not_null())
+                    .col(ColumnDef::new(ChangeLog::Old).json())
+                    .col(ColumnDef::new(ChangeLog::New).json())
+                    .col(ColumnDef::new(ChangeLog::Parents).json().not_null())
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum ChangeLog {
+    Table,
+    Seq,
+    Entity,
+    Action,
+    EntityId,
+    Old,
+    New,
+    Parents,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_str_eq;
+
+    use super::*;
+    use crate::{
+        database::tests::{get_memory_database, get_table_indices, get_table_schema},
+        migrator::Migrator,
+    };
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database().await;
+        Migrator::up(&db, Some(3)).await.unwrap();
+        assert_str_eq!(
+            get_table_schema("change_log", &db).await,
+            "CREATE TABLE \"change_log\" ( \
+            \"seq\" integer NOT NULL PRIMARY KEY AUTOINCREMENT, \
+            \"entity\" text NOT NULL, \
+            \"action\" text NOT NULL, \
+            \"entity_id\" text NOT NULL, \
+            \"old\" text, \
+            \"new\" text, \
+            \"parents\" text NOT NULL \
+            )"
+        );
+        assert!(get_table_indices("change_log", &db).await.is_empty());
+    }
+}
@@ -0,0 +1,110 @@
+//! This module implements the database migration which adds the undo and redo journal.
+//!
+//! It creates [`crate::entity::journal_action`] and references it from every [`crate::entity::change_log`] entry.
+
+use sea_orm_migration::prelude::*;
+
+use crate::migrator::index_name;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(JournalAction::Table)
+                    .col(
+                        ColumnDef::new(JournalAction::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(JournalAction::Undone)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        // SQLite can only add a column with a foreign key via raw SQL.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE \"change_log\" ADD COLUMN \"journal_action_id\" integer REFERENCES \"journal_action\" (\"id\") ON DELETE SET NULL",
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(index_name(&ChangeLog::Table, &ChangeLog::JournalActionId))
+                    .table(ChangeLog::Table)
+                    .col(ChangeLog::JournalActionId)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum JournalAction {
+    Table,
+    Id,
+    Undone,
+}
+
+#[derive(Iden)]
+enum ChangeLog {
+    Table,
+    JournalActionId,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::*;
+    use crate::{
+        database::tests::{get_memory_database, get_table_indices, get_table_schema},
+        migrator::Migrator,
+    };
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database().await;
+        Migrator::up(&db, Some(4)).await.unwrap();
+        assert_str_eq!(
+            get_table_schema("journal_action", &db).await,
+            "CREATE TABLE \"journal_action\" ( \
+            \"id\" integer NOT NULL PRIMARY KEY AUTOINCREMENT, \
+            \"undone\" boolean NOT NULL DEFAULT FALSE \
+            )"
+        );
+        assert!(get_table_indices("journal_action", &db).await.is_empty());
+        assert_str_eq!(
+            get_table_schema("change_log", &db).await,
+            "CREATE TABLE \"change_log\" ( \
+            \"seq\" integer NOT NULL PRIMARY KEY AUTOINCREMENT, \
+            \"entity\" text NOT NULL, \
+            \"action\" text NOT NULL, \
+            \"entity_id\" text NOT NULL, \
+            \"old\" text, \
+            \"new\" text, \
+            \"parents\" text NOT NULL , \
+            \"journal_action_id\" integer REFERENCES \"journal_action\" (\"id\") ON DELETE SET NULL\
+            )"
+        );
+        assert_eq!(
+            get_table_indices("change_log", &db).await,
+            vec![String::from(
+                "CREATE INDEX \"idx-change_log-journal_action_id\" ON \"change_log\" (\"journal_action_id\")"
+            )]
+        );
+    }
+}
@@ -0,0 +1,67 @@
+//! This module implements the database migration which adds the trash bin for recipes.
+//!
+//! A recipe is in the trash bin while its `deleted_at` is set, see [`crate::entity::recipe`].
+
+use sea_orm_migration::prelude::*;
+
+use crate::migrator::index_name;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Recipe::Table)
+                    .add_column(ColumnDef::new(Recipe::DeletedAt).integer())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(index_name(&Recipe::Table, &Recipe::DeletedAt))
+                    .table(Recipe::Table)
+                    .col(Recipe::DeletedAt)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Recipe {
+    Table,
+    DeletedAt,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        database::tests::{get_memory_database, get_table_indices, get_table_schema},
+        migrator::Migrator,
+    };
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database().await;
+        Migrator::up(&db, Some(5)).await.unwrap();
+        let table_schema = get_table_schema("recipe", &db).await;
+        assert!(
+            table_schema.contains(", \"deleted_at\" integer"),
+            "{table_schema}"
+        );
+        assert!(
+            get_table_indices("recipe", &db)
+                .await
+                .contains(&String::from(
+                    "CREATE INDEX \"idx-recipe-deleted_at\" ON \"recipe\" (\"deleted_at\")"
+                ))
+        );
+    }
+}
@@ -0,0 +1,108 @@
+//! This module implements the database migration which creates [`crate::entity::recipe_revision`].
+
+use sea_orm_migration::prelude::*;
+
+use crate::migrator::index_name;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(RecipeRevision::Table)
+                    .col(
+                        ColumnDef::new(RecipeRevision::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(RecipeRevision::RecipeId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RecipeRevision::CreatedAt)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(RecipeRevision::Snapshot).json().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(RecipeRevision::Table, RecipeRevision::RecipeId)
+                            .to(Recipe::Table, Recipe::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(index_name(
+                        &RecipeRevision::Table,
+                        &RecipeRevision::RecipeId,
+                    ))
+                    .table(RecipeRevision::Table)
+                    .col(RecipeRevision::RecipeId)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum RecipeRevision {
+    Table,
+    Id,
+    RecipeId,
+    CreatedAt,
+    Snapshot,
+}
+
+#[derive(Iden)]
+enum Recipe {
+    Table,
+    Id,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::*;
+    use crate::{
+        database::tests::{get_memory_database, get_table_indices, get_table_schema},
+        migrator::Migrator,
+    };
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database().await;
+        Migrator::up(&db, Some(6)).await.unwrap();
+        let table_schema = get_table_schema("recipe_revision", &db).await;
+        assert_str_eq!(
+            table_schema,
+            "CREATE TABLE \"recipe_revision\" ( \
+            \"id\" integer NOT NULL PRIMARY KEY AUTOINCREMENT, \
+            \"recipe_id\" integer NOT NULL, \
+            \"created_at\" integer NOT NULL, \
+            \"snapshot\" text NOT NULL, \
+            FOREIGN KEY (\"recipe_id\") REFERENCES \"recipe\" (\"id\") ON DELETE CASCADE \
+            )"
+        );
+        let indices = get_table_indices("recipe_revision", &db).await;
+        assert_eq!(
+            indices,
+            vec![String::from(
+                "CREATE INDEX \"idx-recipe_revision-recipe_id\" ON \"recipe_revision\" (\"recipe_id\")"
+            ),]
+        );
+    }
+}
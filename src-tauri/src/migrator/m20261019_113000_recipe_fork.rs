@@ -0,0 +1,68 @@
+//! This module implements the database migration which records the recipe a duplicated recipe was forked from.
+//!
+//! The fork origin of a recipe is its `forked_from_id`, see [`crate::entity::recipe`].
+
+use sea_orm_migration::prelude::*;
+
+use crate::migrator::index_name;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // SQLite can only add a column with a foreign key via raw SQL.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE \"recipe\" ADD COLUMN \"forked_from_id\" integer REFERENCES \"recipe\" (\"id\") ON DELETE SET NULL",
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(index_name(&Recipe::Table, &Recipe::ForkedFromId))
+                    .table(Recipe::Table)
+                    .col(Recipe::ForkedFromId)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Recipe {
+    Table,
+    ForkedFromId,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        database::tests::{get_memory_database, get_table_indices, get_table_schema},
+        migrator::Migrator,
+    };
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database().await;
+        Migrator::up(&db, Some(7)).await.unwrap();
+        let table_schema = get_table_schema("recipe", &db).await;
+        assert!(
+            table_schema.contains(
+                ", \"forked_from_id\" integer REFERENCES \"recipe\" (\"id\") ON DELETE SET NULL"
+            ),
+            "{table_schema}"
+        );
+        assert!(
+            get_table_indices("recipe", &db)
+                .await
+                .contains(&String::from(
+                    "CREATE INDEX \"idx-recipe-forked_from_id\" ON \"recipe\" (\"forked_from_id\")"
+                ))
+        );
+    }
+}
@@ -0,0 +1,72 @@
+//! This module implements the database migration which repairs the order of ordered children.
+//!
+//! The order of the children of a parent used to be maintained one update at a time, which could leave gaps.
+//! This migration renumbers the children of every parent from 1 without gaps, keeping their relative order.
+//! The uniqueness of the order within a parent is already enforced by the initial migration.
+//! This is a separate migration, as changing the initial one would not repair databases it already ran on.
+
+use sea_orm_migration::prelude::*;
+
+/// the tables of ordered children with their parent column
+const ORDERED_TABLES: [(&str, &str); 6] = [
+    ("recipe_file", "recipe_id"),
+    ("recipe_ingredient_draft", "recipe_id"),
+    ("recipe_step", "recipe_id"),
+    ("recipe_step_file", "recipe_step_id"),
+    ("recipe_step_ingredient", "recipe_step_id"),
+    ("recipe_step_ingredient_draft", "recipe_step_id"),
+];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        for (table, parent_column) in ORDERED_TABLES {
+            // The new orders are set negated first, so they never collide with the old ones.
+            db.execute_unprepared(&format!(
+                "UPDATE `{table}` SET `order` = -`numbered`.`position` FROM (SELECT `id`, ROW_NUMBER() OVER (PARTITION BY `{parent_column}` ORDER BY `order`, `id`) AS `position` FROM `{table}`) AS `numbered` WHERE `{table}`.`id` = `numbered`.`id`"
+            ))
+            .await?;
+            db.execute_unprepared(&format!("UPDATE `{table}` SET `order` = -`order`"))
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use sea_orm::{ConnectionTrait, Statement};
+
+    use super::*;
+    use crate::{database::tests::get_memory_database, migrator::Migrator};
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database().await;
+        Migrator::up(&db, Some(1)).await.unwrap();
+        db.execute_unprepared(
+            "INSERT INTO `recipe` (`id`, `name`) VALUES (1, 'a'), (2, 'b');
+            INSERT INTO `recipe_step` (`id`, `order`, `description`, `recipe_id`) VALUES (1, 5, 'c', 1), (2, 2, 'a', 1), (3, 9, 'd', 1), (4, 4, 'b', 1), (5, 3, 'e', 2)",
+        )
+        .await
+        .unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        let query_results = db
+            .query_all(Statement::from_string(
+                db.get_database_backend(),
+                "SELECT `id`, `order` FROM `recipe_step` ORDER BY `id`",
+            ))
+            .await
+            .unwrap();
+        let data: Vec<(i64, i64)> = query_results
+            .into_iter()
+            .map(|query_result| query_result.try_get_many_by_index().unwrap())
+            .collect();
+        assert_eq!(data, vec![(1, 3), (2, 1), (3, 4), (4, 2), (5, 1)]);
+    }
+}
@@ -0,0 +1,121 @@
+//! This module implements the database migration which creates [`crate::entity::ingredient_alias`].
+
+use sea_orm_migration::prelude::*;
+
+use crate::migrator::index_name;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(IngredientAlias::Table)
+                    .col(
+                        ColumnDef::new(IngredientAlias::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(IngredientAlias::Name).string().not_null())
+                    .col(ColumnDef::new(IngredientAlias::Language).string())
+                    .col(ColumnDef::new(IngredientAlias::Kind).text().not_null())
+                    .col(
+                        ColumnDef::new(IngredientAlias::IngredientId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(IngredientAlias::Table, IngredientAlias::IngredientId)
+                            .to(Ingredient::Table, Ingredient::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(index_name(&IngredientAlias::Table, &IngredientAlias::Name))
+                    .table(IngredientAlias::Table)
+                    .col(IngredientAlias::Name)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(index_name(
+                        &IngredientAlias::Table,
+                        &IngredientAlias::IngredientId,
+                    ))
+                    .table(IngredientAlias::Table)
+                    .col(IngredientAlias::IngredientId)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum IngredientAlias {
+    Table,
+    Id,
+    Name,
+    Language,
+    Kind,
+    IngredientId,
+}
+
+#[derive(Iden)]
+enum Ingredient {
+    Table,
+    Id,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::*;
+    use crate::{
+        database::tests::{get_memory_database, get_table_indices, get_table_schema},
+        migrator::Migrator,
+    };
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database().await;
+        Migrator::up(&db, Some(9)).await.unwrap();
+        let table_schema = get_table_schema("ingredient_alias", &db).await;
+        assert_str_eq!(
+            table_schema,
+            "CREATE TABLE \"ingredient_alias\" ( \
+            \"id\" integer NOT NULL PRIMARY KEY AUTOINCREMENT, \
+            \"name\" text NOT NULL, \
+            \"language\" text, \
+            \"kind\" text NOT NULL, \
+            \"ingredient_id\" integer NOT NULL, \
+            FOREIGN KEY (\"ingredient_id\") REFERENCES \"ingredient\" (\"id\") ON DELETE CASCADE \
+            )"
+        );
+        let indices = get_table_indices("ingredient_alias", &db).await;
+        assert_eq!(
+            indices,
+            vec![
+                String::from(
+                    "CREATE INDEX \"idx-ingredient_alias-name\" ON \"ingredient_alias\" (\"name\")"
+                ),
+                String::from(
+                    "CREATE INDEX \"idx-ingredient_alias-ingredient_id\" ON \"ingredient_alias\" (\"ingredient_id\")"
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,140 @@
+//! This module implements the database migration which adds [`crate::entity::ingredient_category`].
+//!
+//! It creates the categories and references them from [`crate::entity::ingredient`].
+
+use sea_orm_migration::prelude::*;
+
+use crate::migrator::index_name;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(IngredientCategory::Table)
+                    .col(
+                        ColumnDef::new(IngredientCategory::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(IngredientCategory::Name).string().not_null())
+                    .col(
+                        ColumnDef::new(IngredientCategory::Order)
+                            .integer()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(index_name(
+                        &IngredientCategory::Table,
+                        &IngredientCategory::Name,
+                    ))
+                    .table(IngredientCategory::Table)
+                    .col(IngredientCategory::Name)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(index_name(
+                        &IngredientCategory::Table,
+                        &IngredientCategory::Order,
+                    ))
+                    .table(IngredientCategory::Table)
+                    .col(IngredientCategory::Order)
+                    .to_owned(),
+            )
+            .await?;
+        // SQLite can only add a column with a foreign key via raw SQL.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE \"ingredient\" ADD COLUMN \"ingredient_category_id\" integer REFERENCES \"ingredient_category\" (\"id\") ON DELETE SET NULL",
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(index_name(&Ingredient::Table, &Ingredient::CategoryId))
+                    .table(Ingredient::Table)
+                    .col(Ingredient::CategoryId)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum IngredientCategory {
+    Table,
+    Id,
+    Name,
+    Order,
+}
+
+#[derive(Iden)]
+enum Ingredient {
+    Table,
+    #[iden = "ingredient_category_id"]
+    CategoryId,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::*;
+    use crate::{
+        database::tests::{get_memory_database, get_table_indices, get_table_schema},
+        migrator::Migrator,
+    };
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database().await;
+        Migrator::up(&db, Some(10)).await.unwrap();
+        let table_schema = get_table_schema("ingredient_category", &db).await;
+        assert_str_eq!(
+            table_schema,
+            "CREATE TABLE \"ingredient_category\" ( \
+            \"id\" integer NOT NULL PRIMARY KEY AUTOINCREMENT, \
+            \"name\" text NOT NULL, \
+            \"order\" integer NOT NULL \
+            )"
+        );
+        let indices = get_table_indices("ingredient_category", &db).await;
+        assert_eq!(
+            indices,
+            vec![
+                String::from(
+                    "CREATE INDEX \"idx-ingredient_category-name\" ON \"ingredient_category\" (\"name\")"
+                ),
+                String::from(
+                    "CREATE INDEX \"idx-ingredient_category-order\" ON \"ingredient_category\" (\"order\")"
+                ),
+            ]
+        );
+        let table_schema = get_table_schema("ingredient", &db).await;
+        assert!(
+            table_schema.contains(", \"ingredient_category_id\" integer REFERENCES \"ingredient_category\" (\"id\") ON DELETE SET NULL"),
+            "{table_schema}"
+        );
+        assert!(get_table_indices("ingredient", &db)
+            .await
+            .contains(&String::from(
+                "CREATE INDEX \"idx-ingredient-ingredient_category_id\" ON \"ingredient\" (\"ingredient_category_id\")"
+            )));
+    }
+}
@@ -0,0 +1,156 @@
+//! This module implements the database migration which creates [`crate::entity::tag`] and [`crate::entity::recipe_tag`].
+
+use sea_orm_migration::prelude::*;
+
+use crate::migrator::index_name;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Tag::Table)
+                    .col(
+                        ColumnDef::new(Tag::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Tag::Name).string().not_null())
+                    .index(Index::create().col(Tag::Name).unique())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_table(
+                Table::create()
+                    .table(RecipeTag::Table)
+                    .col(
+                        ColumnDef::new(RecipeTag::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(RecipeTag::RecipeId).integer().not_null())
+                    .col(ColumnDef::new(RecipeTag::TagId).integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(RecipeTag::Table, RecipeTag::RecipeId)
+                            .to(Recipe::Table, Recipe::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(RecipeTag::Table, RecipeTag::TagId)
+                            .to(Tag::Table, Tag::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .col(RecipeTag::RecipeId)
+                            .col(RecipeTag::TagId)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(index_name(&RecipeTag::Table, &RecipeTag::RecipeId))
+                    .table(RecipeTag::Table)
+                    .col(RecipeTag::RecipeId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(index_name(&RecipeTag::Table, &RecipeTag::TagId))
+                    .table(RecipeTag::Table)
+                    .col(RecipeTag::TagId)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Tag {
+    Table,
+    Id,
+    Name,
+}
+
+#[derive(Iden)]
+enum RecipeTag {
+    Table,
+    Id,
+    RecipeId,
+    TagId,
+}
+
+#[derive(Iden)]
+enum Recipe {
+    Table,
+    Id,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::*;
+    use crate::{
+        database::tests::{get_memory_database, get_table_indices, get_table_schema},
+        migrator::Migrator,
+    };
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database().await;
+        Migrator::up(&db, Some(11)).await.unwrap();
+        let table_schema = get_table_schema("tag", &db).await;
+        assert_str_eq!(
+            table_schema,
+            "CREATE TABLE \"tag\" ( \
+            \"id\" integer NOT NULL PRIMARY KEY AUTOINCREMENT, \
+            \"name\" text NOT NULL, \
+            UNIQUE (\"name\") \
+            )"
+        );
+        let indices = get_table_indices("tag", &db).await;
+        assert!(indices.is_empty());
+        let table_schema = get_table_schema("recipe_tag", &db).await;
+        assert_str_eq!(
+            table_schema,
+            "CREATE TABLE \"recipe_tag\" ( \
+            \"id\" integer NOT NULL PRIMARY KEY AUTOINCREMENT, \
+            \"recipe_id\" integer NOT NULL, \
+            \"tag_id\" integer NOT NULL, \
+            UNIQUE (\"recipe_id\", \"tag_id\"), \
+            FOREIGN KEY (\"recipe_id\") REFERENCES \"recipe\" (\"id\") ON DELETE CASCADE, \
+            FOREIGN KEY (\"tag_id\") REFERENCES \"tag\" (\"id\") ON DELETE CASCADE \
+            )"
+        );
+        let indices = get_table_indices("recipe_tag", &db).await;
+        assert_eq!(
+            indices,
+            vec![
+                String::from(
+                    "CREATE INDEX \"idx-recipe_tag-recipe_id\" ON \"recipe_tag\" (\"recipe_id\")"
+                ),
+                String::from(
+                    "CREATE INDEX \"idx-recipe_tag-tag_id\" ON \"recipe_tag\" (\"tag_id\")"
+                ),
+            ]
+        );
+    }
+}
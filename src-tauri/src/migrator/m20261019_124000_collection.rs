@@ -0,0 +1,207 @@
+//! This module implements the database migration which creates [`crate::entity::collection`] and [`crate::entity::collection_recipe`].
+
+use sea_orm_migration::prelude::*;
+
+use crate::migrator::index_name;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Collection::Table)
+                    .col(
+                        ColumnDef::new(Collection::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Collection::Name).string().not_null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(index_name(&Collection::Table, &Collection::Name))
+                    .table(Collection::Table)
+                    .col(Collection::Name)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_table(
+                Table::create()
+                    .table(CollectionRecipe::Table)
+                    .col(
+                        ColumnDef::new(CollectionRecipe::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(CollectionRecipe::Order).integer().not_null())
+                    .col(
+                        ColumnDef::new(CollectionRecipe::CollectionId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionRecipe::RecipeId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(CollectionRecipe::Table, CollectionRecipe::CollectionId)
+                            .to(Collection::Table, Collection::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(CollectionRecipe::Table, CollectionRecipe::RecipeId)
+                            .to(Recipe::Table, Recipe::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .col(CollectionRecipe::Order)
+                            .col(CollectionRecipe::CollectionId)
+                            .unique(),
+                    )
+                    .index(
+                        Index::create()
+                            .col(CollectionRecipe::CollectionId)
+                            .col(CollectionRecipe::RecipeId)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(index_name(
+                        &CollectionRecipe::Table,
+                        &CollectionRecipe::Order,
+                    ))
+                    .table(CollectionRecipe::Table)
+                    .col(CollectionRecipe::Order)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(index_name(
+                        &CollectionRecipe::Table,
+                        &CollectionRecipe::CollectionId,
+                    ))
+                    .table(CollectionRecipe::Table)
+                    .col(CollectionRecipe::CollectionId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(index_name(
+                        &CollectionRecipe::Table,
+                        &CollectionRecipe::RecipeId,
+                    ))
+                    .table(CollectionRecipe::Table)
+                    .col(CollectionRecipe::RecipeId)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Collection {
+    Table,
+    Id,
+    Name,
+}
+
+#[derive(Iden)]
+enum CollectionRecipe {
+    Table,
+    Id,
+    Order,
+    CollectionId,
+    RecipeId,
+}
+
+#[derive(Iden)]
+enum Recipe {
+    Table,
+    Id,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::*;
+    use crate::{
+        database::tests::{get_memory_database, get_table_indices, get_table_schema},
+        migrator::Migrator,
+    };
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database().await;
+        Migrator::up(&db, Some(12)).await.unwrap();
+        let table_schema = get_table_schema("collection", &db).await;
+        assert_str_eq!(
+            table_schema,
+            "CREATE TABLE \"collection\" ( \
+            \"id\" integer NOT NULL PRIMARY KEY AUTOINCREMENT, \
+            \"name\" text NOT NULL \
+            )"
+        );
+        let indices = get_table_indices("collection", &db).await;
+        assert_eq!(
+            indices,
+            vec![String::from(
+                "CREATE INDEX \"idx-collection-name\" ON \"collection\" (\"name\")"
+            ),]
+        );
+        let table_schema = get_table_schema("collection_recipe", &db).await;
+        assert_str_eq!(
+            table_schema,
+            "CREATE TABLE \"collection_recipe\" ( \
+            \"id\" integer NOT NULL PRIMARY KEY AUTOINCREMENT, \
+            \"order\" integer NOT NULL, \
+            \"collection_id\" integer NOT NULL, \
+            \"recipe_id\" integer NOT NULL, \
+            UNIQUE (\"order\", \"collection_id\"), \
+            UNIQUE (\"collection_id\", \"recipe_id\"), \
+            FOREIGN KEY (\"collection_id\") REFERENCES \"collection\" (\"id\") ON DELETE CASCADE, \
+            FOREIGN KEY (\"recipe_id\") REFERENCES \"recipe\" (\"id\") ON DELETE CASCADE \
+            )"
+        );
+        let indices = get_table_indices("collection_recipe", &db).await;
+        assert_eq!(
+            indices,
+            vec![
+                String::from(
+                    "CREATE INDEX \"idx-collection_recipe-order\" ON \"collection_recipe\" (\"order\")"
+                ),
+                String::from(
+                    "CREATE INDEX \"idx-collection_recipe-collection_id\" ON \"collection_recipe\" (\"collection_id\")"
+                ),
+                String::from(
+                    "CREATE INDEX \"idx-collection_recipe-recipe_id\" ON \"collection_recipe\" (\"recipe_id\")"
+                ),
+            ]
+        );
+    }
+}
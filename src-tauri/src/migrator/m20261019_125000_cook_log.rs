@@ -0,0 +1,226 @@
+//! This module implements the database migration which creates [`crate::entity::cook_log`] and [`crate::entity::cook_log_file`].
+
+use sea_orm_migration::prelude::*;
+
+use crate::migrator::index_name;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CookLog::Table)
+                    .col(
+                        ColumnDef::new(CookLog::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(CookLog::RecipeId).integer().not_null())
+                    .col(ColumnDef::new(CookLog::CookedAt).integer().not_null())
+                    .col(ColumnDef::new(CookLog::Servings).integer())
+                    .col(
+                        ColumnDef::new(CookLog::Rating)
+                            .integer()
+                            .check(Expr::col(CookLog::Rating).between(1, 5)),
+                    )
+                    .col(ColumnDef::new(CookLog::Notes).string())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(CookLog::Table, CookLog::RecipeId)
+                            .to(Recipe::Table, Recipe::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(index_name(&CookLog::Table, &CookLog::RecipeId))
+                    .table(CookLog::Table)
+                    .col(CookLog::RecipeId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(index_name(&CookLog::Table, &CookLog::CookedAt))
+                    .table(CookLog::Table)
+                    .col(CookLog::CookedAt)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_table(
+                Table::create()
+                    .table(CookLogFile::Table)
+                    .col(
+                        ColumnDef::new(CookLogFile::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(CookLogFile::Order).integer().not_null())
+                    .col(ColumnDef::new(CookLogFile::CookLogId).integer().not_null())
+                    .col(ColumnDef::new(CookLogFile::FileId).integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(CookLogFile::Table, CookLogFile::CookLogId)
+                            .to(CookLog::Table, CookLog::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(CookLogFile::Table, CookLogFile::FileId)
+                            .to(File::Table, File::Id)
+                            .on_delete(ForeignKeyAction::Restrict),
+                    )
+                    .index(
+                        Index::create()
+                            .col(CookLogFile::Order)
+                            .col(CookLogFile::CookLogId)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(index_name(&CookLogFile::Table, &CookLogFile::Order))
+                    .table(CookLogFile::Table)
+                    .col(CookLogFile::Order)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(index_name(&CookLogFile::Table, &CookLogFile::CookLogId))
+                    .table(CookLogFile::Table)
+                    .col(CookLogFile::CookLogId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(index_name(&CookLogFile::Table, &CookLogFile::FileId))
+                    .table(CookLogFile::Table)
+                    .col(CookLogFile::FileId)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum CookLog {
+    Table,
+    Id,
+    RecipeId,
+    CookedAt,
+    Servings,
+    Rating,
+    Notes,
+}
+
+#[derive(Iden)]
+enum CookLogFile {
+    Table,
+    Id,
+    Order,
+    CookLogId,
+    FileId,
+}
+
+#[derive(Iden)]
+enum File {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum Recipe {
+    Table,
+    Id,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::*;
+    use crate::{
+        database::tests::{get_memory_database, get_table_indices, get_table_schema},
+        migrator::Migrator,
+    };
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database().await;
+        Migrator::up(&db, Some(13)).await.unwrap();
+        let table_schema = get_table_schema("cook_log", &db).await;
+        assert_str_eq!(
+            table_schema,
+            "CREATE TABLE \"cook_log\" ( \
+            \"id\" integer NOT NULL PRIMARY KEY AUTOINCREMENT, \
+            \"recipe_id\" integer NOT NULL, \
+            \"cooked_at\" integer NOT NULL, \
+            \"servings\" integer, \
+            \"rating\" integer CHECK (\"rating\" BETWEEN 1 AND 5), \
+            \"notes\" text, \
+            FOREIGN KEY (\"recipe_id\") REFERENCES \"recipe\" (\"id\") ON DELETE CASCADE \
+            )"
+        );
+        let indices = get_table_indices("cook_log", &db).await;
+        assert_eq!(
+            indices,
+            vec![
+                String::from(
+                    "CREATE INDEX \"idx-cook_log-recipe_id\" ON \"cook_log\" (\"recipe_id\")"
+                ),
+                String::from(
+                    "CREATE INDEX \"idx-cook_log-cooked_at\" ON \"cook_log\" (\"cooked_at\")"
+                ),
+            ]
+        );
+        let table_schema = get_table_schema("cook_log_file", &db).await;
+        assert_str_eq!(
+            table_schema,
+            "CREATE TABLE \"cook_log_file\" ( \
+            \"id\" integer NOT NULL PRIMARY KEY AUTOINCREMENT, \
+            \"order\" integer NOT NULL, \
+            \"cook_log_id\" integer NOT NULL, \
+            \"file_id\" integer NOT NULL, \
+            UNIQUE (\"order\", \"cook_log_id\"), \
+            FOREIGN KEY (\"cook_log_id\") REFERENCES \"cook_log\" (\"id\") ON DELETE CASCADE, \
+            FOREIGN KEY (\"file_id\") REFERENCES \"file\" (\"id\") ON DELETE RESTRICT \
+            )"
+        );
+        let indices = get_table_indices("cook_log_file", &db).await;
+        assert_eq!(
+            indices,
+            vec![
+                String::from(
+                    "CREATE INDEX \"idx-cook_log_file-order\" ON \"cook_log_file\" (\"order\")"
+                ),
+                String::from(
+                    "CREATE INDEX \"idx-cook_log_file-cook_log_id\" ON \"cook_log_file\" (\"cook_log_id\")"
+                ),
+                String::from(
+                    "CREATE INDEX \"idx-cook_log_file-file_id\" ON \"cook_log_file\" (\"file_id\")"
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,125 @@
+//! This module implements the database migration which adds creation and update timestamps.
+//!
+//! Every table of an entity with create, read, update and delete operations gets the columns `created_at` and `updated_at`, see [`crate::entity::touch_timestamps`].
+//! Existing rows are backfilled with the time of the migration, as their actual creation and update times are unknown.
+
+use sea_orm_migration::prelude::*;
+
+use crate::{migrator::index_name, timestamp::unix_timestamp};
+
+/// the tables which get timestamps
+const TIMESTAMPED_TABLES: [&str; 18] = [
+    "collection",
+    "collection_recipe",
+    "cook_log",
+    "cook_log_file",
+    "file",
+    "ingredient",
+    "ingredient_alias",
+    "ingredient_category",
+    "recipe",
+    "recipe_file",
+    "recipe_ingredient_draft",
+    "recipe_step",
+    "recipe_step_file",
+    "recipe_step_ingredient",
+    "recipe_step_ingredient_draft",
+    "recipe_tag",
+    "tag",
+    "unit_name",
+];
+
+/// the timestamp columns
+const TIMESTAMP_COLUMNS: [&str; 2] = ["created_at", "updated_at"];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let now = unix_timestamp().map_err(|err| DbErr::Migration(err.to_string()))?;
+        for table in TIMESTAMPED_TABLES {
+            let table = Alias::new(table);
+            // SQLite can only add one column per statement.
+            for column in TIMESTAMP_COLUMNS {
+                let column = Alias::new(column);
+                manager
+                    .alter_table(
+                        Table::alter()
+                            .table(table.clone())
+                            .add_column(
+                                ColumnDef::new(column.clone())
+                                    .integer()
+                                    .not_null()
+                                    .default(0),
+                            )
+                            .to_owned(),
+                    )
+                    .await?;
+                manager
+                    .create_index(
+                        Index::create()
+                            .name(index_name(&table, &column))
+                            .table(table.clone())
+                            .col(column)
+                            .to_owned(),
+                    )
+                    .await?;
+            }
+            manager
+                .exec_stmt(
+                    Query::update()
+                        .table(table)
+                        .values(TIMESTAMP_COLUMNS.map(|column| (Alias::new(column), now.into())))
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use sea_orm::{ConnectionTrait, Statement};
+
+    use super::*;
+    use crate::{database::tests::get_memory_database, migrator::Migrator};
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database().await;
+        Migrator::up(&db, Some(2)).await.unwrap();
+        db.execute_unprepared("INSERT INTO `recipe` (`id`, `name`) VALUES (1, 'a')")
+            .await
+            .unwrap();
+        let before = unix_timestamp().unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        let after = unix_timestamp().unwrap();
+        let query_result = db
+            .query_one(Statement::from_string(
+                db.get_database_backend(),
+                "SELECT `created_at`, `updated_at` FROM `recipe` WHERE `id` = 1",
+            ))
+            .await
+            .unwrap()
+            .unwrap();
+        let (created_at, updated_at): (i64, i64) = query_result.try_get_many_by_index().unwrap();
+        assert_eq!(created_at, updated_at);
+        assert!((before..=after).contains(&created_at));
+        for table in TIMESTAMPED_TABLES {
+            for column in TIMESTAMP_COLUMNS {
+                let index = db
+                    .query_one(Statement::from_string(
+                        db.get_database_backend(),
+                        format!("SELECT `name` FROM `sqlite_master` WHERE `type` = 'index' AND `tbl_name` = '{table}' AND `name` = 'idx-{table}-{column}'"),
+                    ))
+                    .await
+                    .unwrap();
+                assert!(index.is_some(), "{table}.{column} is not indexed");
+            }
+        }
+    }
+}
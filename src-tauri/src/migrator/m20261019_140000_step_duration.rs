@@ -0,0 +1,49 @@
+//! This module implements the database migration which adds the duration of recipe steps.
+//!
+//! The duration is in seconds and optional, see [`crate::entity::recipe_step`].
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RecipeStep::Table)
+                    .add_column(ColumnDef::new(RecipeStep::Duration).integer())
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum RecipeStep {
+    Table,
+    Duration,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        database::tests::{get_memory_database, get_table_schema},
+        migrator::Migrator,
+    };
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database().await;
+        Migrator::up(&db, Some(15)).await.unwrap();
+        let table_schema = get_table_schema("recipe_step", &db).await;
+        assert!(
+            table_schema.contains(", \"duration\" integer"),
+            "{table_schema}"
+        );
+    }
+}
@@ -0,0 +1,85 @@
+//! This module implements the database migration which adds the preparation, cooking, resting and total time of recipes.
+//!
+//! All times are in seconds and optional, see [`crate::entity::recipe`].
+//! The preparation and total time are indexed, as recipes are filtered by them.
+
+use sea_orm_migration::prelude::*;
+
+use crate::migrator::index_name;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // SQLite can only add one column per statement.
+        for column in [
+            Recipe::PrepTime,
+            Recipe::CookTime,
+            Recipe::RestTime,
+            Recipe::TotalTime,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Recipe::Table)
+                        .add_column(ColumnDef::new(column).integer())
+                        .to_owned(),
+                )
+                .await?;
+        }
+        for column in [Recipe::PrepTime, Recipe::TotalTime] {
+            manager
+                .create_index(
+                    Index::create()
+                        .name(index_name(&Recipe::Table, &column))
+                        .table(Recipe::Table)
+                        .col(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Recipe {
+    Table,
+    PrepTime,
+    CookTime,
+    RestTime,
+    TotalTime,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        database::tests::{get_memory_database, get_table_indices, get_table_schema},
+        migrator::Migrator,
+    };
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database().await;
+        Migrator::up(&db, Some(16)).await.unwrap();
+        let table_schema = get_table_schema("recipe", &db).await;
+        assert!(
+            table_schema.contains(
+                ", \"prep_time\" integer, \"cook_time\" integer, \"rest_time\" integer, \"total_time\" integer"
+            ),
+            "{table_schema}"
+        );
+        let indices = get_table_indices("recipe", &db).await;
+        for column in ["prep_time", "total_time"] {
+            assert!(
+                indices.contains(&format!(
+                    "CREATE INDEX \"idx-recipe-{column}\" ON \"recipe\" (\"{column}\")"
+                )),
+                "recipe.{column} is not indexed"
+            );
+        }
+    }
+}
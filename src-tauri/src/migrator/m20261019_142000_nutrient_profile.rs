@@ -0,0 +1,161 @@
+//! This module implements the database migration which creates [`crate::entity::nutrient_profile`].
+//!
+//! The table is created with the timestamps which the older tables got by a migration, see [`crate::entity::touch_timestamps`].
+
+use sea_orm_migration::prelude::*;
+
+use crate::migrator::index_name;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(NutrientProfile::Table)
+                    .col(
+                        ColumnDef::new(NutrientProfile::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(NutrientProfile::IngredientId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(NutrientProfile::Kcal).double())
+                    .col(ColumnDef::new(NutrientProfile::Protein).double())
+                    .col(ColumnDef::new(NutrientProfile::Fat).double())
+                    .col(ColumnDef::new(NutrientProfile::Carbohydrate).double())
+                    .col(ColumnDef::new(NutrientProfile::Fibre).double())
+                    .col(ColumnDef::new(NutrientProfile::Salt).double())
+                    .col(ColumnDef::new(NutrientProfile::Density).double())
+                    .col(ColumnDef::new(NutrientProfile::PieceMass).double())
+                    .col(
+                        ColumnDef::new(NutrientProfile::CreatedAt)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(NutrientProfile::UpdatedAt)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(NutrientProfile::Table, NutrientProfile::IngredientId)
+                            .to(Ingredient::Table, Ingredient::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(Index::create().col(NutrientProfile::IngredientId).unique())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(index_name(
+                        &NutrientProfile::Table,
+                        &NutrientProfile::IngredientId,
+                    ))
+                    .table(NutrientProfile::Table)
+                    .col(NutrientProfile::IngredientId)
+                    .to_owned(),
+            )
+            .await?;
+        for column in [NutrientProfile::CreatedAt, NutrientProfile::UpdatedAt] {
+            manager
+                .create_index(
+                    Index::create()
+                        .name(index_name(&NutrientProfile::Table, &column))
+                        .table(NutrientProfile::Table)
+                        .col(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum NutrientProfile {
+    Table,
+    Id,
+    IngredientId,
+    Kcal,
+    Protein,
+    Fat,
+    Carbohydrate,
+    Fibre,
+    Salt,
+    Density,
+    PieceMass,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum Ingredient {
+    Table,
+    Id,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::*;
+    use crate::{
+        database::tests::{get_memory_database, get_table_indices, get_table_schema},
+        migrator::Migrator,
+    };
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database().await;
+        Migrator::up(&db, Some(17)).await.unwrap();
+        let table_schema = get_table_schema("nutrient_profile", &db).await;
+        assert_str_eq!(
+            table_schema,
+            "CREATE TABLE \"nutrient_profile\" ( \
+            \"id\" integer NOT NULL PRIMARY KEY AUTOINCREMENT, \
+            \"ingredient_id\" integer NOT NULL, \
+            \"kcal\" real, \
+            \"protein\" real, \
+            \"fat\" real, \
+            \"carbohydrate\" real, \
+            \"fibre\" real, \
+            \"salt\" real, \
+            \"density\" real, \
+            \"piece_mass\" real, \
+            \"created_at\" integer NOT NULL DEFAULT 0, \
+            \"updated_at\" integer NOT NULL DEFAULT 0, \
+            UNIQUE (\"ingredient_id\"), \
+            FOREIGN KEY (\"ingredient_id\") REFERENCES \"ingredient\" (\"id\") ON DELETE CASCADE \
+            )"
+        );
+        let indices = get_table_indices("nutrient_profile", &db).await;
+        assert_eq!(
+            indices,
+            vec![
+                String::from(
+                    "CREATE INDEX \"idx-nutrient_profile-ingredient_id\" ON \"nutrient_profile\" (\"ingredient_id\")"
+                ),
+                String::from(
+                    "CREATE INDEX \"idx-nutrient_profile-created_at\" ON \"nutrient_profile\" (\"created_at\")"
+                ),
+                String::from(
+                    "CREATE INDEX \"idx-nutrient_profile-updated_at\" ON \"nutrient_profile\" (\"updated_at\")"
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,192 @@
+//! This module implements the database migration which adds allergens and diets of ingredients.
+//!
+//! It adds whether an ingredient is vegetarian, vegan, gluten-free and lactose-free to [`crate::entity::ingredient`], which is unknown for existing ingredients.
+//! It also creates [`crate::entity::ingredient_allergen`] with timestamps, see [`crate::entity::touch_timestamps`].
+
+use sea_orm_migration::prelude::*;
+
+use crate::migrator::index_name;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // SQLite can only add one column per statement.
+        for column in [
+            Ingredient::Vegetarian,
+            Ingredient::Vegan,
+            Ingredient::GlutenFree,
+            Ingredient::LactoseFree,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Ingredient::Table)
+                        .add_column(ColumnDef::new(column).boolean())
+                        .to_owned(),
+                )
+                .await?;
+        }
+        manager
+            .create_table(
+                Table::create()
+                    .table(IngredientAllergen::Table)
+                    .col(
+                        ColumnDef::new(IngredientAllergen::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(IngredientAllergen::IngredientId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(IngredientAllergen::Allergen)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(IngredientAllergen::CreatedAt)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(IngredientAllergen::UpdatedAt)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(IngredientAllergen::Table, IngredientAllergen::IngredientId)
+                            .to(Ingredient::Table, Ingredient::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .col(IngredientAllergen::IngredientId)
+                            .col(IngredientAllergen::Allergen)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(index_name(
+                        &IngredientAllergen::Table,
+                        &IngredientAllergen::IngredientId,
+                    ))
+                    .table(IngredientAllergen::Table)
+                    .col(IngredientAllergen::IngredientId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(index_name(
+                        &IngredientAllergen::Table,
+                        &IngredientAllergen::Allergen,
+                    ))
+                    .table(IngredientAllergen::Table)
+                    .col(IngredientAllergen::Allergen)
+                    .to_owned(),
+            )
+            .await?;
+        for column in [IngredientAllergen::CreatedAt, IngredientAllergen::UpdatedAt] {
+            manager
+                .create_index(
+                    Index::create()
+                        .name(index_name(&IngredientAllergen::Table, &column))
+                        .table(IngredientAllergen::Table)
+                        .col(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum IngredientAllergen {
+    Table,
+    Id,
+    IngredientId,
+    Allergen,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum Ingredient {
+    Table,
+    Id,
+    Vegetarian,
+    Vegan,
+    GlutenFree,
+    LactoseFree,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::*;
+    use crate::{
+        database::tests::{get_memory_database, get_table_indices, get_table_schema},
+        migrator::Migrator,
+    };
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database().await;
+        Migrator::up(&db, Some(18)).await.unwrap();
+        let table_schema = get_table_schema("ingredient", &db).await;
+        assert!(
+            table_schema.contains(
+                ", \"vegetarian\" boolean, \"vegan\" boolean, \"gluten_free\" boolean, \"lactose_free\" boolean"
+            ),
+            "{table_schema}"
+        );
+        let table_schema = get_table_schema("ingredient_allergen", &db).await;
+        assert_str_eq!(
+            table_schema,
+            "CREATE TABLE \"ingredient_allergen\" ( \
+            \"id\" integer NOT NULL PRIMARY KEY AUTOINCREMENT, \
+            \"ingredient_id\" integer NOT NULL, \
+            \"allergen\" text NOT NULL, \
+            \"created_at\" integer NOT NULL DEFAULT 0, \
+            \"updated_at\" integer NOT NULL DEFAULT 0, \
+            UNIQUE (\"ingredient_id\", \"allergen\"), \
+            FOREIGN KEY (\"ingredient_id\") REFERENCES \"ingredient\" (\"id\") ON DELETE CASCADE \
+            )"
+        );
+        let indices = get_table_indices("ingredient_allergen", &db).await;
+        assert_eq!(
+            indices,
+            vec![
+                String::from(
+                    "CREATE INDEX \"idx-ingredient_allergen-ingredient_id\" ON \"ingredient_allergen\" (\"ingredient_id\")"
+                ),
+                String::from(
+                    "CREATE INDEX \"idx-ingredient_allergen-allergen\" ON \"ingredient_allergen\" (\"allergen\")"
+                ),
+                String::from(
+                    "CREATE INDEX \"idx-ingredient_allergen-created_at\" ON \"ingredient_allergen\" (\"created_at\")"
+                ),
+                String::from(
+                    "CREATE INDEX \"idx-ingredient_allergen-updated_at\" ON \"ingredient_allergen\" (\"updated_at\")"
+                ),
+            ]
+        );
+    }
+}
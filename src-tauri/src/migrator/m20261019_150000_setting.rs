@@ -0,0 +1,61 @@
+//! This module implements the database migration which adds settings.
+//!
+//! It creates [`crate::entity::setting`].
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Setting::Table)
+                    .col(
+                        ColumnDef::new(Setting::Key)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Setting::Value).json().not_null())
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Setting {
+    Table,
+    Key,
+    Value,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_str_eq;
+
+    use super::*;
+    use crate::{
+        database::tests::{get_memory_database, get_table_indices, get_table_schema},
+        migrator::Migrator,
+    };
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database().await;
+        Migrator::up(&db, Some(19)).await.unwrap();
+        assert_str_eq!(
+            get_table_schema("setting", &db).await,
+            "CREATE TABLE \"setting\" ( \
+            \"key\" text NOT NULL PRIMARY KEY, \
+            \"value\" text NOT NULL \
+            )"
+        );
+        assert!(get_table_indices("setting", &db).await.is_empty());
+    }
+}
@@ -0,0 +1,621 @@
+//! This module handles the nutrition of ingredients and recipes.
+//!
+//! The [nutrient profiles](crate::entity::nutrient_profile) of ingredients are imported from CSV dumps of food composition databases, see [`import`].
+//! The nutrition of a recipe is summed up from the quantities of its recipe step ingredients, see [`calculate`].
+
+use std::{
+    collections::{HashMap, HashSet},
+    ops::AddAssign,
+    path::Path,
+};
+
+use anyhow::{bail, Result};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, ModelTrait, QueryFilter, QueryOrder,
+    TransactionTrait, Value,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    database,
+    entity::{
+        ingredient, ingredient_alias, nutrient_profile, recipe, recipe_step,
+        recipe_step_ingredient, unit_name, unit_name::unit::Unit,
+    },
+    entity_crud::{nutrient_profile::NutrientProfileCrud, EntityCrudTrait},
+    journal, unit_conversion,
+    unit_conversion::{Convert, MassUnit, VolumeUnit},
+};
+
+pub mod csv;
+
+/// This struct names the header of each column of a CSV dump which should be imported.
+///
+/// Columns without a header are not imported, so their values of existing nutrient profiles are kept.
+/// The same goes for empty or unparsable cells, see [`row_values`].
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NutrientColumns {
+    /// the column with the names of the foods, which are matched against the names and aliases of the ingredients
+    pub name: String,
+    pub kcal: Option<String>,
+    pub protein: Option<String>,
+    pub fat: Option<String>,
+    pub carbohydrate: Option<String>,
+    pub fibre: Option<String>,
+    pub salt: Option<String>,
+    pub density: Option<String>,
+    pub piece_mass: Option<String>,
+}
+
+impl NutrientColumns {
+    fn headers(&self) -> [(nutrient_profile::Column, &Option<String>); 8] {
+        [
+            (nutrient_profile::Column::Kcal, &self.kcal),
+            (nutrient_profile::Column::Protein, &self.protein),
+            (nutrient_profile::Column::Fat, &self.fat),
+            (nutrient_profile::Column::Carbohydrate, &self.carbohydrate),
+            (nutrient_profile::Column::Fibre, &self.fibre),
+            (nutrient_profile::Column::Salt, &self.salt),
+            (nutrient_profile::Column::Density, &self.density),
+            (nutrient_profile::Column::PieceMass, &self.piece_mass),
+        ]
+    }
+}
+
+/// This struct reports the result of an [`import`].
+#[derive(Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NutrientImport {
+    /// the number of nutrient profiles created for ingredients which had none
+    pub created: usize,
+    /// the number of existing nutrient profiles whose values changed
+    pub updated: usize,
+    /// the number of rows which match no ingredient
+    pub unmatched: usize,
+}
+
+/// This struct represents the nutrients of an amount of food, the energy in kcal and all others in g.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Nutrients {
+    pub kcal: f64,
+    pub protein: f64,
+    pub fat: f64,
+    pub carbohydrate: f64,
+    pub fibre: f64,
+    pub salt: f64,
+}
+
+impl Nutrients {
+    /// Get the nutrients of a mass in g of an ingredient, where missing values of its nutrient profile count as 0.
+    pub fn from_nutrient_profile(nutrient_profile: &nutrient_profile::Model, grams: f64) -> Self {
+        let of = |per_100_g: Option<f64>| per_100_g.unwrap_or_default() * grams / 100.0;
+        Nutrients {
+            kcal: of(nutrient_profile.kcal),
+            protein: of(nutrient_profile.protein),
+            fat: of(nutrient_profile.fat),
+            carbohydrate: of(nutrient_profile.carbohydrate),
+            fibre: of(nutrient_profile.fibre),
+            salt: of(nutrient_profile.salt),
+        }
+    }
+
+    fn divide(self, divisor: f64) -> Self {
+        Nutrients {
+            kcal: self.kcal / divisor,
+            protein: self.protein / divisor,
+            fat: self.fat / divisor,
+            carbohydrate: self.carbohydrate / divisor,
+            fibre: self.fibre / divisor,
+            salt: self.salt / divisor,
+        }
+    }
+}
+
+impl AddAssign for Nutrients {
+    fn add_assign(&mut self, rhs: Self) {
+        self.kcal += rhs.kcal;
+        self.protein += rhs.protein;
+        self.fat += rhs.fat;
+        self.carbohydrate += rhs.carbohydrate;
+        self.fibre += rhs.fibre;
+        self.salt += rhs.salt;
+    }
+}
+
+/// This enum represents why a recipe step ingredient could not be included in the nutrition of a recipe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum UnresolvedReason {
+    /// The ingredient has no nutrient profile.
+    NoNutrientProfile,
+    /// The recipe step ingredient has no quantity.
+    NoQuantity,
+    /// The unit is no known [unit name](crate::entity::unit_name).
+    UnknownUnit,
+    /// The unit is a volume, but the nutrient profile has no density.
+    NoDensity,
+    /// The quantity has no unit, but the nutrient profile has no piece mass.
+    NoPieceMass,
+}
+
+/// This struct represents a recipe step ingredient which could not be included in the nutrition of a recipe.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnresolvedIngredient {
+    pub recipe_step_ingredient_id: i64,
+    pub ingredient_id: i64,
+    pub ingredient_name: String,
+    pub reason: UnresolvedReason,
+}
+
+/// This struct represents the nutrition of a recipe.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeNutrition {
+    /// the nutrients of all resolved recipe step ingredients
+    pub total: Nutrients,
+    /// the total nutrients divided by the number of servings, if given
+    pub per_serving: Option<Nutrients>,
+    /// the recipe step ingredients missing from the total, so it is a lower bound if there are any
+    pub unresolved: Vec<UnresolvedIngredient>,
+}
+
+/// Import nutrient profiles from a CSV dump of a food composition database like the German BLS.
+///
+/// The dump needs one row per food with a header row, see [`csv::parse`].
+/// Normalized dumps like the USDA FoodData Central, which keep foods and their nutrients in separate files, have to be joined into such a table first.
+/// Each food is matched case-insensitively against the names and aliases of the ingredients, and the first matching row of an ingredient wins.
+/// A matched ingredient gets a new nutrient profile or its existing one is updated with the values of the [imported columns](NutrientColumns).
+/// All changes are recorded as one journal action.
+///
+/// # Errors
+///
+/// - when the file can't be read
+/// - when a named column is missing from the header
+/// - when there is any problem with the database
+/// - when a changed nutrient profile can't be serialized for the change log
+/// - when the tauri window can't be messaged about the changed nutrient profiles
+pub async fn import(path: &Path, columns: NutrientColumns) -> Result<NutrientImport> {
+    let text = String::from_utf8_lossy(&tokio::fs::read(path).await?).into_owned();
+    let mut rows = csv::parse(&text).into_iter();
+    let Some(header) = rows.next() else {
+        return Ok(NutrientImport::default());
+    };
+    let position = |name: &str| {
+        header
+            .iter()
+            .position(|field| field.trim().eq_ignore_ascii_case(name.trim()))
+    };
+    let Some(name_position) = position(&columns.name) else {
+        bail!("The column {} is missing", columns.name);
+    };
+    let mut positions = Vec::new();
+    for (column, name) in columns.headers() {
+        if let Some(name) = name {
+            let Some(position) = position(name) else {
+                bail!("The column {name} is missing");
+            };
+            positions.push((column, position));
+        }
+    }
+    let db = database::connect_writing().await;
+    let txn = db.begin().await?;
+    let mut ingredient_ids = HashMap::new();
+    for ingredient in ingredient::Entity::find()
+        .order_by_asc(ingredient::Column::Id)
+        .all(&txn)
+        .await?
+    {
+        ingredient_ids.insert(ingredient.name.to_lowercase(), ingredient.id);
+    }
+    for alias in ingredient_alias::Entity::find()
+        .order_by_asc(ingredient_alias::Column::Id)
+        .all(&txn)
+        .await?
+    {
+        ingredient_ids
+            .entry(alias.name.to_lowercase())
+            .or_insert(alias.ingredient_id);
+    }
+    let mut nutrient_profiles: HashMap<i64, nutrient_profile::Model> =
+        nutrient_profile::Entity::find()
+            .all(&txn)
+            .await?
+            .into_iter()
+            .map(|nutrient_profile| (nutrient_profile.ingredient_id, nutrient_profile))
+            .collect();
+    let mut report = NutrientImport::default();
+    let mut imported_ingredient_ids = HashSet::new();
+    let mut journal_action_id = None;
+    let mut changes = Vec::new();
+    for row in rows {
+        let name = row.get(name_position).map_or("", |name| name.trim());
+        let Some(&ingredient_id) = ingredient_ids.get(&name.to_lowercase()) else {
+            report.unmatched += 1;
+            continue;
+        };
+        if !imported_ingredient_ids.insert(ingredient_id) {
+            continue;
+        }
+        let values = row_values(&row, &positions);
+        let existing = nutrient_profiles.remove(&ingredient_id);
+        if existing.as_ref().is_some_and(|existing| {
+            values
+                .iter()
+                .all(|(column, value)| existing.get(*column) == *value)
+        }) {
+            continue;
+        }
+        let mut active_model = match &existing {
+            Some(existing) => existing.clone().into(),
+            None => nutrient_profile::ActiveModel {
+                id: ActiveValue::NotSet,
+                ingredient_id: ActiveValue::Set(ingredient_id),
+                ..Default::default()
+            },
+        };
+        for (column, value) in values {
+            active_model.set(column, value);
+        }
+        if journal_action_id.is_none() {
+            journal_action_id = Some(journal::record_action(&txn).await?);
+        }
+        let (_, change) = match existing {
+            Some(_) => {
+                report.updated += 1;
+                NutrientProfileCrud::update_logged(active_model, journal_action_id, &txn).await?
+            }
+            None => {
+                report.created += 1;
+                NutrientProfileCrud::create_logged(active_model, journal_action_id, &txn).await?
+            }
+        };
+        changes.push(change);
+    }
+    if changes.is_empty() {
+        return Ok(report);
+    }
+    txn.commit().await?;
+    journal::emit(&changes)?;
+    Ok(report)
+}
+
+/// Get the values of the imported columns of a row.
+///
+/// Empty or unparsable cells are left out, so they don't overwrite the values of an existing nutrient profile.
+fn row_values(
+    row: &[String],
+    positions: &[(nutrient_profile::Column, usize)],
+) -> Vec<(nutrient_profile::Column, Value)> {
+    positions
+        .iter()
+        .filter_map(|(column, position)| {
+            let value = row
+                .get(*position)
+                .and_then(|field| csv::parse_number(field))?;
+            Some((*column, value.into()))
+        })
+        .collect()
+}
+
+/// Calculate the nutrition of a recipe and optionally per serving, see [`sum`].
+///
+/// Returns [`None`] when the recipe does not exist.
+///
+/// # Errors
+///
+/// - when there is any problem with the database
+pub async fn calculate(recipe_id: i64, servings: Option<f64>) -> Result<Option<RecipeNutrition>> {
+    let db = database::connect().await;
+    if recipe::Entity::find_by_id(recipe_id)
+        .one(db)
+        .await?
+        .is_none()
+    {
+        return Ok(None);
+    }
+    let recipe_step_ingredients: Vec<(recipe_step_ingredient::Model, ingredient::Model)> =
+        recipe_step_ingredient::Entity::find()
+            .inner_join(recipe_step::Entity)
+            .filter(recipe_step::Column::RecipeId.eq(recipe_id))
+            .order_by_asc(recipe_step::Column::Order)
+            .order_by_asc(recipe_step_ingredient::Column::Order)
+            .find_also_related(ingredient::Entity)
+            .all(db)
+            .await?
+            .into_iter()
+            .filter_map(|(recipe_step_ingredient, ingredient)| {
+                Some((recipe_step_ingredient, ingredient?))
+            })
+            .collect();
+    let nutrient_profiles: HashMap<i64, nutrient_profile::Model> = nutrient_profile::Entity::find()
+        .filter(
+            nutrient_profile::Column::IngredientId.is_in(
+                recipe_step_ingredients
+                    .iter()
+                    .map(|(_, ingredient)| ingredient.id),
+            ),
+        )
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|nutrient_profile| (nutrient_profile.ingredient_id, nutrient_profile))
+        .collect();
+    let units = unit_name::Entity::find()
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|unit_name| (unit_name.name.to_lowercase(), unit_name.unit))
+        .collect();
+    Ok(Some(sum(
+        recipe_step_ingredients
+            .iter()
+            .map(|(recipe_step_ingredient, ingredient)| {
+                (
+                    recipe_step_ingredient,
+                    ingredient,
+                    nutrient_profiles.get(&ingredient.id),
+                )
+            }),
+        &units,
+        servings,
+    )))
+}
+
+/// Sum up the nutrients of recipe step ingredients with their ingredients and nutrient profiles.
+///
+/// The units are looked up case-insensitively by their [names](crate::entity::unit_name), see [`grams`].
+/// Servings which are not positive are ignored.
+pub fn sum<'a, I>(
+    recipe_step_ingredients: I,
+    units: &HashMap<String, Unit>,
+    servings: Option<f64>,
+) -> RecipeNutrition
+where
+    I: IntoIterator<
+        Item = (
+            &'a recipe_step_ingredient::Model,
+            &'a ingredient::Model,
+            Option<&'a nutrient_profile::Model>,
+        ),
+    >,
+{
+    let mut total = Nutrients::default();
+    let mut unresolved = Vec::new();
+    for (recipe_step_ingredient, ingredient, nutrient_profile) in recipe_step_ingredients {
+        let nutrients = nutrient_profile
+            .ok_or(UnresolvedReason::NoNutrientProfile)
+            .and_then(|nutrient_profile| {
+                let grams = grams(
+                    recipe_step_ingredient.quantity,
+                    recipe_step_ingredient.unit.as_deref(),
+                    units,
+                    nutrient_profile,
+                )?;
+                Ok(Nutrients::from_nutrient_profile(nutrient_profile, grams))
+            });
+        match nutrients {
+            Ok(nutrients) => total += nutrients,
+            Err(reason) => unresolved.push(UnresolvedIngredient {
+                recipe_step_ingredient_id: recipe_step_ingredient.id,
+                ingredient_id: ingredient.id,
+                ingredient_name: ingredient.name.clone(),
+                reason,
+            }),
+        }
+    }
+    RecipeNutrition {
+        total,
+        per_serving: servings
+            .filter(|servings| *servings > 0.0)
+            .map(|servings| total.divide(servings)),
+        unresolved,
+    }
+}
+
+/// Convert a quantity to grams.
+///
+/// Masses are converted directly, volumes via the density of the nutrient profile and quantities without a unit via its piece mass.
+pub fn grams(
+    quantity: Option<f64>,
+    unit: Option<&str>,
+    units: &HashMap<String, Unit>,
+    nutrient_profile: &nutrient_profile::Model,
+) -> Result<f64, UnresolvedReason> {
+    let quantity = quantity.ok_or(UnresolvedReason::NoQuantity)?;
+    let Some(unit) = unit.map(str::trim).filter(|unit| !unit.is_empty()) else {
+        return nutrient_profile
+            .piece_mass
+            .map(|piece_mass| quantity * piece_mass)
+            .ok_or(UnresolvedReason::NoPieceMass);
+    };
+    let unit = units
+        .get(&unit.to_lowercase())
+        .ok_or(UnresolvedReason::UnknownUnit)?;
+    match unit_conversion::Unit::from(unit.clone()) {
+        unit_conversion::Unit::Mass(mass_unit) => Ok(mass_unit.convert(quantity, &MassUnit::Gram)),
+        unit_conversion::Unit::Volume(volume_unit) => nutrient_profile
+            .density
+            .map(|density| volume_unit.convert(quantity, &VolumeUnit::Millilitre) * density)
+            .ok_or(UnresolvedReason::NoDensity),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use sea_orm::Iden;
+
+    use super::*;
+
+    fn recipe_step_ingredient(
+        id: i64,
+        quantity: Option<f64>,
+        unit: Option<&str>,
+        ingredient_id: i64,
+    ) -> recipe_step_ingredient::Model {
+        recipe_step_ingredient::Model {
+            id,
+            order: id,
+            quantity,
+            unit: unit.map(str::to_string),
+            quality: None,
+            recipe_step_id: 1,
+            ingredient_id,
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    fn ingredient(id: i64, name: &str) -> ingredient::Model {
+        ingredient::Model {
+            id,
+            name: name.to_string(),
+            ingredient_category_id: None,
+            vegetarian: None,
+            vegan: None,
+            gluten_free: None,
+            lactose_free: None,
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    fn nutrient_profile(
+        ingredient_id: i64,
+        kcal: f64,
+        density: Option<f64>,
+        piece_mass: Option<f64>,
+    ) -> nutrient_profile::Model {
+        nutrient_profile::Model {
+            id: ingredient_id,
+            ingredient_id,
+            kcal: Some(kcal),
+            protein: Some(10.0),
+            fat: None,
+            carbohydrate: None,
+            fibre: None,
+            salt: None,
+            density,
+            piece_mass,
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_sum() {
+        let units = HashMap::from([
+            ("g".to_string(), Unit::MassGram),
+            ("ml".to_string(), Unit::VolumeMillilitre),
+        ]);
+        let flour = ingredient(1, "flour");
+        let milk = ingredient(2, "milk");
+        let egg = ingredient(3, "egg");
+        let salt = ingredient(4, "salt");
+        let flour_profile = nutrient_profile(1, 350.0, None, None);
+        let milk_profile = nutrient_profile(2, 50.0, Some(1.0), None);
+        let egg_profile = nutrient_profile(3, 150.0, None, Some(50.0));
+        let recipe_step_ingredients = [
+            (
+                recipe_step_ingredient(1, Some(200.0), Some("G"), 1),
+                &flour,
+                Some(&flour_profile),
+            ),
+            (
+                recipe_step_ingredient(2, Some(400.0), Some("ml"), 2),
+                &milk,
+                Some(&milk_profile),
+            ),
+            (
+                recipe_step_ingredient(3, Some(2.0), None, 3),
+                &egg,
+                Some(&egg_profile),
+            ),
+            (
+                recipe_step_ingredient(4, Some(1.0), Some("Prise"), 4),
+                &salt,
+                None,
+            ),
+            (
+                recipe_step_ingredient(5, Some(1.0), Some("ml"), 1),
+                &flour,
+                Some(&flour_profile),
+            ),
+            (
+                recipe_step_ingredient(6, None, Some("g"), 2),
+                &milk,
+                Some(&milk_profile),
+            ),
+            (
+                recipe_step_ingredient(7, Some(1.0), Some("Prise"), 3),
+                &egg,
+                Some(&egg_profile),
+            ),
+            (
+                recipe_step_ingredient(8, Some(1.0), None, 1),
+                &flour,
+                Some(&flour_profile),
+            ),
+        ];
+        let nutrition = sum(
+            recipe_step_ingredients.iter().map(
+                |(recipe_step_ingredient, ingredient, nutrient_profile)| {
+                    (recipe_step_ingredient, *ingredient, *nutrient_profile)
+                },
+            ),
+            &units,
+            Some(4.0),
+        );
+        assert_eq!(
+            nutrition.total,
+            Nutrients {
+                kcal: 1050.0,
+                protein: 70.0,
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            nutrition.per_serving,
+            Some(Nutrients {
+                kcal: 262.5,
+                protein: 17.5,
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            nutrition
+                .unresolved
+                .iter()
+                .map(|unresolved| (unresolved.recipe_step_ingredient_id, unresolved.reason))
+                .collect::<Vec<_>>(),
+            vec![
+                (4, UnresolvedReason::NoNutrientProfile),
+                (5, UnresolvedReason::NoDensity),
+                (6, UnresolvedReason::NoQuantity),
+                (7, UnresolvedReason::UnknownUnit),
+                (8, UnresolvedReason::NoPieceMass),
+            ]
+        );
+        assert_eq!(nutrition.unresolved[0].ingredient_name, "salt");
+        assert_eq!(sum([], &units, Some(0.0)).per_serving, None);
+    }
+
+    #[test]
+    fn test_row_values() {
+        let row = ["apple", "52", "", "n/a"].map(String::from);
+        let positions = [
+            (nutrient_profile::Column::Kcal, 1),
+            (nutrient_profile::Column::Protein, 2),
+            (nutrient_profile::Column::Fat, 3),
+            (nutrient_profile::Column::Salt, 4),
+        ];
+        let values: Vec<(String, Value)> = row_values(&row, &positions)
+            .into_iter()
+            .map(|(column, value)| (column.to_string(), value))
+            .collect();
+        assert_eq!(values, vec![(String::from("kcal"), Value::from(52.0))]);
+    }
+}
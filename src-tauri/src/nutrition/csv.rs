@@ -0,0 +1,138 @@
+//! This module implements a minimal reader for CSV dumps of food composition databases.
+//!
+//! Dumps differ in their delimiter and decimal separator, e.g. the German BLS uses semicolons and decimal commas,
+//! so both are detected instead of configured.
+
+/// Parse CSV text into rows of fields.
+///
+/// The delimiter is detected from the first line, see [`detect_delimiter`].
+/// Fields may be quoted with `"`, in which case they may contain delimiters, line breaks and doubled quotes.
+/// Empty lines are skipped.
+pub fn parse(text: &str) -> Vec<Vec<String>> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let delimiter = detect_delimiter(text.lines().next().unwrap_or_default());
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = text.chars().peekable();
+    while let Some(char) = chars.next() {
+        if quoted {
+            match char {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => quoted = false,
+                _ => field.push(char),
+            }
+            continue;
+        }
+        match char {
+            '"' if field.is_empty() => quoted = true,
+            '\r' => {}
+            '\n' => {
+                row.push(std::mem::take(&mut field));
+                push_row(&mut rows, std::mem::take(&mut row));
+            }
+            _ if char == delimiter => row.push(std::mem::take(&mut field)),
+            _ => field.push(char),
+        }
+    }
+    row.push(field);
+    push_row(&mut rows, row);
+    rows
+}
+
+fn push_row(rows: &mut Vec<Vec<String>>, row: Vec<String>) {
+    if row.len() > 1 || row.first().is_some_and(|field| !field.is_empty()) {
+        rows.push(row);
+    }
+}
+
+/// Detect the delimiter of a line as the most frequent of `;`, tab and `,` outside of quotes.
+///
+/// Defaults to `,`.
+pub fn detect_delimiter(line: &str) -> char {
+    let mut quoted = false;
+    let mut counts = [(';', 0), ('\t', 0), (',', 0)];
+    for char in line.chars() {
+        if char == '"' {
+            quoted = !quoted;
+        } else if !quoted {
+            if let Some((_, count)) = counts.iter_mut().find(|(delimiter, _)| *delimiter == char) {
+                *count += 1;
+            }
+        }
+    }
+    counts
+        .into_iter()
+        .filter(|(_, count)| *count > 0)
+        .max_by_key(|(_, count)| *count)
+        .map_or(',', |(delimiter, _)| delimiter)
+}
+
+/// Parse a number which may use a decimal comma.
+///
+/// Markers of values below the detection limit like `<0.1` or `~` are ignored.
+/// Returns [`None`] for empty fields and placeholders like `-` or `n.a.`.
+pub fn parse_number(field: &str) -> Option<f64> {
+    let field = field.trim().trim_start_matches(['<', '~']).trim();
+    let number = field.replace(',', ".").parse::<f64>().ok()?;
+    number.is_finite().then_some(number)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse("\u{feff}name,kcal\r\n\"Apples, raw\",52\r\n\r\n\"Say \"\"cheese\"\"\",\n"),
+            vec![
+                vec!["name".to_string(), "kcal".to_string()],
+                vec!["Apples, raw".to_string(), "52".to_string()],
+                vec!["Say \"cheese\"".to_string(), String::new()],
+            ]
+        );
+        assert_eq!(
+            parse("Name;Energie\nApfel;54,5\n\"Brot\nhell\";250"),
+            vec![
+                vec!["Name".to_string(), "Energie".to_string()],
+                vec!["Apfel".to_string(), "54,5".to_string()],
+                vec!["Brot\nhell".to_string(), "250".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_detect_delimiter() {
+        for (line, expected) in [
+            ("name,kcal,protein", ','),
+            ("Name;Energie;\"Eiweiß, gesamt\"", ';'),
+            ("name\tkcal", '\t'),
+            ("name", ','),
+        ] {
+            assert_eq!(detect_delimiter(line), expected, "{line}");
+        }
+    }
+
+    #[test]
+    fn test_parse_number() {
+        for (field, expected) in [
+            ("52", Some(52.0)),
+            (" 54,5 ", Some(54.5)),
+            ("0.25", Some(0.25)),
+            ("<0,1", Some(0.1)),
+            ("", None),
+            ("-", None),
+            ("n.a.", None),
+            ("NaN", None),
+        ] {
+            assert_eq!(parse_number(field), expected, "{field}");
+        }
+    }
+}
@@ -0,0 +1,328 @@
+//! This module handles optical character recognition via [Tesseract](https://github.com/tesseract-ocr/tesseract).
+
+use std::{fs, path::PathBuf};
+
+use image::GrayImage;
+use serde::{Deserialize, Serialize};
+use tesseract::{PageSegMode, Tesseract};
+
+use crate::{
+    app_handle::get_app_handle,
+    ocr::{error::OcrError, preprocessing::OcrPreprocessing},
+    path::app_data_dir,
+};
+
+pub mod error;
+mod pool;
+pub mod preprocessing;
+
+/// The language used for recognizing characters when none is specified.
+pub const DEFAULT_LANGUAGE: &str = "Latin";
+
+/// The file extension of Tesseract's trained data files.
+const TRAINEDDATA_EXTENSION: &str = "traineddata";
+
+/// The directories which contain trained data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TessdataSource {
+    /// The directory in the user's application data, see [`user_tessdata_dir`].
+    User,
+    /// The directory bundled with the application.
+    Bundled,
+}
+
+impl TessdataSource {
+    /// All sources in the order they are searched.
+    const ALL: [TessdataSource; 2] = [TessdataSource::User, TessdataSource::Bundled];
+
+    fn dir(&self) -> PathBuf {
+        match self {
+            TessdataSource::User => user_tessdata_dir(),
+            TessdataSource::Bundled => get_app_handle()
+                .path_resolver()
+                .resolve_resource("tessdata")
+                .unwrap(),
+        }
+    }
+}
+
+/// A language for which trained data is available.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OcrLanguage {
+    pub name: String,
+    pub source: TessdataSource,
+}
+
+/// An image which can be handed to Tesseract.
+#[derive(Debug)]
+pub enum OcrImage {
+    /// A path to an image file.
+    Path(String),
+    /// An encoded image, e.g. a JPEG, which Leptonica can read.
+    Encoded(Vec<u8>),
+    /// Raw pixel data with 8 bits per channel.
+    Raw {
+        data: Vec<u8>,
+        width: i32,
+        height: i32,
+        bytes_per_pixel: i32,
+    },
+}
+
+impl From<GrayImage> for OcrImage {
+    fn from(value: GrayImage) -> Self {
+        OcrImage::Raw {
+            width: value.width() as i32,
+            height: value.height() as i32,
+            bytes_per_pixel: 1,
+            data: value.into_raw(),
+        }
+    }
+}
+
+/// The settings of the Tesseract engine for recognizing an image.
+///
+/// Results are cached per settings, so these are serialized as part of the cache key.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OcrSettings {
+    #[serde(default)]
+    pub page_seg_mode: OcrPageSegMode,
+    #[serde(default)]
+    pub preprocessing: OcrPreprocessing,
+}
+
+/// The supported subset of [`PageSegMode`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OcrPageSegMode {
+    /// Fully automatic page segmentation, e.g. for multiple columns.
+    Auto,
+    /// A single column of text of variable sizes.
+    SingleColumn,
+    /// A single uniform block of text, which is Tesseract's default.
+    #[default]
+    SingleBlock,
+    /// As much text as possible in no particular order.
+    SparseText,
+}
+
+impl From<OcrPageSegMode> for PageSegMode {
+    fn from(value: OcrPageSegMode) -> Self {
+        match value {
+            OcrPageSegMode::Auto => PageSegMode::PsmAuto,
+            OcrPageSegMode::SingleColumn => PageSegMode::PsmSingleColumn,
+            OcrPageSegMode::SingleBlock => PageSegMode::PsmSingleBlock,
+            OcrPageSegMode::SparseText => PageSegMode::PsmSparseText,
+        }
+    }
+}
+
+/// Get the directory in which users can put additional trained data.
+pub fn user_tessdata_dir() -> PathBuf {
+    let mut dir = app_data_dir();
+    dir.push("tessdata");
+    dir
+}
+
+/// List all languages with trained data, sorted by name.
+///
+/// A language available in multiple sources is listed once per source.
+pub fn languages() -> Vec<OcrLanguage> {
+    let mut languages = vec![];
+    for source in TessdataSource::ALL {
+        let Ok(read_dir) = fs::read_dir(source.dir()) else {
+            continue;
+        };
+        for entry in read_dir.flatten() {
+            let path = entry.path();
+            if !path.is_file()
+                || path.extension().and_then(|extension| extension.to_str())
+                    != Some(TRAINEDDATA_EXTENSION)
+            {
+                continue;
+            }
+            if let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) {
+                languages.push(OcrLanguage {
+                    name: String::from(name),
+                    source,
+                });
+            }
+        }
+    }
+    languages.sort_by(|a, b| a.name.cmp(&b.name));
+    languages
+}
+
+/// Get the trained data directory which contains all languages of a Tesseract language specification, e.g. `deu+eng`.
+///
+/// # Errors
+///
+/// - [`OcrError::LanguageUnavailable`] when some languages do not have trained data
+/// - [`OcrError::LanguagesInDifferentSources`] when the languages are not all available in one source
+fn tessdata_dir(language: &str) -> Result<PathBuf, OcrError> {
+    let names: Vec<&str> = language.split('+').collect();
+    let is_valid_name = |name: &&str| {
+        !name.is_empty()
+            && name
+                .chars()
+                .all(|char| char.is_ascii_alphanumeric() || char == '_' || char == '-')
+    };
+    for source in TessdataSource::ALL {
+        let dir = source.dir();
+        let has_all = names.iter().all(|name| {
+            is_valid_name(name)
+                && dir
+                    .join(format!("{name}.{TRAINEDDATA_EXTENSION}"))
+                    .is_file()
+        });
+        if has_all {
+            return Ok(dir);
+        }
+    }
+    let available = languages();
+    let unavailable: Vec<&str> = names
+        .into_iter()
+        .filter(|name| {
+            !is_valid_name(name) || !available.iter().any(|language| language.name == *name)
+        })
+        .collect();
+    if unavailable.is_empty() {
+        return Err(OcrError::LanguagesInDifferentSources(String::from(
+            language,
+        )));
+    }
+    Err(OcrError::LanguageUnavailable(unavailable.join("+")))
+}
+
+/// Set the image of a [`Tesseract`] instance.
+fn set_image(tesseract: Tesseract, image: &OcrImage) -> Result<Tesseract, OcrError> {
+    let tesseract = match image {
+        OcrImage::Path(path) => tesseract.set_image(path)?,
+        OcrImage::Encoded(bytes) => tesseract.set_image_from_mem(bytes)?,
+        OcrImage::Raw {
+            data,
+            width,
+            height,
+            bytes_per_pixel,
+        } => tesseract.set_frame(
+            data,
+            *width,
+            *height,
+            *bytes_per_pixel,
+            width * bytes_per_pixel,
+        )?,
+    };
+    Ok(tesseract)
+}
+
+/// The format of the recognized characters.
+#[derive(Debug, Clone, Copy)]
+enum TextFormat {
+    Hocr,
+    Text,
+}
+
+/// Recognize the characters of an image, preprocessing it according to the settings.
+fn recognize(
+    tesseract: Tesseract,
+    image: OcrImage,
+    settings: OcrSettings,
+    format: TextFormat,
+) -> Result<(Tesseract, String), OcrError> {
+    let (tesseract, image) = if settings.preprocessing.is_enabled() {
+        let (tesseract, preprocessed_image) =
+            preprocessing::preprocess(tesseract, &image, &settings.preprocessing)?;
+        (tesseract, OcrImage::from(preprocessed_image))
+    } else {
+        (tesseract, image)
+    };
+    let mut tesseract = set_image(tesseract, &image)?;
+    tesseract.set_page_seg_mode(settings.page_seg_mode.into());
+    let text = match format {
+        TextFormat::Hocr => tesseract.get_hocr_text(1)?,
+        TextFormat::Text => tesseract.get_text()?,
+    };
+    Ok((tesseract, text))
+}
+
+/// Get the optically recognized characters of an image as [hOCR](https://kba.github.io/hocr-spec/1.2/).
+///
+/// The `language` is a Tesseract language specification like `deu+eng`.
+/// The recognition runs in a dedicated worker thread.
+pub async fn hocr(
+    image: OcrImage,
+    language: String,
+    settings: OcrSettings,
+) -> Result<String, OcrError> {
+    pool::submit(language, move |tesseract| {
+        recognize(tesseract, image, settings, TextFormat::Hocr)
+    })
+    .await
+    .map_err(|_| OcrError::WorkerUnavailable)?
+}
+
+/// Preprocess an image as it would be before recognizing its characters.
+///
+/// The `language` is used for detecting the orientation, see [`hocr`].
+pub async fn preprocessed(
+    image: OcrImage,
+    language: String,
+    preprocessing: OcrPreprocessing,
+) -> Result<GrayImage, OcrError> {
+    pool::submit(language, move |tesseract| {
+        preprocessing::preprocess(tesseract, &image, &preprocessing)
+    })
+    .await
+    .map_err(|_| OcrError::WorkerUnavailable)?
+}
+
+/// Get the optically recognized characters of an image as plain text.
+///
+/// The `language` is a Tesseract language specification like `deu+eng`.
+/// The recognition runs in a dedicated worker thread, but this blocks until it is finished.
+/// It must not be called on an async runtime's worker thread.
+pub fn blocking_text(
+    image: OcrImage,
+    language: String,
+    settings: OcrSettings,
+) -> Result<String, OcrError> {
+    pool::submit(language, move |tesseract| {
+        recognize(tesseract, image, settings, TextFormat::Text)
+    })
+    .blocking_recv()
+    .map_err(|_| OcrError::WorkerUnavailable)?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TEST_NAME;
+
+    #[test]
+    fn test_tessdata_dir() {
+        TEST_NAME.set(Some("ocr__test_tessdata_dir".to_string()));
+        crate::tests::run();
+
+        let dir = user_tessdata_dir();
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("tst.traineddata"), "").unwrap();
+        assert!(languages().contains(&OcrLanguage {
+            name: String::from("tst"),
+            source: TessdataSource::User,
+        }));
+        assert_eq!(tessdata_dir("tst").unwrap(), dir);
+        assert!(matches!(
+            tessdata_dir("tst+missing"),
+            Err(OcrError::LanguageUnavailable(language)) if language == "missing"
+        ));
+        assert!(matches!(
+            tessdata_dir("../tessdata/tst"),
+            Err(OcrError::LanguageUnavailable(_))
+        ));
+
+        TEST_NAME.set(None);
+    }
+}
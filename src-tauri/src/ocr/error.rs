@@ -0,0 +1,35 @@
+//! This module contains the [`std::error::Error`] for the [`crate::ocr`] module.
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum OcrError {
+    #[error("There is no trained data for the OCR language \"{0}\".")]
+    LanguageUnavailable(String),
+    #[error("The OCR languages \"{0}\" are not available in the same trained data directory.")]
+    LanguagesInDifferentSources(String),
+    #[error("The OCR worker stopped unexpectedly.")]
+    WorkerUnavailable,
+    #[error("The raw image data does not match its dimensions.")]
+    InvalidRawImage,
+    #[error("The crop region does not overlap the image.")]
+    EmptyCrop,
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Image(#[from] image::ImageError),
+    #[error(transparent)]
+    Initialize(#[from] tesseract::InitializeError),
+    #[error(transparent)]
+    SetImage(#[from] tesseract::SetImageError),
+    #[error(transparent)]
+    SetImageFromMem(#[from] tesseract::plumbing::leptonica_plumbing::PixReadMemError),
+    #[error(transparent)]
+    SetFrame(#[from] tesseract::plumbing::TessBaseApiSetImageSafetyError),
+    #[error(transparent)]
+    Recognize(#[from] tesseract::plumbing::TessBaseApiRecogniseError),
+    #[error(transparent)]
+    GetHocrText(#[from] tesseract::plumbing::TessBaseApiGetHocrTextError),
+    #[error(transparent)]
+    GetText(#[from] tesseract::plumbing::TessBaseApiGetUtf8TextError),
+}
@@ -0,0 +1,101 @@
+//! This module implements a pool of dedicated threads running Tesseract.
+//!
+//! Initializing Tesseract is expensive, so every thread keeps its instances per trained data directory and language.
+
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{
+        mpsc::{self, Receiver, Sender},
+        Arc, Mutex, OnceLock,
+    },
+    thread,
+};
+
+use tesseract::Tesseract;
+use tokio::sync::oneshot;
+
+use crate::ocr::{error::OcrError, tessdata_dir};
+
+/// The maximum number of worker threads, Tesseract itself also uses multiple threads.
+const MAX_WORKERS: usize = 4;
+
+static JOB_SENDER: OnceLock<Mutex<Sender<Job>>> = OnceLock::new();
+
+type Tesseracts = HashMap<(PathBuf, String), Tesseract>;
+
+type Job = Box<dyn FnOnce(&mut Tesseracts) + Send>;
+
+/// Submit a task to be run by the next free worker with a [`Tesseract`] instance for the `language`.
+///
+/// The task hands the instance back on success, as Tesseract's API consumes it on errors.
+/// The returned receiver errors when the job was lost, e.g. because the worker panicked.
+pub fn submit<T, F>(language: String, task: F) -> oneshot::Receiver<Result<T, OcrError>>
+where
+    T: Send + 'static,
+    F: FnOnce(Tesseract) -> Result<(Tesseract, T), OcrError> + Send + 'static,
+{
+    let (result_sender, result_receiver) = oneshot::channel();
+    let job: Job = Box::new(move |tesseracts| {
+        let result = run(tesseracts, language, task);
+        result_sender.send(result).ok();
+    });
+    if let Err(err) = job_sender().lock().unwrap().send(job) {
+        log::error!("Could not submit OCR job: {err}");
+    }
+    result_receiver
+}
+
+/// Get the sender of jobs, spawning the workers on the first call.
+fn job_sender() -> &'static Mutex<Sender<Job>> {
+    JOB_SENDER.get_or_init(|| {
+        let (job_sender, job_receiver) = mpsc::channel();
+        let job_receiver = Arc::new(Mutex::new(job_receiver));
+        let worker_count = thread::available_parallelism()
+            .map_or(1, |parallelism| parallelism.get() / 2)
+            .clamp(1, MAX_WORKERS);
+        for i in 0..worker_count {
+            let job_receiver = job_receiver.clone();
+            if let Err(err) = thread::Builder::new()
+                .name(format!("ocr-worker-{i}"))
+                .spawn(move || work(&job_receiver))
+            {
+                log::error!("Could not spawn OCR worker: {err}");
+            }
+        }
+        Mutex::new(job_sender)
+    })
+}
+
+/// Run jobs until the job channel is closed.
+fn work(job_receiver: &Mutex<Receiver<Job>>) {
+    let mut tesseracts: Tesseracts = HashMap::new();
+    loop {
+        let job_result = match job_receiver.lock() {
+            Ok(job_receiver) => job_receiver.recv(),
+            Err(_) => return,
+        };
+        let Ok(job) = job_result else {
+            return;
+        };
+        job(&mut tesseracts);
+    }
+}
+
+/// Run a task with a reused or new [`Tesseract`] instance.
+///
+/// The instance is only kept when the task succeeds.
+fn run<T>(
+    tesseracts: &mut Tesseracts,
+    language: String,
+    task: impl FnOnce(Tesseract) -> Result<(Tesseract, T), OcrError>,
+) -> Result<T, OcrError> {
+    let key = (tessdata_dir(&language)?, language);
+    let tesseract = match tesseracts.remove(&key) {
+        Some(tesseract) => tesseract,
+        None => Tesseract::new(Some(&key.0.to_string_lossy()), Some(&key.1))?,
+    };
+    let (tesseract, output) = task(tesseract)?;
+    tesseracts.insert(key, tesseract);
+    Ok(output)
+}
@@ -0,0 +1,389 @@
+//! This module implements preprocessing of images before recognizing their characters.
+//!
+//! Photos of cookbook pages are often rotated, skewed and unevenly lit, which Tesseract handles poorly.
+
+use std::{borrow::Cow, fs, io::Cursor};
+
+use image::{
+    imageops::{self, FilterType},
+    DynamicImage, GrayImage, Luma, RgbImage, RgbaImage,
+};
+use serde::{Deserialize, Serialize};
+use tesseract::Tesseract;
+
+use crate::ocr::{error::OcrError, OcrImage};
+
+/// The maximum width and height of the downscaled image used for detecting the orientation.
+const ORIENTATION_SAMPLE_SIZE: u32 = 1000;
+
+/// The maximum width and height of the downscaled image used for detecting the skew.
+const SKEW_SAMPLE_SIZE: u32 = 800;
+
+/// The maximum detected skew in degrees, larger angles are handled by the orientation detection.
+const MAX_SKEW: f32 = 15.0;
+
+/// How much darker than its surrounding a pixel must be to be considered black when thresholding.
+const THRESHOLD_SENSITIVITY: f64 = 0.15;
+
+/// The preprocessing steps to apply before recognizing characters, all of them are disabled by default.
+///
+/// The steps are applied in the order of the fields.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct OcrPreprocessing {
+    /// Rotate and mirror the image according to its EXIF orientation, as cameras often store it unrotated.
+    pub exif_orientation: bool,
+    /// Crop the image to a region, in pixels of the image after applying the EXIF orientation.
+    pub crop: Option<OcrCropRegion>,
+    /// Rotate the image by quarter turns to the orientation Tesseract is most confident about.
+    pub detect_orientation: bool,
+    /// Rotate the image so that its lines of text are horizontal.
+    pub deskew: bool,
+    /// Convert the image to black and white relative to the brightness of each pixel's surrounding.
+    pub threshold: bool,
+}
+
+impl OcrPreprocessing {
+    /// Whether any preprocessing step is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.exif_orientation
+            || self.crop.is_some()
+            || self.detect_orientation
+            || self.deskew
+            || self.threshold
+    }
+}
+
+/// A rectangular region of an image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OcrCropRegion {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Preprocess an image, converting it to grayscale.
+///
+/// The [`Tesseract`] instance is used for detecting the orientation and handed back on success.
+pub fn preprocess(
+    tesseract: Tesseract,
+    image: &OcrImage,
+    preprocessing: &OcrPreprocessing,
+) -> Result<(Tesseract, GrayImage), OcrError> {
+    let mut tesseract = tesseract;
+    let mut image = load(image, preprocessing.exif_orientation)?.to_luma8();
+    if let Some(region) = preprocessing.crop {
+        image = crop(&image, region)?;
+    }
+    if preprocessing.detect_orientation {
+        let quarter_turns;
+        (tesseract, quarter_turns) = detect_quarter_turns(tesseract, &image)?;
+        image = rotate_quarter_turns(&image, quarter_turns);
+    }
+    if preprocessing.deskew {
+        image = rotate(&image, skew_angle(&image));
+    }
+    if preprocessing.threshold {
+        image = adaptive_threshold(&image);
+    }
+    Ok((tesseract, image))
+}
+
+/// Decode an image, maybe applying its EXIF orientation.
+fn load(image: &OcrImage, exif_orientation: bool) -> Result<DynamicImage, OcrError> {
+    let bytes = match image {
+        OcrImage::Path(path) => Cow::Owned(fs::read(path)?),
+        OcrImage::Encoded(bytes) => Cow::Borrowed(bytes),
+        OcrImage::Raw {
+            data,
+            width,
+            height,
+            bytes_per_pixel,
+        } => {
+            let width = u32::try_from(*width).map_err(|_| OcrError::InvalidRawImage)?;
+            let height = u32::try_from(*height).map_err(|_| OcrError::InvalidRawImage)?;
+            let data = data.clone();
+            let image_option = match bytes_per_pixel {
+                1 => GrayImage::from_raw(width, height, data).map(DynamicImage::ImageLuma8),
+                3 => RgbImage::from_raw(width, height, data).map(DynamicImage::ImageRgb8),
+                4 => RgbaImage::from_raw(width, height, data).map(DynamicImage::ImageRgba8),
+                _ => None,
+            };
+            return image_option.ok_or(OcrError::InvalidRawImage);
+        }
+    };
+    let image = image::load_from_memory(&bytes)?;
+    if !exif_orientation {
+        return Ok(image);
+    }
+    Ok(match read_exif_orientation(&bytes) {
+        Some(orientation) => orient(image, orientation),
+        None => image,
+    })
+}
+
+/// Read the EXIF orientation of an encoded image, see <https://www.exif.org/Exif2-2.PDF> page 18.
+fn read_exif_orientation(bytes: &[u8]) -> Option<u32> {
+    let exif = exif::Reader::new()
+        .read_from_container(&mut Cursor::new(bytes))
+        .ok()?;
+    exif.get_field(exif::Tag::Orientation, exif::In::PRIMARY)?
+        .value
+        .get_uint(0)
+}
+
+/// Transform an image with the EXIF `orientation` so that it is displayed upright.
+fn orient(image: DynamicImage, orientation: u32) -> DynamicImage {
+    match orientation {
+        2 => image.fliph(),
+        3 => image.rotate180(),
+        4 => image.flipv(),
+        5 => image.rotate90().fliph(),
+        6 => image.rotate90(),
+        7 => image.rotate270().fliph(),
+        8 => image.rotate270(),
+        _ => image,
+    }
+}
+
+/// Crop an image to a region, which is clamped to the image's bounds.
+///
+/// # Errors
+///
+/// - [`OcrError::EmptyCrop`] when the region does not overlap the image
+fn crop(image: &GrayImage, region: OcrCropRegion) -> Result<GrayImage, OcrError> {
+    let width = region.width.min(image.width().saturating_sub(region.x));
+    let height = region.height.min(image.height().saturating_sub(region.y));
+    if width == 0 || height == 0 {
+        return Err(OcrError::EmptyCrop);
+    }
+    Ok(imageops::crop_imm(image, region.x, region.y, width, height).to_image())
+}
+
+/// Downscale an image so that its width and height are at most `size`.
+fn downscale(image: &GrayImage, size: u32) -> Cow<'_, GrayImage> {
+    if image.width() <= size && image.height() <= size {
+        return Cow::Borrowed(image);
+    }
+    let scale = size as f32 / image.width().max(image.height()) as f32;
+    let width = ((image.width() as f32 * scale) as u32).max(1);
+    let height = ((image.height() as f32 * scale) as u32).max(1);
+    Cow::Owned(imageops::resize(image, width, height, FilterType::Triangle))
+}
+
+/// Rotate an image clockwise by a number of quarter turns.
+fn rotate_quarter_turns(image: &GrayImage, quarter_turns: u8) -> GrayImage {
+    match quarter_turns % 4 {
+        1 => imageops::rotate90(image),
+        2 => imageops::rotate180(image),
+        3 => imageops::rotate270(image),
+        _ => image.clone(),
+    }
+}
+
+/// Detect the clockwise quarter turns which make the text of an image upright.
+///
+/// Tesseract recognizes the image in every orientation and the one with the highest mean confidence wins.
+fn detect_quarter_turns(
+    tesseract: Tesseract,
+    image: &GrayImage,
+) -> Result<(Tesseract, u8), OcrError> {
+    let sample = downscale(image, ORIENTATION_SAMPLE_SIZE);
+    let mut tesseract = tesseract;
+    let mut best = (0, i32::MIN);
+    for quarter_turns in 0..4 {
+        let rotated = rotate_quarter_turns(&sample, quarter_turns);
+        let width = rotated.width() as i32;
+        tesseract = tesseract
+            .set_frame(rotated.as_raw(), width, rotated.height() as i32, 1, width)?
+            .recognize()?;
+        let confidence = tesseract.mean_text_conf();
+        if confidence > best.1 {
+            best = (quarter_turns, confidence);
+        }
+    }
+    Ok((tesseract, best.0))
+}
+
+/// Detect the angle in degrees by which the lines of text of an image descend to the right.
+///
+/// The dark pixels are projected onto rows along every candidate angle.
+/// The angle whose rows are the most uneven, i.e. which best separates lines from gaps, wins.
+fn skew_angle(image: &GrayImage) -> f32 {
+    let sample = adaptive_threshold(&downscale(image, SKEW_SAMPLE_SIZE));
+    let dark_pixels: Vec<(f32, f32)> = sample
+        .enumerate_pixels()
+        .filter(|(_, _, pixel)| pixel.0[0] == 0)
+        .map(|(x, y, _)| (x as f32, y as f32))
+        .collect();
+    if dark_pixels.is_empty() {
+        return 0.0;
+    }
+    let score = |angle: f32| {
+        let tan = angle.to_radians().tan();
+        let offset = (sample.width() as f32 * tan.abs()).ceil();
+        let mut rows = vec![0u64; sample.height() as usize + 2 * offset as usize + 1];
+        for (x, y) in &dark_pixels {
+            let row = (y - x * tan + offset).round() as usize;
+            rows[row] += 1;
+        }
+        rows.iter().map(|count| count * count).sum::<u64>()
+    };
+    let best_angle = |angles: &mut dyn Iterator<Item = f32>| {
+        angles
+            .map(|angle| (angle, score(angle)))
+            .max_by_key(|(_, score)| *score)
+            .map_or(0.0, |(angle, _)| angle)
+    };
+    let coarse = best_angle(&mut (-30..=30).map(|step| step as f32 * MAX_SKEW / 30.0));
+    best_angle(&mut (-5..=5).map(|step| coarse + step as f32 * 0.1))
+}
+
+/// Rotate an image about its center by `angle` degrees counterclockwise, keeping its size and filling with white.
+///
+/// This makes lines which descend to the right by `angle` horizontal.
+fn rotate(image: &GrayImage, angle: f32) -> GrayImage {
+    if angle == 0.0 {
+        return image.clone();
+    }
+    let (sin, cos) = angle.to_radians().sin_cos();
+    let center_x = image.width() as f32 / 2.0;
+    let center_y = image.height() as f32 / 2.0;
+    GrayImage::from_fn(image.width(), image.height(), |x, y| {
+        let dx = x as f32 + 0.5 - center_x;
+        let dy = y as f32 + 0.5 - center_y;
+        let source_x = center_x + dx * cos - dy * sin - 0.5;
+        let source_y = center_y + dx * sin + dy * cos - 0.5;
+        Luma([interpolate(image, source_x, source_y)])
+    })
+}
+
+/// Get the bilinearly interpolated brightness at a position, which is white outside of the image.
+fn interpolate(image: &GrayImage, x: f32, y: f32) -> u8 {
+    let x0 = x.floor();
+    let y0 = y.floor();
+    let get = |x: f32, y: f32| {
+        if x < 0.0 || y < 0.0 || x >= image.width() as f32 || y >= image.height() as f32 {
+            return 255.0;
+        }
+        image.get_pixel(x as u32, y as u32).0[0] as f32
+    };
+    let (fraction_x, fraction_y) = (x - x0, y - y0);
+    let top = get(x0, y0) * (1.0 - fraction_x) + get(x0 + 1.0, y0) * fraction_x;
+    let bottom = get(x0, y0 + 1.0) * (1.0 - fraction_x) + get(x0 + 1.0, y0 + 1.0) * fraction_x;
+    (top * (1.0 - fraction_y) + bottom * fraction_y).round() as u8
+}
+
+/// Convert an image to black and white, comparing each pixel to the mean of its surrounding.
+///
+/// See Bradley and Roth, "Adaptive Thresholding Using the Integral Image", 2007.
+fn adaptive_threshold(image: &GrayImage) -> GrayImage {
+    let (width, height) = (image.width() as usize, image.height() as usize);
+    let radius = (width.max(height) / 32).max(7);
+    let mut integral = vec![0u64; (width + 1) * (height + 1)];
+    for y in 0..height {
+        let mut row_sum = 0;
+        for x in 0..width {
+            row_sum += image.get_pixel(x as u32, y as u32).0[0] as u64;
+            integral[(y + 1) * (width + 1) + x + 1] = integral[y * (width + 1) + x + 1] + row_sum;
+        }
+    }
+    GrayImage::from_fn(image.width(), image.height(), |x, y| {
+        let (x, y) = (x as usize, y as usize);
+        let (x0, y0) = (x.saturating_sub(radius), y.saturating_sub(radius));
+        let (x1, y1) = ((x + radius + 1).min(width), (y + radius + 1).min(height));
+        let sum = integral[y1 * (width + 1) + x1] + integral[y0 * (width + 1) + x0]
+            - integral[y0 * (width + 1) + x1]
+            - integral[y1 * (width + 1) + x0];
+        let area = ((x1 - x0) * (y1 - y0)) as f64;
+        let value = image.get_pixel(x as u32, y as u32).0[0] as f64;
+        if value * area <= sum as f64 * (1.0 - THRESHOLD_SENSITIVITY) {
+            Luma([0])
+        } else {
+            Luma([255])
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    /// Draw horizontal lines of text-like dashes onto a white image.
+    fn lined_image() -> GrayImage {
+        GrayImage::from_fn(400, 300, |x, y| {
+            if y % 30 < 4 && y > 20 && y < 280 && x > 20 && x < 380 && x % 12 < 9 {
+                Luma([20])
+            } else {
+                Luma([230])
+            }
+        })
+    }
+
+    #[test]
+    fn test_orient() {
+        let image = DynamicImage::ImageLuma8(GrayImage::from_raw(2, 1, vec![1, 2]).unwrap());
+        let pixels = |orientation| orient(image.clone(), orientation).to_luma8().into_raw();
+        assert_eq!(pixels(1), vec![1, 2]);
+        assert_eq!(pixels(2), vec![2, 1]);
+        assert_eq!(pixels(3), vec![2, 1]);
+        assert_eq!(pixels(5), vec![1, 2]);
+        assert_eq!(pixels(6), vec![1, 2]);
+        assert_eq!(pixels(8), vec![2, 1]);
+        assert_eq!(orient(image.clone(), 6).height(), 2);
+    }
+
+    #[test]
+    fn test_crop() {
+        let image = GrayImage::from_fn(4, 3, |x, y| Luma([(y * 4 + x) as u8]));
+        let region = |x, y, width, height| OcrCropRegion {
+            x,
+            y,
+            width,
+            height,
+        };
+        assert_eq!(
+            crop(&image, region(1, 1, 2, 1)).unwrap().into_raw(),
+            vec![5, 6]
+        );
+        assert_eq!(
+            crop(&image, region(2, 2, 10, 10)).unwrap().into_raw(),
+            vec![10, 11]
+        );
+        assert!(matches!(
+            crop(&image, region(4, 0, 1, 1)),
+            Err(OcrError::EmptyCrop)
+        ));
+    }
+
+    #[test]
+    fn test_skew_angle() {
+        let image = lined_image();
+        assert!(skew_angle(&image).abs() < 0.2);
+        let skewed = rotate(&image, -4.0);
+        let angle = skew_angle(&skewed);
+        assert!((angle - 4.0).abs() < 0.2, "detected {angle}");
+        let deskewed = rotate(&skewed, angle);
+        assert!(skew_angle(&deskewed).abs() < 0.2);
+    }
+
+    #[test]
+    fn test_adaptive_threshold() {
+        let image = GrayImage::from_fn(100, 100, |x, y| {
+            // a gradient from dark to bright with a darker square on the darker side
+            if (10..20).contains(&x) && (40..60).contains(&y) {
+                Luma([10])
+            } else {
+                Luma([60 + x as u8])
+            }
+        });
+        let thresholded = adaptive_threshold(&image);
+        assert_eq!(thresholded.get_pixel(15, 50).0[0], 0);
+        assert_eq!(thresholded.get_pixel(15, 10).0[0], 255);
+        assert_eq!(thresholded.get_pixel(90, 50).0[0], 255);
+    }
+}
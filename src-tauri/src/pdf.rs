@@ -0,0 +1,275 @@
+//! This module handles getting the text out of PDF files.
+//!
+//! Pages with a text layer are extracted directly.
+//! Pages without one, e.g. scanned recipes, are rendered with [Pdfium](https://pdfium.googlesource.com/pdfium/) and run through [`crate::ocr`].
+//! Pdfium is loaded at runtime from the `pdfium` directory in the application's data or from the system libraries.
+//! Without it, only the largest image embedded in such a page is recognized.
+
+use std::path::Path;
+
+use lopdf::{Dictionary, Document, Object, ObjectId};
+use pdfium_render::prelude::{PdfDocument, PdfRenderConfig, Pdfium};
+
+use crate::{
+    ocr::{self, OcrImage, OcrSettings},
+    path::app_data_dir,
+    pdf::error::PdfError,
+};
+
+pub mod error;
+
+/// The MIME type of PDF files.
+pub const MIME: &str = "application/pdf";
+
+/// The width pages are rendered with, which is A4 at 300 DPI.
+const RENDER_WIDTH: i32 = 2480;
+
+/// The maximum height pages are rendered with, which is A4 at 300 DPI.
+const RENDER_MAXIMUM_HEIGHT: i32 = 3508;
+
+/// Get the text of all pages of a PDF file, separated by empty lines.
+///
+/// Pages without text layer are recognized in the specified OCR language.
+///
+/// This is blocking and should not be called on an async runtime's worker thread.
+///
+/// # Errors
+///
+/// - [`PdfError::Lopdf`] when the file cannot be loaded
+/// - [`PdfError::Ocr`] when a page without text layer cannot be recognized
+pub fn text(path: impl AsRef<Path>, language: &str) -> Result<String, PdfError> {
+    let path = path.as_ref();
+    let document = Document::load(path)?;
+    let pdfium = bind_pdfium();
+    let pdfium_document =
+        pdfium
+            .as_ref()
+            .and_then(|pdfium| match pdfium.load_pdf_from_file(path, None) {
+                Ok(pdfium_document) => Some(pdfium_document),
+                Err(err) => {
+                    log::warn!("Could not load PDF file with Pdfium: {err}");
+                    None
+                }
+            });
+    let mut texts = vec![];
+    for (page_number, page_id) in document.get_pages() {
+        let text = document.extract_text(&[page_number]).unwrap_or_else(|err| {
+            log::warn!("Could not extract text of PDF page {page_number}: {err}");
+            String::new()
+        });
+        if !text.trim().is_empty() {
+            texts.push(text);
+            continue;
+        }
+        let image = match &pdfium_document {
+            Some(pdfium_document) => render_page(pdfium_document, page_number),
+            None => largest_page_image(&document, page_id),
+        };
+        let Some(image) = image else {
+            log::info!("PDF page {page_number} could not be turned into an image.");
+            continue;
+        };
+        texts.push(ocr::blocking_text(
+            image,
+            String::from(language),
+            OcrSettings::default(),
+        )?);
+    }
+    Ok(texts.join("\n\n"))
+}
+
+/// Bind to the Pdfium library in the application's data, or else in the system libraries.
+fn bind_pdfium() -> Option<Pdfium> {
+    let library_path = Pdfium::pdfium_platform_library_name_at_path(&app_data_dir().join("pdfium"));
+    match Pdfium::bind_to_library(library_path).or_else(|_| Pdfium::bind_to_system_library()) {
+        Ok(bindings) => Some(Pdfium::new(bindings)),
+        Err(err) => {
+            log::warn!("Could not load Pdfium, falling back to embedded images: {err}");
+            None
+        }
+    }
+}
+
+/// Render a page, counted from 1 like lopdf does, to a grayscale image.
+fn render_page(document: &PdfDocument, page_number: u32) -> Option<OcrImage> {
+    let render_config = PdfRenderConfig::new()
+        .set_target_width(RENDER_WIDTH)
+        .set_maximum_height(RENDER_MAXIMUM_HEIGHT);
+    let page_index = u16::try_from(page_number.checked_sub(1)?).ok()?;
+    let image = document.pages().get(page_index).and_then(|page| {
+        let bitmap = page.render_with_config(&render_config)?;
+        Ok(bitmap.as_image().into_luma8())
+    });
+    match image {
+        Ok(image) => Some(image.into()),
+        Err(err) => {
+            log::warn!("Could not render PDF page {page_number}: {err}");
+            None
+        }
+    }
+}
+
+/// Get the largest image which is drawn on a page and which is supported by [`decode_image`].
+fn largest_page_image(document: &Document, page_id: ObjectId) -> Option<OcrImage> {
+    let (resource_dict, resource_ids) = document.get_page_resources(page_id);
+    let resource_dicts = resource_dict.into_iter().chain(
+        resource_ids
+            .into_iter()
+            .filter_map(|resource_id| document.get_dictionary(resource_id).ok()),
+    );
+    let mut largest: Option<(i64, OcrImage)> = None;
+    for resource_dict in resource_dicts {
+        let Ok(x_object_dict) = resource_dict
+            .get_deref(b"XObject", document)
+            .and_then(Object::as_dict)
+        else {
+            continue;
+        };
+        for (_, x_object) in x_object_dict.iter() {
+            let Ok(stream) = document
+                .dereference(x_object)
+                .and_then(|(_, object)| object.as_stream())
+            else {
+                continue;
+            };
+            if stream.dict.get(b"Subtype").and_then(Object::as_name).ok() != Some(b"Image") {
+                continue;
+            }
+            let Some((width, height)) = image_size(&stream.dict) else {
+                continue;
+            };
+            let area = i64::from(width) * i64::from(height);
+            if largest
+                .as_ref()
+                .is_some_and(|(largest_area, _)| *largest_area >= area)
+            {
+                continue;
+            }
+            if let Some(image) = decode_image(document, stream) {
+                largest = Some((area, image));
+            }
+        }
+    }
+    largest.map(|(_, image)| image)
+}
+
+/// Decode an image XObject stream.
+///
+/// JPEG and JPEG 2000 images are passed on as they are.
+/// Other images are only supported with a gray or RGB color space and 1 or 8 bits per component.
+/// Images whose content is shorter than their size requires are skipped.
+fn decode_image(document: &Document, stream: &lopdf::Stream) -> Option<OcrImage> {
+    let filters = stream.filters().unwrap_or_default();
+    if let Some(last_filter) = filters.last() {
+        if last_filter == "DCTDecode" || last_filter == "JPXDecode" {
+            if filters.len() > 1 {
+                return None;
+            }
+            return Some(OcrImage::Encoded(stream.content.clone()));
+        }
+    }
+    let (width, height) = image_size(&stream.dict)?;
+    let bits_per_component = get_i64(&stream.dict, b"BitsPerComponent");
+    let color_space = stream
+        .dict
+        .get_deref(b"ColorSpace", document)
+        .and_then(Object::as_name)
+        .ok()?;
+    let bytes_per_pixel = match color_space {
+        b"DeviceGray" => 1,
+        b"DeviceRGB" => 3,
+        _ => return None,
+    };
+    let pixels_per_row = usize::try_from(width).ok()?;
+    let bytes_per_row = match (bits_per_component, bytes_per_pixel) {
+        (8, _) => pixels_per_row.checked_mul(bytes_per_pixel)?,
+        (1, 1) => (pixels_per_row + 7) / 8,
+        _ => return None,
+    };
+    let content_length = bytes_per_row.checked_mul(usize::try_from(height).ok()?)?;
+    let content = if filters.is_empty() {
+        stream.content.clone()
+    } else {
+        // lopdf refuses to decompress images, so pretend this is not one.
+        let mut stream = stream.clone();
+        stream.dict.remove(b"Subtype");
+        stream.decompressed_content().ok()?
+    };
+    if content.len() < content_length {
+        return None;
+    }
+    let data = if bits_per_component == 1 {
+        expand_bits(&content[..content_length], pixels_per_row)
+    } else {
+        content
+    };
+    Some(OcrImage::Raw {
+        data,
+        width,
+        height,
+        bytes_per_pixel: i32::try_from(bytes_per_pixel).ok()?,
+    })
+}
+
+/// Get the width and height of an image XObject.
+///
+/// Returns [`None`] unless both are positive and fit an [`OcrImage::Raw`],
+/// as they come from the PDF file and cannot be trusted.
+fn image_size(dict: &Dictionary) -> Option<(i32, i32)> {
+    let width = i32::try_from(get_i64(dict, b"Width")).ok()?;
+    let height = i32::try_from(get_i64(dict, b"Height")).ok()?;
+    (width > 0 && height > 0).then_some((width, height))
+}
+
+/// Expand 1 bit per pixel rows, which are padded to full bytes, to 8 bits per pixel.
+fn expand_bits(content: &[u8], width: usize) -> Vec<u8> {
+    let bytes_per_row = (width + 7) / 8;
+    content
+        .chunks(bytes_per_row)
+        .flat_map(|row| {
+            (0..width).map(move |x| {
+                if row
+                    .get(x / 8)
+                    .is_some_and(|byte| byte & (0x80 >> (x % 8)) != 0)
+                {
+                    0xFF
+                } else {
+                    0x00
+                }
+            })
+        })
+        .collect()
+}
+
+fn get_i64(dict: &Dictionary, key: &[u8]) -> i64 {
+    dict.get(key).and_then(Object::as_i64).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_image_size() {
+        for (width, height, expected) in [
+            (3, 2, Some((3, 2))),
+            (0, 2, None),
+            (-3, 2, None),
+            (3, i64::from(i32::MAX) + 1, None),
+            (i64::MAX, i64::MAX, None),
+        ] {
+            let dict = Dictionary::from_iter([("Width", width.into()), ("Height", height.into())]);
+            assert_eq!(image_size(&dict), expected, "{width}x{height}");
+        }
+    }
+
+    #[test]
+    fn test_expand_bits() {
+        assert_eq!(
+            expand_bits(&[0b1010_0000, 0b0110_0000], 3),
+            vec![0xFF, 0x00, 0xFF, 0x00, 0xFF, 0xFF]
+        );
+    }
+}
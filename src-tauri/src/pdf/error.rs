@@ -0,0 +1,13 @@
+//! This module contains the [`std::error::Error`] for the [`crate::pdf`] module.
+
+use thiserror::Error;
+
+use crate::ocr::error::OcrError;
+
+#[derive(Debug, Error)]
+pub enum PdfError {
+    #[error(transparent)]
+    Lopdf(#[from] lopdf::Error),
+    #[error(transparent)]
+    Ocr(#[from] OcrError),
+}
@@ -0,0 +1,841 @@
+//! This module implements the version history of recipes.
+//!
+//! A [revision](recipe_revision::Model) stores a [`RecipeSnapshot`] of the full recipe aggregate.
+//! Revisions can be compared field by field, and a recipe can be reverted to a revision.
+//! Reverting saves the current state as a new revision first and is one [journal](crate::journal) action, so it can be undone.
+//!
+//! Links to files which were deleted in the meantime can't be restored, deleted ingredients are restored though.
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use anyhow::{bail, Result};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DatabaseTransaction, EntityTrait,
+    FromQueryResult, JsonValue, QueryFilter, QueryOrder, QuerySelect, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    database,
+    entity::{
+        change_log, file, ingredient, recipe, recipe_file, recipe_ingredient_draft,
+        recipe_revision, recipe_step, recipe_step_file, recipe_step_ingredient,
+        recipe_step_ingredient_draft,
+    },
+    entity_crud::{
+        ingredient::IngredientCrud, recipe::RecipeCrud, recipe_file::RecipeFileCrud,
+        recipe_ingredient_draft::RecipeIngredientDraftCrud, recipe_step::RecipeStepCrud,
+        recipe_step_file::RecipeStepFileCrud, recipe_step_ingredient::RecipeStepIngredientCrud,
+        recipe_step_ingredient_draft::RecipeStepIngredientDraftCrud, EntityCrudTrait,
+    },
+    journal,
+    timestamp::unix_timestamp,
+};
+
+/// This struct represents the full aggregate of a recipe at one point in time.
+///
+/// The ingredients referenced by the recipe step ingredients are included, so they can be restored.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeSnapshot {
+    pub recipe: recipe::Model,
+    pub recipe_steps: Vec<recipe_step::Model>,
+    pub recipe_step_ingredients: Vec<recipe_step_ingredient::Model>,
+    pub recipe_step_ingredient_drafts: Vec<recipe_step_ingredient_draft::Model>,
+    pub recipe_step_files: Vec<recipe_step_file::Model>,
+    pub recipe_files: Vec<recipe_file::Model>,
+    pub recipe_ingredient_drafts: Vec<recipe_ingredient_draft::Model>,
+    pub ingredients: Vec<ingredient::Model>,
+}
+
+impl RecipeSnapshot {
+    /// Get the serialized fields of all entities, keyed by their entity name and id.
+    fn rows(&self) -> Result<Vec<(&'static str, BTreeMap<i64, Fields>)>> {
+        Ok(vec![
+            ("recipe", rows_of(std::slice::from_ref(&self.recipe))?),
+            ("recipe_step", rows_of(&self.recipe_steps)?),
+            (
+                "recipe_step_ingredient",
+                rows_of(&self.recipe_step_ingredients)?,
+            ),
+            (
+                "recipe_step_ingredient_draft",
+                rows_of(&self.recipe_step_ingredient_drafts)?,
+            ),
+            ("recipe_step_file", rows_of(&self.recipe_step_files)?),
+            ("recipe_file", rows_of(&self.recipe_files)?),
+            (
+                "recipe_ingredient_draft",
+                rows_of(&self.recipe_ingredient_drafts)?,
+            ),
+            ("ingredient", rows_of(&self.ingredients)?),
+        ])
+    }
+}
+
+type Fields = serde_json::Map<String, JsonValue>;
+
+/// This struct represents a revision without its snapshot, for listing.
+#[derive(Debug, PartialEq, Eq, FromQueryResult, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeRevisionSummary {
+    pub id: i64,
+    pub created_at: i64,
+}
+
+/// This struct represents a difference of one field of one entity between two revisions.
+///
+/// The old or new value is [`None`] when the entity does not exist in the respective revision.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RevisionDifference {
+    pub entity: &'static str,
+    pub id: i64,
+    pub field: String,
+    pub old: Option<JsonValue>,
+    pub new: Option<JsonValue>,
+}
+
+/// Save the current state of a recipe as a new revision.
+///
+/// Returns [`None`] when the recipe does not exist.
+///
+/// # Errors
+///
+/// - when there is any problem with the database
+/// - when the snapshot can't be serialized
+pub async fn save(recipe_id: i64) -> Result<Option<i64>> {
+    let db = database::connect_writing().await;
+    let txn = db.begin().await?;
+    let revision_option = save_in(recipe_id, &txn).await?;
+    txn.commit().await?;
+    Ok(revision_option.map(|revision| revision.id))
+}
+
+/// List the revisions of a recipe, the newest first.
+///
+/// # Errors
+///
+/// - when there is any problem with the database
+pub async fn list(recipe_id: i64) -> Result<Vec<RecipeRevisionSummary>> {
+    let db = database::connect().await;
+    let summaries = recipe_revision::Entity::find()
+        .select_only()
+        .column(recipe_revision::Column::Id)
+        .column(recipe_revision::Column::CreatedAt)
+        .filter(recipe_revision::Column::RecipeId.eq(recipe_id))
+        .order_by_desc(recipe_revision::Column::Id)
+        .into_model::<RecipeRevisionSummary>()
+        .all(db)
+        .await?;
+    Ok(summaries)
+}
+
+/// Compare two revisions field by field.
+///
+/// Returns [`None`] when a revision does not exist.
+///
+/// # Errors
+///
+/// - when there is any problem with the database
+/// - when a snapshot can't be deserialized
+pub async fn diff(old_id: i64, new_id: i64) -> Result<Option<Vec<RevisionDifference>>> {
+    let db = database::connect().await;
+    let (Some(old), Some(new)) = (
+        read_snapshot(old_id, db).await?,
+        read_snapshot(new_id, db).await?,
+    ) else {
+        return Ok(None);
+    };
+    Ok(Some(diff_snapshots(&old, &new)?))
+}
+
+/// Revert a recipe to a revision.
+///
+/// Returns whether the revision exists.
+///
+/// # Errors
+///
+/// - when there is any problem with the database, nothing is reverted then
+/// - when a snapshot can't be (de)serialized
+/// - when the tauri window can't be messaged about the changes
+pub async fn revert(id: i64) -> Result<bool> {
+    let db = database::connect_writing().await;
+    let txn = db.begin().await?;
+    let Some(target) = read_snapshot(id, &txn).await? else {
+        return Ok(false);
+    };
+    save_in(target.recipe.id, &txn).await?;
+    let journal_action_id = journal::record_action(&txn).await?;
+    let changes = revert_logged(target, journal_action_id, &txn).await?;
+    txn.commit().await?;
+    journal::emit(&changes)?;
+    Ok(true)
+}
+
+/// Take a snapshot of the current state of a recipe.
+///
+/// Returns [`None`] when the recipe does not exist.
+///
+/// # Errors
+///
+/// - when there is any problem with the database
+pub async fn snapshot<C>(recipe_id: i64, db: &C) -> Result<Option<RecipeSnapshot>>
+where
+    C: ConnectionTrait,
+{
+    let Some(recipe) = recipe::Entity::find_by_id(recipe_id).one(db).await? else {
+        return Ok(None);
+    };
+    let recipe_steps = recipe_step::Entity::find()
+        .filter(recipe_step::Column::RecipeId.eq(recipe_id))
+        .order_by_asc(recipe_step::Column::Id)
+        .all(db)
+        .await?;
+    let recipe_step_ids: Vec<i64> = recipe_steps.iter().map(|model| model.id).collect();
+    let recipe_step_ingredients = recipe_step_ingredient::Entity::find()
+        .filter(recipe_step_ingredient::Column::RecipeStepId.is_in(recipe_step_ids.clone()))
+        .order_by_asc(recipe_step_ingredient::Column::Id)
+        .all(db)
+        .await?;
+    let recipe_step_ingredient_drafts = recipe_step_ingredient_draft::Entity::find()
+        .filter(recipe_step_ingredient_draft::Column::RecipeStepId.is_in(recipe_step_ids.clone()))
+        .order_by_asc(recipe_step_ingredient_draft::Column::Id)
+        .all(db)
+        .await?;
+    let recipe_step_files = recipe_step_file::Entity::find()
+        .filter(recipe_step_file::Column::RecipeStepId.is_in(recipe_step_ids))
+        .order_by_asc(recipe_step_file::Column::Id)
+        .all(db)
+        .await?;
+    let recipe_files = recipe_file::Entity::find()
+        .filter(recipe_file::Column::RecipeId.eq(recipe_id))
+        .order_by_asc(recipe_file::Column::Id)
+        .all(db)
+        .await?;
+    let recipe_ingredient_drafts = recipe_ingredient_draft::Entity::find()
+        .filter(recipe_ingredient_draft::Column::RecipeId.eq(recipe_id))
+        .order_by_asc(recipe_ingredient_draft::Column::Id)
+        .all(db)
+        .await?;
+    let ingredient_ids: BTreeSet<i64> = recipe_step_ingredients
+        .iter()
+        .map(|model| model.ingredient_id)
+        .collect();
+    let ingredients = ingredient::Entity::find()
+        .filter(ingredient::Column::Id.is_in(ingredient_ids))
+        .order_by_asc(ingredient::Column::Id)
+        .all(db)
+        .await?;
+    Ok(Some(RecipeSnapshot {
+        recipe,
+        recipe_steps,
+        recipe_step_ingredients,
+        recipe_step_ingredient_drafts,
+        recipe_step_files,
+        recipe_files,
+        recipe_ingredient_drafts,
+        ingredients,
+    }))
+}
+
+/// Compare two snapshots field by field.
+///
+/// The differences are ordered by entity, id and field name.
+/// The creation and update timestamps are not compared, as they are no content of the recipe.
+///
+/// # Errors
+///
+/// - when a snapshot can't be serialized
+pub fn diff_snapshots(
+    old: &RecipeSnapshot,
+    new: &RecipeSnapshot,
+) -> Result<Vec<RevisionDifference>> {
+    let mut differences = Vec::new();
+    for ((entity, old_rows), (_, new_rows)) in old.rows()?.into_iter().zip(new.rows()?) {
+        let ids: BTreeSet<i64> = old_rows.keys().chain(new_rows.keys()).copied().collect();
+        for id in ids {
+            let old_fields = old_rows.get(&id);
+            let new_fields = new_rows.get(&id);
+            let field_names: BTreeSet<&String> = old_fields
+                .into_iter()
+                .chain(new_fields)
+                .flat_map(Fields::keys)
+                .filter(|field_name| {
+                    !["id", "createdAt", "updatedAt"].contains(&field_name.as_str())
+                })
+                .collect();
+            for field_name in field_names {
+                let old_value = old_fields.and_then(|fields| fields.get(field_name));
+                let new_value = new_fields.and_then(|fields| fields.get(field_name));
+                if old_value != new_value {
+                    differences.push(RevisionDifference {
+                        entity,
+                        id,
+                        field: field_name.clone(),
+                        old: old_value.cloned(),
+                        new: new_value.cloned(),
+                    });
+                }
+            }
+        }
+    }
+    Ok(differences)
+}
+
+/// Revert a recipe to a snapshot inside a transaction and log the changes.
+///
+/// Only the rows which differ are changed, so unchanged children keep their ids and are not logged.
+/// The recipe stays in or out of the trash and keeps its fork origin, ingredients which still exist are not changed.
+///
+/// # Errors
+///
+/// - when there is any problem with the database, e.g. when the recipe does not exist
+/// - when an entity can't be (de)serialized
+pub async fn revert_logged(
+    target: RecipeSnapshot,
+    journal_action_id: i64,
+    txn: &DatabaseTransaction,
+) -> Result<Vec<change_log::Model>> {
+    let Some(current) = snapshot(target.recipe.id, txn).await? else {
+        bail!("The recipe of the revision does not exist");
+    };
+    let mut target = target;
+    target.recipe.deleted_at = current.recipe.deleted_at;
+    target.recipe.forked_from_id = current.recipe.forked_from_id;
+    let file_ids: HashSet<i64> = file::Entity::find()
+        .filter(
+            file::Column::Id.is_in(
+                target
+                    .recipe_files
+                    .iter()
+                    .map(|model| model.file_id)
+                    .chain(target.recipe_step_files.iter().map(|model| model.file_id))
+                    .collect::<BTreeSet<i64>>(),
+            ),
+        )
+        .all(txn)
+        .await?
+        .into_iter()
+        .map(|model| model.id)
+        .collect();
+    target
+        .recipe_files
+        .retain(|model| file_ids.contains(&model.file_id));
+    target
+        .recipe_step_files
+        .retain(|model| file_ids.contains(&model.file_id));
+
+    let journal_action_id = Some(journal_action_id);
+    let mut changes = Vec::new();
+    // remove children before their parents
+    changes.append(
+        &mut remove::<RecipeStepIngredientCrud>(
+            current.recipe_step_ingredients.clone(),
+            &target.recipe_step_ingredients,
+            journal_action_id,
+            txn,
+        )
+        .await?,
+    );
+    changes.append(
+        &mut remove::<RecipeStepIngredientDraftCrud>(
+            current.recipe_step_ingredient_drafts.clone(),
+            &target.recipe_step_ingredient_drafts,
+            journal_action_id,
+            txn,
+        )
+        .await?,
+    );
+    changes.append(
+        &mut remove::<RecipeStepFileCrud>(
+            current.recipe_step_files.clone(),
+            &target.recipe_step_files,
+            journal_action_id,
+            txn,
+        )
+        .await?,
+    );
+    changes.append(
+        &mut remove::<RecipeFileCrud>(
+            current.recipe_files.clone(),
+            &target.recipe_files,
+            journal_action_id,
+            txn,
+        )
+        .await?,
+    );
+    changes.append(
+        &mut remove::<RecipeIngredientDraftCrud>(
+            current.recipe_ingredient_drafts.clone(),
+            &target.recipe_ingredient_drafts,
+            journal_action_id,
+            txn,
+        )
+        .await?,
+    );
+    changes.append(
+        &mut remove::<RecipeStepCrud>(
+            current.recipe_steps.clone(),
+            &target.recipe_steps,
+            journal_action_id,
+            txn,
+        )
+        .await?,
+    );
+    // free the orders of reordered entities, which must be unique per parent
+    changes.append(
+        &mut unorder::<RecipeStepCrud>(
+            &current.recipe_steps,
+            &target.recipe_steps,
+            journal_action_id,
+            txn,
+        )
+        .await?,
+    );
+    changes.append(
+        &mut unorder::<RecipeStepIngredientCrud>(
+            &current.recipe_step_ingredients,
+            &target.recipe_step_ingredients,
+            journal_action_id,
+            txn,
+        )
+        .await?,
+    );
+    changes.append(
+        &mut unorder::<RecipeStepIngredientDraftCrud>(
+            &current.recipe_step_ingredient_drafts,
+            &target.recipe_step_ingredient_drafts,
+            journal_action_id,
+            txn,
+        )
+        .await?,
+    );
+    changes.append(
+        &mut unorder::<RecipeStepFileCrud>(
+            &current.recipe_step_files,
+            &target.recipe_step_files,
+            journal_action_id,
+            txn,
+        )
+        .await?,
+    );
+    changes.append(
+        &mut unorder::<RecipeFileCrud>(
+            &current.recipe_files,
+            &target.recipe_files,
+            journal_action_id,
+            txn,
+        )
+        .await?,
+    );
+    changes.append(
+        &mut unorder::<RecipeIngredientDraftCrud>(
+            &current.recipe_ingredient_drafts,
+            &target.recipe_ingredient_drafts,
+            journal_action_id,
+            txn,
+        )
+        .await?,
+    );
+    // restore parents before their children
+    changes.append(
+        &mut restore::<IngredientCrud>(target.ingredients, false, journal_action_id, txn).await?,
+    );
+    changes.append(
+        &mut restore::<RecipeCrud>(vec![target.recipe], true, journal_action_id, txn).await?,
+    );
+    changes.append(
+        &mut restore::<RecipeStepCrud>(target.recipe_steps, true, journal_action_id, txn).await?,
+    );
+    changes.append(
+        &mut restore::<RecipeStepIngredientCrud>(
+            target.recipe_step_ingredients,
+            true,
+            journal_action_id,
+            txn,
+        )
+        .await?,
+    );
+    changes.append(
+        &mut restore::<RecipeStepIngredientDraftCrud>(
+            target.recipe_step_ingredient_drafts,
+            true,
+            journal_action_id,
+            txn,
+        )
+        .await?,
+    );
+    changes.append(
+        &mut restore::<RecipeStepFileCrud>(target.recipe_step_files, true, journal_action_id, txn)
+            .await?,
+    );
+    changes.append(
+        &mut restore::<RecipeFileCrud>(target.recipe_files, true, journal_action_id, txn).await?,
+    );
+    changes.append(
+        &mut restore::<RecipeIngredientDraftCrud>(
+            target.recipe_ingredient_drafts,
+            true,
+            journal_action_id,
+            txn,
+        )
+        .await?,
+    );
+    Ok(changes)
+}
+
+/// Save the current state of a recipe as a new revision inside a transaction.
+async fn save_in(
+    recipe_id: i64,
+    txn: &DatabaseTransaction,
+) -> Result<Option<recipe_revision::Model>> {
+    let Some(snapshot) = snapshot(recipe_id, txn).await? else {
+        return Ok(None);
+    };
+    let revision = recipe_revision::ActiveModel {
+        id: ActiveValue::NotSet,
+        recipe_id: ActiveValue::Set(recipe_id),
+        created_at: ActiveValue::Set(unix_timestamp()?),
+        snapshot: ActiveValue::Set(serde_json::to_value(snapshot)?),
+    }
+    .insert(txn)
+    .await?;
+    Ok(Some(revision))
+}
+
+/// Read the snapshot of a revision.
+async fn read_snapshot<C>(id: i64, db: &C) -> Result<Option<RecipeSnapshot>>
+where
+    C: ConnectionTrait,
+{
+    let Some(revision) = recipe_revision::Entity::find_by_id(id).one(db).await? else {
+        return Ok(None);
+    };
+    Ok(Some(serde_json::from_value(revision.snapshot)?))
+}
+
+/// Get the serialized fields of models, keyed by their id.
+fn rows_of<M>(models: &[M]) -> Result<BTreeMap<i64, Fields>>
+where
+    M: Serialize,
+{
+    let mut rows = BTreeMap::new();
+    for model in models {
+        let JsonValue::Object(fields) = serde_json::to_value(model)? else {
+            bail!("A model must be serialized to an object");
+        };
+        let Some(id) = fields.get("id").and_then(JsonValue::as_i64) else {
+            bail!("A model must have an integer id");
+        };
+        rows.insert(id, fields);
+    }
+    Ok(rows)
+}
+
+/// Delete the current entities which are not in the target, and log the changes.
+async fn remove<C>(
+    current: Vec<C::Model>,
+    target: &[C::Model],
+    journal_action_id: Option<i64>,
+    txn: &DatabaseTransaction,
+) -> Result<Vec<change_log::Model>>
+where
+    C: EntityCrudTrait + Send,
+{
+    let target_ids: Vec<C::PrimaryKeyValue> = target.iter().map(C::primary_key_value).collect();
+    let mut changes = Vec::new();
+    for model in current {
+        if !target_ids.contains(&C::primary_key_value(&model)) {
+            changes.append(&mut C::delete_model_logged(model, journal_action_id, txn).await?);
+        }
+    }
+    Ok(changes)
+}
+
+/// Move the current entities whose order differs from the target to a temporary negative order, and log the changes.
+///
+/// This way they can't collide with the target orders, which are unique per parent.
+async fn unorder<C>(
+    current: &[C::Model],
+    target: &[C::Model],
+    journal_action_id: Option<i64>,
+    txn: &DatabaseTransaction,
+) -> Result<Vec<change_log::Model>>
+where
+    C: EntityCrudTrait + Send,
+{
+    let mut changes = Vec::new();
+    for model in current {
+        let id = C::primary_key_value(model);
+        let Some(target_model) = target
+            .iter()
+            .find(|target_model| C::primary_key_value(target_model) == id)
+        else {
+            continue;
+        };
+        let from = serde_json::to_value(model)?;
+        let target_order = serde_json::to_value(target_model)?.get("order").cloned();
+        let mut to = from.clone();
+        let (Some(order), Some(id)) = (
+            to.get_mut("order"),
+            from.get("id").and_then(JsonValue::as_i64),
+        ) else {
+            continue;
+        };
+        if Some(&*order) == target_order.as_ref() {
+            continue;
+        }
+        *order = JsonValue::from(-id);
+        changes.push(C::apply(Some(from), Some(to), journal_action_id, txn).await?);
+    }
+    Ok(changes)
+}
+
+/// Insert the target entities which don't exist and update those which differ, and log the changes.
+async fn restore<C>(
+    target: Vec<C::Model>,
+    update_existing: bool,
+    journal_action_id: Option<i64>,
+    txn: &DatabaseTransaction,
+) -> Result<Vec<change_log::Model>>
+where
+    C: EntityCrudTrait + Send,
+{
+    let mut changes = Vec::new();
+    for model in target {
+        let from = C::Entity::find_by_id(C::primary_key_value(&model))
+            .one(txn)
+            .await?
+            .map(serde_json::to_value)
+            .transpose()?;
+        let to = serde_json::to_value(model)?;
+        if from.is_some() && (!update_existing || from.as_ref() == Some(&to)) {
+            continue;
+        }
+        changes.push(C::apply(from, Some(to), journal_action_id, txn).await?);
+    }
+    Ok(changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::migrator::tests::get_memory_database_migrated;
+
+    #[tokio::test]
+    async fn test_revert_logged() {
+        let db = get_memory_database_migrated().await;
+        recipe::ActiveModel {
+            name: ActiveValue::Set(String::from("soup")),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        for (order, description) in [(1, "chop"), (2, "boil")] {
+            recipe_step::ActiveModel {
+                order: ActiveValue::Set(order),
+                description: ActiveValue::Set(description.to_string()),
+                recipe_id: ActiveValue::Set(1),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        ingredient::ActiveModel {
+            name: ActiveValue::Set(String::from("leek")),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        recipe_step_ingredient::ActiveModel {
+            order: ActiveValue::Set(1),
+            quantity: ActiveValue::Set(Some(2.0)),
+            unit: ActiveValue::Set(None),
+            quality: ActiveValue::Set(None),
+            recipe_step_id: ActiveValue::Set(1),
+            ingredient_id: ActiveValue::Set(1),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        let target = snapshot(1, &db).await.unwrap().unwrap();
+
+        // swap the steps, add a step and delete the ingredient
+        let txn = db.begin().await.unwrap();
+        let journal_action_id = journal::record_action(&txn).await.unwrap();
+        RecipeStepIngredientCrud::delete_logged(1, Some(journal_action_id), &txn)
+            .await
+            .unwrap();
+        IngredientCrud::delete_logged(1, Some(journal_action_id), &txn)
+            .await
+            .unwrap();
+        for (id, order) in [(1, 3), (2, 1), (1, 2)] {
+            recipe_step::ActiveModel {
+                id: ActiveValue::Unchanged(id),
+                order: ActiveValue::Set(order),
+                ..Default::default()
+            }
+            .update(&txn)
+            .await
+            .unwrap();
+        }
+        recipe_step::ActiveModel {
+            order: ActiveValue::Set(3),
+            description: ActiveValue::Set(String::from("serve")),
+            recipe_id: ActiveValue::Set(1),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await
+        .unwrap();
+        txn.commit().await.unwrap();
+        let changed = snapshot(1, &db).await.unwrap().unwrap();
+        assert_eq!(
+            diff_snapshots(&target, &changed).unwrap(),
+            vec![
+                RevisionDifference {
+                    entity: "recipe_step",
+                    id: 1,
+                    field: String::from("order"),
+                    old: Some(json!(1)),
+                    new: Some(json!(2)),
+                },
+                RevisionDifference {
+                    entity: "recipe_step",
+                    id: 2,
+                    field: String::from("order"),
+                    old: Some(json!(2)),
+                    new: Some(json!(1)),
+                },
+                RevisionDifference {
+                    entity: "recipe_step",
+                    id: 3,
+                    field: String::from("description"),
+                    old: None,
+                    new: Some(json!("serve")),
+                },
+                RevisionDifference {
+                    entity: "recipe_step",
+                    id: 3,
+                    field: String::from("duration"),
+                    old: None,
+                    new: Some(json!(null)),
+                },
+                RevisionDifference {
+                    entity: "recipe_step",
+                    id: 3,
+                    field: String::from("order"),
+                    old: None,
+                    new: Some(json!(3)),
+                },
+                RevisionDifference {
+                    entity: "recipe_step",
+                    id: 3,
+                    field: String::from("recipeId"),
+                    old: None,
+                    new: Some(json!(1)),
+                },
+                RevisionDifference {
+                    entity: "recipe_step_ingredient",
+                    id: 1,
+                    field: String::from("ingredientId"),
+                    old: Some(json!(1)),
+                    new: None,
+                },
+                RevisionDifference {
+                    entity: "recipe_step_ingredient",
+                    id: 1,
+                    field: String::from("order"),
+                    old: Some(json!(1)),
+                    new: None,
+                },
+                RevisionDifference {
+                    entity: "recipe_step_ingredient",
+                    id: 1,
+                    field: String::from("quality"),
+                    old: Some(json!(null)),
+                    new: None,
+                },
+                RevisionDifference {
+                    entity: "recipe_step_ingredient",
+                    id: 1,
+                    field: String::from("quantity"),
+                    old: Some(json!(2.0)),
+                    new: None,
+                },
+                RevisionDifference {
+                    entity: "recipe_step_ingredient",
+                    id: 1,
+                    field: String::from("recipeStepId"),
+                    old: Some(json!(1)),
+                    new: None,
+                },
+                RevisionDifference {
+                    entity: "recipe_step_ingredient",
+                    id: 1,
+                    field: String::from("unit"),
+                    old: Some(json!(null)),
+                    new: None,
+                },
+                RevisionDifference {
+                    entity: "ingredient",
+                    id: 1,
+                    field: String::from("glutenFree"),
+                    old: Some(json!(null)),
+                    new: None,
+                },
+                RevisionDifference {
+                    entity: "ingredient",
+                    id: 1,
+                    field: String::from("ingredientCategoryId"),
+                    old: Some(json!(null)),
+                    new: None,
+                },
+                RevisionDifference {
+                    entity: "ingredient",
+                    id: 1,
+                    field: String::from("lactoseFree"),
+                    old: Some(json!(null)),
+                    new: None,
+                },
+                RevisionDifference {
+                    entity: "ingredient",
+                    id: 1,
+                    field: String::from("name"),
+                    old: Some(json!("leek")),
+                    new: None,
+                },
+                RevisionDifference {
+                    entity: "ingredient",
+                    id: 1,
+                    field: String::from("vegan"),
+                    old: Some(json!(null)),
+                    new: None,
+                },
+                RevisionDifference {
+                    entity: "ingredient",
+                    id: 1,
+                    field: String::from("vegetarian"),
+                    old: Some(json!(null)),
+                    new: None,
+                },
+            ]
+        );
+
+        let txn = db.begin().await.unwrap();
+        let journal_action_id = journal::record_action(&txn).await.unwrap();
+        revert_logged(target.clone(), journal_action_id, &txn)
+            .await
+            .unwrap();
+        txn.commit().await.unwrap();
+        assert_eq!(snapshot(1, &db).await.unwrap().unwrap(), target);
+    }
+}
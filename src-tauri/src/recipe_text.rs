@@ -0,0 +1,389 @@
+//! This module handles recognizing the parts of a recipe in unstructured text, e.g. text extracted from a PDF file.
+
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+use crate::hocr::HocrPage;
+
+static INGREDIENTS_HEADING_REGEX: OnceLock<Regex> = OnceLock::new();
+
+static INSTRUCTIONS_HEADING_REGEX: OnceLock<Regex> = OnceLock::new();
+
+static QUANTITY_REGEX: OnceLock<Regex> = OnceLock::new();
+
+static STEP_NUMBER_REGEX: OnceLock<Regex> = OnceLock::new();
+
+/// Lines longer than this are never treated as ingredients or names.
+const MAX_SHORT_LINE_CHARS: usize = 60;
+
+/// How much taller than the median line a line must be to be a title.
+const MIN_TITLE_HEIGHT_FACTOR: f64 = 1.3;
+
+/// The share of lines in a paragraph which must start with a quantity for it to be an ingredient block.
+const MIN_INGREDIENT_BLOCK_SHARE: f64 = 0.5;
+
+/// The recognized parts of a recipe.
+#[derive(Debug, Default, PartialEq)]
+pub struct RecipeText {
+    pub name: Option<String>,
+    pub ingredients: Vec<String>,
+    pub steps: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Section {
+    Unknown,
+    Ingredients,
+    Instructions,
+}
+
+/// Recognize the parts of a recipe in a text whose paragraphs are separated by empty lines.
+pub fn parse(text: &str) -> RecipeText {
+    let mut paragraphs = vec![];
+    let mut paragraph = vec![];
+    for line in text.lines() {
+        if line.trim().is_empty() {
+            if !paragraph.is_empty() {
+                paragraphs.push(std::mem::take(&mut paragraph));
+            }
+            continue;
+        }
+        paragraph.push(line);
+    }
+    if !paragraph.is_empty() {
+        paragraphs.push(paragraph);
+    }
+    parse_paragraphs(RecipeText::default(), &paragraphs)
+}
+
+/// Recognize the parts of a recipe in already separated paragraphs of lines and add them to the already recognized parts.
+///
+/// - The first short line which is neither a heading nor an ingredient is the name.
+/// - Lines starting with a quantity are ingredients, as are short lines following an ingredients heading.
+/// - All other lines are joined into steps, one per paragraph or numbered item.
+fn parse_paragraphs<Line>(mut recipe_text: RecipeText, paragraphs: &[Vec<Line>]) -> RecipeText
+where
+    Line: AsRef<str>,
+{
+    let step_number_regex =
+        STEP_NUMBER_REGEX.get_or_init(|| Regex::new(r"^\d{1,2}\s*[.)]\s").unwrap());
+    let mut section = Section::Unknown;
+    for paragraph in paragraphs {
+        let mut step_lines: Vec<&str> = vec![];
+        for line in paragraph {
+            let line = line.as_ref().trim();
+            if line.is_empty() {
+                continue;
+            }
+            if is_heading(line, &INGREDIENTS_HEADING_REGEX, ingredients_heading_regex) {
+                push_step(&mut recipe_text.steps, &mut step_lines);
+                section = Section::Ingredients;
+                continue;
+            }
+            if is_heading(
+                line,
+                &INSTRUCTIONS_HEADING_REGEX,
+                instructions_heading_regex,
+            ) {
+                push_step(&mut recipe_text.steps, &mut step_lines);
+                section = Section::Instructions;
+                continue;
+            }
+            if step_number_regex.is_match(line) {
+                push_step(&mut recipe_text.steps, &mut step_lines);
+                step_lines.push(line);
+                continue;
+            }
+            if section != Section::Instructions && is_short(line) {
+                if is_ingredient(line) {
+                    push_step(&mut recipe_text.steps, &mut step_lines);
+                    recipe_text.ingredients.push(String::from(line));
+                    continue;
+                }
+                if recipe_text.name.is_none()
+                    && section == Section::Unknown
+                    && recipe_text.ingredients.is_empty()
+                    && recipe_text.steps.is_empty()
+                    && step_lines.is_empty()
+                {
+                    recipe_text.name = Some(String::from(line));
+                    continue;
+                }
+                if section == Section::Ingredients && !is_sentence(line) {
+                    // Sub headings like "Für den Teig:" are not ingredients.
+                    if !line.ends_with(':') {
+                        recipe_text.ingredients.push(String::from(line));
+                    }
+                    continue;
+                }
+            }
+            step_lines.push(line);
+        }
+        push_step(&mut recipe_text.steps, &mut step_lines);
+    }
+    recipe_text
+}
+
+/// Recognize the parts of a recipe in optically recognized pages, e.g. a photographed cookbook page.
+///
+/// In addition to the content, this uses the layout:
+/// - The title is the tallest short line in the upper half of the first page, if it is notably taller than the median line.
+/// - A paragraph of short lines which mostly start with quantities is an ingredient block, even without a heading.
+pub fn from_hocr(pages: &[HocrPage]) -> RecipeText {
+    let mut paragraphs: Vec<Vec<String>> = vec![];
+    let mut title: Option<(usize, usize, i32)> = None;
+    let mut heights = vec![];
+    for (page_index, page) in pages.iter().enumerate() {
+        for paragraph in page.blocks.iter().flat_map(|block| &block.paragraphs) {
+            let mut lines = vec![];
+            for line in &paragraph.lines {
+                let text = line.text();
+                let height = line.bbox.height();
+                heights.push(height);
+                let is_title_candidate = page_index == 0
+                    && line.bbox.y1 <= page.bbox.height() / 2
+                    && is_short(&text)
+                    && !is_ingredient(&text)
+                    && !is_sentence(&text)
+                    && title.map_or(true, |(_, _, title_height)| height > title_height);
+                if is_title_candidate {
+                    title = Some((paragraphs.len(), lines.len(), height));
+                }
+                lines.push(text);
+            }
+            paragraphs.push(lines);
+        }
+    }
+    heights.sort_unstable();
+    let median_height = heights.get(heights.len() / 2).copied().unwrap_or_default();
+    let mut name = None;
+    if let Some((paragraph_index, line_index, height)) = title {
+        if f64::from(height) >= f64::from(median_height) * MIN_TITLE_HEIGHT_FACTOR {
+            name = Some(paragraphs[paragraph_index].remove(line_index));
+        }
+    }
+    let mut ingredients = vec![];
+    let mut other_paragraphs = vec![];
+    for lines in paragraphs {
+        if is_ingredient_block(&lines) {
+            ingredients.extend(lines.into_iter().filter(|line| !line.ends_with(':')));
+        } else {
+            other_paragraphs.push(lines);
+        }
+    }
+    parse_paragraphs(
+        RecipeText {
+            name,
+            ingredients,
+            steps: vec![],
+        },
+        &other_paragraphs,
+    )
+}
+
+fn is_ingredient_block(lines: &[String]) -> bool {
+    if lines.is_empty()
+        || !lines
+            .iter()
+            .all(|line| is_short(line) && !is_sentence(line))
+    {
+        return false;
+    }
+    let ingredient_count = lines.iter().filter(|line| is_ingredient(line)).count();
+    ingredient_count as f64 / lines.len() as f64 >= MIN_INGREDIENT_BLOCK_SHARE
+}
+
+fn ingredients_heading_regex() -> Regex {
+    Regex::new(r"(?i)^(zutaten|ingredients?)\b").unwrap()
+}
+
+fn instructions_heading_regex() -> Regex {
+    Regex::new(
+        r"(?i)^(zubereitung|so wird['’`´]?s gemacht|anleitung|instructions?|directions?|method|preparation)\b",
+    )
+    .unwrap()
+}
+
+fn is_heading(line: &str, regex: &OnceLock<Regex>, init: fn() -> Regex) -> bool {
+    is_short(line) && !is_sentence(line) && regex.get_or_init(init).is_match(line)
+}
+
+/// Whether the line starts with a quantity, e.g. "250 g", "1/2", "½" or "2-3".
+pub fn is_ingredient(line: &str) -> bool {
+    is_short(line)
+        && QUANTITY_REGEX
+            .get_or_init(|| {
+                Regex::new(
+                    r"^(ca\.?\s*)?(\d+([.,/]\d+)?(\s*[-–]\s*\d+([.,/]\d+)?)?|[½¼¾⅓⅔⅛])(\s|\p{L}|$)",
+                )
+                .unwrap()
+            })
+            .is_match(line)
+}
+
+fn is_short(line: &str) -> bool {
+    line.chars().count() <= MAX_SHORT_LINE_CHARS
+}
+
+fn is_sentence(line: &str) -> bool {
+    line.ends_with(['.', '!', '?'])
+}
+
+/// Join the collected lines into a step and push it.
+///
+/// Words hyphenated at a line break are joined again.
+fn push_step(steps: &mut Vec<String>, step_lines: &mut Vec<&str>) {
+    if step_lines.is_empty() {
+        return;
+    }
+    let mut step = String::new();
+    for line in step_lines.drain(..) {
+        let continues_word = line.starts_with(char::is_lowercase);
+        if step.ends_with('-') && continues_word {
+            step.pop();
+        } else if !step.is_empty() {
+            step.push(' ');
+        }
+        step.push_str(line);
+    }
+    steps.push(step);
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::hocr::{BoundingBox, HocrBlock, HocrLine, HocrParagraph, HocrWord};
+
+    #[test]
+    fn test_parse() {
+        let text = "Apfel-Mazarin-Kuchen
+
+Zutaten für eine 26er Springform:
+Für den Teig:
+200 g Mehl
+100g Butter
+1 Ei
+½ TL Backpulver
+Prise Salz
+
+Zubereitung
+Mehl, Butter und Ei zu einem Teig ver-
+kneten und kalt stellen.
+
+Den Ofen auf 180 °C vorheizen.
+";
+        assert_eq!(
+            parse(text),
+            RecipeText {
+                name: Some(String::from("Apfel-Mazarin-Kuchen")),
+                ingredients: vec![
+                    String::from("200 g Mehl"),
+                    String::from("100g Butter"),
+                    String::from("1 Ei"),
+                    String::from("½ TL Backpulver"),
+                    String::from("Prise Salz"),
+                ],
+                steps: vec![
+                    String::from("Mehl, Butter und Ei zu einem Teig verkneten und kalt stellen."),
+                    String::from("Den Ofen auf 180 °C vorheizen."),
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_numbered_steps_without_headings() {
+        let text = "Pancakes
+2 eggs
+1-2 cups milk
+1. Whisk the eggs and the milk.
+2. Fry in a pan.";
+        assert_eq!(
+            parse(text),
+            RecipeText {
+                name: Some(String::from("Pancakes")),
+                ingredients: vec![String::from("2 eggs"), String::from("1-2 cups milk")],
+                steps: vec![
+                    String::from("1. Whisk the eggs and the milk."),
+                    String::from("2. Fry in a pan."),
+                ],
+            }
+        );
+    }
+
+    fn hocr_paragraph(lines: &[(&str, i32, i32)]) -> HocrBlock {
+        HocrBlock {
+            paragraphs: vec![HocrParagraph {
+                lines: lines
+                    .iter()
+                    .map(|(text, y0, y1)| HocrLine {
+                        bbox: BoundingBox {
+                            x0: 0,
+                            y0: *y0,
+                            x1: 500,
+                            y1: *y1,
+                        },
+                        words: vec![HocrWord {
+                            text: String::from(*text),
+                            ..Default::default()
+                        }],
+                        ..Default::default()
+                    })
+                    .collect(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_from_hocr() {
+        let pages = vec![HocrPage {
+            bbox: BoundingBox {
+                x0: 0,
+                y0: 0,
+                x1: 1000,
+                y1: 1400,
+            },
+            blocks: vec![
+                hocr_paragraph(&[("Apfelkuchen", 50, 110)]),
+                hocr_paragraph(&[
+                    ("250 g Mehl", 150, 180),
+                    ("100 g Zucker", 190, 220),
+                    ("Prise Salz", 230, 260),
+                ]),
+                hocr_paragraph(&[
+                    ("Den Ofen vorheizen und den Teig", 300, 330),
+                    ("kneten.", 340, 370),
+                ]),
+            ],
+            ..Default::default()
+        }];
+        assert_eq!(
+            from_hocr(&pages),
+            RecipeText {
+                name: Some(String::from("Apfelkuchen")),
+                ingredients: vec![
+                    String::from("250 g Mehl"),
+                    String::from("100 g Zucker"),
+                    String::from("Prise Salz"),
+                ],
+                steps: vec![String::from("Den Ofen vorheizen und den Teig kneten.")],
+            }
+        );
+    }
+
+    #[test]
+    fn test_is_ingredient() {
+        assert!(is_ingredient("250ml lauwarmes Wasser"));
+        assert!(is_ingredient("ca. 2-3 EL Zucker"));
+        assert!(is_ingredient("1/2 cup sugar"));
+        assert!(!is_ingredient("Salz"));
+        assert!(!is_ingredient(
+            "20 Minuten ruhen lassen, dann den Teig ausrollen und mit der Sauce bestreichen."
+        ));
+    }
+}
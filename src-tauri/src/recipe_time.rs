@@ -0,0 +1,275 @@
+//! This module handles the times of a recipe: active preparation, passive cooking, resting and the total time.
+//!
+//! Times are given as ISO 8601 durations by external recipes, see [`parse_iso_8601_duration`],
+//! or are estimated from the [durations of the recipe steps](crate::step_duration), see [`estimate`].
+
+use std::sync::OnceLock;
+
+use anyhow::Result;
+use regex::Regex;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+
+use crate::{
+    database,
+    entity::{recipe, recipe_step},
+    entity_crud::{
+        recipe::{RecipeCrud, RecipeUpdate},
+        EntityCrudTrait,
+    },
+    step_duration,
+    step_duration::StepDuration,
+};
+
+static ISO_8601_DURATION_REGEX: OnceLock<Regex> = OnceLock::new();
+
+static REST_REGEX: OnceLock<Regex> = OnceLock::new();
+
+static COOK_REGEX: OnceLock<Regex> = OnceLock::new();
+
+/// The times of a recipe in seconds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RecipeTime {
+    pub prep_time: Option<i64>,
+    pub cook_time: Option<i64>,
+    pub rest_time: Option<i64>,
+    pub total_time: Option<i64>,
+}
+
+impl RecipeTime {
+    /// Fill the missing times from an estimate, and the missing total time from the sum of the other times.
+    pub fn complete(self, estimate: RecipeTime) -> RecipeTime {
+        let prep_time = self.prep_time.or(estimate.prep_time);
+        let cook_time = self.cook_time.or(estimate.cook_time);
+        let rest_time = self.rest_time.or(estimate.rest_time);
+        let total_time = self.total_time.or_else(|| {
+            let times = [prep_time, cook_time, rest_time];
+            times
+                .iter()
+                .any(Option::is_some)
+                .then(|| times.into_iter().flatten().sum())
+        });
+        RecipeTime {
+            prep_time,
+            cook_time,
+            rest_time,
+            total_time,
+        }
+    }
+}
+
+/// Parse an ISO 8601 duration like `PT1H30M` or `P1DT2H` into seconds.
+///
+/// Years and months are not supported, as their length in seconds is ambiguous.
+/// Returns [`None`] when the text is no such duration.
+pub fn parse_iso_8601_duration(text: &str) -> Option<i64> {
+    let iso_8601_duration_regex = ISO_8601_DURATION_REGEX.get_or_init(|| {
+        Regex::new(r"^P(?:(?P<weeks>\d+)W)?(?:(?P<days>\d+)D)?(?:T(?:(?P<hours>\d+(?:[.,]\d+)?)H)?(?:(?P<minutes>\d+(?:[.,]\d+)?)M)?(?:(?P<seconds>\d+(?:[.,]\d+)?)S)?)?$").unwrap()
+    });
+    let text = text.trim();
+    if text == "P" || text.ends_with('T') {
+        return None;
+    }
+    let captures = iso_8601_duration_regex.captures(text)?;
+    let mut seconds = 0.0;
+    for (name, factor) in [
+        ("weeks", 7.0 * 24.0 * 60.0 * 60.0),
+        ("days", 24.0 * 60.0 * 60.0),
+        ("hours", 60.0 * 60.0),
+        ("minutes", 60.0),
+        ("seconds", 1.0),
+    ] {
+        if let Some(value) = captures.name(name) {
+            seconds += value.as_str().replace(',', ".").parse::<f64>().ok()? * factor;
+        }
+    }
+    Some(seconds.round() as i64)
+}
+
+/// Estimate the times of a recipe from the descriptions and durations of its steps.
+///
+/// Each step counts as resting or cooking when its description mentions it, otherwise as preparation.
+/// The maximum of a duration range is used, so that filtering by a maximum time does not match recipes which might take longer.
+/// All times are [`None`] when no step has a duration.
+pub fn estimate<'a, I>(steps: I) -> RecipeTime
+where
+    I: IntoIterator<Item = (&'a str, Vec<StepDuration>)>,
+{
+    let rest_regex = REST_REGEX.get_or_init(|| {
+        Regex::new(r"(?i)\b(?:ruhen|rest\b|resting|kühl|abkühl|kalt stellen|chill|cool|gehen lassen|rise|rising|prove|proof|marinier|marinate|ziehen lassen)").unwrap()
+    });
+    let cook_regex = COOK_REGEX.get_or_init(|| {
+        Regex::new(r"(?i)\b(?:back|bake|baking|koch|cook|simmer|köchel|gar(?:en|t)\b|brat|roast|frittier|fry|fries|frying|boil|grill|schmor|braise|dünst|steam|dämpf)").unwrap()
+    });
+    let mut has_duration = false;
+    let (mut prep_time, mut cook_time, mut rest_time) = (0, 0, 0);
+    for (description, durations) in steps {
+        if durations.is_empty() {
+            continue;
+        }
+        has_duration = true;
+        let seconds: i64 = durations.iter().map(|duration| duration.max).sum();
+        if rest_regex.is_match(description) {
+            rest_time += seconds;
+        } else if cook_regex.is_match(description) {
+            cook_time += seconds;
+        } else {
+            prep_time += seconds;
+        }
+    }
+    if !has_duration {
+        return RecipeTime::default();
+    }
+    RecipeTime {
+        prep_time: Some(prep_time),
+        cook_time: Some(cook_time),
+        rest_time: Some(rest_time),
+        total_time: Some(prep_time + cook_time + rest_time),
+    }
+}
+
+/// Fill the missing times of a recipe with estimates from its steps, see [`RecipeTime::complete`].
+///
+/// Times which are already set are kept.
+/// Returns [`None`] when the recipe does not exist.
+///
+/// # Errors
+///
+/// - when there is any problem with the database
+/// - when the updated recipe can't be serialized for the change log
+/// - when the tauri window can't be messaged about the updated recipe
+pub async fn fill(recipe_id: i64) -> Result<Option<recipe::Model>> {
+    let Some(recipe) = RecipeCrud::read(recipe_id).await? else {
+        return Ok(None);
+    };
+    let db = database::connect().await;
+    let recipe_steps = recipe_step::Entity::find()
+        .filter(recipe_step::Column::RecipeId.eq(recipe_id))
+        .order_by_asc(recipe_step::Column::Order)
+        .all(db)
+        .await?;
+    let current = RecipeTime {
+        prep_time: recipe.prep_time,
+        cook_time: recipe.cook_time,
+        rest_time: recipe.rest_time,
+        total_time: recipe.total_time,
+    };
+    let completed = current.complete(estimate(recipe_steps.iter().map(|recipe_step| {
+        (
+            recipe_step.description.as_str(),
+            step_duration::from_recipe_step(recipe_step),
+        )
+    })));
+    if completed == current {
+        return Ok(Some(recipe));
+    }
+    let filled = |current: Option<i64>, completed: Option<i64>| match current {
+        Some(_) => None,
+        None => completed.map(Some),
+    };
+    let recipe = RecipeCrud::update(RecipeUpdate {
+        id: recipe_id,
+        name: None,
+        prep_time: filled(current.prep_time, completed.prep_time),
+        cook_time: filled(current.cook_time, completed.cook_time),
+        rest_time: filled(current.rest_time, completed.rest_time),
+        total_time: filled(current.total_time, completed.total_time),
+    })
+    .await?;
+    Ok(Some(recipe))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_iso_8601_duration() {
+        for (text, expected) in [
+            ("PT20M", Some(1200)),
+            ("PT1H30M", Some(5400)),
+            ("P1DT2H", Some(93600)),
+            ("PT1.5H", Some(5400)),
+            ("PT0S", Some(0)),
+            ("P", None),
+            ("PT", None),
+            ("PT20 min", None),
+            ("20 minutes", None),
+        ] {
+            assert_eq!(parse_iso_8601_duration(text), expected, "{text}");
+        }
+    }
+
+    #[test]
+    fn test_estimate() {
+        let steps = [
+            "Zwiebeln 5 Minuten schneiden.",
+            "Den Teig 1–2 Stunden gehen lassen.",
+            "Im Ofen 20 Minuten backen.",
+            "Servieren.",
+        ];
+        assert_eq!(
+            estimate(
+                steps
+                    .into_iter()
+                    .map(|step| (step, step_duration::parse(step)))
+            ),
+            RecipeTime {
+                prep_time: Some(300),
+                cook_time: Some(1200),
+                rest_time: Some(7200),
+                total_time: Some(8700),
+            }
+        );
+        assert_eq!(estimate([("Servieren.", vec![])]), RecipeTime::default());
+    }
+
+    #[test]
+    fn test_complete() {
+        let estimate = RecipeTime {
+            prep_time: Some(300),
+            cook_time: Some(1200),
+            rest_time: Some(0),
+            total_time: Some(1500),
+        };
+        assert_eq!(
+            RecipeTime {
+                prep_time: Some(600),
+                ..Default::default()
+            }
+            .complete(estimate),
+            RecipeTime {
+                prep_time: Some(600),
+                cook_time: Some(1200),
+                rest_time: Some(0),
+                total_time: Some(1800),
+            }
+        );
+        assert_eq!(
+            RecipeTime {
+                total_time: Some(3600),
+                ..Default::default()
+            }
+            .complete(RecipeTime::default()),
+            RecipeTime {
+                total_time: Some(3600),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            RecipeTime {
+                prep_time: Some(600),
+                cook_time: Some(900),
+                ..Default::default()
+            }
+            .complete(RecipeTime::default()),
+            RecipeTime {
+                prep_time: Some(600),
+                cook_time: Some(900),
+                rest_time: None,
+                total_time: Some(1500),
+            }
+        );
+    }
+}
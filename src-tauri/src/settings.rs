@@ -0,0 +1,103 @@
+//! This module implements the settings of the application, which are stored in the database.
+//!
+//! Settings which are not stored have their default value, see [`Settings::default`].
+
+use anyhow::Result;
+use sea_orm::{sea_query::OnConflict, ActiveValue, ConnectionTrait, EntityTrait, JsonValue};
+use serde::{Deserialize, Serialize};
+
+use crate::{database, entity::setting};
+
+/// This struct represents the settings of the application.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Settings {
+    /// the number of days after which recipes in the trash are purged automatically, or none to keep them until they are purged explicitly
+    pub trash_retention_days: Option<u64>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            trash_retention_days: Some(30),
+        }
+    }
+}
+
+/// Get the settings.
+///
+/// # Errors
+///
+/// - when there is any problem with the database
+/// - when a stored setting can't be deserialized
+pub async fn get() -> Result<Settings> {
+    get_from(database::connect().await).await
+}
+
+/// Set and store all settings.
+///
+/// # Errors
+///
+/// - when there is any problem with the database
+/// - when the settings can't be serialized
+pub async fn set(settings: Settings) -> Result<()> {
+    let db = database::connect_writing().await;
+    set_into(settings, *db).await
+}
+
+async fn get_from<C>(db: &C) -> Result<Settings>
+where
+    C: ConnectionTrait,
+{
+    let mut value = serde_json::to_value(Settings::default())?;
+    for setting in setting::Entity::find().all(db).await? {
+        value[setting.key] = setting.value;
+    }
+    Ok(serde_json::from_value(value)?)
+}
+
+async fn set_into<C>(settings: Settings, db: &C) -> Result<()>
+where
+    C: ConnectionTrait,
+{
+    let JsonValue::Object(values) = serde_json::to_value(settings)? else {
+        unreachable!("the settings are serialized as an object")
+    };
+    let active_models = values.into_iter().map(|(key, value)| setting::ActiveModel {
+        key: ActiveValue::Set(key),
+        value: ActiveValue::Set(value),
+    });
+    setting::Entity::insert_many(active_models)
+        .on_conflict(
+            OnConflict::column(setting::Column::Key)
+                .update_column(setting::Column::Value)
+                .to_owned(),
+        )
+        .exec(db)
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::migrator::tests::get_memory_database_migrated;
+
+    #[tokio::test]
+    async fn test_set() {
+        let db = get_memory_database_migrated().await;
+        assert_eq!(get_from(&db).await.unwrap(), Settings::default());
+        let settings = Settings {
+            trash_retention_days: None,
+        };
+        set_into(settings.clone(), &db).await.unwrap();
+        assert_eq!(get_from(&db).await.unwrap(), settings);
+        let settings = Settings {
+            trash_retention_days: Some(7),
+        };
+        set_into(settings.clone(), &db).await.unwrap();
+        assert_eq!(get_from(&db).await.unwrap(), settings);
+    }
+}
@@ -0,0 +1,245 @@
+//! This module handles recognizing durations in the description of a recipe step, e.g. "20 Minuten backen" or "simmer for 1–2 hours".
+
+use std::sync::OnceLock;
+
+use regex::{Captures, Regex};
+use serde::Serialize;
+
+use crate::entity::recipe_step::Model as RecipeStep;
+
+static DURATION_REGEX: OnceLock<Regex> = OnceLock::new();
+
+static COMPOUND_SEPARATOR_REGEX: OnceLock<Regex> = OnceLock::new();
+
+/// A number, either in digits with an optional decimal part or written out in German or English.
+const NUMBER_PATTERN: &str = r"\d+(?:[.,]\d+)?|eine[mnrs]?|eins?|zwei|drei|vier|fünf|sechs|sieben|acht|neun|zehn|zwölf|fünfzehn|zwanzig|dreißig|an?|one|two|three|four|five|six|seven|eight|nine|ten|twelve|fifteen|twenty|thirty";
+
+/// The words separating the two numbers of a range.
+const RANGE_SEPARATOR_PATTERN: &str = r"\s*(?:-|–|—|bis|to|or|oder)\s*";
+
+/// The units of time, longest first so that no unit is cut short.
+const UNIT_PATTERN: &str = r"sekunden|sekunde|seconds|second|secs|sec|sek|minuten|minutes|minute|mins|min|stunden|stunde|std|hours|hour|hrs|hr|h";
+
+/// This struct represents a duration, which may be a range, in seconds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StepDuration {
+    /// the text the duration was recognized in, or [`None`] for the explicit duration of a recipe step
+    pub text: Option<String>,
+    pub min: i64,
+    pub max: i64,
+}
+
+/// A recognized duration before merging compound durations like "1 Stunde und 30 Minuten".
+struct Match {
+    start: usize,
+    end: usize,
+    /// the minimum in seconds
+    min: f64,
+    /// the maximum in seconds
+    max: f64,
+    /// the seconds per unit of the last part
+    unit: f64,
+}
+
+/// Get the durations of a recipe step.
+///
+/// The explicit duration takes precedence over the durations recognized in the description.
+pub fn from_recipe_step(recipe_step: &RecipeStep) -> Vec<StepDuration> {
+    match recipe_step.duration {
+        Some(duration) => vec![StepDuration {
+            text: None,
+            min: duration,
+            max: duration,
+        }],
+        None => parse(&recipe_step.description),
+    }
+}
+
+/// Recognize all durations in a text.
+///
+/// - Numbers may be written in digits, with a decimal point or comma, or in German or English words.
+/// - Ranges like "1–2", "10 bis 15" or "2 or 3" have differing minimum and maximum.
+/// - Half hours like "eine halbe Stunde" or "half an hour" are recognized.
+/// - Adjacent durations with decreasing units like "1 hour and 30 minutes" are added up.
+pub fn parse(text: &str) -> Vec<StepDuration> {
+    let duration_regex = DURATION_REGEX.get_or_init(|| {
+        Regex::new(&format!(
+            r"(?i)\b(?:(?P<half>(?:eine\s+)?halbe\s+stunde|half\s+an\s+hour)|(?P<min>{NUMBER_PATTERN})(?:{RANGE_SEPARATOR_PATTERN}(?P<max>{NUMBER_PATTERN}))?\s*(?P<unit>{UNIT_PATTERN}))\b"
+        ))
+        .unwrap()
+    });
+    let compound_separator_regex =
+        COMPOUND_SEPARATOR_REGEX.get_or_init(|| Regex::new(r"(?i)^\s*(?:,|und|and)?\s*$").unwrap());
+    let mut matches: Vec<Match> = vec![];
+    for captures in duration_regex.captures_iter(text) {
+        let Some(current) = to_match(&captures) else {
+            continue;
+        };
+        if let Some(previous) = matches.last_mut() {
+            if previous.unit > current.unit
+                && compound_separator_regex.is_match(&text[previous.end..current.start])
+            {
+                previous.end = current.end;
+                previous.min += current.min;
+                previous.max += current.max;
+                previous.unit = current.unit;
+                continue;
+            }
+        }
+        matches.push(current);
+    }
+    matches
+        .into_iter()
+        .map(|m| StepDuration {
+            text: Some(text[m.start..m.end].to_string()),
+            min: m.min.round() as i64,
+            max: m.max.round() as i64,
+        })
+        .collect()
+}
+
+fn to_match(captures: &Captures) -> Option<Match> {
+    let whole = captures.get(0)?;
+    if captures.name("half").is_some() {
+        return Some(Match {
+            start: whole.start(),
+            end: whole.end(),
+            min: 1800.0,
+            max: 1800.0,
+            unit: 3600.0,
+        });
+    }
+    let min = parse_number(captures.name("min")?.as_str())?;
+    let max = match captures.name("max") {
+        Some(max) => parse_number(max.as_str())?,
+        None => min,
+    };
+    let unit = parse_unit(captures.name("unit")?.as_str())?;
+    Some(Match {
+        start: whole.start(),
+        end: whole.end(),
+        min: min.min(max) * unit,
+        max: min.max(max) * unit,
+        unit,
+    })
+}
+
+fn parse_number(number: &str) -> Option<f64> {
+    let number = number.to_lowercase();
+    let value = match number.as_str() {
+        "a" | "an" | "one" | "ein" | "eins" | "eine" | "einem" | "einen" | "einer" | "eines" => 1.0,
+        "two" | "zwei" => 2.0,
+        "three" | "drei" => 3.0,
+        "four" | "vier" => 4.0,
+        "five" | "fünf" => 5.0,
+        "six" | "sechs" => 6.0,
+        "seven" | "sieben" => 7.0,
+        "eight" | "acht" => 8.0,
+        "nine" | "neun" => 9.0,
+        "ten" | "zehn" => 10.0,
+        "twelve" | "zwölf" => 12.0,
+        "fifteen" | "fünfzehn" => 15.0,
+        "twenty" | "zwanzig" => 20.0,
+        "thirty" | "dreißig" => 30.0,
+        number => number.replace(',', ".").parse().ok()?,
+    };
+    Some(value)
+}
+
+fn parse_unit(unit: &str) -> Option<f64> {
+    let unit = unit.to_lowercase();
+    if unit.starts_with("st") || unit.starts_with('h') {
+        Some(3600.0)
+    } else if unit.starts_with('m') {
+        Some(60.0)
+    } else if unit.starts_with('s') {
+        Some(1.0)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn duration(text: &str, min: i64, max: i64) -> StepDuration {
+        StepDuration {
+            text: Some(text.to_string()),
+            min,
+            max,
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse("Im Ofen 20 Minuten backen."),
+            vec![duration("20 Minuten", 1200, 1200)]
+        );
+        assert_eq!(
+            parse("Simmer for 1–2 hours, stirring occasionally."),
+            vec![duration("1–2 hours", 3600, 7200)]
+        );
+        assert_eq!(
+            parse("10 bis 15 Min. ruhen lassen, dann 1,5 Std. gehen lassen"),
+            vec![
+                duration("10 bis 15 Min", 600, 900),
+                duration("1,5 Std", 5400, 5400),
+            ]
+        );
+        assert_eq!(
+            parse("Eine halbe Stunde kühlen, then rest for half an hour"),
+            vec![
+                duration("Eine halbe Stunde", 1800, 1800),
+                duration("half an hour", 1800, 1800),
+            ]
+        );
+        assert_eq!(
+            parse("Bake for 1 hour and 30 minutes, then broil 2 or 3 mins"),
+            vec![
+                duration("1 hour and 30 minutes", 5400, 5400),
+                duration("2 or 3 mins", 120, 180),
+            ]
+        );
+        assert_eq!(
+            parse("Eine Stunde 10-15 Minuten garen, zwei Minuten warten, 30 sec mixen"),
+            vec![
+                duration("Eine Stunde 10-15 Minuten", 4200, 4500),
+                duration("zwei Minuten", 120, 120),
+                duration("30 sec", 30, 30),
+            ]
+        );
+        assert_eq!(parse("2 Eier mit 200 g Mehl verrühren."), vec![]);
+        assert_eq!(parse("Add the chopped herbs."), vec![]);
+    }
+
+    #[test]
+    fn test_from_recipe_step() {
+        let mut recipe_step = RecipeStep {
+            id: 1,
+            order: 1,
+            description: String::from("20 Minuten backen"),
+            duration: None,
+            recipe_id: 1,
+            created_at: 0,
+            updated_at: 0,
+        };
+        assert_eq!(
+            from_recipe_step(&recipe_step),
+            vec![duration("20 Minuten", 1200, 1200)]
+        );
+        recipe_step.duration = Some(1500);
+        assert_eq!(
+            from_recipe_step(&recipe_step),
+            vec![StepDuration {
+                text: None,
+                min: 1500,
+                max: 1500,
+            }]
+        );
+    }
+}
@@ -0,0 +1,122 @@
+//! This module implements named countdown timers which run in the backend, so that they survive reloads of the frontend.
+//!
+//! Every running timer emits a [`TIMER_TICK`] event each second and a [`TIMER_FINISH`] event when it has run out.
+
+use std::{collections::BTreeMap, sync::Mutex, time::Duration};
+
+use anyhow::{bail, Result};
+use serde::Serialize;
+use tokio::time::Instant;
+use uuid::Uuid;
+
+use crate::{
+    event::channel::{TIMER_CANCEL, TIMER_FINISH, TIMER_TICK},
+    window::try_get_window,
+};
+
+static TIMERS: Mutex<BTreeMap<String, RunningTimer>> = Mutex::new(BTreeMap::new());
+
+/// This struct represents the state of a timer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Timer {
+    pub id: String,
+    pub name: String,
+    /// the total duration in seconds
+    pub duration: u64,
+    /// the remaining duration in seconds
+    pub remaining: u64,
+}
+
+struct RunningTimer {
+    name: String,
+    duration: u64,
+    deadline: Instant,
+}
+
+impl RunningTimer {
+    fn to_timer(&self, id: &str) -> Timer {
+        let remaining = self.deadline.saturating_duration_since(Instant::now());
+        Timer {
+            id: id.to_string(),
+            name: self.name.clone(),
+            duration: self.duration,
+            // A tick may fire a little late, which must not skip a second.
+            remaining: remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0),
+        }
+    }
+}
+
+/// Start a timer with a name and a duration in seconds.
+pub fn start(name: String, duration: u64) -> Result<Timer> {
+    let Some(deadline) = Instant::now().checked_add(Duration::from_secs(duration)) else {
+        bail!("The timer duration {duration} is too long");
+    };
+    let id = Uuid::new_v4().to_string();
+    let running_timer = RunningTimer {
+        name,
+        duration,
+        deadline,
+    };
+    let timer = running_timer.to_timer(&id);
+    // The timer is inserted before its task can look it up.
+    TIMERS.lock().unwrap().insert(id.clone(), running_timer);
+    tokio::spawn(run(id));
+    Ok(timer)
+}
+
+/// Cancel a timer, returning whether it was running.
+///
+/// The task of the timer ends on its next tick, when it no longer finds the timer.
+pub fn cancel(id: &str) -> bool {
+    let running_timer = TIMERS.lock().unwrap().remove(id);
+    let Some(running_timer) = running_timer else {
+        return false;
+    };
+    emit(TIMER_CANCEL, &running_timer.to_timer(id));
+    true
+}
+
+/// List all running timers, the one running out first first.
+pub fn list() -> Vec<Timer> {
+    let timers = TIMERS.lock().unwrap();
+    let mut list: Vec<(Instant, Timer)> = timers
+        .iter()
+        .map(|(id, running_timer)| (running_timer.deadline, running_timer.to_timer(id)))
+        .collect();
+    list.sort_by_key(|(deadline, _)| *deadline);
+    list.into_iter().map(|(_, timer)| timer).collect()
+}
+
+/// Emit a tick each second until the timer has run out, then remove it and emit its finish.
+async fn run(id: String) {
+    let mut interval = tokio::time::interval(Duration::from_secs(1));
+    // The first tick completes immediately.
+    interval.tick().await;
+    loop {
+        interval.tick().await;
+        let (channel, timer) = {
+            let mut timers = TIMERS.lock().unwrap();
+            let Some(running_timer) = timers.get(&id) else {
+                return;
+            };
+            let timer = running_timer.to_timer(&id);
+            if timer.remaining > 0 {
+                (TIMER_TICK, timer)
+            } else {
+                timers.remove(&id);
+                (TIMER_FINISH, timer)
+            }
+        };
+        emit(channel, &timer);
+        if timer.remaining == 0 {
+            return;
+        }
+    }
+}
+
+fn emit(channel: &str, timer: &Timer) {
+    if let Some(window) = try_get_window() {
+        window.emit(channel, timer).ok();
+    }
+}
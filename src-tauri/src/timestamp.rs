@@ -0,0 +1,15 @@
+//! This module implements timestamps, which are stored as seconds since the Unix epoch.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::Result;
+
+/// Get the current time as seconds since the Unix epoch.
+///
+/// # Errors
+///
+/// - when the system time is before the Unix epoch or too far after it
+pub fn unix_timestamp() -> Result<i64> {
+    let duration = SystemTime::now().duration_since(UNIX_EPOCH)?;
+    Ok(i64::try_from(duration.as_secs())?)
+}
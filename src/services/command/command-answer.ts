@@ -1,13 +1,47 @@
+import type { DietarySummary } from "../../types/dietary.ts";
+import type { EntityChange } from "../../types/entity-change.ts";
+import type {
+  CollectionExport,
+  CollectionInterface,
+} from "../../types/entity/collection-interface.ts";
+import type { CollectionRecipeInterface } from "../../types/entity/collection-recipe-interface.ts";
+import type { CookLogFileInterface } from "../../types/entity/cook-log-file-interface.ts";
+import type { CookLogInterface } from "../../types/entity/cook-log-interface.ts";
 import type { FileInterface } from "../../types/entity/file-interface.ts";
+import type { IngredientAliasInterface } from "../../types/entity/ingredient-alias-interface.ts";
+import type { IngredientAllergenInterface } from "../../types/entity/ingredient-allergen-interface.ts";
+import type { IngredientCategoryInterface } from "../../types/entity/ingredient-category-interface.ts";
 import type { IngredientInterface } from "../../types/entity/ingredient-interface.ts";
+import type { NutrientProfileInterface } from "../../types/entity/nutrient-profile-interface.ts";
 import type { RecipeFileInterface } from "../../types/entity/recipe-file-interface.ts";
 import type { RecipeIngredientDraftInterface } from "../../types/entity/recipe-ingredient-draft-interface.ts";
-import type { RecipeInterface } from "../../types/entity/recipe-interface.ts";
+import type {
+  RecipeExport,
+  RecipeInterface,
+} from "../../types/entity/recipe-interface.ts";
 import type { RecipeStepFileInterface } from "../../types/entity/recipe-step-file-interface.ts";
 import type { RecipeStepIngredientDraftInterface } from "../../types/entity/recipe-step-ingredient-draft-interface.ts";
 import type { RecipeStepIngredientInterface } from "../../types/entity/recipe-step-ingredient-interface.ts";
 import type { RecipeStepInterface } from "../../types/entity/recipe-step-interface.ts";
+import type { RecipeTagInterface } from "../../types/entity/recipe-tag-interface.ts";
+import type {
+  TagInterface,
+  TagUsageInterface,
+} from "../../types/entity/tag-interface.ts";
 import type { UnitNameInterface } from "../../types/entity/unit-name-interface.ts";
+import type { ListPage } from "../../types/filter-interface.ts";
+import type {
+  NutrientImport,
+  RecipeNutrition,
+} from "../../types/nutrition.ts";
+import type { OcrLanguage, OcrOutput } from "../../types/ocr.ts";
+import type {
+  RecipeRevisionSummary,
+  RevisionDifference,
+} from "../../types/recipe-revision.ts";
+import type { Settings } from "../../types/settings.ts";
+import type { StepDuration } from "../../types/step-duration.ts";
+import type { Timer } from "../../types/timer.ts";
 import type { UnitConversion } from "../../types/unit-conversion.ts";
 import type { Command } from "./command.ts";
 
@@ -16,78 +50,289 @@ type CommandAnswerMap = {
   [Command.ENTITY_READ_FILE]: FileInterface;
   [Command.ENTITY_UPDATE_FILE]: void;
   [Command.ENTITY_DELETE_FILE]: void;
+  [Command.ENTITY_CREATE_MANY_FILE]: number[];
+  [Command.ENTITY_UPDATE_MANY_FILE]: void;
+  [Command.ENTITY_DELETE_MANY_FILE]: void;
   [Command.ENTITY_LIST_FILE]: number[];
+  [Command.ENTITY_LIST_PAGE_FILE]: ListPage<number>;
   [Command.ENTITY_COUNT_FILE]: number;
 
   [Command.ENTITY_CREATE_INGREDIENT]: number;
   [Command.ENTITY_READ_INGREDIENT]: IngredientInterface;
   [Command.ENTITY_UPDATE_INGREDIENT]: void;
   [Command.ENTITY_DELETE_INGREDIENT]: void;
+  [Command.ENTITY_CREATE_MANY_INGREDIENT]: number[];
+  [Command.ENTITY_UPDATE_MANY_INGREDIENT]: void;
+  [Command.ENTITY_DELETE_MANY_INGREDIENT]: void;
   [Command.ENTITY_LIST_INGREDIENT]: number[];
+  [Command.ENTITY_LIST_PAGE_INGREDIENT]: ListPage<number>;
   [Command.ENTITY_COUNT_INGREDIENT]: number;
+  [Command.INGREDIENT_MERGE]: void;
+  [Command.INGREDIENT_MERGE_SUGGESTIONS]: number[][];
+
+  [Command.ENTITY_CREATE_INGREDIENT_ALIAS]: number;
+  [Command.ENTITY_READ_INGREDIENT_ALIAS]: IngredientAliasInterface;
+  [Command.ENTITY_UPDATE_INGREDIENT_ALIAS]: void;
+  [Command.ENTITY_DELETE_INGREDIENT_ALIAS]: void;
+  [Command.ENTITY_CREATE_MANY_INGREDIENT_ALIAS]: number[];
+  [Command.ENTITY_UPDATE_MANY_INGREDIENT_ALIAS]: void;
+  [Command.ENTITY_DELETE_MANY_INGREDIENT_ALIAS]: void;
+  [Command.ENTITY_LIST_INGREDIENT_ALIAS]: number[];
+  [Command.ENTITY_LIST_PAGE_INGREDIENT_ALIAS]: ListPage<number>;
+  [Command.ENTITY_COUNT_INGREDIENT_ALIAS]: number;
+
+  [Command.ENTITY_CREATE_INGREDIENT_ALLERGEN]: number;
+  [Command.ENTITY_READ_INGREDIENT_ALLERGEN]: IngredientAllergenInterface;
+  [Command.ENTITY_UPDATE_INGREDIENT_ALLERGEN]: void;
+  [Command.ENTITY_DELETE_INGREDIENT_ALLERGEN]: void;
+  [Command.ENTITY_CREATE_MANY_INGREDIENT_ALLERGEN]: number[];
+  [Command.ENTITY_UPDATE_MANY_INGREDIENT_ALLERGEN]: void;
+  [Command.ENTITY_DELETE_MANY_INGREDIENT_ALLERGEN]: void;
+  [Command.ENTITY_LIST_INGREDIENT_ALLERGEN]: number[];
+  [Command.ENTITY_LIST_PAGE_INGREDIENT_ALLERGEN]: ListPage<number>;
+  [Command.ENTITY_COUNT_INGREDIENT_ALLERGEN]: number;
+
+  [Command.ENTITY_CREATE_COOK_LOG]: number;
+  [Command.ENTITY_READ_COOK_LOG]: CookLogInterface;
+  [Command.ENTITY_UPDATE_COOK_LOG]: void;
+  [Command.ENTITY_DELETE_COOK_LOG]: void;
+  [Command.ENTITY_CREATE_MANY_COOK_LOG]: number[];
+  [Command.ENTITY_UPDATE_MANY_COOK_LOG]: void;
+  [Command.ENTITY_DELETE_MANY_COOK_LOG]: void;
+  [Command.ENTITY_LIST_COOK_LOG]: number[];
+  [Command.ENTITY_LIST_PAGE_COOK_LOG]: ListPage<number>;
+  [Command.ENTITY_COUNT_COOK_LOG]: number;
+
+  [Command.ENTITY_CREATE_COLLECTION]: number;
+  [Command.ENTITY_READ_COLLECTION]: CollectionInterface;
+  [Command.ENTITY_UPDATE_COLLECTION]: void;
+  [Command.ENTITY_DELETE_COLLECTION]: void;
+  [Command.ENTITY_CREATE_MANY_COLLECTION]: number[];
+  [Command.ENTITY_UPDATE_MANY_COLLECTION]: void;
+  [Command.ENTITY_DELETE_MANY_COLLECTION]: void;
+  [Command.ENTITY_LIST_COLLECTION]: number[];
+  [Command.ENTITY_LIST_PAGE_COLLECTION]: ListPage<number>;
+  [Command.ENTITY_COUNT_COLLECTION]: number;
+  [Command.COLLECTION_EXPORT]: CollectionExport;
+
+  [Command.ENTITY_CREATE_TAG]: number;
+  [Command.ENTITY_READ_TAG]: TagInterface;
+  [Command.ENTITY_UPDATE_TAG]: void;
+  [Command.ENTITY_DELETE_TAG]: void;
+  [Command.ENTITY_CREATE_MANY_TAG]: number[];
+  [Command.ENTITY_UPDATE_MANY_TAG]: void;
+  [Command.ENTITY_DELETE_MANY_TAG]: void;
+  [Command.ENTITY_LIST_TAG]: number[];
+  [Command.ENTITY_LIST_PAGE_TAG]: ListPage<number>;
+  [Command.ENTITY_COUNT_TAG]: number;
+  [Command.TAG_RENAME]: number;
+  [Command.TAG_CLOUD]: TagUsageInterface[];
+
+  [Command.ENTITY_CREATE_RECIPE_TAG]: number;
+  [Command.ENTITY_READ_RECIPE_TAG]: RecipeTagInterface;
+  [Command.ENTITY_UPDATE_RECIPE_TAG]: void;
+  [Command.ENTITY_DELETE_RECIPE_TAG]: void;
+  [Command.ENTITY_CREATE_MANY_RECIPE_TAG]: number[];
+  [Command.ENTITY_UPDATE_MANY_RECIPE_TAG]: void;
+  [Command.ENTITY_DELETE_MANY_RECIPE_TAG]: void;
+  [Command.ENTITY_LIST_RECIPE_TAG]: number[];
+  [Command.ENTITY_LIST_PAGE_RECIPE_TAG]: ListPage<number>;
+  [Command.ENTITY_COUNT_RECIPE_TAG]: number;
+
+  [Command.ENTITY_CREATE_INGREDIENT_CATEGORY]: number;
+  [Command.ENTITY_READ_INGREDIENT_CATEGORY]: IngredientCategoryInterface;
+  [Command.ENTITY_UPDATE_INGREDIENT_CATEGORY]: void;
+  [Command.ENTITY_DELETE_INGREDIENT_CATEGORY]: void;
+  [Command.ENTITY_CREATE_MANY_INGREDIENT_CATEGORY]: number[];
+  [Command.ENTITY_UPDATE_MANY_INGREDIENT_CATEGORY]: void;
+  [Command.ENTITY_DELETE_MANY_INGREDIENT_CATEGORY]: void;
+  [Command.ENTITY_LIST_INGREDIENT_CATEGORY]: number[];
+  [Command.ENTITY_LIST_PAGE_INGREDIENT_CATEGORY]: ListPage<number>;
+  [Command.ENTITY_COUNT_INGREDIENT_CATEGORY]: number;
+
+  [Command.ENTITY_CREATE_NUTRIENT_PROFILE]: number;
+  [Command.ENTITY_READ_NUTRIENT_PROFILE]: NutrientProfileInterface;
+  [Command.ENTITY_UPDATE_NUTRIENT_PROFILE]: void;
+  [Command.ENTITY_DELETE_NUTRIENT_PROFILE]: void;
+  [Command.ENTITY_CREATE_MANY_NUTRIENT_PROFILE]: number[];
+  [Command.ENTITY_UPDATE_MANY_NUTRIENT_PROFILE]: void;
+  [Command.ENTITY_DELETE_MANY_NUTRIENT_PROFILE]: void;
+  [Command.ENTITY_LIST_NUTRIENT_PROFILE]: number[];
+  [Command.ENTITY_LIST_PAGE_NUTRIENT_PROFILE]: ListPage<number>;
+  [Command.ENTITY_COUNT_NUTRIENT_PROFILE]: number;
 
   [Command.ENTITY_CREATE_RECIPE]: number;
   [Command.ENTITY_READ_RECIPE]: RecipeInterface;
   [Command.ENTITY_UPDATE_RECIPE]: void;
   [Command.ENTITY_DELETE_RECIPE]: void;
+  [Command.ENTITY_CREATE_MANY_RECIPE]: number[];
+  [Command.ENTITY_UPDATE_MANY_RECIPE]: void;
+  [Command.ENTITY_DELETE_MANY_RECIPE]: void;
   [Command.ENTITY_LIST_RECIPE]: number[];
+  [Command.ENTITY_LIST_PAGE_RECIPE]: ListPage<number>;
   [Command.ENTITY_COUNT_RECIPE]: number;
+  [Command.ENTITY_TRASH_RECIPE]: void;
+  [Command.ENTITY_RESTORE_RECIPE]: void;
+  [Command.ENTITY_PURGE_RECIPE]: void;
+  [Command.ENTITY_PURGE_TRASH_RECIPE]: void;
+  [Command.RECIPE_DUPLICATE]: number;
+  [Command.RECIPE_EXPORT]: RecipeExport;
 
   [Command.ENTITY_CREATE_RECIPE_FILE]: number;
   [Command.ENTITY_READ_RECIPE_FILE]: RecipeFileInterface;
   [Command.ENTITY_UPDATE_RECIPE_FILE]: void;
   [Command.ENTITY_DELETE_RECIPE_FILE]: void;
+  [Command.ENTITY_CREATE_MANY_RECIPE_FILE]: number[];
+  [Command.ENTITY_UPDATE_MANY_RECIPE_FILE]: void;
+  [Command.ENTITY_DELETE_MANY_RECIPE_FILE]: void;
   [Command.ENTITY_LIST_RECIPE_FILE]: number[];
+  [Command.ENTITY_LIST_PAGE_RECIPE_FILE]: ListPage<number>;
   [Command.ENTITY_COUNT_RECIPE_FILE]: number;
+  [Command.ENTITY_MOVE_RECIPE_FILE]: void;
+  [Command.ENTITY_REORDER_RECIPE_FILE]: void;
+
+  [Command.ENTITY_CREATE_COOK_LOG_FILE]: number;
+  [Command.ENTITY_READ_COOK_LOG_FILE]: CookLogFileInterface;
+  [Command.ENTITY_UPDATE_COOK_LOG_FILE]: void;
+  [Command.ENTITY_DELETE_COOK_LOG_FILE]: void;
+  [Command.ENTITY_CREATE_MANY_COOK_LOG_FILE]: number[];
+  [Command.ENTITY_UPDATE_MANY_COOK_LOG_FILE]: void;
+  [Command.ENTITY_DELETE_MANY_COOK_LOG_FILE]: void;
+  [Command.ENTITY_LIST_COOK_LOG_FILE]: number[];
+  [Command.ENTITY_LIST_PAGE_COOK_LOG_FILE]: ListPage<number>;
+  [Command.ENTITY_COUNT_COOK_LOG_FILE]: number;
+  [Command.ENTITY_MOVE_COOK_LOG_FILE]: void;
+  [Command.ENTITY_REORDER_COOK_LOG_FILE]: void;
+
+  [Command.ENTITY_CREATE_COLLECTION_RECIPE]: number;
+  [Command.ENTITY_READ_COLLECTION_RECIPE]: CollectionRecipeInterface;
+  [Command.ENTITY_UPDATE_COLLECTION_RECIPE]: void;
+  [Command.ENTITY_DELETE_COLLECTION_RECIPE]: void;
+  [Command.ENTITY_CREATE_MANY_COLLECTION_RECIPE]: number[];
+  [Command.ENTITY_UPDATE_MANY_COLLECTION_RECIPE]: void;
+  [Command.ENTITY_DELETE_MANY_COLLECTION_RECIPE]: void;
+  [Command.ENTITY_LIST_COLLECTION_RECIPE]: number[];
+  [Command.ENTITY_LIST_PAGE_COLLECTION_RECIPE]: ListPage<number>;
+  [Command.ENTITY_COUNT_COLLECTION_RECIPE]: number;
+  [Command.ENTITY_MOVE_COLLECTION_RECIPE]: void;
+  [Command.ENTITY_REORDER_COLLECTION_RECIPE]: void;
 
   [Command.ENTITY_CREATE_RECIPE_INGREDIENT_DRAFT]: number;
   [Command.ENTITY_READ_RECIPE_INGREDIENT_DRAFT]: RecipeIngredientDraftInterface;
   [Command.ENTITY_UPDATE_RECIPE_INGREDIENT_DRAFT]: void;
   [Command.ENTITY_DELETE_RECIPE_INGREDIENT_DRAFT]: void;
+  [Command.ENTITY_CREATE_MANY_RECIPE_INGREDIENT_DRAFT]: number[];
+  [Command.ENTITY_UPDATE_MANY_RECIPE_INGREDIENT_DRAFT]: void;
+  [Command.ENTITY_DELETE_MANY_RECIPE_INGREDIENT_DRAFT]: void;
   [Command.ENTITY_LIST_RECIPE_INGREDIENT_DRAFT]: number[];
+  [Command.ENTITY_LIST_PAGE_RECIPE_INGREDIENT_DRAFT]: ListPage<number>;
   [Command.ENTITY_COUNT_RECIPE_INGREDIENT_DRAFT]: number;
+  [Command.ENTITY_MOVE_RECIPE_INGREDIENT_DRAFT]: void;
+  [Command.ENTITY_REORDER_RECIPE_INGREDIENT_DRAFT]: void;
 
   [Command.ENTITY_CREATE_RECIPE_STEP_FILE]: number;
   [Command.ENTITY_READ_RECIPE_STEP_FILE]: RecipeStepFileInterface;
   [Command.ENTITY_UPDATE_RECIPE_STEP_FILE]: void;
   [Command.ENTITY_DELETE_RECIPE_STEP_FILE]: void;
+  [Command.ENTITY_CREATE_MANY_RECIPE_STEP_FILE]: number[];
+  [Command.ENTITY_UPDATE_MANY_RECIPE_STEP_FILE]: void;
+  [Command.ENTITY_DELETE_MANY_RECIPE_STEP_FILE]: void;
   [Command.ENTITY_LIST_RECIPE_STEP_FILE]: number[];
+  [Command.ENTITY_LIST_PAGE_RECIPE_STEP_FILE]: ListPage<number>;
   [Command.ENTITY_COUNT_RECIPE_STEP_FILE]: number;
+  [Command.ENTITY_MOVE_RECIPE_STEP_FILE]: void;
+  [Command.ENTITY_REORDER_RECIPE_STEP_FILE]: void;
 
   [Command.ENTITY_CREATE_RECIPE_STEP_INGREDIENT]: number;
   [Command.ENTITY_READ_RECIPE_STEP_INGREDIENT]: RecipeStepIngredientInterface;
   [Command.ENTITY_UPDATE_RECIPE_STEP_INGREDIENT]: void;
   [Command.ENTITY_DELETE_RECIPE_STEP_INGREDIENT]: void;
+  [Command.ENTITY_CREATE_MANY_RECIPE_STEP_INGREDIENT]: number[];
+  [Command.ENTITY_UPDATE_MANY_RECIPE_STEP_INGREDIENT]: void;
+  [Command.ENTITY_DELETE_MANY_RECIPE_STEP_INGREDIENT]: void;
   [Command.ENTITY_LIST_RECIPE_STEP_INGREDIENT]: number[];
+  [Command.ENTITY_LIST_PAGE_RECIPE_STEP_INGREDIENT]: ListPage<number>;
   [Command.ENTITY_COUNT_RECIPE_STEP_INGREDIENT]: number;
+  [Command.ENTITY_MOVE_RECIPE_STEP_INGREDIENT]: void;
+  [Command.ENTITY_REORDER_RECIPE_STEP_INGREDIENT]: void;
 
   [Command.ENTITY_CREATE_RECIPE_STEP_INGREDIENT_DRAFT]: number;
   [Command.ENTITY_READ_RECIPE_STEP_INGREDIENT_DRAFT]: RecipeStepIngredientDraftInterface;
   [Command.ENTITY_UPDATE_RECIPE_STEP_INGREDIENT_DRAFT]: void;
   [Command.ENTITY_DELETE_RECIPE_STEP_INGREDIENT_DRAFT]: void;
+  [Command.ENTITY_CREATE_MANY_RECIPE_STEP_INGREDIENT_DRAFT]: number[];
+  [Command.ENTITY_UPDATE_MANY_RECIPE_STEP_INGREDIENT_DRAFT]: void;
+  [Command.ENTITY_DELETE_MANY_RECIPE_STEP_INGREDIENT_DRAFT]: void;
   [Command.ENTITY_LIST_RECIPE_STEP_INGREDIENT_DRAFT]: number[];
+  [Command.ENTITY_LIST_PAGE_RECIPE_STEP_INGREDIENT_DRAFT]: ListPage<number>;
   [Command.ENTITY_COUNT_RECIPE_STEP_INGREDIENT_DRAFT]: number;
+  [Command.ENTITY_MOVE_RECIPE_STEP_INGREDIENT_DRAFT]: void;
+  [Command.ENTITY_REORDER_RECIPE_STEP_INGREDIENT_DRAFT]: void;
 
   [Command.ENTITY_CREATE_RECIPE_STEP]: number;
   [Command.ENTITY_READ_RECIPE_STEP]: RecipeStepInterface;
   [Command.ENTITY_UPDATE_RECIPE_STEP]: void;
   [Command.ENTITY_DELETE_RECIPE_STEP]: void;
+  [Command.ENTITY_CREATE_MANY_RECIPE_STEP]: number[];
+  [Command.ENTITY_UPDATE_MANY_RECIPE_STEP]: void;
+  [Command.ENTITY_DELETE_MANY_RECIPE_STEP]: void;
   [Command.ENTITY_LIST_RECIPE_STEP]: number[];
+  [Command.ENTITY_LIST_PAGE_RECIPE_STEP]: ListPage<number>;
   [Command.ENTITY_COUNT_RECIPE_STEP]: number;
+  [Command.ENTITY_MOVE_RECIPE_STEP]: void;
+  [Command.ENTITY_REORDER_RECIPE_STEP]: void;
 
   [Command.ENTITY_CREATE_UNIT_NAME]: string;
   [Command.ENTITY_READ_UNIT_NAME]: UnitNameInterface;
   [Command.ENTITY_UPDATE_UNIT_NAME]: void;
   [Command.ENTITY_DELETE_UNIT_NAME]: void;
+  [Command.ENTITY_CREATE_MANY_UNIT_NAME]: string[];
+  [Command.ENTITY_UPDATE_MANY_UNIT_NAME]: void;
+  [Command.ENTITY_DELETE_MANY_UNIT_NAME]: void;
   [Command.ENTITY_LIST_UNIT_NAME]: string[];
+  [Command.ENTITY_LIST_PAGE_UNIT_NAME]: ListPage<string>;
   [Command.ENTITY_COUNT_UNIT_NAME]: number;
 
   [Command.EXTERNAL_RECIPE]: number;
 
   [Command.OCR]: string;
+  [Command.OCR_STRUCTURED]: OcrOutput;
+  [Command.OCR_RECIPE]: number;
+  [Command.OCR_LANGUAGE_LIST]: OcrLanguage[];
+  [Command.OCR_PREPROCESS]: number;
+
+  [Command.PDF_TEXT]: string;
 
   [Command.UNIT_CONVERT]: UnitConversion;
 
   [Command.UNIT_LIST_GET]: string[];
+
+  [Command.CHANGES_SINCE]: EntityChange[];
+
+  [Command.UNDO]: boolean;
+  [Command.REDO]: boolean;
+
+  [Command.RECIPE_REVISION_SAVE]: number;
+  [Command.RECIPE_REVISION_LIST]: RecipeRevisionSummary[];
+  [Command.RECIPE_REVISION_DIFF]: RevisionDifference[];
+  [Command.RECIPE_REVISION_REVERT]: void;
+
+  [Command.RECIPE_TIME_FILL]: RecipeInterface;
+
+  [Command.NUTRITION_IMPORT]: NutrientImport;
+  [Command.NUTRITION_CALCULATE]: RecipeNutrition;
+
+  [Command.DIETARY_SUMMARY]: DietarySummary;
+
+  [Command.STEP_DURATION_LIST]: StepDuration[];
+  [Command.STEP_DURATION_PARSE]: StepDuration[];
+
+  [Command.TIMER_START]: Timer;
+  [Command.TIMER_CANCEL]: boolean;
+  [Command.TIMER_LIST]: Timer[];
+
+  [Command.SETTINGS_GET]: Settings;
+  [Command.SETTINGS_SET]: void;
 };
+
 export type CommandAnswer<T extends Command> = CommandAnswerMap[T];
@@ -3,6 +3,9 @@ export type CommandError =
   | { OcrInitialize: string }
   | { OcrSetImage: string }
   | { OcrGetHocrText: string }
+  | { OcrLanguageUnavailable: string }
+  | { Ocr: string }
+  | { Pdf: string }
   | { Tauri: string }
   | { Anyhow: string }
   | { ExternalRecipeUrlNotSupported: string }
@@ -1,11 +1,43 @@
+import type {
+  CollectionCreateInterface,
+  CollectionUpdateInterface,
+} from "../../types/entity/collection-interface.ts";
+import type {
+  CollectionRecipeCreateInterface,
+  CollectionRecipeUpdateInterface,
+} from "../../types/entity/collection-recipe-interface.ts";
+import type {
+  CookLogFileCreateInterface,
+  CookLogFileUpdateInterface,
+} from "../../types/entity/cook-log-file-interface.ts";
+import type {
+  CookLogCreateInterface,
+  CookLogUpdateInterface,
+} from "../../types/entity/cook-log-interface.ts";
 import type {
   FileCreateInterface,
   FileUpdateInterface,
 } from "../../types/entity/file-interface.ts";
+import type {
+  IngredientAliasCreateInterface,
+  IngredientAliasUpdateInterface,
+} from "../../types/entity/ingredient-alias-interface.ts";
+import type {
+  IngredientAllergenCreateInterface,
+  IngredientAllergenUpdateInterface,
+} from "../../types/entity/ingredient-allergen-interface.ts";
+import type {
+  IngredientCategoryCreateInterface,
+  IngredientCategoryUpdateInterface,
+} from "../../types/entity/ingredient-category-interface.ts";
 import type {
   IngredientCreateInterface,
   IngredientUpdateInterface,
 } from "../../types/entity/ingredient-interface.ts";
+import type {
+  NutrientProfileCreateInterface,
+  NutrientProfileUpdateInterface,
+} from "../../types/entity/nutrient-profile-interface.ts";
 import type {
   RecipeFileCreateInterface,
   RecipeFileUpdateInterface,
@@ -34,19 +66,59 @@ import type {
   RecipeStepCreateInterface,
   RecipeStepUpdateInterface,
 } from "../../types/entity/recipe-step-interface.ts";
+import type {
+  RecipeTagCreateInterface,
+  RecipeTagUpdateInterface,
+} from "../../types/entity/recipe-tag-interface.ts";
+import type {
+  TagCreateInterface,
+  TagUpdateInterface,
+} from "../../types/entity/tag-interface.ts";
 import type {
   Unit,
   UnitNameCreateInterface,
   UnitNameUpdateInterface,
 } from "../../types/entity/unit-name-interface.ts";
+import type {
+  CollectionCondition,
+  CollectionFilter,
+} from "../../types/filter/collection-filter.ts";
+import type {
+  CollectionRecipeCondition,
+  CollectionRecipeFilter,
+} from "../../types/filter/collection-recipe-filter.ts";
+import type {
+  CookLogFileCondition,
+  CookLogFileFilter,
+} from "../../types/filter/cook-log-file-filter.ts";
+import type {
+  CookLogCondition,
+  CookLogFilter,
+} from "../../types/filter/cook-log-filter.ts";
 import type {
   FileCondition,
   FileFilter,
 } from "../../types/filter/file-filter.ts";
+import type {
+  IngredientAliasCondition,
+  IngredientAliasFilter,
+} from "../../types/filter/ingredient-alias-filter.ts";
+import type {
+  IngredientAllergenCondition,
+  IngredientAllergenFilter,
+} from "../../types/filter/ingredient-allergen-filter.ts";
+import type {
+  IngredientCategoryCondition,
+  IngredientCategoryFilter,
+} from "../../types/filter/ingredient-category-filter.ts";
 import type {
   IngredientCondition,
   IngredientFilter,
 } from "../../types/filter/ingredient-filter.ts";
+import type {
+  NutrientProfileCondition,
+  NutrientProfileFilter,
+} from "../../types/filter/nutrient-profile-filter.ts";
 import type {
   RecipeFileCondition,
   RecipeFileFilter,
@@ -75,10 +147,25 @@ import type {
   RecipeStepIngredientCondition,
   RecipeStepIngredientFilter,
 } from "../../types/filter/recipe-step-ingredient-filter.ts";
+import type {
+  RecipeTagCondition,
+  RecipeTagFilter,
+} from "../../types/filter/recipe-tag-filter.ts";
+import type {
+  TagCondition,
+  TagFilter,
+} from "../../types/filter/tag-filter.ts";
 import type {
   UnitNameCondition,
   UnitNameFilter,
 } from "../../types/filter/unit-name-filter.ts";
+import type {
+  OcrFormat,
+  OcrPreprocessing,
+  OcrSettings,
+} from "../../types/ocr.ts";
+import type { NutrientColumns } from "../../types/nutrition.ts";
+import type { Settings } from "../../types/settings.ts";
 import type { Command } from "./command.ts";
 
 type CommandParameterMap = {
@@ -86,22 +173,197 @@ type CommandParameterMap = {
   [Command.ENTITY_READ_FILE]: { id: number };
   [Command.ENTITY_UPDATE_FILE]: { update: FileUpdateInterface };
   [Command.ENTITY_DELETE_FILE]: { id: number };
+  [Command.ENTITY_CREATE_MANY_FILE]: { creates: FileCreateInterface[] };
+  [Command.ENTITY_UPDATE_MANY_FILE]: { updates: FileUpdateInterface[] };
+  [Command.ENTITY_DELETE_MANY_FILE]: { ids: number[] };
   [Command.ENTITY_LIST_FILE]: { filter: FileFilter };
+  [Command.ENTITY_LIST_PAGE_FILE]: { filter: FileFilter };
   [Command.ENTITY_COUNT_FILE]: { condition?: FileCondition };
 
   [Command.ENTITY_CREATE_INGREDIENT]: { create: IngredientCreateInterface };
   [Command.ENTITY_READ_INGREDIENT]: { id: number };
   [Command.ENTITY_UPDATE_INGREDIENT]: { update: IngredientUpdateInterface };
   [Command.ENTITY_DELETE_INGREDIENT]: { id: number };
+  [Command.ENTITY_CREATE_MANY_INGREDIENT]: {
+    creates: IngredientCreateInterface[];
+  };
+  [Command.ENTITY_UPDATE_MANY_INGREDIENT]: {
+    updates: IngredientUpdateInterface[];
+  };
+  [Command.ENTITY_DELETE_MANY_INGREDIENT]: { ids: number[] };
   [Command.ENTITY_LIST_INGREDIENT]: { filter: IngredientFilter };
+  [Command.ENTITY_LIST_PAGE_INGREDIENT]: { filter: IngredientFilter };
   [Command.ENTITY_COUNT_INGREDIENT]: { condition?: IngredientCondition };
+  [Command.INGREDIENT_MERGE]: { targetId: number; sourceIds: number[] };
+  [Command.INGREDIENT_MERGE_SUGGESTIONS]: undefined;
+
+  [Command.ENTITY_CREATE_INGREDIENT_ALIAS]: {
+    create: IngredientAliasCreateInterface;
+  };
+  [Command.ENTITY_READ_INGREDIENT_ALIAS]: { id: number };
+  [Command.ENTITY_UPDATE_INGREDIENT_ALIAS]: {
+    update: IngredientAliasUpdateInterface;
+  };
+  [Command.ENTITY_DELETE_INGREDIENT_ALIAS]: { id: number };
+  [Command.ENTITY_CREATE_MANY_INGREDIENT_ALIAS]: {
+    creates: IngredientAliasCreateInterface[];
+  };
+  [Command.ENTITY_UPDATE_MANY_INGREDIENT_ALIAS]: {
+    updates: IngredientAliasUpdateInterface[];
+  };
+  [Command.ENTITY_DELETE_MANY_INGREDIENT_ALIAS]: { ids: number[] };
+  [Command.ENTITY_LIST_INGREDIENT_ALIAS]: { filter: IngredientAliasFilter };
+  [Command.ENTITY_LIST_PAGE_INGREDIENT_ALIAS]: {
+    filter: IngredientAliasFilter;
+  };
+  [Command.ENTITY_COUNT_INGREDIENT_ALIAS]: {
+    condition?: IngredientAliasCondition;
+  };
+
+  [Command.ENTITY_CREATE_INGREDIENT_ALLERGEN]: {
+    create: IngredientAllergenCreateInterface;
+  };
+  [Command.ENTITY_READ_INGREDIENT_ALLERGEN]: { id: number };
+  [Command.ENTITY_UPDATE_INGREDIENT_ALLERGEN]: {
+    update: IngredientAllergenUpdateInterface;
+  };
+  [Command.ENTITY_DELETE_INGREDIENT_ALLERGEN]: { id: number };
+  [Command.ENTITY_CREATE_MANY_INGREDIENT_ALLERGEN]: {
+    creates: IngredientAllergenCreateInterface[];
+  };
+  [Command.ENTITY_UPDATE_MANY_INGREDIENT_ALLERGEN]: {
+    updates: IngredientAllergenUpdateInterface[];
+  };
+  [Command.ENTITY_DELETE_MANY_INGREDIENT_ALLERGEN]: { ids: number[] };
+  [Command.ENTITY_LIST_INGREDIENT_ALLERGEN]: {
+    filter: IngredientAllergenFilter;
+  };
+  [Command.ENTITY_LIST_PAGE_INGREDIENT_ALLERGEN]: {
+    filter: IngredientAllergenFilter;
+  };
+  [Command.ENTITY_COUNT_INGREDIENT_ALLERGEN]: {
+    condition?: IngredientAllergenCondition;
+  };
+
+  [Command.ENTITY_CREATE_COOK_LOG]: { create: CookLogCreateInterface };
+  [Command.ENTITY_READ_COOK_LOG]: { id: number };
+  [Command.ENTITY_UPDATE_COOK_LOG]: { update: CookLogUpdateInterface };
+  [Command.ENTITY_DELETE_COOK_LOG]: { id: number };
+  [Command.ENTITY_CREATE_MANY_COOK_LOG]: { creates: CookLogCreateInterface[] };
+  [Command.ENTITY_UPDATE_MANY_COOK_LOG]: { updates: CookLogUpdateInterface[] };
+  [Command.ENTITY_DELETE_MANY_COOK_LOG]: { ids: number[] };
+  [Command.ENTITY_LIST_COOK_LOG]: { filter: CookLogFilter };
+  [Command.ENTITY_LIST_PAGE_COOK_LOG]: { filter: CookLogFilter };
+  [Command.ENTITY_COUNT_COOK_LOG]: { condition?: CookLogCondition };
+
+  [Command.ENTITY_CREATE_COLLECTION]: { create: CollectionCreateInterface };
+  [Command.ENTITY_READ_COLLECTION]: { id: number };
+  [Command.ENTITY_UPDATE_COLLECTION]: { update: CollectionUpdateInterface };
+  [Command.ENTITY_DELETE_COLLECTION]: { id: number };
+  [Command.ENTITY_CREATE_MANY_COLLECTION]: {
+    creates: CollectionCreateInterface[];
+  };
+  [Command.ENTITY_UPDATE_MANY_COLLECTION]: {
+    updates: CollectionUpdateInterface[];
+  };
+  [Command.ENTITY_DELETE_MANY_COLLECTION]: { ids: number[] };
+  [Command.ENTITY_LIST_COLLECTION]: { filter: CollectionFilter };
+  [Command.ENTITY_LIST_PAGE_COLLECTION]: { filter: CollectionFilter };
+  [Command.ENTITY_COUNT_COLLECTION]: { condition?: CollectionCondition };
+  [Command.COLLECTION_EXPORT]: { id: number };
+
+  [Command.ENTITY_CREATE_TAG]: { create: TagCreateInterface };
+  [Command.ENTITY_READ_TAG]: { id: number };
+  [Command.ENTITY_UPDATE_TAG]: { update: TagUpdateInterface };
+  [Command.ENTITY_DELETE_TAG]: { id: number };
+  [Command.ENTITY_CREATE_MANY_TAG]: { creates: TagCreateInterface[] };
+  [Command.ENTITY_UPDATE_MANY_TAG]: { updates: TagUpdateInterface[] };
+  [Command.ENTITY_DELETE_MANY_TAG]: { ids: number[] };
+  [Command.ENTITY_LIST_TAG]: { filter: TagFilter };
+  [Command.ENTITY_LIST_PAGE_TAG]: { filter: TagFilter };
+  [Command.ENTITY_COUNT_TAG]: { condition?: TagCondition };
+  [Command.TAG_RENAME]: { id: number; name: string };
+  [Command.TAG_CLOUD]: undefined;
+
+  [Command.ENTITY_CREATE_RECIPE_TAG]: { create: RecipeTagCreateInterface };
+  [Command.ENTITY_READ_RECIPE_TAG]: { id: number };
+  [Command.ENTITY_UPDATE_RECIPE_TAG]: { update: RecipeTagUpdateInterface };
+  [Command.ENTITY_DELETE_RECIPE_TAG]: { id: number };
+  [Command.ENTITY_CREATE_MANY_RECIPE_TAG]: {
+    creates: RecipeTagCreateInterface[];
+  };
+  [Command.ENTITY_UPDATE_MANY_RECIPE_TAG]: {
+    updates: RecipeTagUpdateInterface[];
+  };
+  [Command.ENTITY_DELETE_MANY_RECIPE_TAG]: { ids: number[] };
+  [Command.ENTITY_LIST_RECIPE_TAG]: { filter: RecipeTagFilter };
+  [Command.ENTITY_LIST_PAGE_RECIPE_TAG]: { filter: RecipeTagFilter };
+  [Command.ENTITY_COUNT_RECIPE_TAG]: { condition?: RecipeTagCondition };
+
+  [Command.ENTITY_CREATE_INGREDIENT_CATEGORY]: {
+    create: IngredientCategoryCreateInterface;
+  };
+  [Command.ENTITY_READ_INGREDIENT_CATEGORY]: { id: number };
+  [Command.ENTITY_UPDATE_INGREDIENT_CATEGORY]: {
+    update: IngredientCategoryUpdateInterface;
+  };
+  [Command.ENTITY_DELETE_INGREDIENT_CATEGORY]: { id: number };
+  [Command.ENTITY_CREATE_MANY_INGREDIENT_CATEGORY]: {
+    creates: IngredientCategoryCreateInterface[];
+  };
+  [Command.ENTITY_UPDATE_MANY_INGREDIENT_CATEGORY]: {
+    updates: IngredientCategoryUpdateInterface[];
+  };
+  [Command.ENTITY_DELETE_MANY_INGREDIENT_CATEGORY]: { ids: number[] };
+  [Command.ENTITY_LIST_INGREDIENT_CATEGORY]: {
+    filter: IngredientCategoryFilter;
+  };
+  [Command.ENTITY_LIST_PAGE_INGREDIENT_CATEGORY]: {
+    filter: IngredientCategoryFilter;
+  };
+  [Command.ENTITY_COUNT_INGREDIENT_CATEGORY]: {
+    condition?: IngredientCategoryCondition;
+  };
+
+  [Command.ENTITY_CREATE_NUTRIENT_PROFILE]: {
+    create: NutrientProfileCreateInterface;
+  };
+  [Command.ENTITY_READ_NUTRIENT_PROFILE]: { id: number };
+  [Command.ENTITY_UPDATE_NUTRIENT_PROFILE]: {
+    update: NutrientProfileUpdateInterface;
+  };
+  [Command.ENTITY_DELETE_NUTRIENT_PROFILE]: { id: number };
+  [Command.ENTITY_CREATE_MANY_NUTRIENT_PROFILE]: {
+    creates: NutrientProfileCreateInterface[];
+  };
+  [Command.ENTITY_UPDATE_MANY_NUTRIENT_PROFILE]: {
+    updates: NutrientProfileUpdateInterface[];
+  };
+  [Command.ENTITY_DELETE_MANY_NUTRIENT_PROFILE]: { ids: number[] };
+  [Command.ENTITY_LIST_NUTRIENT_PROFILE]: { filter: NutrientProfileFilter; };
+  [Command.ENTITY_LIST_PAGE_NUTRIENT_PROFILE]: {
+    filter: NutrientProfileFilter;
+  };
+  [Command.ENTITY_COUNT_NUTRIENT_PROFILE]: {
+    condition?: NutrientProfileCondition;
+  };
 
   [Command.ENTITY_CREATE_RECIPE]: { create: RecipeCreateInterface };
   [Command.ENTITY_READ_RECIPE]: { id: number };
   [Command.ENTITY_UPDATE_RECIPE]: { update: RecipeUpdateInterface };
   [Command.ENTITY_DELETE_RECIPE]: { id: number };
+  [Command.ENTITY_CREATE_MANY_RECIPE]: { creates: RecipeCreateInterface[] };
+  [Command.ENTITY_UPDATE_MANY_RECIPE]: { updates: RecipeUpdateInterface[] };
+  [Command.ENTITY_DELETE_MANY_RECIPE]: { ids: number[] };
   [Command.ENTITY_LIST_RECIPE]: { filter: RecipeFilter };
+  [Command.ENTITY_LIST_PAGE_RECIPE]: { filter: RecipeFilter };
   [Command.ENTITY_COUNT_RECIPE]: { condition?: RecipeCondition };
+  [Command.ENTITY_TRASH_RECIPE]: { id: number };
+  [Command.ENTITY_RESTORE_RECIPE]: { id: number };
+  [Command.ENTITY_PURGE_RECIPE]: { ids: number[] };
+  [Command.ENTITY_PURGE_TRASH_RECIPE]: { olderThanDays?: number };
+  [Command.RECIPE_DUPLICATE]: { id: number; name?: string; fork: boolean };
+  [Command.RECIPE_EXPORT]: { id: number };
 
   [Command.ENTITY_CREATE_RECIPE_FILE]: {
     create: RecipeFileCreateInterface;
@@ -111,12 +373,69 @@ type CommandParameterMap = {
     update: RecipeFileUpdateInterface;
   };
   [Command.ENTITY_DELETE_RECIPE_FILE]: { id: number };
+  [Command.ENTITY_CREATE_MANY_RECIPE_FILE]: {
+    creates: RecipeFileCreateInterface[];
+  };
+  [Command.ENTITY_UPDATE_MANY_RECIPE_FILE]: {
+    updates: RecipeFileUpdateInterface[];
+  };
+  [Command.ENTITY_DELETE_MANY_RECIPE_FILE]: { ids: number[] };
   [Command.ENTITY_LIST_RECIPE_FILE]: {
     filter: RecipeFileFilter;
   };
+  [Command.ENTITY_LIST_PAGE_RECIPE_FILE]: {
+    filter: RecipeFileFilter;
+  };
   [Command.ENTITY_COUNT_RECIPE_FILE]: {
     condition?: RecipeFileCondition;
   };
+  [Command.ENTITY_MOVE_RECIPE_FILE]: { id: number; position: number };
+  [Command.ENTITY_REORDER_RECIPE_FILE]: { recipeId: number; ids: number[] };
+
+  [Command.ENTITY_CREATE_COOK_LOG_FILE]: { create: CookLogFileCreateInterface };
+  [Command.ENTITY_READ_COOK_LOG_FILE]: { id: number };
+  [Command.ENTITY_UPDATE_COOK_LOG_FILE]: { update: CookLogFileUpdateInterface };
+  [Command.ENTITY_DELETE_COOK_LOG_FILE]: { id: number };
+  [Command.ENTITY_CREATE_MANY_COOK_LOG_FILE]: {
+    creates: CookLogFileCreateInterface[];
+  };
+  [Command.ENTITY_UPDATE_MANY_COOK_LOG_FILE]: {
+    updates: CookLogFileUpdateInterface[];
+  };
+  [Command.ENTITY_DELETE_MANY_COOK_LOG_FILE]: { ids: number[] };
+  [Command.ENTITY_LIST_COOK_LOG_FILE]: { filter: CookLogFileFilter };
+  [Command.ENTITY_LIST_PAGE_COOK_LOG_FILE]: { filter: CookLogFileFilter };
+  [Command.ENTITY_COUNT_COOK_LOG_FILE]: { condition?: CookLogFileCondition };
+  [Command.ENTITY_MOVE_COOK_LOG_FILE]: { id: number; position: number };
+  [Command.ENTITY_REORDER_COOK_LOG_FILE]: { cookLogId: number; ids: number[] };
+
+  [Command.ENTITY_CREATE_COLLECTION_RECIPE]: {
+    create: CollectionRecipeCreateInterface;
+  };
+  [Command.ENTITY_READ_COLLECTION_RECIPE]: { id: number };
+  [Command.ENTITY_UPDATE_COLLECTION_RECIPE]: {
+    update: CollectionRecipeUpdateInterface;
+  };
+  [Command.ENTITY_DELETE_COLLECTION_RECIPE]: { id: number };
+  [Command.ENTITY_CREATE_MANY_COLLECTION_RECIPE]: {
+    creates: CollectionRecipeCreateInterface[];
+  };
+  [Command.ENTITY_UPDATE_MANY_COLLECTION_RECIPE]: {
+    updates: CollectionRecipeUpdateInterface[];
+  };
+  [Command.ENTITY_DELETE_MANY_COLLECTION_RECIPE]: { ids: number[] };
+  [Command.ENTITY_LIST_COLLECTION_RECIPE]: { filter: CollectionRecipeFilter };
+  [Command.ENTITY_LIST_PAGE_COLLECTION_RECIPE]: {
+    filter: CollectionRecipeFilter;
+  };
+  [Command.ENTITY_COUNT_COLLECTION_RECIPE]: {
+    condition?: CollectionRecipeCondition;
+  };
+  [Command.ENTITY_MOVE_COLLECTION_RECIPE]: { id: number; position: number };
+  [Command.ENTITY_REORDER_COLLECTION_RECIPE]: {
+    collectionId: number;
+    ids: number[];
+  };
 
   [Command.ENTITY_CREATE_RECIPE_INGREDIENT_DRAFT]: {
     create: RecipeIngredientDraftCreateInterface;
@@ -126,12 +445,30 @@ type CommandParameterMap = {
     update: RecipeIngredientDraftUpdateInterface;
   };
   [Command.ENTITY_DELETE_RECIPE_INGREDIENT_DRAFT]: { id: number };
+  [Command.ENTITY_CREATE_MANY_RECIPE_INGREDIENT_DRAFT]: {
+    creates: RecipeIngredientDraftCreateInterface[];
+  };
+  [Command.ENTITY_UPDATE_MANY_RECIPE_INGREDIENT_DRAFT]: {
+    updates: RecipeIngredientDraftUpdateInterface[];
+  };
+  [Command.ENTITY_DELETE_MANY_RECIPE_INGREDIENT_DRAFT]: { ids: number[] };
   [Command.ENTITY_LIST_RECIPE_INGREDIENT_DRAFT]: {
     filter: RecipeIngredientDraftFilter;
   };
+  [Command.ENTITY_LIST_PAGE_RECIPE_INGREDIENT_DRAFT]: {
+    filter: RecipeIngredientDraftFilter;
+  };
   [Command.ENTITY_COUNT_RECIPE_INGREDIENT_DRAFT]: {
     condition?: RecipeIngredientDraftCondition;
   };
+  [Command.ENTITY_MOVE_RECIPE_INGREDIENT_DRAFT]: {
+    id: number;
+    position: number;
+  };
+  [Command.ENTITY_REORDER_RECIPE_INGREDIENT_DRAFT]: {
+    recipeId: number;
+    ids: number[];
+  };
 
   [Command.ENTITY_CREATE_RECIPE_STEP_FILE]: {
     create: RecipeStepFileCreateInterface;
@@ -141,12 +478,27 @@ type CommandParameterMap = {
     update: RecipeStepFileUpdateInterface;
   };
   [Command.ENTITY_DELETE_RECIPE_STEP_FILE]: { id: number };
+  [Command.ENTITY_CREATE_MANY_RECIPE_STEP_FILE]: {
+    creates: RecipeStepFileCreateInterface[];
+  };
+  [Command.ENTITY_UPDATE_MANY_RECIPE_STEP_FILE]: {
+    updates: RecipeStepFileUpdateInterface[];
+  };
+  [Command.ENTITY_DELETE_MANY_RECIPE_STEP_FILE]: { ids: number[] };
   [Command.ENTITY_LIST_RECIPE_STEP_FILE]: {
     filter: RecipeStepFileFilter;
   };
+  [Command.ENTITY_LIST_PAGE_RECIPE_STEP_FILE]: {
+    filter: RecipeStepFileFilter;
+  };
   [Command.ENTITY_COUNT_RECIPE_STEP_FILE]: {
     condition?: RecipeStepFileCondition;
   };
+  [Command.ENTITY_MOVE_RECIPE_STEP_FILE]: { id: number; position: number };
+  [Command.ENTITY_REORDER_RECIPE_STEP_FILE]: {
+    recipeStepId: number;
+    ids: number[];
+  };
 
   [Command.ENTITY_CREATE_RECIPE_STEP_INGREDIENT]: {
     create: RecipeStepIngredientCreateInterface;
@@ -156,12 +508,30 @@ type CommandParameterMap = {
     update: RecipeStepIngredientUpdateInterface;
   };
   [Command.ENTITY_DELETE_RECIPE_STEP_INGREDIENT]: { id: number };
+  [Command.ENTITY_CREATE_MANY_RECIPE_STEP_INGREDIENT]: {
+    creates: RecipeStepIngredientCreateInterface[];
+  };
+  [Command.ENTITY_UPDATE_MANY_RECIPE_STEP_INGREDIENT]: {
+    updates: RecipeStepIngredientUpdateInterface[];
+  };
+  [Command.ENTITY_DELETE_MANY_RECIPE_STEP_INGREDIENT]: { ids: number[] };
   [Command.ENTITY_LIST_RECIPE_STEP_INGREDIENT]: {
     filter: RecipeStepIngredientFilter;
   };
+  [Command.ENTITY_LIST_PAGE_RECIPE_STEP_INGREDIENT]: {
+    filter: RecipeStepIngredientFilter;
+  };
   [Command.ENTITY_COUNT_RECIPE_STEP_INGREDIENT]: {
     condition?: RecipeStepIngredientCondition;
   };
+  [Command.ENTITY_MOVE_RECIPE_STEP_INGREDIENT]: {
+    id: number;
+    position: number;
+  };
+  [Command.ENTITY_REORDER_RECIPE_STEP_INGREDIENT]: {
+    recipeStepId: number;
+    ids: number[];
+  };
 
   [Command.ENTITY_CREATE_RECIPE_STEP_INGREDIENT_DRAFT]: {
     create: RecipeStepIngredientDraftCreateInterface;
@@ -171,33 +541,121 @@ type CommandParameterMap = {
     update: RecipeStepIngredientDraftUpdateInterface;
   };
   [Command.ENTITY_DELETE_RECIPE_STEP_INGREDIENT_DRAFT]: { id: number };
+  [Command.ENTITY_CREATE_MANY_RECIPE_STEP_INGREDIENT_DRAFT]: {
+    creates: RecipeStepIngredientDraftCreateInterface[];
+  };
+  [Command.ENTITY_UPDATE_MANY_RECIPE_STEP_INGREDIENT_DRAFT]: {
+    updates: RecipeStepIngredientDraftUpdateInterface[];
+  };
+  [Command.ENTITY_DELETE_MANY_RECIPE_STEP_INGREDIENT_DRAFT]: { ids: number[] };
   [Command.ENTITY_LIST_RECIPE_STEP_INGREDIENT_DRAFT]: {
     filter: RecipeStepIngredientDraftFilter;
   };
+  [Command.ENTITY_LIST_PAGE_RECIPE_STEP_INGREDIENT_DRAFT]: {
+    filter: RecipeStepIngredientDraftFilter;
+  };
   [Command.ENTITY_COUNT_RECIPE_STEP_INGREDIENT_DRAFT]: {
     condition?: RecipeStepIngredientDraftCondition;
   };
+  [Command.ENTITY_MOVE_RECIPE_STEP_INGREDIENT_DRAFT]: {
+    id: number;
+    position: number;
+  };
+  [Command.ENTITY_REORDER_RECIPE_STEP_INGREDIENT_DRAFT]: {
+    recipeStepId: number;
+    ids: number[];
+  };
 
   [Command.ENTITY_CREATE_RECIPE_STEP]: { create: RecipeStepCreateInterface };
   [Command.ENTITY_READ_RECIPE_STEP]: { id: number };
   [Command.ENTITY_UPDATE_RECIPE_STEP]: { update: RecipeStepUpdateInterface };
   [Command.ENTITY_DELETE_RECIPE_STEP]: { id: number };
+  [Command.ENTITY_CREATE_MANY_RECIPE_STEP]: {
+    creates: RecipeStepCreateInterface[];
+  };
+  [Command.ENTITY_UPDATE_MANY_RECIPE_STEP]: {
+    updates: RecipeStepUpdateInterface[];
+  };
+  [Command.ENTITY_DELETE_MANY_RECIPE_STEP]: { ids: number[] };
   [Command.ENTITY_LIST_RECIPE_STEP]: { filter: RecipeStepFilter };
+  [Command.ENTITY_LIST_PAGE_RECIPE_STEP]: { filter: RecipeStepFilter };
   [Command.ENTITY_COUNT_RECIPE_STEP]: { condition?: RecipeStepCondition };
+  [Command.ENTITY_MOVE_RECIPE_STEP]: { id: number; position: number };
+  [Command.ENTITY_REORDER_RECIPE_STEP]: { recipeId: number; ids: number[] };
 
   [Command.ENTITY_CREATE_UNIT_NAME]: { create: UnitNameCreateInterface };
   [Command.ENTITY_READ_UNIT_NAME]: { id: string };
   [Command.ENTITY_UPDATE_UNIT_NAME]: { update: UnitNameUpdateInterface };
   [Command.ENTITY_DELETE_UNIT_NAME]: { id: string };
+  [Command.ENTITY_CREATE_MANY_UNIT_NAME]: {
+    creates: UnitNameCreateInterface[];
+  };
+  [Command.ENTITY_UPDATE_MANY_UNIT_NAME]: {
+    updates: UnitNameUpdateInterface[];
+  };
+  [Command.ENTITY_DELETE_MANY_UNIT_NAME]: { ids: string[] };
   [Command.ENTITY_LIST_UNIT_NAME]: { filter: UnitNameFilter };
+  [Command.ENTITY_LIST_PAGE_UNIT_NAME]: { filter: UnitNameFilter };
   [Command.ENTITY_COUNT_UNIT_NAME]: { condition?: UnitNameCondition };
 
   [Command.EXTERNAL_RECIPE]: { url: string };
 
-  [Command.OCR]: { fileId: number };
+  [Command.OCR]: {
+    fileId: number;
+    language?: string;
+    settings?: OcrSettings;
+  };
+  [Command.OCR_STRUCTURED]: {
+    fileId: number;
+    language?: string;
+    settings?: OcrSettings;
+    format: OcrFormat;
+    minConfidence?: number;
+  };
+  [Command.OCR_RECIPE]: {
+    fileId: number;
+    language?: string;
+    preprocessing?: OcrPreprocessing;
+  };
+  [Command.OCR_LANGUAGE_LIST]: undefined;
+  [Command.OCR_PREPROCESS]: {
+    fileId: number;
+    language?: string;
+    preprocessing: OcrPreprocessing;
+    recipeStepId?: number;
+  };
+
+  [Command.PDF_TEXT]: { fileId: number; language?: string };
 
   [Command.UNIT_CONVERT]: { value: number; unit: Unit };
 
   [Command.UNIT_LIST_GET]: undefined;
+
+  [Command.CHANGES_SINCE]: { seq: number };
+
+  [Command.UNDO]: undefined;
+  [Command.REDO]: undefined;
+
+  [Command.RECIPE_REVISION_SAVE]: { recipeId: number };
+  [Command.RECIPE_REVISION_LIST]: { recipeId: number };
+  [Command.RECIPE_REVISION_DIFF]: { oldId: number; newId: number };
+  [Command.RECIPE_REVISION_REVERT]: { id: number };
+
+  [Command.RECIPE_TIME_FILL]: { recipeId: number };
+
+  [Command.NUTRITION_IMPORT]: { path: string; columns: NutrientColumns };
+  [Command.NUTRITION_CALCULATE]: { recipeId: number; servings?: number };
+
+  [Command.DIETARY_SUMMARY]: { recipeId: number };
+
+  [Command.STEP_DURATION_LIST]: { recipeStepId: number };
+  [Command.STEP_DURATION_PARSE]: { text: string };
+
+  [Command.TIMER_START]: { name: string; duration: number };
+  [Command.TIMER_CANCEL]: { id: string };
+  [Command.TIMER_LIST]: undefined;
+
+  [Command.SETTINGS_GET]: undefined;
+  [Command.SETTINGS_SET]: { settings: Settings };
 };
 export type CommandParameter<T extends Command> = CommandParameterMap[T];
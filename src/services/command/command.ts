@@ -3,77 +3,287 @@ export const enum Command {
   ENTITY_READ_FILE = "entity_read_file",
   ENTITY_UPDATE_FILE = "entity_update_file",
   ENTITY_DELETE_FILE = "entity_delete_file",
+  ENTITY_CREATE_MANY_FILE = "entity_create_many_file",
+  ENTITY_UPDATE_MANY_FILE = "entity_update_many_file",
+  ENTITY_DELETE_MANY_FILE = "entity_delete_many_file",
   ENTITY_LIST_FILE = "entity_list_file",
+  ENTITY_LIST_PAGE_FILE = "entity_list_page_file",
   ENTITY_COUNT_FILE = "entity_count_file",
 
   ENTITY_CREATE_INGREDIENT = "entity_create_ingredient",
   ENTITY_READ_INGREDIENT = "entity_read_ingredient",
   ENTITY_UPDATE_INGREDIENT = "entity_update_ingredient",
   ENTITY_DELETE_INGREDIENT = "entity_delete_ingredient",
+  ENTITY_CREATE_MANY_INGREDIENT = "entity_create_many_ingredient",
+  ENTITY_UPDATE_MANY_INGREDIENT = "entity_update_many_ingredient",
+  ENTITY_DELETE_MANY_INGREDIENT = "entity_delete_many_ingredient",
   ENTITY_LIST_INGREDIENT = "entity_list_ingredient",
+  ENTITY_LIST_PAGE_INGREDIENT = "entity_list_page_ingredient",
   ENTITY_COUNT_INGREDIENT = "entity_count_ingredient",
+  INGREDIENT_MERGE = "ingredient_merge",
+  INGREDIENT_MERGE_SUGGESTIONS = "ingredient_merge_suggestions",
+
+  ENTITY_CREATE_INGREDIENT_ALIAS = "entity_create_ingredient_alias",
+  ENTITY_READ_INGREDIENT_ALIAS = "entity_read_ingredient_alias",
+  ENTITY_UPDATE_INGREDIENT_ALIAS = "entity_update_ingredient_alias",
+  ENTITY_DELETE_INGREDIENT_ALIAS = "entity_delete_ingredient_alias",
+  ENTITY_CREATE_MANY_INGREDIENT_ALIAS = "entity_create_many_ingredient_alias",
+  ENTITY_UPDATE_MANY_INGREDIENT_ALIAS = "entity_update_many_ingredient_alias",
+  ENTITY_DELETE_MANY_INGREDIENT_ALIAS = "entity_delete_many_ingredient_alias",
+  ENTITY_LIST_INGREDIENT_ALIAS = "entity_list_ingredient_alias",
+  ENTITY_LIST_PAGE_INGREDIENT_ALIAS = "entity_list_page_ingredient_alias",
+  ENTITY_COUNT_INGREDIENT_ALIAS = "entity_count_ingredient_alias",
+
+  ENTITY_CREATE_INGREDIENT_ALLERGEN = "entity_create_ingredient_allergen",
+  ENTITY_READ_INGREDIENT_ALLERGEN = "entity_read_ingredient_allergen",
+  ENTITY_UPDATE_INGREDIENT_ALLERGEN = "entity_update_ingredient_allergen",
+  ENTITY_DELETE_INGREDIENT_ALLERGEN = "entity_delete_ingredient_allergen",
+  ENTITY_CREATE_MANY_INGREDIENT_ALLERGEN = "entity_create_many_ingredient_allergen",
+  ENTITY_UPDATE_MANY_INGREDIENT_ALLERGEN = "entity_update_many_ingredient_allergen",
+  ENTITY_DELETE_MANY_INGREDIENT_ALLERGEN = "entity_delete_many_ingredient_allergen",
+  ENTITY_LIST_INGREDIENT_ALLERGEN = "entity_list_ingredient_allergen",
+  ENTITY_LIST_PAGE_INGREDIENT_ALLERGEN = "entity_list_page_ingredient_allergen",
+  ENTITY_COUNT_INGREDIENT_ALLERGEN = "entity_count_ingredient_allergen",
+
+  ENTITY_CREATE_COOK_LOG = "entity_create_cook_log",
+  ENTITY_READ_COOK_LOG = "entity_read_cook_log",
+  ENTITY_UPDATE_COOK_LOG = "entity_update_cook_log",
+  ENTITY_DELETE_COOK_LOG = "entity_delete_cook_log",
+  ENTITY_CREATE_MANY_COOK_LOG = "entity_create_many_cook_log",
+  ENTITY_UPDATE_MANY_COOK_LOG = "entity_update_many_cook_log",
+  ENTITY_DELETE_MANY_COOK_LOG = "entity_delete_many_cook_log",
+  ENTITY_LIST_COOK_LOG = "entity_list_cook_log",
+  ENTITY_LIST_PAGE_COOK_LOG = "entity_list_page_cook_log",
+  ENTITY_COUNT_COOK_LOG = "entity_count_cook_log",
+
+  ENTITY_CREATE_COLLECTION = "entity_create_collection",
+  ENTITY_READ_COLLECTION = "entity_read_collection",
+  ENTITY_UPDATE_COLLECTION = "entity_update_collection",
+  ENTITY_DELETE_COLLECTION = "entity_delete_collection",
+  ENTITY_CREATE_MANY_COLLECTION = "entity_create_many_collection",
+  ENTITY_UPDATE_MANY_COLLECTION = "entity_update_many_collection",
+  ENTITY_DELETE_MANY_COLLECTION = "entity_delete_many_collection",
+  ENTITY_LIST_COLLECTION = "entity_list_collection",
+  ENTITY_LIST_PAGE_COLLECTION = "entity_list_page_collection",
+  ENTITY_COUNT_COLLECTION = "entity_count_collection",
+  COLLECTION_EXPORT = "collection_export",
+
+  ENTITY_CREATE_TAG = "entity_create_tag",
+  ENTITY_READ_TAG = "entity_read_tag",
+  ENTITY_UPDATE_TAG = "entity_update_tag",
+  ENTITY_DELETE_TAG = "entity_delete_tag",
+  ENTITY_CREATE_MANY_TAG = "entity_create_many_tag",
+  ENTITY_UPDATE_MANY_TAG = "entity_update_many_tag",
+  ENTITY_DELETE_MANY_TAG = "entity_delete_many_tag",
+  ENTITY_LIST_TAG = "entity_list_tag",
+  ENTITY_LIST_PAGE_TAG = "entity_list_page_tag",
+  ENTITY_COUNT_TAG = "entity_count_tag",
+  TAG_RENAME = "tag_rename",
+  TAG_CLOUD = "tag_cloud",
+
+  ENTITY_CREATE_RECIPE_TAG = "entity_create_recipe_tag",
+  ENTITY_READ_RECIPE_TAG = "entity_read_recipe_tag",
+  ENTITY_UPDATE_RECIPE_TAG = "entity_update_recipe_tag",
+  ENTITY_DELETE_RECIPE_TAG = "entity_delete_recipe_tag",
+  ENTITY_CREATE_MANY_RECIPE_TAG = "entity_create_many_recipe_tag",
+  ENTITY_UPDATE_MANY_RECIPE_TAG = "entity_update_many_recipe_tag",
+  ENTITY_DELETE_MANY_RECIPE_TAG = "entity_delete_many_recipe_tag",
+  ENTITY_LIST_RECIPE_TAG = "entity_list_recipe_tag",
+  ENTITY_LIST_PAGE_RECIPE_TAG = "entity_list_page_recipe_tag",
+  ENTITY_COUNT_RECIPE_TAG = "entity_count_recipe_tag",
+
+  ENTITY_CREATE_INGREDIENT_CATEGORY = "entity_create_ingredient_category",
+  ENTITY_READ_INGREDIENT_CATEGORY = "entity_read_ingredient_category",
+  ENTITY_UPDATE_INGREDIENT_CATEGORY = "entity_update_ingredient_category",
+  ENTITY_DELETE_INGREDIENT_CATEGORY = "entity_delete_ingredient_category",
+  ENTITY_CREATE_MANY_INGREDIENT_CATEGORY = "entity_create_many_ingredient_category",
+  ENTITY_UPDATE_MANY_INGREDIENT_CATEGORY = "entity_update_many_ingredient_category",
+  ENTITY_DELETE_MANY_INGREDIENT_CATEGORY = "entity_delete_many_ingredient_category",
+  ENTITY_LIST_INGREDIENT_CATEGORY = "entity_list_ingredient_category",
+  ENTITY_LIST_PAGE_INGREDIENT_CATEGORY = "entity_list_page_ingredient_category",
+  ENTITY_COUNT_INGREDIENT_CATEGORY = "entity_count_ingredient_category",
+
+  ENTITY_CREATE_NUTRIENT_PROFILE = "entity_create_nutrient_profile",
+  ENTITY_READ_NUTRIENT_PROFILE = "entity_read_nutrient_profile",
+  ENTITY_UPDATE_NUTRIENT_PROFILE = "entity_update_nutrient_profile",
+  ENTITY_DELETE_NUTRIENT_PROFILE = "entity_delete_nutrient_profile",
+  ENTITY_CREATE_MANY_NUTRIENT_PROFILE = "entity_create_many_nutrient_profile",
+  ENTITY_UPDATE_MANY_NUTRIENT_PROFILE = "entity_update_many_nutrient_profile",
+  ENTITY_DELETE_MANY_NUTRIENT_PROFILE = "entity_delete_many_nutrient_profile",
+  ENTITY_LIST_NUTRIENT_PROFILE = "entity_list_nutrient_profile",
+  ENTITY_LIST_PAGE_NUTRIENT_PROFILE = "entity_list_page_nutrient_profile",
+  ENTITY_COUNT_NUTRIENT_PROFILE = "entity_count_nutrient_profile",
 
   ENTITY_CREATE_RECIPE = "entity_create_recipe",
   ENTITY_READ_RECIPE = "entity_read_recipe",
   ENTITY_UPDATE_RECIPE = "entity_update_recipe",
   ENTITY_DELETE_RECIPE = "entity_delete_recipe",
+  ENTITY_CREATE_MANY_RECIPE = "entity_create_many_recipe",
+  ENTITY_UPDATE_MANY_RECIPE = "entity_update_many_recipe",
+  ENTITY_DELETE_MANY_RECIPE = "entity_delete_many_recipe",
   ENTITY_LIST_RECIPE = "entity_list_recipe",
+  ENTITY_LIST_PAGE_RECIPE = "entity_list_page_recipe",
   ENTITY_COUNT_RECIPE = "entity_count_recipe",
+  ENTITY_TRASH_RECIPE = "entity_trash_recipe",
+  ENTITY_RESTORE_RECIPE = "entity_restore_recipe",
+  ENTITY_PURGE_RECIPE = "entity_purge_recipe",
+  ENTITY_PURGE_TRASH_RECIPE = "entity_purge_trash_recipe",
+  RECIPE_DUPLICATE = "recipe_duplicate",
+  RECIPE_EXPORT = "recipe_export",
 
   ENTITY_CREATE_RECIPE_FILE = "entity_create_recipe_file",
   ENTITY_READ_RECIPE_FILE = "entity_read_recipe_file",
   ENTITY_UPDATE_RECIPE_FILE = "entity_update_recipe_file",
   ENTITY_DELETE_RECIPE_FILE = "entity_delete_recipe_file",
+  ENTITY_CREATE_MANY_RECIPE_FILE = "entity_create_many_recipe_file",
+  ENTITY_UPDATE_MANY_RECIPE_FILE = "entity_update_many_recipe_file",
+  ENTITY_DELETE_MANY_RECIPE_FILE = "entity_delete_many_recipe_file",
   ENTITY_LIST_RECIPE_FILE = "entity_list_recipe_file",
+  ENTITY_LIST_PAGE_RECIPE_FILE = "entity_list_page_recipe_file",
   ENTITY_COUNT_RECIPE_FILE = "entity_count_recipe_file",
+  ENTITY_MOVE_RECIPE_FILE = "entity_move_recipe_file",
+  ENTITY_REORDER_RECIPE_FILE = "entity_reorder_recipe_file",
+
+  ENTITY_CREATE_COOK_LOG_FILE = "entity_create_cook_log_file",
+  ENTITY_READ_COOK_LOG_FILE = "entity_read_cook_log_file",
+  ENTITY_UPDATE_COOK_LOG_FILE = "entity_update_cook_log_file",
+  ENTITY_DELETE_COOK_LOG_FILE = "entity_delete_cook_log_file",
+  ENTITY_CREATE_MANY_COOK_LOG_FILE = "entity_create_many_cook_log_file",
+  ENTITY_UPDATE_MANY_COOK_LOG_FILE = "entity_update_many_cook_log_file",
+  ENTITY_DELETE_MANY_COOK_LOG_FILE = "entity_delete_many_cook_log_file",
+  ENTITY_LIST_COOK_LOG_FILE = "entity_list_cook_log_file",
+  ENTITY_LIST_PAGE_COOK_LOG_FILE = "entity_list_page_cook_log_file",
+  ENTITY_COUNT_COOK_LOG_FILE = "entity_count_cook_log_file",
+  ENTITY_MOVE_COOK_LOG_FILE = "entity_move_cook_log_file",
+  ENTITY_REORDER_COOK_LOG_FILE = "entity_reorder_cook_log_file",
+
+  ENTITY_CREATE_COLLECTION_RECIPE = "entity_create_collection_recipe",
+  ENTITY_READ_COLLECTION_RECIPE = "entity_read_collection_recipe",
+  ENTITY_UPDATE_COLLECTION_RECIPE = "entity_update_collection_recipe",
+  ENTITY_DELETE_COLLECTION_RECIPE = "entity_delete_collection_recipe",
+  ENTITY_CREATE_MANY_COLLECTION_RECIPE = "entity_create_many_collection_recipe",
+  ENTITY_UPDATE_MANY_COLLECTION_RECIPE = "entity_update_many_collection_recipe",
+  ENTITY_DELETE_MANY_COLLECTION_RECIPE = "entity_delete_many_collection_recipe",
+  ENTITY_LIST_COLLECTION_RECIPE = "entity_list_collection_recipe",
+  ENTITY_LIST_PAGE_COLLECTION_RECIPE = "entity_list_page_collection_recipe",
+  ENTITY_COUNT_COLLECTION_RECIPE = "entity_count_collection_recipe",
+  ENTITY_MOVE_COLLECTION_RECIPE = "entity_move_collection_recipe",
+  ENTITY_REORDER_COLLECTION_RECIPE = "entity_reorder_collection_recipe",
 
   ENTITY_CREATE_RECIPE_INGREDIENT_DRAFT = "entity_create_recipe_ingredient_draft",
   ENTITY_READ_RECIPE_INGREDIENT_DRAFT = "entity_read_recipe_ingredient_draft",
   ENTITY_UPDATE_RECIPE_INGREDIENT_DRAFT = "entity_update_recipe_ingredient_draft",
   ENTITY_DELETE_RECIPE_INGREDIENT_DRAFT = "entity_delete_recipe_ingredient_draft",
+  ENTITY_CREATE_MANY_RECIPE_INGREDIENT_DRAFT = "entity_create_many_recipe_ingredient_draft",
+  ENTITY_UPDATE_MANY_RECIPE_INGREDIENT_DRAFT = "entity_update_many_recipe_ingredient_draft",
+  ENTITY_DELETE_MANY_RECIPE_INGREDIENT_DRAFT = "entity_delete_many_recipe_ingredient_draft",
   ENTITY_LIST_RECIPE_INGREDIENT_DRAFT = "entity_list_recipe_ingredient_draft",
+  ENTITY_LIST_PAGE_RECIPE_INGREDIENT_DRAFT = "entity_list_page_recipe_ingredient_draft",
   ENTITY_COUNT_RECIPE_INGREDIENT_DRAFT = "entity_count_recipe_ingredient_draft",
+  ENTITY_MOVE_RECIPE_INGREDIENT_DRAFT = "entity_move_recipe_ingredient_draft",
+  ENTITY_REORDER_RECIPE_INGREDIENT_DRAFT = "entity_reorder_recipe_ingredient_draft",
 
   ENTITY_CREATE_RECIPE_STEP_FILE = "entity_create_recipe_step_file",
   ENTITY_READ_RECIPE_STEP_FILE = "entity_read_recipe_step_file",
   ENTITY_UPDATE_RECIPE_STEP_FILE = "entity_update_recipe_step_file",
   ENTITY_DELETE_RECIPE_STEP_FILE = "entity_delete_recipe_step_file",
+  ENTITY_CREATE_MANY_RECIPE_STEP_FILE = "entity_create_many_recipe_step_file",
+  ENTITY_UPDATE_MANY_RECIPE_STEP_FILE = "entity_update_many_recipe_step_file",
+  ENTITY_DELETE_MANY_RECIPE_STEP_FILE = "entity_delete_many_recipe_step_file",
   ENTITY_LIST_RECIPE_STEP_FILE = "entity_list_recipe_step_file",
+  ENTITY_LIST_PAGE_RECIPE_STEP_FILE = "entity_list_page_recipe_step_file",
   ENTITY_COUNT_RECIPE_STEP_FILE = "entity_count_recipe_step_file",
+  ENTITY_MOVE_RECIPE_STEP_FILE = "entity_move_recipe_step_file",
+  ENTITY_REORDER_RECIPE_STEP_FILE = "entity_reorder_recipe_step_file",
 
   ENTITY_CREATE_RECIPE_STEP_INGREDIENT = "entity_create_recipe_step_ingredient",
   ENTITY_READ_RECIPE_STEP_INGREDIENT = "entity_read_recipe_step_ingredient",
   ENTITY_UPDATE_RECIPE_STEP_INGREDIENT = "entity_update_recipe_step_ingredient",
   ENTITY_DELETE_RECIPE_STEP_INGREDIENT = "entity_delete_recipe_step_ingredient",
+  ENTITY_CREATE_MANY_RECIPE_STEP_INGREDIENT = "entity_create_many_recipe_step_ingredient",
+  ENTITY_UPDATE_MANY_RECIPE_STEP_INGREDIENT = "entity_update_many_recipe_step_ingredient",
+  ENTITY_DELETE_MANY_RECIPE_STEP_INGREDIENT = "entity_delete_many_recipe_step_ingredient",
   ENTITY_LIST_RECIPE_STEP_INGREDIENT = "entity_list_recipe_step_ingredient",
+  ENTITY_LIST_PAGE_RECIPE_STEP_INGREDIENT = "entity_list_page_recipe_step_ingredient",
   ENTITY_COUNT_RECIPE_STEP_INGREDIENT = "entity_count_recipe_step_ingredient",
+  ENTITY_MOVE_RECIPE_STEP_INGREDIENT = "entity_move_recipe_step_ingredient",
+  ENTITY_REORDER_RECIPE_STEP_INGREDIENT = "entity_reorder_recipe_step_ingredient",
 
   ENTITY_CREATE_RECIPE_STEP_INGREDIENT_DRAFT = "entity_create_recipe_step_ingredient_draft",
   ENTITY_READ_RECIPE_STEP_INGREDIENT_DRAFT = "entity_read_recipe_step_ingredient_draft",
   ENTITY_UPDATE_RECIPE_STEP_INGREDIENT_DRAFT = "entity_update_recipe_step_ingredient_draft",
   ENTITY_DELETE_RECIPE_STEP_INGREDIENT_DRAFT = "entity_delete_recipe_step_ingredient_draft",
+  ENTITY_CREATE_MANY_RECIPE_STEP_INGREDIENT_DRAFT = "entity_create_many_recipe_step_ingredient_draft",
+  ENTITY_UPDATE_MANY_RECIPE_STEP_INGREDIENT_DRAFT = "entity_update_many_recipe_step_ingredient_draft",
+  ENTITY_DELETE_MANY_RECIPE_STEP_INGREDIENT_DRAFT = "entity_delete_many_recipe_step_ingredient_draft",
   ENTITY_LIST_RECIPE_STEP_INGREDIENT_DRAFT = "entity_list_recipe_step_ingredient_draft",
+  ENTITY_LIST_PAGE_RECIPE_STEP_INGREDIENT_DRAFT = "entity_list_page_recipe_step_ingredient_draft",
   ENTITY_COUNT_RECIPE_STEP_INGREDIENT_DRAFT = "entity_count_recipe_step_ingredient_draft",
+  ENTITY_MOVE_RECIPE_STEP_INGREDIENT_DRAFT = "entity_move_recipe_step_ingredient_draft",
+  ENTITY_REORDER_RECIPE_STEP_INGREDIENT_DRAFT = "entity_reorder_recipe_step_ingredient_draft",
 
   ENTITY_CREATE_RECIPE_STEP = "entity_create_recipe_step",
   ENTITY_READ_RECIPE_STEP = "entity_read_recipe_step",
   ENTITY_UPDATE_RECIPE_STEP = "entity_update_recipe_step",
   ENTITY_DELETE_RECIPE_STEP = "entity_delete_recipe_step",
+  ENTITY_CREATE_MANY_RECIPE_STEP = "entity_create_many_recipe_step",
+  ENTITY_UPDATE_MANY_RECIPE_STEP = "entity_update_many_recipe_step",
+  ENTITY_DELETE_MANY_RECIPE_STEP = "entity_delete_many_recipe_step",
   ENTITY_LIST_RECIPE_STEP = "entity_list_recipe_step",
+  ENTITY_LIST_PAGE_RECIPE_STEP = "entity_list_page_recipe_step",
   ENTITY_COUNT_RECIPE_STEP = "entity_count_recipe_step",
+  ENTITY_MOVE_RECIPE_STEP = "entity_move_recipe_step",
+  ENTITY_REORDER_RECIPE_STEP = "entity_reorder_recipe_step",
 
   ENTITY_CREATE_UNIT_NAME = "entity_create_unit_name",
   ENTITY_READ_UNIT_NAME = "entity_read_unit_name",
   ENTITY_UPDATE_UNIT_NAME = "entity_update_unit_name",
   ENTITY_DELETE_UNIT_NAME = "entity_delete_unit_name",
+  ENTITY_CREATE_MANY_UNIT_NAME = "entity_create_many_unit_name",
+  ENTITY_UPDATE_MANY_UNIT_NAME = "entity_update_many_unit_name",
+  ENTITY_DELETE_MANY_UNIT_NAME = "entity_delete_many_unit_name",
   ENTITY_LIST_UNIT_NAME = "entity_list_unit_name",
+  ENTITY_LIST_PAGE_UNIT_NAME = "entity_list_page_unit_name",
   ENTITY_COUNT_UNIT_NAME = "entity_count_unit_name",
 
   EXTERNAL_RECIPE = "external_recipe",
 
   OCR = "ocr",
+  OCR_STRUCTURED = "ocr_structured",
+  OCR_RECIPE = "ocr_recipe",
+  OCR_LANGUAGE_LIST = "ocr_language_list",
+  OCR_PREPROCESS = "ocr_preprocess",
+
+  PDF_TEXT = "pdf_text",
 
   UNIT_CONVERT = "unit_convert",
 
   UNIT_LIST_GET = "unit_list_get",
+
+  CHANGES_SINCE = "changes_since",
+
+  UNDO = "undo",
+  REDO = "redo",
+
+  RECIPE_REVISION_SAVE = "recipe_revision_save",
+  RECIPE_REVISION_LIST = "recipe_revision_list",
+  RECIPE_REVISION_DIFF = "recipe_revision_diff",
+  RECIPE_REVISION_REVERT = "recipe_revision_revert",
+
+  RECIPE_TIME_FILL = "recipe_time_fill",
+
+  NUTRITION_IMPORT = "nutrition_import",
+  NUTRITION_CALCULATE = "nutrition_calculate",
+
+  DIETARY_SUMMARY = "dietary_summary",
+
+  STEP_DURATION_LIST = "step_duration_list",
+  STEP_DURATION_PARSE = "step_duration_parse",
+
+  TIMER_START = "timer_start",
+  TIMER_CANCEL = "timer_cancel",
+  TIMER_LIST = "timer_list",
+
+  SETTINGS_GET = "settings_get",
+  SETTINGS_SET = "settings_set",
 }
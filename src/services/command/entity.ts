@@ -1,13 +1,54 @@
+import type {
+  CollectionCreateInterface,
+  CollectionExport,
+  CollectionInterface,
+  CollectionUpdateInterface,
+} from "../../types/entity/collection-interface.ts";
+import type {
+  CollectionRecipeCreateInterface,
+  CollectionRecipeInterface,
+  CollectionRecipeUpdateInterface,
+} from "../../types/entity/collection-recipe-interface.ts";
+import type {
+  CookLogFileCreateInterface,
+  CookLogFileInterface,
+  CookLogFileUpdateInterface,
+} from "../../types/entity/cook-log-file-interface.ts";
+import type {
+  CookLogCreateInterface,
+  CookLogInterface,
+  CookLogUpdateInterface,
+} from "../../types/entity/cook-log-interface.ts";
 import type {
   FileCreateInterface,
   FileInterface,
   FileUpdateInterface,
 } from "../../types/entity/file-interface.ts";
+import type {
+  IngredientAliasCreateInterface,
+  IngredientAliasInterface,
+  IngredientAliasUpdateInterface,
+} from "../../types/entity/ingredient-alias-interface.ts";
+import type {
+  IngredientAllergenCreateInterface,
+  IngredientAllergenInterface,
+  IngredientAllergenUpdateInterface,
+} from "../../types/entity/ingredient-allergen-interface.ts";
+import type {
+  IngredientCategoryCreateInterface,
+  IngredientCategoryInterface,
+  IngredientCategoryUpdateInterface,
+} from "../../types/entity/ingredient-category-interface.ts";
 import type {
   IngredientCreateInterface,
   IngredientInterface,
   IngredientUpdateInterface,
 } from "../../types/entity/ingredient-interface.ts";
+import type {
+  NutrientProfileCreateInterface,
+  NutrientProfileInterface,
+  NutrientProfileUpdateInterface,
+} from "../../types/entity/nutrient-profile-interface.ts";
 import type {
   RecipeFileCreateInterface,
   RecipeFileInterface,
@@ -20,6 +61,7 @@ import type {
 } from "../../types/entity/recipe-ingredient-draft-interface.ts";
 import type {
   RecipeCreateInterface,
+  RecipeExport,
   RecipeInterface,
   RecipeUpdateInterface,
 } from "../../types/entity/recipe-interface.ts";
@@ -43,19 +85,63 @@ import type {
   RecipeStepInterface,
   RecipeStepUpdateInterface,
 } from "../../types/entity/recipe-step-interface.ts";
+import type {
+  RecipeTagCreateInterface,
+  RecipeTagInterface,
+  RecipeTagUpdateInterface,
+} from "../../types/entity/recipe-tag-interface.ts";
+import type {
+  TagCreateInterface,
+  TagInterface,
+  TagUpdateInterface,
+  TagUsageInterface,
+} from "../../types/entity/tag-interface.ts";
 import type {
   UnitNameCreateInterface,
   UnitNameInterface,
   UnitNameUpdateInterface,
 } from "../../types/entity/unit-name-interface.ts";
+import type { ListPage } from "../../types/filter-interface.ts";
+import type {
+  CollectionCondition,
+  CollectionFilter,
+} from "../../types/filter/collection-filter.ts";
+import type {
+  CollectionRecipeCondition,
+  CollectionRecipeFilter,
+} from "../../types/filter/collection-recipe-filter.ts";
+import type {
+  CookLogFileCondition,
+  CookLogFileFilter,
+} from "../../types/filter/cook-log-file-filter.ts";
+import type {
+  CookLogCondition,
+  CookLogFilter,
+} from "../../types/filter/cook-log-filter.ts";
 import type {
   FileCondition,
   FileFilter,
 } from "../../types/filter/file-filter.ts";
+import type {
+  IngredientAliasCondition,
+  IngredientAliasFilter,
+} from "../../types/filter/ingredient-alias-filter.ts";
+import type {
+  IngredientAllergenCondition,
+  IngredientAllergenFilter,
+} from "../../types/filter/ingredient-allergen-filter.ts";
+import type {
+  IngredientCategoryCondition,
+  IngredientCategoryFilter,
+} from "../../types/filter/ingredient-category-filter.ts";
 import type {
   IngredientCondition,
   IngredientFilter,
 } from "../../types/filter/ingredient-filter.ts";
+import type {
+  NutrientProfileCondition,
+  NutrientProfileFilter,
+} from "../../types/filter/nutrient-profile-filter.ts";
 import type {
   RecipeFileCondition,
   RecipeFileFilter,
@@ -84,6 +170,14 @@ import type {
   RecipeStepIngredientCondition,
   RecipeStepIngredientFilter,
 } from "../../types/filter/recipe-step-ingredient-filter.ts";
+import type {
+  RecipeTagCondition,
+  RecipeTagFilter,
+} from "../../types/filter/recipe-tag-filter.ts";
+import type {
+  TagCondition,
+  TagFilter,
+} from "../../types/filter/tag-filter.ts";
 import type {
   UnitNameCondition,
   UnitNameFilter,
@@ -97,8 +191,18 @@ import { Command } from "./command.ts";
 type CommandEntityRead =
   | Command.ENTITY_READ_FILE
   | Command.ENTITY_READ_INGREDIENT
+  | Command.ENTITY_READ_INGREDIENT_ALIAS
+  | Command.ENTITY_READ_INGREDIENT_ALLERGEN
+  | Command.ENTITY_READ_COOK_LOG
+  | Command.ENTITY_READ_COLLECTION
+  | Command.ENTITY_READ_TAG
+  | Command.ENTITY_READ_RECIPE_TAG
+  | Command.ENTITY_READ_INGREDIENT_CATEGORY
+  | Command.ENTITY_READ_NUTRIENT_PROFILE
   | Command.ENTITY_READ_RECIPE
   | Command.ENTITY_READ_RECIPE_FILE
+  | Command.ENTITY_READ_COOK_LOG_FILE
+  | Command.ENTITY_READ_COLLECTION_RECIPE
   | Command.ENTITY_READ_RECIPE_INGREDIENT_DRAFT
   | Command.ENTITY_READ_RECIPE_STEP_FILE
   | Command.ENTITY_READ_RECIPE_STEP_INGREDIENT
@@ -109,8 +213,18 @@ type CommandEntityRead =
 type CommandEntityList =
   | Command.ENTITY_LIST_FILE
   | Command.ENTITY_LIST_INGREDIENT
+  | Command.ENTITY_LIST_INGREDIENT_ALIAS
+  | Command.ENTITY_LIST_INGREDIENT_ALLERGEN
+  | Command.ENTITY_LIST_COOK_LOG
+  | Command.ENTITY_LIST_COLLECTION
+  | Command.ENTITY_LIST_TAG
+  | Command.ENTITY_LIST_RECIPE_TAG
+  | Command.ENTITY_LIST_INGREDIENT_CATEGORY
+  | Command.ENTITY_LIST_NUTRIENT_PROFILE
   | Command.ENTITY_LIST_RECIPE
   | Command.ENTITY_LIST_RECIPE_FILE
+  | Command.ENTITY_LIST_COOK_LOG_FILE
+  | Command.ENTITY_LIST_COLLECTION_RECIPE
   | Command.ENTITY_LIST_RECIPE_INGREDIENT_DRAFT
   | Command.ENTITY_LIST_RECIPE_STEP_FILE
   | Command.ENTITY_LIST_RECIPE_STEP_INGREDIENT
@@ -121,8 +235,18 @@ type CommandEntityList =
 type CommandEntityCount =
   | Command.ENTITY_COUNT_FILE
   | Command.ENTITY_COUNT_INGREDIENT
+  | Command.ENTITY_COUNT_INGREDIENT_ALIAS
+  | Command.ENTITY_COUNT_INGREDIENT_ALLERGEN
+  | Command.ENTITY_COUNT_COOK_LOG
+  | Command.ENTITY_COUNT_COLLECTION
+  | Command.ENTITY_COUNT_TAG
+  | Command.ENTITY_COUNT_RECIPE_TAG
+  | Command.ENTITY_COUNT_INGREDIENT_CATEGORY
+  | Command.ENTITY_COUNT_NUTRIENT_PROFILE
   | Command.ENTITY_COUNT_RECIPE
   | Command.ENTITY_COUNT_RECIPE_FILE
+  | Command.ENTITY_COUNT_COOK_LOG_FILE
+  | Command.ENTITY_COUNT_COLLECTION_RECIPE
   | Command.ENTITY_COUNT_RECIPE_INGREDIENT_DRAFT
   | Command.ENTITY_COUNT_RECIPE_STEP_FILE
   | Command.ENTITY_COUNT_RECIPE_STEP_INGREDIENT
@@ -137,8 +261,18 @@ const entityReadPromiseCollector: {
 } = {
   [Command.ENTITY_READ_FILE]: {},
   [Command.ENTITY_READ_INGREDIENT]: {},
+  [Command.ENTITY_READ_INGREDIENT_ALIAS]: {},
+  [Command.ENTITY_READ_INGREDIENT_ALLERGEN]: {},
+  [Command.ENTITY_READ_COOK_LOG]: {},
+  [Command.ENTITY_READ_COLLECTION]: {},
+  [Command.ENTITY_READ_TAG]: {},
+  [Command.ENTITY_READ_RECIPE_TAG]: {},
+  [Command.ENTITY_READ_INGREDIENT_CATEGORY]: {},
+  [Command.ENTITY_READ_NUTRIENT_PROFILE]: {},
   [Command.ENTITY_READ_RECIPE]: {},
   [Command.ENTITY_READ_RECIPE_FILE]: {},
+  [Command.ENTITY_READ_COOK_LOG_FILE]: {},
+  [Command.ENTITY_READ_COLLECTION_RECIPE]: {},
   [Command.ENTITY_READ_RECIPE_INGREDIENT_DRAFT]: {},
   [Command.ENTITY_READ_RECIPE_STEP_FILE]: {},
   [Command.ENTITY_READ_RECIPE_STEP_INGREDIENT]: {},
@@ -154,8 +288,18 @@ const entityListPromiseCollector: {
 } = {
   [Command.ENTITY_LIST_FILE]: {},
   [Command.ENTITY_LIST_INGREDIENT]: {},
+  [Command.ENTITY_LIST_INGREDIENT_ALIAS]: {},
+  [Command.ENTITY_LIST_INGREDIENT_ALLERGEN]: {},
+  [Command.ENTITY_LIST_COOK_LOG]: {},
+  [Command.ENTITY_LIST_COLLECTION]: {},
+  [Command.ENTITY_LIST_TAG]: {},
+  [Command.ENTITY_LIST_RECIPE_TAG]: {},
+  [Command.ENTITY_LIST_INGREDIENT_CATEGORY]: {},
+  [Command.ENTITY_LIST_NUTRIENT_PROFILE]: {},
   [Command.ENTITY_LIST_RECIPE]: {},
   [Command.ENTITY_LIST_RECIPE_FILE]: {},
+  [Command.ENTITY_LIST_COOK_LOG_FILE]: {},
+  [Command.ENTITY_LIST_COLLECTION_RECIPE]: {},
   [Command.ENTITY_LIST_RECIPE_INGREDIENT_DRAFT]: {},
   [Command.ENTITY_LIST_RECIPE_STEP_FILE]: {},
   [Command.ENTITY_LIST_RECIPE_STEP_INGREDIENT]: {},
@@ -171,8 +315,18 @@ const entityCountPromiseCollector: {
 } = {
   [Command.ENTITY_COUNT_FILE]: {},
   [Command.ENTITY_COUNT_INGREDIENT]: {},
+  [Command.ENTITY_COUNT_INGREDIENT_ALIAS]: {},
+  [Command.ENTITY_COUNT_INGREDIENT_ALLERGEN]: {},
+  [Command.ENTITY_COUNT_COOK_LOG]: {},
+  [Command.ENTITY_COUNT_COLLECTION]: {},
+  [Command.ENTITY_COUNT_TAG]: {},
+  [Command.ENTITY_COUNT_RECIPE_TAG]: {},
+  [Command.ENTITY_COUNT_INGREDIENT_CATEGORY]: {},
+  [Command.ENTITY_COUNT_NUTRIENT_PROFILE]: {},
   [Command.ENTITY_COUNT_RECIPE]: {},
   [Command.ENTITY_COUNT_RECIPE_FILE]: {},
+  [Command.ENTITY_COUNT_COOK_LOG_FILE]: {},
+  [Command.ENTITY_COUNT_COLLECTION_RECIPE]: {},
   [Command.ENTITY_COUNT_RECIPE_INGREDIENT_DRAFT]: {},
   [Command.ENTITY_COUNT_RECIPE_STEP_FILE]: {},
   [Command.ENTITY_COUNT_RECIPE_STEP_INGREDIENT]: {},
@@ -261,10 +415,28 @@ export function deleteFile(id: number): Promise<void> {
   return invoke(Command.ENTITY_DELETE_FILE, { id });
 }
 
+export function createManyFile(
+  creates: FileCreateInterface[],
+): Promise<number[]> {
+  return invoke(Command.ENTITY_CREATE_MANY_FILE, { creates });
+}
+
+export function updateManyFile(updates: FileUpdateInterface[]): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_MANY_FILE, { updates });
+}
+
+export function deleteManyFile(ids: number[]): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_MANY_FILE, { ids });
+}
+
 export function listFile(filter: FileFilter): Promise<number[]> {
   return listCollected(Command.ENTITY_LIST_FILE, filter);
 }
 
+export function listPageFile(filter: FileFilter): Promise<ListPage<number>> {
+  return invoke(Command.ENTITY_LIST_PAGE_FILE, { filter });
+}
+
 export function countFile(condition?: FileCondition): Promise<number> {
   return countCollected(Command.ENTITY_COUNT_FILE, condition);
 }
@@ -289,16 +461,477 @@ export function deleteIngredient(id: number): Promise<void> {
   return invoke(Command.ENTITY_DELETE_INGREDIENT, { id });
 }
 
+export function createManyIngredient(
+  creates: IngredientCreateInterface[],
+): Promise<number[]> {
+  return invoke(Command.ENTITY_CREATE_MANY_INGREDIENT, { creates });
+}
+
+export function updateManyIngredient(
+  updates: IngredientUpdateInterface[],
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_MANY_INGREDIENT, { updates });
+}
+
+export function deleteManyIngredient(ids: number[]): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_MANY_INGREDIENT, { ids });
+}
+
 export function listIngredient(filter: IngredientFilter): Promise<number[]> {
   return listCollected(Command.ENTITY_LIST_INGREDIENT, filter);
 }
 
+export function listPageIngredient(
+  filter: IngredientFilter,
+): Promise<ListPage<number>> {
+  return invoke(Command.ENTITY_LIST_PAGE_INGREDIENT, { filter });
+}
+
 export function countIngredient(
   condition?: IngredientCondition,
 ): Promise<number> {
   return countCollected(Command.ENTITY_COUNT_INGREDIENT, condition);
 }
 
+export function mergeIngredients(
+  targetId: number,
+  sourceIds: number[],
+): Promise<void> {
+  return invoke(Command.INGREDIENT_MERGE, { targetId, sourceIds });
+}
+
+export function ingredientMergeSuggestions(): Promise<number[][]> {
+  return invoke(Command.INGREDIENT_MERGE_SUGGESTIONS, undefined);
+}
+
+export function createIngredientAlias(
+  create: IngredientAliasCreateInterface,
+): Promise<number> {
+  return invoke(Command.ENTITY_CREATE_INGREDIENT_ALIAS, { create });
+}
+
+export function readIngredientAlias(
+  id: number,
+): Promise<IngredientAliasInterface> {
+  return readCollected(Command.ENTITY_READ_INGREDIENT_ALIAS, id);
+}
+
+export function updateIngredientAlias(
+  update: IngredientAliasUpdateInterface,
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_INGREDIENT_ALIAS, { update });
+}
+
+export function deleteIngredientAlias(id: number): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_INGREDIENT_ALIAS, { id });
+}
+
+export function createManyIngredientAlias(
+  creates: IngredientAliasCreateInterface[],
+): Promise<number[]> {
+  return invoke(Command.ENTITY_CREATE_MANY_INGREDIENT_ALIAS, { creates });
+}
+
+export function updateManyIngredientAlias(
+  updates: IngredientAliasUpdateInterface[],
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_MANY_INGREDIENT_ALIAS, { updates });
+}
+
+export function deleteManyIngredientAlias(ids: number[]): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_MANY_INGREDIENT_ALIAS, { ids });
+}
+
+export function listIngredientAlias(
+  filter: IngredientAliasFilter,
+): Promise<number[]> {
+  return listCollected(Command.ENTITY_LIST_INGREDIENT_ALIAS, filter);
+}
+
+export function listPageIngredientAlias(
+  filter: IngredientAliasFilter,
+): Promise<ListPage<number>> {
+  return invoke(Command.ENTITY_LIST_PAGE_INGREDIENT_ALIAS, { filter });
+}
+
+export function countIngredientAlias(
+  condition?: IngredientAliasCondition,
+): Promise<number> {
+  return countCollected(Command.ENTITY_COUNT_INGREDIENT_ALIAS, condition);
+}
+
+export function createIngredientAllergen(
+  create: IngredientAllergenCreateInterface,
+): Promise<number> {
+  return invoke(Command.ENTITY_CREATE_INGREDIENT_ALLERGEN, { create });
+}
+
+export function readIngredientAllergen(
+  id: number,
+): Promise<IngredientAllergenInterface> {
+  return readCollected(Command.ENTITY_READ_INGREDIENT_ALLERGEN, id);
+}
+
+export function updateIngredientAllergen(
+  update: IngredientAllergenUpdateInterface,
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_INGREDIENT_ALLERGEN, { update });
+}
+
+export function deleteIngredientAllergen(id: number): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_INGREDIENT_ALLERGEN, { id });
+}
+
+export function createManyIngredientAllergen(
+  creates: IngredientAllergenCreateInterface[],
+): Promise<number[]> {
+  return invoke(Command.ENTITY_CREATE_MANY_INGREDIENT_ALLERGEN, { creates });
+}
+
+export function updateManyIngredientAllergen(
+  updates: IngredientAllergenUpdateInterface[],
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_MANY_INGREDIENT_ALLERGEN, { updates });
+}
+
+export function deleteManyIngredientAllergen(ids: number[]): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_MANY_INGREDIENT_ALLERGEN, { ids });
+}
+
+export function listIngredientAllergen(
+  filter: IngredientAllergenFilter,
+): Promise<number[]> {
+  return listCollected(Command.ENTITY_LIST_INGREDIENT_ALLERGEN, filter);
+}
+
+export function listPageIngredientAllergen(
+  filter: IngredientAllergenFilter,
+): Promise<ListPage<number>> {
+  return invoke(Command.ENTITY_LIST_PAGE_INGREDIENT_ALLERGEN, { filter });
+}
+
+export function countIngredientAllergen(
+  condition?: IngredientAllergenCondition,
+): Promise<number> {
+  return countCollected(Command.ENTITY_COUNT_INGREDIENT_ALLERGEN, condition);
+}
+
+export function createCookLog(create: CookLogCreateInterface): Promise<number> {
+  return invoke(Command.ENTITY_CREATE_COOK_LOG, { create });
+}
+
+export function readCookLog(id: number): Promise<CookLogInterface> {
+  return readCollected(Command.ENTITY_READ_COOK_LOG, id);
+}
+
+export function updateCookLog(update: CookLogUpdateInterface): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_COOK_LOG, { update });
+}
+
+export function deleteCookLog(id: number): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_COOK_LOG, { id });
+}
+
+export function createManyCookLog(
+  creates: CookLogCreateInterface[],
+): Promise<number[]> {
+  return invoke(Command.ENTITY_CREATE_MANY_COOK_LOG, { creates });
+}
+
+export function updateManyCookLog(
+  updates: CookLogUpdateInterface[],
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_MANY_COOK_LOG, { updates });
+}
+
+export function deleteManyCookLog(ids: number[]): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_MANY_COOK_LOG, { ids });
+}
+
+export function listCookLog(filter: CookLogFilter): Promise<number[]> {
+  return listCollected(Command.ENTITY_LIST_COOK_LOG, filter);
+}
+
+export function listPageCookLog(
+  filter: CookLogFilter,
+): Promise<ListPage<number>> {
+  return invoke(Command.ENTITY_LIST_PAGE_COOK_LOG, { filter });
+}
+
+export function countCookLog(condition?: CookLogCondition): Promise<number> {
+  return countCollected(Command.ENTITY_COUNT_COOK_LOG, condition);
+}
+
+export function createCollection(
+  create: CollectionCreateInterface,
+): Promise<number> {
+  return invoke(Command.ENTITY_CREATE_COLLECTION, { create });
+}
+
+export function readCollection(id: number): Promise<CollectionInterface> {
+  return readCollected(Command.ENTITY_READ_COLLECTION, id);
+}
+
+export function updateCollection(
+  update: CollectionUpdateInterface,
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_COLLECTION, { update });
+}
+
+export function deleteCollection(id: number): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_COLLECTION, { id });
+}
+
+export function createManyCollection(
+  creates: CollectionCreateInterface[],
+): Promise<number[]> {
+  return invoke(Command.ENTITY_CREATE_MANY_COLLECTION, { creates });
+}
+
+export function updateManyCollection(
+  updates: CollectionUpdateInterface[],
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_MANY_COLLECTION, { updates });
+}
+
+export function deleteManyCollection(ids: number[]): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_MANY_COLLECTION, { ids });
+}
+
+export function listCollection(filter: CollectionFilter): Promise<number[]> {
+  return listCollected(Command.ENTITY_LIST_COLLECTION, filter);
+}
+
+export function listPageCollection(
+  filter: CollectionFilter,
+): Promise<ListPage<number>> {
+  return invoke(Command.ENTITY_LIST_PAGE_COLLECTION, { filter });
+}
+
+export function countCollection(
+  condition?: CollectionCondition,
+): Promise<number> {
+  return countCollected(Command.ENTITY_COUNT_COLLECTION, condition);
+}
+
+export function exportCollection(id: number): Promise<CollectionExport> {
+  return invoke(Command.COLLECTION_EXPORT, { id });
+}
+
+export function createTag(create: TagCreateInterface): Promise<number> {
+  return invoke(Command.ENTITY_CREATE_TAG, { create });
+}
+
+export function readTag(id: number): Promise<TagInterface> {
+  return readCollected(Command.ENTITY_READ_TAG, id);
+}
+
+export function updateTag(update: TagUpdateInterface): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_TAG, { update });
+}
+
+export function deleteTag(id: number): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_TAG, { id });
+}
+
+export function createManyTag(
+  creates: TagCreateInterface[],
+): Promise<number[]> {
+  return invoke(Command.ENTITY_CREATE_MANY_TAG, { creates });
+}
+
+export function updateManyTag(updates: TagUpdateInterface[]): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_MANY_TAG, { updates });
+}
+
+export function deleteManyTag(ids: number[]): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_MANY_TAG, { ids });
+}
+
+export function listTag(filter: TagFilter): Promise<number[]> {
+  return listCollected(Command.ENTITY_LIST_TAG, filter);
+}
+
+export function listPageTag(filter: TagFilter): Promise<ListPage<number>> {
+  return invoke(Command.ENTITY_LIST_PAGE_TAG, { filter });
+}
+
+export function countTag(condition?: TagCondition): Promise<number> {
+  return countCollected(Command.ENTITY_COUNT_TAG, condition);
+}
+
+export function renameTag(id: number, name: string): Promise<number> {
+  return invoke(Command.TAG_RENAME, { id, name });
+}
+
+export function tagCloud(): Promise<TagUsageInterface[]> {
+  return invoke(Command.TAG_CLOUD, undefined);
+}
+
+export function createRecipeTag(
+  create: RecipeTagCreateInterface,
+): Promise<number> {
+  return invoke(Command.ENTITY_CREATE_RECIPE_TAG, { create });
+}
+
+export function readRecipeTag(id: number): Promise<RecipeTagInterface> {
+  return readCollected(Command.ENTITY_READ_RECIPE_TAG, id);
+}
+
+export function updateRecipeTag(
+  update: RecipeTagUpdateInterface,
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_RECIPE_TAG, { update });
+}
+
+export function deleteRecipeTag(id: number): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_RECIPE_TAG, { id });
+}
+
+export function createManyRecipeTag(
+  creates: RecipeTagCreateInterface[],
+): Promise<number[]> {
+  return invoke(Command.ENTITY_CREATE_MANY_RECIPE_TAG, { creates });
+}
+
+export function updateManyRecipeTag(
+  updates: RecipeTagUpdateInterface[],
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_MANY_RECIPE_TAG, { updates });
+}
+
+export function deleteManyRecipeTag(ids: number[]): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_MANY_RECIPE_TAG, { ids });
+}
+
+export function listRecipeTag(filter: RecipeTagFilter): Promise<number[]> {
+  return listCollected(Command.ENTITY_LIST_RECIPE_TAG, filter);
+}
+
+export function listPageRecipeTag(
+  filter: RecipeTagFilter,
+): Promise<ListPage<number>> {
+  return invoke(Command.ENTITY_LIST_PAGE_RECIPE_TAG, { filter });
+}
+
+export function countRecipeTag(
+  condition?: RecipeTagCondition,
+): Promise<number> {
+  return countCollected(Command.ENTITY_COUNT_RECIPE_TAG, condition);
+}
+
+export function createIngredientCategory(
+  create: IngredientCategoryCreateInterface,
+): Promise<number> {
+  return invoke(Command.ENTITY_CREATE_INGREDIENT_CATEGORY, { create });
+}
+
+export function readIngredientCategory(
+  id: number,
+): Promise<IngredientCategoryInterface> {
+  return readCollected(Command.ENTITY_READ_INGREDIENT_CATEGORY, id);
+}
+
+export function updateIngredientCategory(
+  update: IngredientCategoryUpdateInterface,
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_INGREDIENT_CATEGORY, { update });
+}
+
+export function deleteIngredientCategory(id: number): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_INGREDIENT_CATEGORY, { id });
+}
+
+export function createManyIngredientCategory(
+  creates: IngredientCategoryCreateInterface[],
+): Promise<number[]> {
+  return invoke(Command.ENTITY_CREATE_MANY_INGREDIENT_CATEGORY, { creates });
+}
+
+export function updateManyIngredientCategory(
+  updates: IngredientCategoryUpdateInterface[],
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_MANY_INGREDIENT_CATEGORY, { updates });
+}
+
+export function deleteManyIngredientCategory(ids: number[]): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_MANY_INGREDIENT_CATEGORY, { ids });
+}
+
+export function listIngredientCategory(
+  filter: IngredientCategoryFilter,
+): Promise<number[]> {
+  return listCollected(Command.ENTITY_LIST_INGREDIENT_CATEGORY, filter);
+}
+
+export function listPageIngredientCategory(
+  filter: IngredientCategoryFilter,
+): Promise<ListPage<number>> {
+  return invoke(Command.ENTITY_LIST_PAGE_INGREDIENT_CATEGORY, { filter });
+}
+
+export function countIngredientCategory(
+  condition?: IngredientCategoryCondition,
+): Promise<number> {
+  return countCollected(Command.ENTITY_COUNT_INGREDIENT_CATEGORY, condition);
+}
+
+export function createNutrientProfile(
+  create: NutrientProfileCreateInterface,
+): Promise<number> {
+  return invoke(Command.ENTITY_CREATE_NUTRIENT_PROFILE, { create });
+}
+
+export function readNutrientProfile(
+  id: number,
+): Promise<NutrientProfileInterface> {
+  return readCollected(Command.ENTITY_READ_NUTRIENT_PROFILE, id);
+}
+
+export function updateNutrientProfile(
+  update: NutrientProfileUpdateInterface,
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_NUTRIENT_PROFILE, { update });
+}
+
+export function deleteNutrientProfile(id: number): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_NUTRIENT_PROFILE, { id });
+}
+
+export function createManyNutrientProfile(
+  creates: NutrientProfileCreateInterface[],
+): Promise<number[]> {
+  return invoke(Command.ENTITY_CREATE_MANY_NUTRIENT_PROFILE, { creates });
+}
+
+export function updateManyNutrientProfile(
+  updates: NutrientProfileUpdateInterface[],
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_MANY_NUTRIENT_PROFILE, { updates });
+}
+
+export function deleteManyNutrientProfile(ids: number[]): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_MANY_NUTRIENT_PROFILE, { ids });
+}
+
+export function listNutrientProfile(
+  filter: NutrientProfileFilter,
+): Promise<number[]> {
+  return listCollected(Command.ENTITY_LIST_NUTRIENT_PROFILE, filter);
+}
+
+export function listPageNutrientProfile(
+  filter: NutrientProfileFilter,
+): Promise<ListPage<number>> {
+  return invoke(Command.ENTITY_LIST_PAGE_NUTRIENT_PROFILE, { filter });
+}
+
+export function countNutrientProfile(
+  condition?: NutrientProfileCondition,
+): Promise<number> {
+  return countCollected(Command.ENTITY_COUNT_NUTRIENT_PROFILE, condition);
+}
+
 export function createRecipe(create: RecipeCreateInterface): Promise<number> {
   return invoke(Command.ENTITY_CREATE_RECIPE, { create });
 }
@@ -315,14 +948,64 @@ export function deleteRecipe(id: number): Promise<void> {
   return invoke(Command.ENTITY_DELETE_RECIPE, { id });
 }
 
+export function createManyRecipe(
+  creates: RecipeCreateInterface[],
+): Promise<number[]> {
+  return invoke(Command.ENTITY_CREATE_MANY_RECIPE, { creates });
+}
+
+export function updateManyRecipe(
+  updates: RecipeUpdateInterface[],
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_MANY_RECIPE, { updates });
+}
+
+export function deleteManyRecipe(ids: number[]): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_MANY_RECIPE, { ids });
+}
+
 export function listRecipe(filter: RecipeFilter): Promise<number[]> {
   return listCollected(Command.ENTITY_LIST_RECIPE, filter);
 }
 
+export function listPageRecipe(
+  filter: RecipeFilter,
+): Promise<ListPage<number>> {
+  return invoke(Command.ENTITY_LIST_PAGE_RECIPE, { filter });
+}
+
 export function countRecipe(condition?: RecipeCondition): Promise<number> {
   return countCollected(Command.ENTITY_COUNT_RECIPE, condition);
 }
 
+export function trashRecipe(id: number): Promise<void> {
+  return invoke(Command.ENTITY_TRASH_RECIPE, { id });
+}
+
+export function restoreRecipe(id: number): Promise<void> {
+  return invoke(Command.ENTITY_RESTORE_RECIPE, { id });
+}
+
+export function purgeRecipe(ids: number[]): Promise<void> {
+  return invoke(Command.ENTITY_PURGE_RECIPE, { ids });
+}
+
+export function purgeTrashRecipe(olderThanDays?: number): Promise<void> {
+  return invoke(Command.ENTITY_PURGE_TRASH_RECIPE, { olderThanDays });
+}
+
+export function duplicateRecipe(
+  id: number,
+  name?: string,
+  fork = true,
+): Promise<number> {
+  return invoke(Command.RECIPE_DUPLICATE, { id, name, fork });
+}
+
+export function exportRecipe(id: number): Promise<RecipeExport> {
+  return invoke(Command.RECIPE_EXPORT, { id });
+}
+
 export function createRecipeFile(
   create: RecipeFileCreateInterface,
 ): Promise<number> {
@@ -343,16 +1026,181 @@ export function deleteRecipeFile(id: number): Promise<void> {
   return invoke(Command.ENTITY_DELETE_RECIPE_FILE, { id });
 }
 
+export function createManyRecipeFile(
+  creates: RecipeFileCreateInterface[],
+): Promise<number[]> {
+  return invoke(Command.ENTITY_CREATE_MANY_RECIPE_FILE, { creates });
+}
+
+export function updateManyRecipeFile(
+  updates: RecipeFileUpdateInterface[],
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_MANY_RECIPE_FILE, { updates });
+}
+
+export function deleteManyRecipeFile(ids: number[]): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_MANY_RECIPE_FILE, { ids });
+}
+
 export function listRecipeFile(filter: RecipeFileFilter): Promise<number[]> {
   return listCollected(Command.ENTITY_LIST_RECIPE_FILE, filter);
 }
 
+export function listPageRecipeFile(
+  filter: RecipeFileFilter,
+): Promise<ListPage<number>> {
+  return invoke(Command.ENTITY_LIST_PAGE_RECIPE_FILE, { filter });
+}
+
 export function countRecipeFile(
   condition?: RecipeFileCondition,
 ): Promise<number> {
   return countCollected(Command.ENTITY_COUNT_RECIPE_FILE, condition);
 }
 
+export function createCookLogFile(
+  create: CookLogFileCreateInterface,
+): Promise<number> {
+  return invoke(Command.ENTITY_CREATE_COOK_LOG_FILE, { create });
+}
+
+export function readCookLogFile(id: number): Promise<CookLogFileInterface> {
+  return readCollected(Command.ENTITY_READ_COOK_LOG_FILE, id);
+}
+
+export function updateCookLogFile(
+  update: CookLogFileUpdateInterface,
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_COOK_LOG_FILE, { update });
+}
+
+export function deleteCookLogFile(id: number): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_COOK_LOG_FILE, { id });
+}
+
+export function createManyCookLogFile(
+  creates: CookLogFileCreateInterface[],
+): Promise<number[]> {
+  return invoke(Command.ENTITY_CREATE_MANY_COOK_LOG_FILE, { creates });
+}
+
+export function updateManyCookLogFile(
+  updates: CookLogFileUpdateInterface[],
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_MANY_COOK_LOG_FILE, { updates });
+}
+
+export function deleteManyCookLogFile(ids: number[]): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_MANY_COOK_LOG_FILE, { ids });
+}
+
+export function listCookLogFile(filter: CookLogFileFilter): Promise<number[]> {
+  return listCollected(Command.ENTITY_LIST_COOK_LOG_FILE, filter);
+}
+
+export function listPageCookLogFile(
+  filter: CookLogFileFilter,
+): Promise<ListPage<number>> {
+  return invoke(Command.ENTITY_LIST_PAGE_COOK_LOG_FILE, { filter });
+}
+
+export function countCookLogFile(
+  condition?: CookLogFileCondition,
+): Promise<number> {
+  return countCollected(Command.ENTITY_COUNT_COOK_LOG_FILE, condition);
+}
+
+export function moveCookLogFile(id: number, position: number): Promise<void> {
+  return invoke(Command.ENTITY_MOVE_COOK_LOG_FILE, { id, position });
+}
+
+export function reorderCookLogFile(
+  cookLogId: number,
+  ids: number[],
+): Promise<void> {
+  return invoke(Command.ENTITY_REORDER_COOK_LOG_FILE, { cookLogId, ids });
+}
+
+export function createCollectionRecipe(
+  create: CollectionRecipeCreateInterface,
+): Promise<number> {
+  return invoke(Command.ENTITY_CREATE_COLLECTION_RECIPE, { create });
+}
+
+export function readCollectionRecipe(id: number): Promise<CollectionRecipeInterface> {
+  return readCollected(Command.ENTITY_READ_COLLECTION_RECIPE, id);
+}
+
+export function updateCollectionRecipe(
+  update: CollectionRecipeUpdateInterface,
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_COLLECTION_RECIPE, { update });
+}
+
+export function deleteCollectionRecipe(id: number): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_COLLECTION_RECIPE, { id });
+}
+
+export function createManyCollectionRecipe(
+  creates: CollectionRecipeCreateInterface[],
+): Promise<number[]> {
+  return invoke(Command.ENTITY_CREATE_MANY_COLLECTION_RECIPE, { creates });
+}
+
+export function updateManyCollectionRecipe(
+  updates: CollectionRecipeUpdateInterface[],
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_MANY_COLLECTION_RECIPE, { updates });
+}
+
+export function deleteManyCollectionRecipe(ids: number[]): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_MANY_COLLECTION_RECIPE, { ids });
+}
+
+export function listCollectionRecipe(filter: CollectionRecipeFilter): Promise<number[]> {
+  return listCollected(Command.ENTITY_LIST_COLLECTION_RECIPE, filter);
+}
+
+export function listPageCollectionRecipe(
+  filter: CollectionRecipeFilter,
+): Promise<ListPage<number>> {
+  return invoke(Command.ENTITY_LIST_PAGE_COLLECTION_RECIPE, { filter });
+}
+
+export function countCollectionRecipe(
+  condition?: CollectionRecipeCondition,
+): Promise<number> {
+  return countCollected(Command.ENTITY_COUNT_COLLECTION_RECIPE, condition);
+}
+
+export function moveCollectionRecipe(
+  id: number,
+  position: number,
+): Promise<void> {
+  return invoke(Command.ENTITY_MOVE_COLLECTION_RECIPE, { id, position });
+}
+
+export function reorderCollectionRecipe(
+  collectionId: number,
+  ids: number[],
+): Promise<void> {
+  return invoke(Command.ENTITY_REORDER_COLLECTION_RECIPE, {
+    collectionId,
+    ids,
+  });
+}
+
+export function moveRecipeFile(id: number, position: number): Promise<void> {
+  return invoke(Command.ENTITY_MOVE_RECIPE_FILE, { id, position });
+}
+
+export function reorderRecipeFile(
+  recipeId: number,
+  ids: number[],
+): Promise<void> {
+  return invoke(Command.ENTITY_REORDER_RECIPE_FILE, { recipeId, ids });
+}
+
 export function createRecipeIngredientDraft(
   create: RecipeIngredientDraftCreateInterface,
 ): Promise<number> {
@@ -375,12 +1223,38 @@ export function deleteRecipeIngredientDraft(id: number): Promise<void> {
   return invoke(Command.ENTITY_DELETE_RECIPE_INGREDIENT_DRAFT, { id });
 }
 
+export function createManyRecipeIngredientDraft(
+  creates: RecipeIngredientDraftCreateInterface[],
+): Promise<number[]> {
+  return invoke(Command.ENTITY_CREATE_MANY_RECIPE_INGREDIENT_DRAFT, {
+    creates,
+  });
+}
+
+export function updateManyRecipeIngredientDraft(
+  updates: RecipeIngredientDraftUpdateInterface[],
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_MANY_RECIPE_INGREDIENT_DRAFT, {
+    updates,
+  });
+}
+
+export function deleteManyRecipeIngredientDraft(ids: number[]): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_MANY_RECIPE_INGREDIENT_DRAFT, { ids });
+}
+
 export function listRecipeIngredientDraft(
   filter: RecipeIngredientDraftFilter,
 ): Promise<number[]> {
   return listCollected(Command.ENTITY_LIST_RECIPE_INGREDIENT_DRAFT, filter);
 }
 
+export function listPageRecipeIngredientDraft(
+  filter: RecipeIngredientDraftFilter,
+): Promise<ListPage<number>> {
+  return invoke(Command.ENTITY_LIST_PAGE_RECIPE_INGREDIENT_DRAFT, { filter });
+}
+
 export function countRecipeIngredientDraft(
   condition?: RecipeIngredientDraftCondition,
 ): Promise<number> {
@@ -390,6 +1264,23 @@ export function countRecipeIngredientDraft(
   );
 }
 
+export function moveRecipeIngredientDraft(
+  id: number,
+  position: number,
+): Promise<void> {
+  return invoke(Command.ENTITY_MOVE_RECIPE_INGREDIENT_DRAFT, { id, position });
+}
+
+export function reorderRecipeIngredientDraft(
+  recipeId: number,
+  ids: number[],
+): Promise<void> {
+  return invoke(Command.ENTITY_REORDER_RECIPE_INGREDIENT_DRAFT, {
+    recipeId,
+    ids,
+  });
+}
+
 export function createRecipeStepFile(
   create: RecipeStepFileCreateInterface,
 ): Promise<number> {
@@ -412,18 +1303,54 @@ export function deleteRecipeStepFile(id: number): Promise<void> {
   return invoke(Command.ENTITY_DELETE_RECIPE_STEP_FILE, { id });
 }
 
+export function createManyRecipeStepFile(
+  creates: RecipeStepFileCreateInterface[],
+): Promise<number[]> {
+  return invoke(Command.ENTITY_CREATE_MANY_RECIPE_STEP_FILE, { creates });
+}
+
+export function updateManyRecipeStepFile(
+  updates: RecipeStepFileUpdateInterface[],
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_MANY_RECIPE_STEP_FILE, { updates });
+}
+
+export function deleteManyRecipeStepFile(ids: number[]): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_MANY_RECIPE_STEP_FILE, { ids });
+}
+
 export function listRecipeStepFile(
   filter: RecipeStepFileFilter,
 ): Promise<number[]> {
   return listCollected(Command.ENTITY_LIST_RECIPE_STEP_FILE, filter);
 }
 
+export function listPageRecipeStepFile(
+  filter: RecipeStepFileFilter,
+): Promise<ListPage<number>> {
+  return invoke(Command.ENTITY_LIST_PAGE_RECIPE_STEP_FILE, { filter });
+}
+
 export function countRecipeStepFile(
   condition?: RecipeStepFileCondition,
 ): Promise<number> {
   return countCollected(Command.ENTITY_COUNT_RECIPE_STEP_FILE, condition);
 }
 
+export function moveRecipeStepFile(
+  id: number,
+  position: number,
+): Promise<void> {
+  return invoke(Command.ENTITY_MOVE_RECIPE_STEP_FILE, { id, position });
+}
+
+export function reorderRecipeStepFile(
+  recipeStepId: number,
+  ids: number[],
+): Promise<void> {
+  return invoke(Command.ENTITY_REORDER_RECIPE_STEP_FILE, { recipeStepId, ids });
+}
+
 export function createRecipeStepIngredient(
   create: RecipeStepIngredientCreateInterface,
 ): Promise<number> {
@@ -446,18 +1373,57 @@ export function deleteRecipeStepIngredient(id: number): Promise<void> {
   return invoke(Command.ENTITY_DELETE_RECIPE_STEP_INGREDIENT, { id });
 }
 
+export function createManyRecipeStepIngredient(
+  creates: RecipeStepIngredientCreateInterface[],
+): Promise<number[]> {
+  return invoke(Command.ENTITY_CREATE_MANY_RECIPE_STEP_INGREDIENT, { creates });
+}
+
+export function updateManyRecipeStepIngredient(
+  updates: RecipeStepIngredientUpdateInterface[],
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_MANY_RECIPE_STEP_INGREDIENT, { updates });
+}
+
+export function deleteManyRecipeStepIngredient(ids: number[]): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_MANY_RECIPE_STEP_INGREDIENT, { ids });
+}
+
 export function listRecipeStepIngredient(
   filter: RecipeStepIngredientFilter,
 ): Promise<number[]> {
   return listCollected(Command.ENTITY_LIST_RECIPE_STEP_INGREDIENT, filter);
 }
 
+export function listPageRecipeStepIngredient(
+  filter: RecipeStepIngredientFilter,
+): Promise<ListPage<number>> {
+  return invoke(Command.ENTITY_LIST_PAGE_RECIPE_STEP_INGREDIENT, { filter });
+}
+
 export function countRecipeStepIngredient(
   condition?: RecipeStepIngredientCondition,
 ): Promise<number> {
   return countCollected(Command.ENTITY_COUNT_RECIPE_STEP_INGREDIENT, condition);
 }
 
+export function moveRecipeStepIngredient(
+  id: number,
+  position: number,
+): Promise<void> {
+  return invoke(Command.ENTITY_MOVE_RECIPE_STEP_INGREDIENT, { id, position });
+}
+
+export function reorderRecipeStepIngredient(
+  recipeStepId: number,
+  ids: number[],
+): Promise<void> {
+  return invoke(Command.ENTITY_REORDER_RECIPE_STEP_INGREDIENT, {
+    recipeStepId,
+    ids,
+  });
+}
+
 export function createRecipeStepIngredientDraft(
   create: RecipeStepIngredientDraftCreateInterface,
 ): Promise<number> {
@@ -480,6 +1446,30 @@ export function deleteRecipeStepIngredientDraft(id: number): Promise<void> {
   return invoke(Command.ENTITY_DELETE_RECIPE_STEP_INGREDIENT_DRAFT, { id });
 }
 
+export function createManyRecipeStepIngredientDraft(
+  creates: RecipeStepIngredientDraftCreateInterface[],
+): Promise<number[]> {
+  return invoke(Command.ENTITY_CREATE_MANY_RECIPE_STEP_INGREDIENT_DRAFT, {
+    creates,
+  });
+}
+
+export function updateManyRecipeStepIngredientDraft(
+  updates: RecipeStepIngredientDraftUpdateInterface[],
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_MANY_RECIPE_STEP_INGREDIENT_DRAFT, {
+    updates,
+  });
+}
+
+export function deleteManyRecipeStepIngredientDraft(
+  ids: number[],
+): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_MANY_RECIPE_STEP_INGREDIENT_DRAFT, {
+    ids,
+  });
+}
+
 export function listRecipeStepIngredientDraft(
   filter: RecipeStepIngredientDraftFilter,
 ): Promise<number[]> {
@@ -489,6 +1479,14 @@ export function listRecipeStepIngredientDraft(
   );
 }
 
+export function listPageRecipeStepIngredientDraft(
+  filter: RecipeStepIngredientDraftFilter,
+): Promise<ListPage<number>> {
+  return invoke(Command.ENTITY_LIST_PAGE_RECIPE_STEP_INGREDIENT_DRAFT, {
+    filter,
+  });
+}
+
 export function countRecipeStepIngredientDraft(
   condition?: RecipeStepIngredientDraftCondition,
 ): Promise<number> {
@@ -498,6 +1496,26 @@ export function countRecipeStepIngredientDraft(
   );
 }
 
+export function moveRecipeStepIngredientDraft(
+  id: number,
+  position: number,
+): Promise<void> {
+  return invoke(Command.ENTITY_MOVE_RECIPE_STEP_INGREDIENT_DRAFT, {
+    id,
+    position,
+  });
+}
+
+export function reorderRecipeStepIngredientDraft(
+  recipeStepId: number,
+  ids: number[],
+): Promise<void> {
+  return invoke(Command.ENTITY_REORDER_RECIPE_STEP_INGREDIENT_DRAFT, {
+    recipeStepId,
+    ids,
+  });
+}
+
 export function createRecipeStep(
   create: RecipeStepCreateInterface,
 ): Promise<number> {
@@ -518,16 +1536,49 @@ export function deleteRecipeStep(id: number): Promise<void> {
   return invoke(Command.ENTITY_DELETE_RECIPE_STEP, { id });
 }
 
+export function createManyRecipeStep(
+  creates: RecipeStepCreateInterface[],
+): Promise<number[]> {
+  return invoke(Command.ENTITY_CREATE_MANY_RECIPE_STEP, { creates });
+}
+
+export function updateManyRecipeStep(
+  updates: RecipeStepUpdateInterface[],
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_MANY_RECIPE_STEP, { updates });
+}
+
+export function deleteManyRecipeStep(ids: number[]): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_MANY_RECIPE_STEP, { ids });
+}
+
 export function listRecipeStep(filter: RecipeStepFilter): Promise<number[]> {
   return listCollected(Command.ENTITY_LIST_RECIPE_STEP, filter);
 }
 
+export function listPageRecipeStep(
+  filter: RecipeStepFilter,
+): Promise<ListPage<number>> {
+  return invoke(Command.ENTITY_LIST_PAGE_RECIPE_STEP, { filter });
+}
+
 export function countRecipeStep(
   condition?: RecipeStepCondition,
 ): Promise<number> {
   return countCollected(Command.ENTITY_COUNT_RECIPE_STEP, condition);
 }
 
+export function moveRecipeStep(id: number, position: number): Promise<void> {
+  return invoke(Command.ENTITY_MOVE_RECIPE_STEP, { id, position });
+}
+
+export function reorderRecipeStep(
+  recipeId: number,
+  ids: number[],
+): Promise<void> {
+  return invoke(Command.ENTITY_REORDER_RECIPE_STEP, { recipeId, ids });
+}
+
 export function createUnitName(
   create: UnitNameCreateInterface,
 ): Promise<string> {
@@ -546,10 +1597,32 @@ export function deleteUnitName(id: string): Promise<void> {
   return invoke(Command.ENTITY_DELETE_UNIT_NAME, { id });
 }
 
+export function createManyUnitName(
+  creates: UnitNameCreateInterface[],
+): Promise<string[]> {
+  return invoke(Command.ENTITY_CREATE_MANY_UNIT_NAME, { creates });
+}
+
+export function updateManyUnitName(
+  updates: UnitNameUpdateInterface[],
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_MANY_UNIT_NAME, { updates });
+}
+
+export function deleteManyUnitName(ids: string[]): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_MANY_UNIT_NAME, { ids });
+}
+
 export function listUnitName(filter: UnitNameFilter): Promise<string[]> {
   return listCollected(Command.ENTITY_LIST_UNIT_NAME, filter);
 }
 
+export function listPageUnitName(
+  filter: UnitNameFilter,
+): Promise<ListPage<string>> {
+  return invoke(Command.ENTITY_LIST_PAGE_UNIT_NAME, { filter });
+}
+
 export function countUnitName(condition?: UnitNameCondition): Promise<number> {
   return countCollected(Command.ENTITY_COUNT_UNIT_NAME, condition);
 }
@@ -2,34 +2,128 @@ export const enum EventChannel {
   ENTITY_ACTION_CREATED_FILE = "ENTITY_ACTION_CREATED_FILE",
   ENTITY_ACTION_UPDATED_FILE = "ENTITY_ACTION_UPDATED_FILE",
   ENTITY_ACTION_DELETED_FILE = "ENTITY_ACTION_DELETED_FILE",
+  ENTITY_ACTION_CREATED_MANY_FILE = "ENTITY_ACTION_CREATED_MANY_FILE",
+  ENTITY_ACTION_UPDATED_MANY_FILE = "ENTITY_ACTION_UPDATED_MANY_FILE",
+  ENTITY_ACTION_DELETED_MANY_FILE = "ENTITY_ACTION_DELETED_MANY_FILE",
   ENTITY_ACTION_CREATED_INGREDIENT = "ENTITY_ACTION_CREATED_INGREDIENT",
   ENTITY_ACTION_UPDATED_INGREDIENT = "ENTITY_ACTION_UPDATED_INGREDIENT",
   ENTITY_ACTION_DELETED_INGREDIENT = "ENTITY_ACTION_DELETED_INGREDIENT",
+  ENTITY_ACTION_CREATED_MANY_INGREDIENT = "ENTITY_ACTION_CREATED_MANY_INGREDIENT",
+  ENTITY_ACTION_UPDATED_MANY_INGREDIENT = "ENTITY_ACTION_UPDATED_MANY_INGREDIENT",
+  ENTITY_ACTION_DELETED_MANY_INGREDIENT = "ENTITY_ACTION_DELETED_MANY_INGREDIENT",
   ENTITY_ACTION_CREATED_RECIPE = "ENTITY_ACTION_CREATED_RECIPE",
   ENTITY_ACTION_UPDATED_RECIPE = "ENTITY_ACTION_UPDATED_RECIPE",
   ENTITY_ACTION_DELETED_RECIPE = "ENTITY_ACTION_DELETED_RECIPE",
+  ENTITY_ACTION_CREATED_MANY_RECIPE = "ENTITY_ACTION_CREATED_MANY_RECIPE",
+  ENTITY_ACTION_UPDATED_MANY_RECIPE = "ENTITY_ACTION_UPDATED_MANY_RECIPE",
+  ENTITY_ACTION_DELETED_MANY_RECIPE = "ENTITY_ACTION_DELETED_MANY_RECIPE",
   ENTITY_ACTION_CREATED_RECIPE_FILE = "ENTITY_ACTION_CREATED_RECIPE_FILE",
   ENTITY_ACTION_UPDATED_RECIPE_FILE = "ENTITY_ACTION_UPDATED_RECIPE_FILE",
   ENTITY_ACTION_DELETED_RECIPE_FILE = "ENTITY_ACTION_DELETED_RECIPE_FILE",
+  ENTITY_ACTION_CREATED_MANY_RECIPE_FILE = "ENTITY_ACTION_CREATED_MANY_RECIPE_FILE",
+  ENTITY_ACTION_UPDATED_MANY_RECIPE_FILE = "ENTITY_ACTION_UPDATED_MANY_RECIPE_FILE",
+  ENTITY_ACTION_DELETED_MANY_RECIPE_FILE = "ENTITY_ACTION_DELETED_MANY_RECIPE_FILE",
+  ENTITY_ACTION_CREATED_COOK_LOG_FILE = "ENTITY_ACTION_CREATED_COOK_LOG_FILE",
+  ENTITY_ACTION_UPDATED_COOK_LOG_FILE = "ENTITY_ACTION_UPDATED_COOK_LOG_FILE",
+  ENTITY_ACTION_DELETED_COOK_LOG_FILE = "ENTITY_ACTION_DELETED_COOK_LOG_FILE",
+  ENTITY_ACTION_CREATED_MANY_COOK_LOG_FILE = "ENTITY_ACTION_CREATED_MANY_COOK_LOG_FILE",
+  ENTITY_ACTION_UPDATED_MANY_COOK_LOG_FILE = "ENTITY_ACTION_UPDATED_MANY_COOK_LOG_FILE",
+  ENTITY_ACTION_DELETED_MANY_COOK_LOG_FILE = "ENTITY_ACTION_DELETED_MANY_COOK_LOG_FILE",
+  ENTITY_ACTION_CREATED_COLLECTION_RECIPE = "ENTITY_ACTION_CREATED_COLLECTION_RECIPE",
+  ENTITY_ACTION_UPDATED_COLLECTION_RECIPE = "ENTITY_ACTION_UPDATED_COLLECTION_RECIPE",
+  ENTITY_ACTION_DELETED_COLLECTION_RECIPE = "ENTITY_ACTION_DELETED_COLLECTION_RECIPE",
+  ENTITY_ACTION_CREATED_MANY_COLLECTION_RECIPE = "ENTITY_ACTION_CREATED_MANY_COLLECTION_RECIPE",
+  ENTITY_ACTION_UPDATED_MANY_COLLECTION_RECIPE = "ENTITY_ACTION_UPDATED_MANY_COLLECTION_RECIPE",
+  ENTITY_ACTION_DELETED_MANY_COLLECTION_RECIPE = "ENTITY_ACTION_DELETED_MANY_COLLECTION_RECIPE",
   ENTITY_ACTION_CREATED_RECIPE_INGREDIENT_DRAFT = "ENTITY_ACTION_CREATED_RECIPE_INGREDIENT_DRAFT",
   ENTITY_ACTION_UPDATED_RECIPE_INGREDIENT_DRAFT = "ENTITY_ACTION_UPDATED_RECIPE_INGREDIENT_DRAFT",
   ENTITY_ACTION_DELETED_RECIPE_INGREDIENT_DRAFT = "ENTITY_ACTION_DELETED_RECIPE_INGREDIENT_DRAFT",
+  ENTITY_ACTION_CREATED_MANY_RECIPE_INGREDIENT_DRAFT = "ENTITY_ACTION_CREATED_MANY_RECIPE_INGREDIENT_DRAFT",
+  ENTITY_ACTION_UPDATED_MANY_RECIPE_INGREDIENT_DRAFT = "ENTITY_ACTION_UPDATED_MANY_RECIPE_INGREDIENT_DRAFT",
+  ENTITY_ACTION_DELETED_MANY_RECIPE_INGREDIENT_DRAFT = "ENTITY_ACTION_DELETED_MANY_RECIPE_INGREDIENT_DRAFT",
   ENTITY_ACTION_CREATED_RECIPE_STEP_FILE = "ENTITY_ACTION_CREATED_RECIPE_STEP_FILE",
   ENTITY_ACTION_UPDATED_RECIPE_STEP_FILE = "ENTITY_ACTION_UPDATED_RECIPE_STEP_FILE",
   ENTITY_ACTION_DELETED_RECIPE_STEP_FILE = "ENTITY_ACTION_DELETED_RECIPE_STEP_FILE",
+  ENTITY_ACTION_CREATED_MANY_RECIPE_STEP_FILE = "ENTITY_ACTION_CREATED_MANY_RECIPE_STEP_FILE",
+  ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP_FILE = "ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP_FILE",
+  ENTITY_ACTION_DELETED_MANY_RECIPE_STEP_FILE = "ENTITY_ACTION_DELETED_MANY_RECIPE_STEP_FILE",
   ENTITY_ACTION_CREATED_RECIPE_STEP_INGREDIENT = "ENTITY_ACTION_CREATED_RECIPE_STEP_INGREDIENT",
   ENTITY_ACTION_UPDATED_RECIPE_STEP_INGREDIENT = "ENTITY_ACTION_UPDATED_RECIPE_STEP_INGREDIENT",
   ENTITY_ACTION_DELETED_RECIPE_STEP_INGREDIENT = "ENTITY_ACTION_DELETED_RECIPE_STEP_INGREDIENT",
+  ENTITY_ACTION_CREATED_MANY_RECIPE_STEP_INGREDIENT = "ENTITY_ACTION_CREATED_MANY_RECIPE_STEP_INGREDIENT",
+  ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP_INGREDIENT = "ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP_INGREDIENT",
+  ENTITY_ACTION_DELETED_MANY_RECIPE_STEP_INGREDIENT = "ENTITY_ACTION_DELETED_MANY_RECIPE_STEP_INGREDIENT",
+  ENTITY_ACTION_CREATED_INGREDIENT_ALIAS = "ENTITY_ACTION_CREATED_INGREDIENT_ALIAS",
+  ENTITY_ACTION_UPDATED_INGREDIENT_ALIAS = "ENTITY_ACTION_UPDATED_INGREDIENT_ALIAS",
+  ENTITY_ACTION_DELETED_INGREDIENT_ALIAS = "ENTITY_ACTION_DELETED_INGREDIENT_ALIAS",
+  ENTITY_ACTION_CREATED_MANY_INGREDIENT_ALIAS = "ENTITY_ACTION_CREATED_MANY_INGREDIENT_ALIAS",
+  ENTITY_ACTION_UPDATED_MANY_INGREDIENT_ALIAS = "ENTITY_ACTION_UPDATED_MANY_INGREDIENT_ALIAS",
+  ENTITY_ACTION_DELETED_MANY_INGREDIENT_ALIAS = "ENTITY_ACTION_DELETED_MANY_INGREDIENT_ALIAS",
+  ENTITY_ACTION_CREATED_INGREDIENT_ALLERGEN = "ENTITY_ACTION_CREATED_INGREDIENT_ALLERGEN",
+  ENTITY_ACTION_UPDATED_INGREDIENT_ALLERGEN = "ENTITY_ACTION_UPDATED_INGREDIENT_ALLERGEN",
+  ENTITY_ACTION_DELETED_INGREDIENT_ALLERGEN = "ENTITY_ACTION_DELETED_INGREDIENT_ALLERGEN",
+  ENTITY_ACTION_CREATED_MANY_INGREDIENT_ALLERGEN = "ENTITY_ACTION_CREATED_MANY_INGREDIENT_ALLERGEN",
+  ENTITY_ACTION_UPDATED_MANY_INGREDIENT_ALLERGEN = "ENTITY_ACTION_UPDATED_MANY_INGREDIENT_ALLERGEN",
+  ENTITY_ACTION_DELETED_MANY_INGREDIENT_ALLERGEN = "ENTITY_ACTION_DELETED_MANY_INGREDIENT_ALLERGEN",
+  ENTITY_ACTION_CREATED_COOK_LOG = "ENTITY_ACTION_CREATED_COOK_LOG",
+  ENTITY_ACTION_UPDATED_COOK_LOG = "ENTITY_ACTION_UPDATED_COOK_LOG",
+  ENTITY_ACTION_DELETED_COOK_LOG = "ENTITY_ACTION_DELETED_COOK_LOG",
+  ENTITY_ACTION_CREATED_MANY_COOK_LOG = "ENTITY_ACTION_CREATED_MANY_COOK_LOG",
+  ENTITY_ACTION_UPDATED_MANY_COOK_LOG = "ENTITY_ACTION_UPDATED_MANY_COOK_LOG",
+  ENTITY_ACTION_DELETED_MANY_COOK_LOG = "ENTITY_ACTION_DELETED_MANY_COOK_LOG",
+  ENTITY_ACTION_CREATED_COLLECTION = "ENTITY_ACTION_CREATED_COLLECTION",
+  ENTITY_ACTION_UPDATED_COLLECTION = "ENTITY_ACTION_UPDATED_COLLECTION",
+  ENTITY_ACTION_DELETED_COLLECTION = "ENTITY_ACTION_DELETED_COLLECTION",
+  ENTITY_ACTION_CREATED_MANY_COLLECTION = "ENTITY_ACTION_CREATED_MANY_COLLECTION",
+  ENTITY_ACTION_UPDATED_MANY_COLLECTION = "ENTITY_ACTION_UPDATED_MANY_COLLECTION",
+  ENTITY_ACTION_DELETED_MANY_COLLECTION = "ENTITY_ACTION_DELETED_MANY_COLLECTION",
+  ENTITY_ACTION_CREATED_TAG = "ENTITY_ACTION_CREATED_TAG",
+  ENTITY_ACTION_UPDATED_TAG = "ENTITY_ACTION_UPDATED_TAG",
+  ENTITY_ACTION_DELETED_TAG = "ENTITY_ACTION_DELETED_TAG",
+  ENTITY_ACTION_CREATED_MANY_TAG = "ENTITY_ACTION_CREATED_MANY_TAG",
+  ENTITY_ACTION_UPDATED_MANY_TAG = "ENTITY_ACTION_UPDATED_MANY_TAG",
+  ENTITY_ACTION_DELETED_MANY_TAG = "ENTITY_ACTION_DELETED_MANY_TAG",
+  ENTITY_ACTION_CREATED_RECIPE_TAG = "ENTITY_ACTION_CREATED_RECIPE_TAG",
+  ENTITY_ACTION_UPDATED_RECIPE_TAG = "ENTITY_ACTION_UPDATED_RECIPE_TAG",
+  ENTITY_ACTION_DELETED_RECIPE_TAG = "ENTITY_ACTION_DELETED_RECIPE_TAG",
+  ENTITY_ACTION_CREATED_MANY_RECIPE_TAG = "ENTITY_ACTION_CREATED_MANY_RECIPE_TAG",
+  ENTITY_ACTION_UPDATED_MANY_RECIPE_TAG = "ENTITY_ACTION_UPDATED_MANY_RECIPE_TAG",
+  ENTITY_ACTION_DELETED_MANY_RECIPE_TAG = "ENTITY_ACTION_DELETED_MANY_RECIPE_TAG",
+  ENTITY_ACTION_CREATED_INGREDIENT_CATEGORY = "ENTITY_ACTION_CREATED_INGREDIENT_CATEGORY",
+  ENTITY_ACTION_UPDATED_INGREDIENT_CATEGORY = "ENTITY_ACTION_UPDATED_INGREDIENT_CATEGORY",
+  ENTITY_ACTION_DELETED_INGREDIENT_CATEGORY = "ENTITY_ACTION_DELETED_INGREDIENT_CATEGORY",
+  ENTITY_ACTION_CREATED_MANY_INGREDIENT_CATEGORY = "ENTITY_ACTION_CREATED_MANY_INGREDIENT_CATEGORY",
+  ENTITY_ACTION_UPDATED_MANY_INGREDIENT_CATEGORY = "ENTITY_ACTION_UPDATED_MANY_INGREDIENT_CATEGORY",
+  ENTITY_ACTION_DELETED_MANY_INGREDIENT_CATEGORY = "ENTITY_ACTION_DELETED_MANY_INGREDIENT_CATEGORY",
+  ENTITY_ACTION_CREATED_NUTRIENT_PROFILE = "ENTITY_ACTION_CREATED_NUTRIENT_PROFILE",
+  ENTITY_ACTION_UPDATED_NUTRIENT_PROFILE = "ENTITY_ACTION_UPDATED_NUTRIENT_PROFILE",
+  ENTITY_ACTION_DELETED_NUTRIENT_PROFILE = "ENTITY_ACTION_DELETED_NUTRIENT_PROFILE",
+  ENTITY_ACTION_CREATED_MANY_NUTRIENT_PROFILE = "ENTITY_ACTION_CREATED_MANY_NUTRIENT_PROFILE",
+  ENTITY_ACTION_UPDATED_MANY_NUTRIENT_PROFILE = "ENTITY_ACTION_UPDATED_MANY_NUTRIENT_PROFILE",
+  ENTITY_ACTION_DELETED_MANY_NUTRIENT_PROFILE = "ENTITY_ACTION_DELETED_MANY_NUTRIENT_PROFILE",
   ENTITY_ACTION_CREATED_RECIPE_STEP_INGREDIENT_DRAFT = "ENTITY_ACTION_CREATED_RECIPE_STEP_INGREDIENT_DRAFT",
   ENTITY_ACTION_UPDATED_RECIPE_STEP_INGREDIENT_DRAFT = "ENTITY_ACTION_UPDATED_RECIPE_STEP_INGREDIENT_DRAFT",
   ENTITY_ACTION_DELETED_RECIPE_STEP_INGREDIENT_DRAFT = "ENTITY_ACTION_DELETED_RECIPE_STEP_INGREDIENT_DRAFT",
+  ENTITY_ACTION_CREATED_MANY_RECIPE_STEP_INGREDIENT_DRAFT = "ENTITY_ACTION_CREATED_MANY_RECIPE_STEP_INGREDIENT_DRAFT",
+  ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP_INGREDIENT_DRAFT = "ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP_INGREDIENT_DRAFT",
+  ENTITY_ACTION_DELETED_MANY_RECIPE_STEP_INGREDIENT_DRAFT = "ENTITY_ACTION_DELETED_MANY_RECIPE_STEP_INGREDIENT_DRAFT",
   ENTITY_ACTION_CREATED_RECIPE_STEP = "ENTITY_ACTION_CREATED_RECIPE_STEP",
   ENTITY_ACTION_UPDATED_RECIPE_STEP = "ENTITY_ACTION_UPDATED_RECIPE_STEP",
   ENTITY_ACTION_DELETED_RECIPE_STEP = "ENTITY_ACTION_DELETED_RECIPE_STEP",
+  ENTITY_ACTION_CREATED_MANY_RECIPE_STEP = "ENTITY_ACTION_CREATED_MANY_RECIPE_STEP",
+  ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP = "ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP",
+  ENTITY_ACTION_DELETED_MANY_RECIPE_STEP = "ENTITY_ACTION_DELETED_MANY_RECIPE_STEP",
   ENTITY_ACTION_CREATED_UNIT_NAME = "ENTITY_ACTION_CREATED_UNIT_NAME",
   ENTITY_ACTION_UPDATED_UNIT_NAME = "ENTITY_ACTION_UPDATED_UNIT_NAME",
   ENTITY_ACTION_DELETED_UNIT_NAME = "ENTITY_ACTION_DELETED_UNIT_NAME",
+  ENTITY_ACTION_CREATED_MANY_UNIT_NAME = "ENTITY_ACTION_CREATED_MANY_UNIT_NAME",
+  ENTITY_ACTION_UPDATED_MANY_UNIT_NAME = "ENTITY_ACTION_UPDATED_MANY_UNIT_NAME",
+  ENTITY_ACTION_DELETED_MANY_UNIT_NAME = "ENTITY_ACTION_DELETED_MANY_UNIT_NAME",
 
   SCRAPER_DOM_DROP = "SCRAPER_DOM_DROP",
   SCRAPER_ELEMENT_DROP = "SCRAPER_ELEMENT_DROP",
+
+  TIMER_TICK = "TIMER_TICK",
+  TIMER_FINISH = "TIMER_FINISH",
+  TIMER_CANCEL = "TIMER_CANCEL",
 }
@@ -1,39 +1,161 @@
+import type { EntityChange } from "../../types/entity-change.ts";
+import type { CollectionInterface } from "../../types/entity/collection-interface.ts";
+import type { CollectionRecipeInterface } from "../../types/entity/collection-recipe-interface.ts";
+import type { CookLogFileInterface } from "../../types/entity/cook-log-file-interface.ts";
+import type { CookLogInterface } from "../../types/entity/cook-log-interface.ts";
+import type { FileInterface } from "../../types/entity/file-interface.ts";
+import type { IngredientAliasInterface } from "../../types/entity/ingredient-alias-interface.ts";
+import type { IngredientAllergenInterface } from "../../types/entity/ingredient-allergen-interface.ts";
+import type { IngredientCategoryInterface } from "../../types/entity/ingredient-category-interface.ts";
+import type { IngredientInterface } from "../../types/entity/ingredient-interface.ts";
+import type { NutrientProfileInterface } from "../../types/entity/nutrient-profile-interface.ts";
+import type { RecipeFileInterface } from "../../types/entity/recipe-file-interface.ts";
+import type { RecipeIngredientDraftInterface } from "../../types/entity/recipe-ingredient-draft-interface.ts";
+import type { RecipeInterface } from "../../types/entity/recipe-interface.ts";
+import type { RecipeStepFileInterface } from "../../types/entity/recipe-step-file-interface.ts";
+import type { RecipeStepIngredientDraftInterface } from "../../types/entity/recipe-step-ingredient-draft-interface.ts";
+import type { RecipeStepIngredientInterface } from "../../types/entity/recipe-step-ingredient-interface.ts";
+import type { RecipeStepInterface } from "../../types/entity/recipe-step-interface.ts";
+import type { RecipeTagInterface } from "../../types/entity/recipe-tag-interface.ts";
+import type { TagInterface } from "../../types/entity/tag-interface.ts";
+import type { UnitNameInterface } from "../../types/entity/unit-name-interface.ts";
+import type { Timer } from "../../types/timer.ts";
 import type { EventChannel } from "./event-channel.ts";
 
 type EventPayloadMap = {
-  [EventChannel.ENTITY_ACTION_CREATED_FILE]: void;
-  [EventChannel.ENTITY_ACTION_UPDATED_FILE]: number;
-  [EventChannel.ENTITY_ACTION_DELETED_FILE]: number;
-  [EventChannel.ENTITY_ACTION_CREATED_INGREDIENT]: void;
-  [EventChannel.ENTITY_ACTION_UPDATED_INGREDIENT]: number;
-  [EventChannel.ENTITY_ACTION_DELETED_INGREDIENT]: number;
-  [EventChannel.ENTITY_ACTION_CREATED_RECIPE]: void;
-  [EventChannel.ENTITY_ACTION_UPDATED_RECIPE]: number;
-  [EventChannel.ENTITY_ACTION_DELETED_RECIPE]: number;
-  [EventChannel.ENTITY_ACTION_CREATED_RECIPE_FILE]: void;
-  [EventChannel.ENTITY_ACTION_UPDATED_RECIPE_FILE]: number;
-  [EventChannel.ENTITY_ACTION_DELETED_RECIPE_FILE]: number;
-  [EventChannel.ENTITY_ACTION_CREATED_RECIPE_INGREDIENT_DRAFT]: void;
-  [EventChannel.ENTITY_ACTION_UPDATED_RECIPE_INGREDIENT_DRAFT]: number;
-  [EventChannel.ENTITY_ACTION_DELETED_RECIPE_INGREDIENT_DRAFT]: number;
-  [EventChannel.ENTITY_ACTION_CREATED_RECIPE_STEP_FILE]: void;
-  [EventChannel.ENTITY_ACTION_UPDATED_RECIPE_STEP_FILE]: number;
-  [EventChannel.ENTITY_ACTION_DELETED_RECIPE_STEP_FILE]: number;
-  [EventChannel.ENTITY_ACTION_CREATED_RECIPE_STEP_INGREDIENT]: void;
-  [EventChannel.ENTITY_ACTION_UPDATED_RECIPE_STEP_INGREDIENT]: number;
-  [EventChannel.ENTITY_ACTION_DELETED_RECIPE_STEP_INGREDIENT]: number;
-  [EventChannel.ENTITY_ACTION_CREATED_RECIPE_STEP_INGREDIENT_DRAFT]: void;
-  [EventChannel.ENTITY_ACTION_UPDATED_RECIPE_STEP_INGREDIENT_DRAFT]: number;
-  [EventChannel.ENTITY_ACTION_DELETED_RECIPE_STEP_INGREDIENT_DRAFT]: number;
-  [EventChannel.ENTITY_ACTION_CREATED_RECIPE_STEP]: void;
-  [EventChannel.ENTITY_ACTION_UPDATED_RECIPE_STEP]: number;
-  [EventChannel.ENTITY_ACTION_DELETED_RECIPE_STEP]: number;
-  [EventChannel.ENTITY_ACTION_CREATED_UNIT_NAME]: void;
-  [EventChannel.ENTITY_ACTION_UPDATED_UNIT_NAME]: string;
-  [EventChannel.ENTITY_ACTION_DELETED_UNIT_NAME]: string;
+  [EventChannel.ENTITY_ACTION_CREATED_FILE]: EntityChange<FileInterface>;
+  [EventChannel.ENTITY_ACTION_UPDATED_FILE]: EntityChange<FileInterface>;
+  [EventChannel.ENTITY_ACTION_DELETED_FILE]: EntityChange<FileInterface>;
+  [EventChannel.ENTITY_ACTION_CREATED_MANY_FILE]: EntityChange<FileInterface>[];
+  [EventChannel.ENTITY_ACTION_UPDATED_MANY_FILE]: EntityChange<FileInterface>[];
+  [EventChannel.ENTITY_ACTION_DELETED_MANY_FILE]: EntityChange<FileInterface>[];
+  [EventChannel.ENTITY_ACTION_CREATED_INGREDIENT]: EntityChange<IngredientInterface>;
+  [EventChannel.ENTITY_ACTION_UPDATED_INGREDIENT]: EntityChange<IngredientInterface>;
+  [EventChannel.ENTITY_ACTION_DELETED_INGREDIENT]: EntityChange<IngredientInterface>;
+  [EventChannel.ENTITY_ACTION_CREATED_MANY_INGREDIENT]: EntityChange<IngredientInterface>[];
+  [EventChannel.ENTITY_ACTION_UPDATED_MANY_INGREDIENT]: EntityChange<IngredientInterface>[];
+  [EventChannel.ENTITY_ACTION_DELETED_MANY_INGREDIENT]: EntityChange<IngredientInterface>[];
+  [EventChannel.ENTITY_ACTION_CREATED_RECIPE]: EntityChange<RecipeInterface>;
+  [EventChannel.ENTITY_ACTION_UPDATED_RECIPE]: EntityChange<RecipeInterface>;
+  [EventChannel.ENTITY_ACTION_DELETED_RECIPE]: EntityChange<RecipeInterface>;
+  [EventChannel.ENTITY_ACTION_CREATED_MANY_RECIPE]: EntityChange<RecipeInterface>[];
+  [EventChannel.ENTITY_ACTION_UPDATED_MANY_RECIPE]: EntityChange<RecipeInterface>[];
+  [EventChannel.ENTITY_ACTION_DELETED_MANY_RECIPE]: EntityChange<RecipeInterface>[];
+  [EventChannel.ENTITY_ACTION_CREATED_RECIPE_FILE]: EntityChange<RecipeFileInterface>;
+  [EventChannel.ENTITY_ACTION_UPDATED_RECIPE_FILE]: EntityChange<RecipeFileInterface>;
+  [EventChannel.ENTITY_ACTION_DELETED_RECIPE_FILE]: EntityChange<RecipeFileInterface>;
+  [EventChannel.ENTITY_ACTION_CREATED_MANY_RECIPE_FILE]: EntityChange<RecipeFileInterface>[];
+  [EventChannel.ENTITY_ACTION_UPDATED_MANY_RECIPE_FILE]: EntityChange<RecipeFileInterface>[];
+  [EventChannel.ENTITY_ACTION_DELETED_MANY_RECIPE_FILE]: EntityChange<RecipeFileInterface>[];
+  [EventChannel.ENTITY_ACTION_CREATED_COOK_LOG_FILE]: EntityChange<CookLogFileInterface>;
+  [EventChannel.ENTITY_ACTION_UPDATED_COOK_LOG_FILE]: EntityChange<CookLogFileInterface>;
+  [EventChannel.ENTITY_ACTION_DELETED_COOK_LOG_FILE]: EntityChange<CookLogFileInterface>;
+  [EventChannel.ENTITY_ACTION_CREATED_MANY_COOK_LOG_FILE]: EntityChange<CookLogFileInterface>[];
+  [EventChannel.ENTITY_ACTION_UPDATED_MANY_COOK_LOG_FILE]: EntityChange<CookLogFileInterface>[];
+  [EventChannel.ENTITY_ACTION_DELETED_MANY_COOK_LOG_FILE]: EntityChange<CookLogFileInterface>[];
+  [EventChannel.ENTITY_ACTION_CREATED_COLLECTION_RECIPE]: EntityChange<CollectionRecipeInterface>;
+  [EventChannel.ENTITY_ACTION_UPDATED_COLLECTION_RECIPE]: EntityChange<CollectionRecipeInterface>;
+  [EventChannel.ENTITY_ACTION_DELETED_COLLECTION_RECIPE]: EntityChange<CollectionRecipeInterface>;
+  [EventChannel.ENTITY_ACTION_CREATED_MANY_COLLECTION_RECIPE]: EntityChange<CollectionRecipeInterface>[];
+  [EventChannel.ENTITY_ACTION_UPDATED_MANY_COLLECTION_RECIPE]: EntityChange<CollectionRecipeInterface>[];
+  [EventChannel.ENTITY_ACTION_DELETED_MANY_COLLECTION_RECIPE]: EntityChange<CollectionRecipeInterface>[];
+  [EventChannel.ENTITY_ACTION_CREATED_RECIPE_INGREDIENT_DRAFT]: EntityChange<RecipeIngredientDraftInterface>;
+  [EventChannel.ENTITY_ACTION_UPDATED_RECIPE_INGREDIENT_DRAFT]: EntityChange<RecipeIngredientDraftInterface>;
+  [EventChannel.ENTITY_ACTION_DELETED_RECIPE_INGREDIENT_DRAFT]: EntityChange<RecipeIngredientDraftInterface>;
+  [EventChannel.ENTITY_ACTION_CREATED_MANY_RECIPE_INGREDIENT_DRAFT]: EntityChange<RecipeIngredientDraftInterface>[];
+  [EventChannel.ENTITY_ACTION_UPDATED_MANY_RECIPE_INGREDIENT_DRAFT]: EntityChange<RecipeIngredientDraftInterface>[];
+  [EventChannel.ENTITY_ACTION_DELETED_MANY_RECIPE_INGREDIENT_DRAFT]: EntityChange<RecipeIngredientDraftInterface>[];
+  [EventChannel.ENTITY_ACTION_CREATED_RECIPE_STEP_FILE]: EntityChange<RecipeStepFileInterface>;
+  [EventChannel.ENTITY_ACTION_UPDATED_RECIPE_STEP_FILE]: EntityChange<RecipeStepFileInterface>;
+  [EventChannel.ENTITY_ACTION_DELETED_RECIPE_STEP_FILE]: EntityChange<RecipeStepFileInterface>;
+  [EventChannel.ENTITY_ACTION_CREATED_MANY_RECIPE_STEP_FILE]: EntityChange<RecipeStepFileInterface>[];
+  [EventChannel.ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP_FILE]: EntityChange<RecipeStepFileInterface>[];
+  [EventChannel.ENTITY_ACTION_DELETED_MANY_RECIPE_STEP_FILE]: EntityChange<RecipeStepFileInterface>[];
+  [EventChannel.ENTITY_ACTION_CREATED_RECIPE_STEP_INGREDIENT]: EntityChange<RecipeStepIngredientInterface>;
+  [EventChannel.ENTITY_ACTION_UPDATED_RECIPE_STEP_INGREDIENT]: EntityChange<RecipeStepIngredientInterface>;
+  [EventChannel.ENTITY_ACTION_DELETED_RECIPE_STEP_INGREDIENT]: EntityChange<RecipeStepIngredientInterface>;
+  [EventChannel.ENTITY_ACTION_CREATED_MANY_RECIPE_STEP_INGREDIENT]: EntityChange<RecipeStepIngredientInterface>[];
+  [EventChannel.ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP_INGREDIENT]: EntityChange<RecipeStepIngredientInterface>[];
+  [EventChannel.ENTITY_ACTION_DELETED_MANY_RECIPE_STEP_INGREDIENT]: EntityChange<RecipeStepIngredientInterface>[];
+  [EventChannel.ENTITY_ACTION_CREATED_INGREDIENT_ALIAS]: EntityChange<IngredientAliasInterface>;
+  [EventChannel.ENTITY_ACTION_UPDATED_INGREDIENT_ALIAS]: EntityChange<IngredientAliasInterface>;
+  [EventChannel.ENTITY_ACTION_DELETED_INGREDIENT_ALIAS]: EntityChange<IngredientAliasInterface>;
+  [EventChannel.ENTITY_ACTION_CREATED_MANY_INGREDIENT_ALIAS]: EntityChange<IngredientAliasInterface>[];
+  [EventChannel.ENTITY_ACTION_UPDATED_MANY_INGREDIENT_ALIAS]: EntityChange<IngredientAliasInterface>[];
+  [EventChannel.ENTITY_ACTION_DELETED_MANY_INGREDIENT_ALIAS]: EntityChange<IngredientAliasInterface>[];
+  [EventChannel.ENTITY_ACTION_CREATED_INGREDIENT_ALLERGEN]: EntityChange<IngredientAllergenInterface>;
+  [EventChannel.ENTITY_ACTION_UPDATED_INGREDIENT_ALLERGEN]: EntityChange<IngredientAllergenInterface>;
+  [EventChannel.ENTITY_ACTION_DELETED_INGREDIENT_ALLERGEN]: EntityChange<IngredientAllergenInterface>;
+  [EventChannel.ENTITY_ACTION_CREATED_MANY_INGREDIENT_ALLERGEN]: EntityChange<IngredientAllergenInterface>[];
+  [EventChannel.ENTITY_ACTION_UPDATED_MANY_INGREDIENT_ALLERGEN]: EntityChange<IngredientAllergenInterface>[];
+  [EventChannel.ENTITY_ACTION_DELETED_MANY_INGREDIENT_ALLERGEN]: EntityChange<IngredientAllergenInterface>[];
+  [EventChannel.ENTITY_ACTION_CREATED_COOK_LOG]: EntityChange<CookLogInterface>;
+  [EventChannel.ENTITY_ACTION_UPDATED_COOK_LOG]: EntityChange<CookLogInterface>;
+  [EventChannel.ENTITY_ACTION_DELETED_COOK_LOG]: EntityChange<CookLogInterface>;
+  [EventChannel.ENTITY_ACTION_CREATED_MANY_COOK_LOG]: EntityChange<CookLogInterface>[];
+  [EventChannel.ENTITY_ACTION_UPDATED_MANY_COOK_LOG]: EntityChange<CookLogInterface>[];
+  [EventChannel.ENTITY_ACTION_DELETED_MANY_COOK_LOG]: EntityChange<CookLogInterface>[];
+  [EventChannel.ENTITY_ACTION_CREATED_COLLECTION]: EntityChange<CollectionInterface>;
+  [EventChannel.ENTITY_ACTION_UPDATED_COLLECTION]: EntityChange<CollectionInterface>;
+  [EventChannel.ENTITY_ACTION_DELETED_COLLECTION]: EntityChange<CollectionInterface>;
+  [EventChannel.ENTITY_ACTION_CREATED_MANY_COLLECTION]: EntityChange<CollectionInterface>[];
+  [EventChannel.ENTITY_ACTION_UPDATED_MANY_COLLECTION]: EntityChange<CollectionInterface>[];
+  [EventChannel.ENTITY_ACTION_DELETED_MANY_COLLECTION]: EntityChange<CollectionInterface>[];
+  [EventChannel.ENTITY_ACTION_CREATED_TAG]: EntityChange<TagInterface>;
+  [EventChannel.ENTITY_ACTION_UPDATED_TAG]: EntityChange<TagInterface>;
+  [EventChannel.ENTITY_ACTION_DELETED_TAG]: EntityChange<TagInterface>;
+  [EventChannel.ENTITY_ACTION_CREATED_MANY_TAG]: EntityChange<TagInterface>[];
+  [EventChannel.ENTITY_ACTION_UPDATED_MANY_TAG]: EntityChange<TagInterface>[];
+  [EventChannel.ENTITY_ACTION_DELETED_MANY_TAG]: EntityChange<TagInterface>[];
+  [EventChannel.ENTITY_ACTION_CREATED_RECIPE_TAG]: EntityChange<RecipeTagInterface>;
+  [EventChannel.ENTITY_ACTION_UPDATED_RECIPE_TAG]: EntityChange<RecipeTagInterface>;
+  [EventChannel.ENTITY_ACTION_DELETED_RECIPE_TAG]: EntityChange<RecipeTagInterface>;
+  [EventChannel.ENTITY_ACTION_CREATED_MANY_RECIPE_TAG]: EntityChange<RecipeTagInterface>[];
+  [EventChannel.ENTITY_ACTION_UPDATED_MANY_RECIPE_TAG]: EntityChange<RecipeTagInterface>[];
+  [EventChannel.ENTITY_ACTION_DELETED_MANY_RECIPE_TAG]: EntityChange<RecipeTagInterface>[];
+  [EventChannel.ENTITY_ACTION_CREATED_INGREDIENT_CATEGORY]: EntityChange<IngredientCategoryInterface>;
+  [EventChannel.ENTITY_ACTION_UPDATED_INGREDIENT_CATEGORY]: EntityChange<IngredientCategoryInterface>;
+  [EventChannel.ENTITY_ACTION_DELETED_INGREDIENT_CATEGORY]: EntityChange<IngredientCategoryInterface>;
+  [EventChannel.ENTITY_ACTION_CREATED_MANY_INGREDIENT_CATEGORY]: EntityChange<IngredientCategoryInterface>[];
+  [EventChannel.ENTITY_ACTION_UPDATED_MANY_INGREDIENT_CATEGORY]: EntityChange<IngredientCategoryInterface>[];
+  [EventChannel.ENTITY_ACTION_DELETED_MANY_INGREDIENT_CATEGORY]: EntityChange<IngredientCategoryInterface>[];
+  [EventChannel.ENTITY_ACTION_CREATED_NUTRIENT_PROFILE]: EntityChange<NutrientProfileInterface>;
+  [EventChannel.ENTITY_ACTION_UPDATED_NUTRIENT_PROFILE]: EntityChange<NutrientProfileInterface>;
+  [EventChannel.ENTITY_ACTION_DELETED_NUTRIENT_PROFILE]: EntityChange<NutrientProfileInterface>;
+  [EventChannel.ENTITY_ACTION_CREATED_MANY_NUTRIENT_PROFILE]: EntityChange<NutrientProfileInterface>[];
+  [EventChannel.ENTITY_ACTION_UPDATED_MANY_NUTRIENT_PROFILE]: EntityChange<NutrientProfileInterface>[];
+  [EventChannel.ENTITY_ACTION_DELETED_MANY_NUTRIENT_PROFILE]: EntityChange<NutrientProfileInterface>[];
+  [EventChannel.ENTITY_ACTION_CREATED_RECIPE_STEP_INGREDIENT_DRAFT]: EntityChange<RecipeStepIngredientDraftInterface>;
+  [EventChannel.ENTITY_ACTION_UPDATED_RECIPE_STEP_INGREDIENT_DRAFT]: EntityChange<RecipeStepIngredientDraftInterface>;
+  [EventChannel.ENTITY_ACTION_DELETED_RECIPE_STEP_INGREDIENT_DRAFT]: EntityChange<RecipeStepIngredientDraftInterface>;
+  [EventChannel.ENTITY_ACTION_CREATED_MANY_RECIPE_STEP_INGREDIENT_DRAFT]: EntityChange<RecipeStepIngredientDraftInterface>[];
+  [EventChannel.ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP_INGREDIENT_DRAFT]: EntityChange<RecipeStepIngredientDraftInterface>[];
+  [EventChannel.ENTITY_ACTION_DELETED_MANY_RECIPE_STEP_INGREDIENT_DRAFT]: EntityChange<RecipeStepIngredientDraftInterface>[];
+  [EventChannel.ENTITY_ACTION_CREATED_RECIPE_STEP]: EntityChange<RecipeStepInterface>;
+  [EventChannel.ENTITY_ACTION_UPDATED_RECIPE_STEP]: EntityChange<RecipeStepInterface>;
+  [EventChannel.ENTITY_ACTION_DELETED_RECIPE_STEP]: EntityChange<RecipeStepInterface>;
+  [EventChannel.ENTITY_ACTION_CREATED_MANY_RECIPE_STEP]: EntityChange<RecipeStepInterface>[];
+  [EventChannel.ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP]: EntityChange<RecipeStepInterface>[];
+  [EventChannel.ENTITY_ACTION_DELETED_MANY_RECIPE_STEP]: EntityChange<RecipeStepInterface>[];
+  [EventChannel.ENTITY_ACTION_CREATED_UNIT_NAME]:
+    EntityChange<UnitNameInterface, string>;
+  [EventChannel.ENTITY_ACTION_UPDATED_UNIT_NAME]:
+    EntityChange<UnitNameInterface, string>;
+  [EventChannel.ENTITY_ACTION_DELETED_UNIT_NAME]:
+    EntityChange<UnitNameInterface, string>;
+  [EventChannel.ENTITY_ACTION_CREATED_MANY_UNIT_NAME]:
+    EntityChange<UnitNameInterface, string>[];
+  [EventChannel.ENTITY_ACTION_UPDATED_MANY_UNIT_NAME]:
+    EntityChange<UnitNameInterface, string>[];
+  [EventChannel.ENTITY_ACTION_DELETED_MANY_UNIT_NAME]:
+    EntityChange<UnitNameInterface, string>[];
 
   [EventChannel.SCRAPER_DOM_DROP]: string;
   [EventChannel.SCRAPER_ELEMENT_DROP]: string;
+
+  [EventChannel.TIMER_TICK]: Timer;
+  [EventChannel.TIMER_FINISH]: Timer;
+  [EventChannel.TIMER_CANCEL]: Timer;
 };
 
 export type EventPayload<T extends EventChannel> = EventPayloadMap[T];
@@ -0,0 +1,78 @@
+import type {
+  CollectionRecipeCreateInterface,
+  CollectionRecipeInterface,
+  CollectionRecipeUpdateInterface,
+} from "../../../types/entity/collection-recipe-interface.ts";
+import type {
+  CollectionRecipeCondition,
+  CollectionRecipeOrderBy,
+} from "../../../types/filter/collection-recipe-filter.ts";
+import {
+  countCollectionRecipe,
+  createCollectionRecipe,
+  deleteCollectionRecipe,
+  listCollectionRecipe,
+  readCollectionRecipe,
+  updateCollectionRecipe,
+} from "../../command/entity.ts";
+import { listen } from "../../event/client.ts";
+import { EventChannel } from "../../event/event-channel.ts";
+import { EntityRepository } from "./entity-repository.ts";
+
+export const collectionRecipeRepository: EntityRepository<
+  CollectionRecipeInterface,
+  CollectionRecipeCreateInterface,
+  CollectionRecipeUpdateInterface,
+  CollectionRecipeCondition,
+  CollectionRecipeOrderBy
+> = new EntityRepository(
+  (entityCreate) => createCollectionRecipe(entityCreate),
+  (identifier) => readCollectionRecipe(identifier),
+  (entityUpdate) => updateCollectionRecipe(entityUpdate),
+  (identifier) => deleteCollectionRecipe(identifier),
+  (filter) => listCollectionRecipe(filter),
+  (condition) => countCollectionRecipe(condition),
+  undefined,
+  undefined,
+  (reactFunction) => {
+    void listen(
+      EventChannel.ENTITY_ACTION_UPDATED_COLLECTION_RECIPE,
+      (event) => {
+        reactFunction(event.payload.entityId, event.payload.new);
+      },
+    );
+    void listen(
+      EventChannel.ENTITY_ACTION_UPDATED_MANY_COLLECTION_RECIPE,
+      (event) => {
+        event.payload.forEach((change) =>
+          reactFunction(change.entityId, change.new),
+        );
+      },
+    );
+  },
+  (reactFunction) => {
+    void listen(EventChannel.ENTITY_ACTION_CREATED_COLLECTION_RECIPE, () => {
+      reactFunction();
+    });
+    void listen(
+      EventChannel.ENTITY_ACTION_CREATED_MANY_COLLECTION_RECIPE,
+      () => {
+        reactFunction();
+      },
+    );
+  },
+  (reactFunction) => {
+    void listen(
+      EventChannel.ENTITY_ACTION_DELETED_COLLECTION_RECIPE,
+      (event) => {
+        reactFunction(event.payload.entityId);
+      },
+    );
+    void listen(
+      EventChannel.ENTITY_ACTION_DELETED_MANY_COLLECTION_RECIPE,
+      (event) => {
+        event.payload.forEach((change) => reactFunction(change.entityId));
+      },
+    );
+  },
+);
@@ -0,0 +1,63 @@
+import type {
+  CollectionCreateInterface,
+  CollectionInterface,
+  CollectionUpdateInterface,
+} from "../../../types/entity/collection-interface.ts";
+import type {
+  CollectionCondition,
+  CollectionOrderBy,
+} from "../../../types/filter/collection-filter.ts";
+import {
+  countCollection,
+  createCollection,
+  deleteCollection,
+  listCollection,
+  readCollection,
+  updateCollection,
+} from "../../command/entity.ts";
+import { listen } from "../../event/client.ts";
+import { EventChannel } from "../../event/event-channel.ts";
+import { EntityRepository } from "./entity-repository.ts";
+
+export const collectionRepository: EntityRepository<
+  CollectionInterface,
+  CollectionCreateInterface,
+  CollectionUpdateInterface,
+  CollectionCondition,
+  CollectionOrderBy
+> = new EntityRepository(
+  (entityCreate) => createCollection(entityCreate),
+  (identifier) => readCollection(identifier),
+  (entityUpdate) => updateCollection(entityUpdate),
+  (identifier) => deleteCollection(identifier),
+  (filter) => listCollection(filter),
+  (condition) => countCollection(condition),
+  undefined,
+  undefined,
+  (reactFunction) => {
+    void listen(EventChannel.ENTITY_ACTION_UPDATED_COLLECTION, (event) => {
+      reactFunction(event.payload.entityId, event.payload.new);
+    });
+    void listen(EventChannel.ENTITY_ACTION_UPDATED_MANY_COLLECTION, (event) => {
+      event.payload.forEach((change) =>
+        reactFunction(change.entityId, change.new),
+      );
+    });
+  },
+  (reactFunction) => {
+    void listen(EventChannel.ENTITY_ACTION_CREATED_COLLECTION, () => {
+      reactFunction();
+    });
+    void listen(EventChannel.ENTITY_ACTION_CREATED_MANY_COLLECTION, () => {
+      reactFunction();
+    });
+  },
+  (reactFunction) => {
+    void listen(EventChannel.ENTITY_ACTION_DELETED_COLLECTION, (event) => {
+      reactFunction(event.payload.entityId);
+    });
+    void listen(EventChannel.ENTITY_ACTION_DELETED_MANY_COLLECTION, (event) => {
+      event.payload.forEach((change) => reactFunction(change.entityId));
+    });
+  },
+);
@@ -0,0 +1,69 @@
+import type {
+  CookLogFileCreateInterface,
+  CookLogFileInterface,
+  CookLogFileUpdateInterface,
+} from "../../../types/entity/cook-log-file-interface.ts";
+import type {
+  CookLogFileCondition,
+  CookLogFileOrderBy,
+} from "../../../types/filter/cook-log-file-filter.ts";
+import {
+  countCookLogFile,
+  createCookLogFile,
+  deleteCookLogFile,
+  listCookLogFile,
+  readCookLogFile,
+  updateCookLogFile,
+} from "../../command/entity.ts";
+import { listen } from "../../event/client.ts";
+import { EventChannel } from "../../event/event-channel.ts";
+import { EntityRepository } from "./entity-repository.ts";
+
+export const cookLogFileRepository: EntityRepository<
+  CookLogFileInterface,
+  CookLogFileCreateInterface,
+  CookLogFileUpdateInterface,
+  CookLogFileCondition,
+  CookLogFileOrderBy
+> = new EntityRepository(
+  (entityCreate) => createCookLogFile(entityCreate),
+  (identifier) => readCookLogFile(identifier),
+  (entityUpdate) => updateCookLogFile(entityUpdate),
+  (identifier) => deleteCookLogFile(identifier),
+  (filter) => listCookLogFile(filter),
+  (condition) => countCookLogFile(condition),
+  undefined,
+  undefined,
+  (reactFunction) => {
+    void listen(EventChannel.ENTITY_ACTION_UPDATED_COOK_LOG_FILE, (event) => {
+      reactFunction(event.payload.entityId, event.payload.new);
+    });
+    void listen(
+      EventChannel.ENTITY_ACTION_UPDATED_MANY_COOK_LOG_FILE,
+      (event) => {
+        event.payload.forEach((change) =>
+          reactFunction(change.entityId, change.new),
+        );
+      },
+    );
+  },
+  (reactFunction) => {
+    void listen(EventChannel.ENTITY_ACTION_CREATED_COOK_LOG_FILE, () => {
+      reactFunction();
+    });
+    void listen(EventChannel.ENTITY_ACTION_CREATED_MANY_COOK_LOG_FILE, () => {
+      reactFunction();
+    });
+  },
+  (reactFunction) => {
+    void listen(EventChannel.ENTITY_ACTION_DELETED_COOK_LOG_FILE, (event) => {
+      reactFunction(event.payload.entityId);
+    });
+    void listen(
+      EventChannel.ENTITY_ACTION_DELETED_MANY_COOK_LOG_FILE,
+      (event) => {
+        event.payload.forEach((change) => reactFunction(change.entityId));
+      },
+    );
+  },
+);
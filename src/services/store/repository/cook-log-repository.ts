@@ -0,0 +1,63 @@
+import type {
+  CookLogCreateInterface,
+  CookLogInterface,
+  CookLogUpdateInterface,
+} from "../../../types/entity/cook-log-interface.ts";
+import type {
+  CookLogCondition,
+  CookLogOrderBy,
+} from "../../../types/filter/cook-log-filter.ts";
+import {
+  countCookLog,
+  createCookLog,
+  deleteCookLog,
+  listCookLog,
+  readCookLog,
+  updateCookLog,
+} from "../../command/entity.ts";
+import { listen } from "../../event/client.ts";
+import { EventChannel } from "../../event/event-channel.ts";
+import { EntityRepository } from "./entity-repository.ts";
+
+export const cookLogRepository: EntityRepository<
+  CookLogInterface,
+  CookLogCreateInterface,
+  CookLogUpdateInterface,
+  CookLogCondition,
+  CookLogOrderBy
+> = new EntityRepository(
+  (entityCreate) => createCookLog(entityCreate),
+  (identifier) => readCookLog(identifier),
+  (entityUpdate) => updateCookLog(entityUpdate),
+  (identifier) => deleteCookLog(identifier),
+  (filter) => listCookLog(filter),
+  (condition) => countCookLog(condition),
+  undefined,
+  undefined,
+  (reactFunction) => {
+    void listen(EventChannel.ENTITY_ACTION_UPDATED_COOK_LOG, (event) => {
+      reactFunction(event.payload.entityId, event.payload.new);
+    });
+    void listen(EventChannel.ENTITY_ACTION_UPDATED_MANY_COOK_LOG, (event) => {
+      event.payload.forEach((change) =>
+        reactFunction(change.entityId, change.new),
+      );
+    });
+  },
+  (reactFunction) => {
+    void listen(EventChannel.ENTITY_ACTION_CREATED_COOK_LOG, () => {
+      reactFunction();
+    });
+    void listen(EventChannel.ENTITY_ACTION_CREATED_MANY_COOK_LOG, () => {
+      reactFunction();
+    });
+  },
+  (reactFunction) => {
+    void listen(EventChannel.ENTITY_ACTION_DELETED_COOK_LOG, (event) => {
+      reactFunction(event.payload.entityId);
+    });
+    void listen(EventChannel.ENTITY_ACTION_DELETED_MANY_COOK_LOG, (event) => {
+      event.payload.forEach((change) => reactFunction(change.entityId));
+    });
+  },
+);
@@ -201,7 +201,7 @@ export class EntityRepository<
    * @param apiCount - {@see apiCount}
    * @param defaultCondition - {@see defaultCondition}
    * @param defaultOrderBy - {@see defaultOrderBy}
-   * @param registerUpdate - register callbacks for reacting to entity updates
+   * @param registerUpdate - register callbacks for reacting to entity updates, optionally with the updated entity to skip reading it
    * @param registerCreate - register callbacks for reacting to entity creations
    * @param registerDelete - register callbacks for reacting to entity deletions
    * @param registerFilterRelatedActions - register callbacks for reacting to related entity actions which might influence filtered lists and counts
@@ -215,7 +215,9 @@ export class EntityRepository<
     apiCount: ApiCount<Condition>,
     defaultCondition: Condition | undefined,
     defaultOrderBy: OrderBy[] | undefined,
-    registerUpdate: (reactFunction: (identifier: PrimaryKey) => void) => void,
+    registerUpdate: (
+      reactFunction: (identifier: PrimaryKey, entity?: Entity | null) => void,
+    ) => void,
     registerCreate: (reactFunction: () => void) => void,
     registerDelete: (reactFunction: (identifier: PrimaryKey) => void) => void,
     registerFilterRelatedActions?: (reactFunction: () => void) => void,
@@ -233,11 +235,14 @@ export class EntityRepository<
       orderBy: this.defaultOrderBy,
     };
 
-    const react = async (identifier: PrimaryKey): Promise<void> => {
+    const react = async (
+      identifier: PrimaryKey,
+      entity?: Entity | null,
+    ): Promise<void> => {
       if (!this.state[identifier]) {
         return;
       }
-      this.state[identifier] = await this.apiRead(identifier);
+      this.state[identifier] = entity ?? (await this.apiRead(identifier));
       this.run(identifier);
     };
 
@@ -298,8 +303,8 @@ export class EntityRepository<
       }
     };
 
-    registerUpdate((identifier: PrimaryKey) => {
-      void react(identifier);
+    registerUpdate((identifier: PrimaryKey, entity?: Entity | null) => {
+      void react(identifier, entity);
       void reactFilteredList();
       void reactFilteredCount();
     });
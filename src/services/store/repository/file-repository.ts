@@ -36,17 +36,28 @@ export const fileRepository: EntityRepository<
   undefined,
   (reactFunction) => {
     void listen(EventChannel.ENTITY_ACTION_UPDATED_FILE, (event) => {
-      reactFunction(event.payload);
+      reactFunction(event.payload.entityId, event.payload.new);
+    });
+    void listen(EventChannel.ENTITY_ACTION_UPDATED_MANY_FILE, (event) => {
+      event.payload.forEach((change) =>
+        reactFunction(change.entityId, change.new),
+      );
     });
   },
   (reactFunction) => {
     void listen(EventChannel.ENTITY_ACTION_CREATED_FILE, () => {
       reactFunction();
     });
+    void listen(EventChannel.ENTITY_ACTION_CREATED_MANY_FILE, () => {
+      reactFunction();
+    });
   },
   (reactFunction) => {
     void listen(EventChannel.ENTITY_ACTION_DELETED_FILE, (event) => {
-      reactFunction(event.payload);
+      reactFunction(event.payload.entityId);
+    });
+    void listen(EventChannel.ENTITY_ACTION_DELETED_MANY_FILE, (event) => {
+      event.payload.forEach((change) => reactFunction(change.entityId));
     });
   },
 );
@@ -0,0 +1,78 @@
+import type {
+  IngredientAliasCreateInterface,
+  IngredientAliasInterface,
+  IngredientAliasUpdateInterface,
+} from "../../../types/entity/ingredient-alias-interface.ts";
+import type {
+  IngredientAliasCondition,
+  IngredientAliasOrderBy,
+} from "../../../types/filter/ingredient-alias-filter.ts";
+import {
+  countIngredientAlias,
+  createIngredientAlias,
+  deleteIngredientAlias,
+  listIngredientAlias,
+  readIngredientAlias,
+  updateIngredientAlias,
+} from "../../command/entity.ts";
+import { listen } from "../../event/client.ts";
+import { EventChannel } from "../../event/event-channel.ts";
+import { EntityRepository } from "./entity-repository.ts";
+
+export const ingredientAliasRepository: EntityRepository<
+  IngredientAliasInterface,
+  IngredientAliasCreateInterface,
+  IngredientAliasUpdateInterface,
+  IngredientAliasCondition,
+  IngredientAliasOrderBy
+> = new EntityRepository(
+  (entityCreate) => createIngredientAlias(entityCreate),
+  (identifier) => readIngredientAlias(identifier),
+  (entityUpdate) => updateIngredientAlias(entityUpdate),
+  (identifier) => deleteIngredientAlias(identifier),
+  (filter) => listIngredientAlias(filter),
+  (condition) => countIngredientAlias(condition),
+  undefined,
+  undefined,
+  (reactFunction) => {
+    void listen(
+      EventChannel.ENTITY_ACTION_UPDATED_INGREDIENT_ALIAS,
+      (event) => {
+        reactFunction(event.payload.entityId, event.payload.new);
+      },
+    );
+    void listen(
+      EventChannel.ENTITY_ACTION_UPDATED_MANY_INGREDIENT_ALIAS,
+      (event) => {
+        event.payload.forEach((change) =>
+          reactFunction(change.entityId, change.new),
+        );
+      },
+    );
+  },
+  (reactFunction) => {
+    void listen(EventChannel.ENTITY_ACTION_CREATED_INGREDIENT_ALIAS, () => {
+      reactFunction();
+    });
+    void listen(
+      EventChannel.ENTITY_ACTION_CREATED_MANY_INGREDIENT_ALIAS,
+      () => {
+        reactFunction();
+      },
+    );
+  },
+  (reactFunction) => {
+    void listen(
+      EventChannel.ENTITY_ACTION_DELETED_INGREDIENT_ALIAS,
+      (event) => {
+        reactFunction(event.payload.entityId);
+      },
+    );
+    void listen(
+      EventChannel.ENTITY_ACTION_DELETED_MANY_INGREDIENT_ALIAS,
+      (event) => {
+        event.payload.forEach((change) => reactFunction(change.entityId));
+      },
+    );
+  },
+);
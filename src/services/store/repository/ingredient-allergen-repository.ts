@@ -0,0 +1,78 @@
+import type {
+  IngredientAllergenCreateInterface,
+  IngredientAllergenInterface,
+  IngredientAllergenUpdateInterface,
+} from "../../../types/entity/ingredient-allergen-interface.ts";
+import type {
+  IngredientAllergenCondition,
+  IngredientAllergenOrderBy,
+} from "../../../types/filter/ingredient-allergen-filter.ts";
+import {
+  countIngredientAllergen,
+  createIngredientAllergen,
+  deleteIngredientAllergen,
+  listIngredientAllergen,
+  readIngredientAllergen,
+  updateIngredientAllergen,
+} from "../../command/entity.ts";
+import { listen } from "../../event/client.ts";
+import { EventChannel } from "../../event/event-channel.ts";
+import { EntityRepository } from "./entity-repository.ts";
+
+export const ingredientAllergenRepository: EntityRepository<
+  IngredientAllergenInterface,
+  IngredientAllergenCreateInterface,
+  IngredientAllergenUpdateInterface,
+  IngredientAllergenCondition,
+  IngredientAllergenOrderBy
+> = new EntityRepository(
+  (entityCreate) => createIngredientAllergen(entityCreate),
+  (identifier) => readIngredientAllergen(identifier),
+  (entityUpdate) => updateIngredientAllergen(entityUpdate),
+  (identifier) => deleteIngredientAllergen(identifier),
+  (filter) => listIngredientAllergen(filter),
+  (condition) => countIngredientAllergen(condition),
+  undefined,
+  undefined,
+  (reactFunction) => {
+    void listen(
+      EventChannel.ENTITY_ACTION_UPDATED_INGREDIENT_ALLERGEN,
+      (event) => {
+        reactFunction(event.payload.entityId, event.payload.new);
+      },
+    );
+    void listen(
+      EventChannel.ENTITY_ACTION_UPDATED_MANY_INGREDIENT_ALLERGEN,
+      (event) => {
+        event.payload.forEach((change) =>
+          reactFunction(change.entityId, change.new),
+        );
+      },
+    );
+  },
+  (reactFunction) => {
+    void listen(EventChannel.ENTITY_ACTION_CREATED_INGREDIENT_ALLERGEN, () => {
+      reactFunction();
+    });
+    void listen(
+      EventChannel.ENTITY_ACTION_CREATED_MANY_INGREDIENT_ALLERGEN,
+      () => {
+        reactFunction();
+      },
+    );
+  },
+  (reactFunction) => {
+    void listen(
+      EventChannel.ENTITY_ACTION_DELETED_INGREDIENT_ALLERGEN,
+      (event) => {
+        reactFunction(event.payload.entityId);
+      },
+    );
+    void listen(
+      EventChannel.ENTITY_ACTION_DELETED_MANY_INGREDIENT_ALLERGEN,
+      (event) => {
+        event.payload.forEach((change) => reactFunction(change.entityId));
+      },
+    );
+  },
+);
@@ -0,0 +1,78 @@
+import type {
+  IngredientCategoryCreateInterface,
+  IngredientCategoryInterface,
+  IngredientCategoryUpdateInterface,
+} from "../../../types/entity/ingredient-category-interface.ts";
+import type {
+  IngredientCategoryCondition,
+  IngredientCategoryOrderBy,
+} from "../../../types/filter/ingredient-category-filter.ts";
+import {
+  countIngredientCategory,
+  createIngredientCategory,
+  deleteIngredientCategory,
+  listIngredientCategory,
+  readIngredientCategory,
+  updateIngredientCategory,
+} from "../../command/entity.ts";
+import { listen } from "../../event/client.ts";
+import { EventChannel } from "../../event/event-channel.ts";
+import { EntityRepository } from "./entity-repository.ts";
+
+export const ingredientCategoryRepository: EntityRepository<
+  IngredientCategoryInterface,
+  IngredientCategoryCreateInterface,
+  IngredientCategoryUpdateInterface,
+  IngredientCategoryCondition,
+  IngredientCategoryOrderBy
+> = new EntityRepository(
+  (entityCreate) => createIngredientCategory(entityCreate),
+  (identifier) => readIngredientCategory(identifier),
+  (entityUpdate) => updateIngredientCategory(entityUpdate),
+  (identifier) => deleteIngredientCategory(identifier),
+  (filter) => listIngredientCategory(filter),
+  (condition) => countIngredientCategory(condition),
+  undefined,
+  undefined,
+  (reactFunction) => {
+    void listen(
+      EventChannel.ENTITY_ACTION_UPDATED_INGREDIENT_CATEGORY,
+      (event) => {
+        reactFunction(event.payload.entityId, event.payload.new);
+      },
+    );
+    void listen(
+      EventChannel.ENTITY_ACTION_UPDATED_MANY_INGREDIENT_CATEGORY,
+      (event) => {
+        event.payload.forEach((change) =>
+          reactFunction(change.entityId, change.new),
+        );
+      },
+    );
+  },
+  (reactFunction) => {
+    void listen(EventChannel.ENTITY_ACTION_CREATED_INGREDIENT_CATEGORY, () => {
+      reactFunction();
+    });
+    void listen(
+      EventChannel.ENTITY_ACTION_CREATED_MANY_INGREDIENT_CATEGORY,
+      () => {
+        reactFunction();
+      },
+    );
+  },
+  (reactFunction) => {
+    void listen(
+      EventChannel.ENTITY_ACTION_DELETED_INGREDIENT_CATEGORY,
+      (event) => {
+        reactFunction(event.payload.entityId);
+      },
+    );
+    void listen(
+      EventChannel.ENTITY_ACTION_DELETED_MANY_INGREDIENT_CATEGORY,
+      (event) => {
+        event.payload.forEach((change) => reactFunction(change.entityId));
+      },
+    );
+  },
+);
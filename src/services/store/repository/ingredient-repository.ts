@@ -36,17 +36,28 @@ export const ingredientRepository: EntityRepository<
   undefined,
   (reactFunction) => {
     void listen(EventChannel.ENTITY_ACTION_UPDATED_INGREDIENT, (event) => {
-      reactFunction(event.payload);
+      reactFunction(event.payload.entityId, event.payload.new);
+    });
+    void listen(EventChannel.ENTITY_ACTION_UPDATED_MANY_INGREDIENT, (event) => {
+      event.payload.forEach((change) =>
+        reactFunction(change.entityId, change.new),
+      );
     });
   },
   (reactFunction) => {
     void listen(EventChannel.ENTITY_ACTION_CREATED_INGREDIENT, () => {
       reactFunction();
     });
+    void listen(EventChannel.ENTITY_ACTION_CREATED_MANY_INGREDIENT, () => {
+      reactFunction();
+    });
   },
   (reactFunction) => {
     void listen(EventChannel.ENTITY_ACTION_DELETED_INGREDIENT, (event) => {
-      reactFunction(event.payload);
+      reactFunction(event.payload.entityId);
+    });
+    void listen(EventChannel.ENTITY_ACTION_DELETED_MANY_INGREDIENT, (event) => {
+      event.payload.forEach((change) => reactFunction(change.entityId));
     });
   },
   (reactFunction) => {
@@ -56,17 +67,35 @@ export const ingredientRepository: EntityRepository<
         reactFunction();
       },
     );
+    void listen(
+      EventChannel.ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP_INGREDIENT,
+      () => {
+        reactFunction();
+      },
+    );
     void listen(
       EventChannel.ENTITY_ACTION_CREATED_RECIPE_STEP_INGREDIENT,
       () => {
         reactFunction();
       },
     );
+    void listen(
+      EventChannel.ENTITY_ACTION_CREATED_MANY_RECIPE_STEP_INGREDIENT,
+      () => {
+        reactFunction();
+      },
+    );
     void listen(
       EventChannel.ENTITY_ACTION_DELETED_RECIPE_STEP_INGREDIENT,
       () => {
         reactFunction();
       },
     );
+    void listen(
+      EventChannel.ENTITY_ACTION_DELETED_MANY_RECIPE_STEP_INGREDIENT,
+      () => {
+        reactFunction();
+      },
+    );
   },
 );
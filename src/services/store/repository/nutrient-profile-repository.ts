@@ -0,0 +1,78 @@
+import type {
+  NutrientProfileCreateInterface,
+  NutrientProfileInterface,
+  NutrientProfileUpdateInterface,
+} from "../../../types/entity/nutrient-profile-interface.ts";
+import type {
+  NutrientProfileCondition,
+  NutrientProfileOrderBy,
+} from "../../../types/filter/nutrient-profile-filter.ts";
+import {
+  countNutrientProfile,
+  createNutrientProfile,
+  deleteNutrientProfile,
+  listNutrientProfile,
+  readNutrientProfile,
+  updateNutrientProfile,
+} from "../../command/entity.ts";
+import { listen } from "../../event/client.ts";
+import { EventChannel } from "../../event/event-channel.ts";
+import { EntityRepository } from "./entity-repository.ts";
+
+export const nutrientProfileRepository: EntityRepository<
+  NutrientProfileInterface,
+  NutrientProfileCreateInterface,
+  NutrientProfileUpdateInterface,
+  NutrientProfileCondition,
+  NutrientProfileOrderBy
+> = new EntityRepository(
+  (entityCreate) => createNutrientProfile(entityCreate),
+  (identifier) => readNutrientProfile(identifier),
+  (entityUpdate) => updateNutrientProfile(entityUpdate),
+  (identifier) => deleteNutrientProfile(identifier),
+  (filter) => listNutrientProfile(filter),
+  (condition) => countNutrientProfile(condition),
+  undefined,
+  undefined,
+  (reactFunction) => {
+    void listen(
+      EventChannel.ENTITY_ACTION_UPDATED_NUTRIENT_PROFILE,
+      (event) => {
+        reactFunction(event.payload.entityId, event.payload.new);
+      },
+    );
+    void listen(
+      EventChannel.ENTITY_ACTION_UPDATED_MANY_NUTRIENT_PROFILE,
+      (event) => {
+        event.payload.forEach((change) =>
+          reactFunction(change.entityId, change.new),
+        );
+      },
+    );
+  },
+  (reactFunction) => {
+    void listen(EventChannel.ENTITY_ACTION_CREATED_NUTRIENT_PROFILE, () => {
+      reactFunction();
+    });
+    void listen(
+      EventChannel.ENTITY_ACTION_CREATED_MANY_NUTRIENT_PROFILE,
+      () => {
+        reactFunction();
+      },
+    );
+  },
+  (reactFunction) => {
+    void listen(
+      EventChannel.ENTITY_ACTION_DELETED_NUTRIENT_PROFILE,
+      (event) => {
+        reactFunction(event.payload.entityId);
+      },
+    );
+    void listen(
+      EventChannel.ENTITY_ACTION_DELETED_MANY_NUTRIENT_PROFILE,
+      (event) => {
+        event.payload.forEach((change) => reactFunction(change.entityId));
+      },
+    );
+  },
+);
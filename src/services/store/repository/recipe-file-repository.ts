@@ -36,17 +36,34 @@ export const recipeFileRepository: EntityRepository<
   undefined,
   (reactFunction) => {
     void listen(EventChannel.ENTITY_ACTION_UPDATED_RECIPE_FILE, (event) => {
-      reactFunction(event.payload);
+      reactFunction(event.payload.entityId, event.payload.new);
     });
+    void listen(
+      EventChannel.ENTITY_ACTION_UPDATED_MANY_RECIPE_FILE,
+      (event) => {
+        event.payload.forEach((change) =>
+          reactFunction(change.entityId, change.new),
+        );
+      },
+    );
   },
   (reactFunction) => {
     void listen(EventChannel.ENTITY_ACTION_CREATED_RECIPE_FILE, () => {
       reactFunction();
     });
+    void listen(EventChannel.ENTITY_ACTION_CREATED_MANY_RECIPE_FILE, () => {
+      reactFunction();
+    });
   },
   (reactFunction) => {
     void listen(EventChannel.ENTITY_ACTION_DELETED_RECIPE_FILE, (event) => {
-      reactFunction(event.payload);
+      reactFunction(event.payload.entityId);
     });
+    void listen(
+      EventChannel.ENTITY_ACTION_DELETED_MANY_RECIPE_FILE,
+      (event) => {
+        event.payload.forEach((change) => reactFunction(change.entityId));
+      },
+    );
   },
 );
@@ -38,7 +38,15 @@ export const recipeIngredientDraftRepository: EntityRepository<
     void listen(
       EventChannel.ENTITY_ACTION_UPDATED_RECIPE_INGREDIENT_DRAFT,
       (event) => {
-        reactFunction(event.payload);
+        reactFunction(event.payload.entityId, event.payload.new);
+      },
+    );
+    void listen(
+      EventChannel.ENTITY_ACTION_UPDATED_MANY_RECIPE_INGREDIENT_DRAFT,
+      (event) => {
+        event.payload.forEach((change) =>
+          reactFunction(change.entityId, change.new),
+        );
       },
     );
   },
@@ -49,12 +57,24 @@ export const recipeIngredientDraftRepository: EntityRepository<
         reactFunction();
       },
     );
+    void listen(
+      EventChannel.ENTITY_ACTION_CREATED_MANY_RECIPE_INGREDIENT_DRAFT,
+      () => {
+        reactFunction();
+      },
+    );
   },
   (reactFunction) => {
     void listen(
       EventChannel.ENTITY_ACTION_DELETED_RECIPE_INGREDIENT_DRAFT,
       (event) => {
-        reactFunction(event.payload);
+        reactFunction(event.payload.entityId);
+      },
+    );
+    void listen(
+      EventChannel.ENTITY_ACTION_DELETED_MANY_RECIPE_INGREDIENT_DRAFT,
+      (event) => {
+        event.payload.forEach((change) => reactFunction(change.entityId));
       },
     );
   },
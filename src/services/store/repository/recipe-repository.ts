@@ -36,17 +36,43 @@ export const recipeRepository: EntityRepository<
   undefined,
   (reactFunction) => {
     void listen(EventChannel.ENTITY_ACTION_UPDATED_RECIPE, (event) => {
-      reactFunction(event.payload);
+      reactFunction(event.payload.entityId, event.payload.new);
+    });
+    void listen(EventChannel.ENTITY_ACTION_UPDATED_MANY_RECIPE, (event) => {
+      event.payload.forEach((change) =>
+        reactFunction(change.entityId, change.new),
+      );
     });
   },
   (reactFunction) => {
     void listen(EventChannel.ENTITY_ACTION_CREATED_RECIPE, () => {
       reactFunction();
     });
+    void listen(EventChannel.ENTITY_ACTION_CREATED_MANY_RECIPE, () => {
+      reactFunction();
+    });
+    // moving a recipe into or out of the trash changes the unfiltered list and count like a creation or deletion
+    void listen(EventChannel.ENTITY_ACTION_UPDATED_RECIPE, (event) => {
+      if (event.payload.old?.deletedAt !== event.payload.new?.deletedAt) {
+        reactFunction();
+      }
+    });
+    void listen(EventChannel.ENTITY_ACTION_UPDATED_MANY_RECIPE, (event) => {
+      if (
+        event.payload.some(
+          (change) => change.old?.deletedAt !== change.new?.deletedAt,
+        )
+      ) {
+        reactFunction();
+      }
+    });
   },
   (reactFunction) => {
     void listen(EventChannel.ENTITY_ACTION_DELETED_RECIPE, (event) => {
-      reactFunction(event.payload);
+      reactFunction(event.payload.entityId);
+    });
+    void listen(EventChannel.ENTITY_ACTION_DELETED_MANY_RECIPE, (event) => {
+      event.payload.forEach((change) => reactFunction(change.entityId));
     });
   },
 );
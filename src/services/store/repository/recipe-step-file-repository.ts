@@ -38,7 +38,15 @@ export const recipeStepFileRepository: EntityRepository<
     void listen(
       EventChannel.ENTITY_ACTION_UPDATED_RECIPE_STEP_FILE,
       (event) => {
-        reactFunction(event.payload);
+        reactFunction(event.payload.entityId, event.payload.new);
+      },
+    );
+    void listen(
+      EventChannel.ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP_FILE,
+      (event) => {
+        event.payload.forEach((change) =>
+          reactFunction(change.entityId, change.new),
+        );
       },
     );
   },
@@ -46,12 +54,24 @@ export const recipeStepFileRepository: EntityRepository<
     void listen(EventChannel.ENTITY_ACTION_CREATED_RECIPE_STEP_FILE, () => {
       reactFunction();
     });
+    void listen(
+      EventChannel.ENTITY_ACTION_CREATED_MANY_RECIPE_STEP_FILE,
+      () => {
+        reactFunction();
+      },
+    );
   },
   (reactFunction) => {
     void listen(
       EventChannel.ENTITY_ACTION_DELETED_RECIPE_STEP_FILE,
       (event) => {
-        reactFunction(event.payload);
+        reactFunction(event.payload.entityId);
+      },
+    );
+    void listen(
+      EventChannel.ENTITY_ACTION_DELETED_MANY_RECIPE_STEP_FILE,
+      (event) => {
+        event.payload.forEach((change) => reactFunction(change.entityId));
       },
     );
   },
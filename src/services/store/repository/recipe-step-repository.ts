@@ -36,17 +36,34 @@ export const recipeStepRepository: EntityRepository<
   undefined,
   (reactFunction) => {
     void listen(EventChannel.ENTITY_ACTION_UPDATED_RECIPE_STEP, (event) => {
-      reactFunction(event.payload);
+      reactFunction(event.payload.entityId, event.payload.new);
     });
+    void listen(
+      EventChannel.ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP,
+      (event) => {
+        event.payload.forEach((change) =>
+          reactFunction(change.entityId, change.new),
+        );
+      },
+    );
   },
   (reactFunction) => {
     void listen(EventChannel.ENTITY_ACTION_CREATED_RECIPE_STEP, () => {
       reactFunction();
     });
+    void listen(EventChannel.ENTITY_ACTION_CREATED_MANY_RECIPE_STEP, () => {
+      reactFunction();
+    });
   },
   (reactFunction) => {
     void listen(EventChannel.ENTITY_ACTION_DELETED_RECIPE_STEP, (event) => {
-      reactFunction(event.payload);
+      reactFunction(event.payload.entityId);
     });
+    void listen(
+      EventChannel.ENTITY_ACTION_DELETED_MANY_RECIPE_STEP,
+      (event) => {
+        event.payload.forEach((change) => reactFunction(change.entityId));
+      },
+    );
   },
 );
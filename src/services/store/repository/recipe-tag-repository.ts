@@ -0,0 +1,63 @@
+import type {
+  RecipeTagCreateInterface,
+  RecipeTagInterface,
+  RecipeTagUpdateInterface,
+} from "../../../types/entity/recipe-tag-interface.ts";
+import type {
+  RecipeTagCondition,
+  RecipeTagOrderBy,
+} from "../../../types/filter/recipe-tag-filter.ts";
+import {
+  countRecipeTag,
+  createRecipeTag,
+  deleteRecipeTag,
+  listRecipeTag,
+  readRecipeTag,
+  updateRecipeTag,
+} from "../../command/entity.ts";
+import { listen } from "../../event/client.ts";
+import { EventChannel } from "../../event/event-channel.ts";
+import { EntityRepository } from "./entity-repository.ts";
+
+export const recipeTagRepository: EntityRepository<
+  RecipeTagInterface,
+  RecipeTagCreateInterface,
+  RecipeTagUpdateInterface,
+  RecipeTagCondition,
+  RecipeTagOrderBy
+> = new EntityRepository(
+  (entityCreate) => createRecipeTag(entityCreate),
+  (identifier) => readRecipeTag(identifier),
+  (entityUpdate) => updateRecipeTag(entityUpdate),
+  (identifier) => deleteRecipeTag(identifier),
+  (filter) => listRecipeTag(filter),
+  (condition) => countRecipeTag(condition),
+  undefined,
+  undefined,
+  (reactFunction) => {
+    void listen(EventChannel.ENTITY_ACTION_UPDATED_RECIPE_TAG, (event) => {
+      reactFunction(event.payload.entityId, event.payload.new);
+    });
+    void listen(EventChannel.ENTITY_ACTION_UPDATED_MANY_RECIPE_TAG, (event) => {
+      event.payload.forEach((change) =>
+        reactFunction(change.entityId, change.new),
+      );
+    });
+  },
+  (reactFunction) => {
+    void listen(EventChannel.ENTITY_ACTION_CREATED_RECIPE_TAG, () => {
+      reactFunction();
+    });
+    void listen(EventChannel.ENTITY_ACTION_CREATED_MANY_RECIPE_TAG, () => {
+      reactFunction();
+    });
+  },
+  (reactFunction) => {
+    void listen(EventChannel.ENTITY_ACTION_DELETED_RECIPE_TAG, (event) => {
+      reactFunction(event.payload.entityId);
+    });
+    void listen(EventChannel.ENTITY_ACTION_DELETED_MANY_RECIPE_TAG, (event) => {
+      event.payload.forEach((change) => reactFunction(change.entityId));
+    });
+  },
+);
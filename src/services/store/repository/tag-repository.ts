@@ -0,0 +1,63 @@
+import type {
+  TagCreateInterface,
+  TagInterface,
+  TagUpdateInterface,
+} from "../../../types/entity/tag-interface.ts";
+import type {
+  TagCondition,
+  TagOrderBy,
+} from "../../../types/filter/tag-filter.ts";
+import {
+  countTag,
+  createTag,
+  deleteTag,
+  listTag,
+  readTag,
+  updateTag,
+} from "../../command/entity.ts";
+import { listen } from "../../event/client.ts";
+import { EventChannel } from "../../event/event-channel.ts";
+import { EntityRepository } from "./entity-repository.ts";
+
+export const tagRepository: EntityRepository<
+  TagInterface,
+  TagCreateInterface,
+  TagUpdateInterface,
+  TagCondition,
+  TagOrderBy
+> = new EntityRepository(
+  (entityCreate) => createTag(entityCreate),
+  (identifier) => readTag(identifier),
+  (entityUpdate) => updateTag(entityUpdate),
+  (identifier) => deleteTag(identifier),
+  (filter) => listTag(filter),
+  (condition) => countTag(condition),
+  undefined,
+  undefined,
+  (reactFunction) => {
+    void listen(EventChannel.ENTITY_ACTION_UPDATED_TAG, (event) => {
+      reactFunction(event.payload.entityId, event.payload.new);
+    });
+    void listen(EventChannel.ENTITY_ACTION_UPDATED_MANY_TAG, (event) => {
+      event.payload.forEach((change) =>
+        reactFunction(change.entityId, change.new),
+      );
+    });
+  },
+  (reactFunction) => {
+    void listen(EventChannel.ENTITY_ACTION_CREATED_TAG, () => {
+      reactFunction();
+    });
+    void listen(EventChannel.ENTITY_ACTION_CREATED_MANY_TAG, () => {
+      reactFunction();
+    });
+  },
+  (reactFunction) => {
+    void listen(EventChannel.ENTITY_ACTION_DELETED_TAG, (event) => {
+      reactFunction(event.payload.entityId);
+    });
+    void listen(EventChannel.ENTITY_ACTION_DELETED_MANY_TAG, (event) => {
+      event.payload.forEach((change) => reactFunction(change.entityId));
+    });
+  },
+);
@@ -37,17 +37,28 @@ export const unitNameRepository: EntityRepository<
   undefined,
   (reactFunction) => {
     void listen(EventChannel.ENTITY_ACTION_UPDATED_UNIT_NAME, (event) => {
-      reactFunction(event.payload);
+      reactFunction(event.payload.entityId, event.payload.new);
+    });
+    void listen(EventChannel.ENTITY_ACTION_UPDATED_MANY_UNIT_NAME, (event) => {
+      event.payload.forEach((change) =>
+        reactFunction(change.entityId, change.new),
+      );
     });
   },
   (reactFunction) => {
     void listen(EventChannel.ENTITY_ACTION_CREATED_UNIT_NAME, () => {
       reactFunction();
     });
+    void listen(EventChannel.ENTITY_ACTION_CREATED_MANY_UNIT_NAME, () => {
+      reactFunction();
+    });
   },
   (reactFunction) => {
     void listen(EventChannel.ENTITY_ACTION_DELETED_UNIT_NAME, (event) => {
-      reactFunction(event.payload);
+      reactFunction(event.payload.entityId);
+    });
+    void listen(EventChannel.ENTITY_ACTION_DELETED_MANY_UNIT_NAME, (event) => {
+      event.payload.forEach((change) => reactFunction(change.entityId));
     });
   },
 );
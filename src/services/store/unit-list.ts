@@ -22,6 +22,9 @@ function createUnitList(): Readable<Loadable<string[]>> {
     EventChannel.ENTITY_ACTION_CREATED_RECIPE_STEP_INGREDIENT,
     EventChannel.ENTITY_ACTION_UPDATED_RECIPE_STEP_INGREDIENT,
     EventChannel.ENTITY_ACTION_DELETED_RECIPE_STEP_INGREDIENT,
+    EventChannel.ENTITY_ACTION_CREATED_MANY_RECIPE_STEP_INGREDIENT,
+    EventChannel.ENTITY_ACTION_UPDATED_MANY_RECIPE_STEP_INGREDIENT,
+    EventChannel.ENTITY_ACTION_DELETED_MANY_RECIPE_STEP_INGREDIENT,
   ]) {
     void listen(channel, () => {
       setUnitList();
@@ -9,6 +9,14 @@ const translationStrings: TranslationStrings = {
       update: "Update",
       delete: "Delete",
       deleteSelectedItems: "Delete selected items",
+      trash: "Move to trash",
+      trashSelectedItems: "Move selected items to trash",
+      duplicate: "Duplicate",
+      duplicateSelectedItems: "Duplicate selected items",
+      restore: "Restore",
+      restoreSelectedItems: "Restore selected items",
+      purge: "Delete permanently",
+      purgeSelectedItems: "Delete selected items permanently",
       cancel: "Cancel",
       confirm: "Confirm",
       add: "Add",
@@ -78,6 +86,9 @@ const translationStrings: TranslationStrings = {
     ingredients: "Ingredients",
     description: "Description",
     files: "Files",
+    trash: "Trash",
+    shoppingList: "Shopping List",
+    uncategorized: "Other",
   },
   units: {
     kilogram: "kg",
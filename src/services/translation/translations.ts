@@ -8,6 +8,14 @@ type Translations<T> = {
       update: T;
       delete: T;
       deleteSelectedItems: T;
+      trash: T;
+      trashSelectedItems: T;
+      duplicate: T;
+      duplicateSelectedItems: T;
+      restore: T;
+      restoreSelectedItems: T;
+      purge: T;
+      purgeSelectedItems: T;
       cancel: T;
       confirm: T;
       add: T;
@@ -72,6 +80,9 @@ type Translations<T> = {
     ingredients: T;
     description: T;
     files: T;
+    trash: T;
+    shoppingList: T;
+    uncategorized: T;
   };
   units: {
     kilogram: T;
@@ -0,0 +1,28 @@
+import type { Allergen } from "./entity/ingredient-allergen-interface.ts";
+
+export type Diet = "vegetarian" | "vegan" | "glutenFree" | "lactoseFree";
+
+export type RecipeAllergen = {
+  allergen: Allergen;
+  /**
+   * the ingredients containing the allergen
+   */
+  ingredientIds: number[];
+};
+
+export type RecipeDiet = {
+  diet: Diet;
+  /**
+   * whether all ingredients are compatible, where null is unknown
+   */
+  compatible: boolean | null;
+  /**
+   * the ingredients which are incompatible or whose compatibility is unknown
+   */
+  ingredientIds: number[];
+};
+
+export type DietarySummary = {
+  allergens: RecipeAllergen[];
+  diets: RecipeDiet[];
+};
@@ -0,0 +1,21 @@
+export type EntityChangeAction = "Created" | "Updated" | "Deleted";
+
+/**
+ * A logged change of an entity, as emitted on the entity action channels and returned by the change feed.
+ *
+ * The sequence number increases monotonically over all entities.
+ * Changes by undo, redo and purging do not belong to a journal action.
+ */
+export type EntityChange<
+  Entity = unknown,
+  PrimaryKey extends number | string = number,
+> = {
+  seq: number;
+  entity: string;
+  action: EntityChangeAction;
+  entityId: PrimaryKey;
+  old: Entity | null;
+  new: Entity | null;
+  parents: { [parentKey: string]: number };
+  journalActionId: number | null;
+};
@@ -0,0 +1,27 @@
+import type { IdentifiableInterface } from "../identifiable-interface.ts";
+import type { TimestampedInterface } from "../timestamped-interface.ts";
+import type { RecipeExport } from "./recipe-interface.ts";
+
+export interface CollectionInterface
+  extends IdentifiableInterface,
+    TimestampedInterface {
+  name: string;
+}
+
+export interface CollectionCreateInterface {
+  name: string;
+}
+
+export interface CollectionUpdateInterface extends IdentifiableInterface {
+  name?: string;
+}
+
+/**
+ * An exported collection with its recipes in the order of the collection.
+ *
+ * Each recipe is in the same format as a single recipe, recipes in the trash are left out.
+ */
+export type CollectionExport = {
+  collection: CollectionInterface;
+  recipes: RecipeExport[];
+};
@@ -0,0 +1,23 @@
+import type { IdentifiableInterface } from "../identifiable-interface.ts";
+import type {
+  SortableInterface,
+  SortableUpdateInterface,
+} from "../sortable-interface.ts";
+import type { TimestampedInterface } from "../timestamped-interface.ts";
+
+export interface CollectionRecipeInterface
+  extends IdentifiableInterface,
+    SortableInterface,
+    TimestampedInterface {
+  collectionId: number;
+  recipeId: number;
+}
+
+export interface CollectionRecipeCreateInterface extends SortableInterface {
+  collectionId: number;
+  recipeId: number;
+}
+
+export interface CollectionRecipeUpdateInterface
+  extends IdentifiableInterface,
+    SortableUpdateInterface {}
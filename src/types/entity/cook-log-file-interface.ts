@@ -0,0 +1,23 @@
+import type { IdentifiableInterface } from "../identifiable-interface.ts";
+import type {
+  SortableInterface,
+  SortableUpdateInterface,
+} from "../sortable-interface.ts";
+import type { TimestampedInterface } from "../timestamped-interface.ts";
+
+export interface CookLogFileInterface
+  extends IdentifiableInterface,
+    SortableInterface,
+    TimestampedInterface {
+  cookLogId: number;
+  fileId: number;
+}
+
+export interface CookLogFileCreateInterface extends SortableInterface {
+  cookLogId: number;
+  fileId: number;
+}
+
+export interface CookLogFileUpdateInterface
+  extends IdentifiableInterface,
+    SortableUpdateInterface {}
@@ -0,0 +1,27 @@
+import type { IdentifiableInterface } from "../identifiable-interface.ts";
+import type { TimestampedInterface } from "../timestamped-interface.ts";
+
+export interface CookLogInterface
+  extends IdentifiableInterface,
+    TimestampedInterface {
+  recipeId: number;
+  cookedAt: number;
+  servings: number | null;
+  rating: number | null;
+  notes: string | null;
+}
+
+export interface CookLogCreateInterface {
+  recipeId: number;
+  cookedAt: number;
+  servings: number | null;
+  rating: number | null;
+  notes: string | null;
+}
+
+export interface CookLogUpdateInterface extends IdentifiableInterface {
+  cookedAt?: number;
+  servings?: number | null;
+  rating?: number | null;
+  notes?: string | null;
+}
@@ -1,6 +1,9 @@
 import type { IdentifiableInterface } from "../identifiable-interface.ts";
+import type { TimestampedInterface } from "../timestamped-interface.ts";
 
-export interface FileInterface extends IdentifiableInterface {
+export interface FileInterface
+  extends IdentifiableInterface,
+    TimestampedInterface {
   name: string;
   mime: string;
   path: string;
@@ -0,0 +1,26 @@
+import type { IdentifiableInterface } from "../identifiable-interface.ts";
+import type { TimestampedInterface } from "../timestamped-interface.ts";
+
+export type IngredientAliasKind = "Singular" | "Plural" | "Alias";
+
+export interface IngredientAliasInterface
+  extends IdentifiableInterface,
+    TimestampedInterface {
+  name: string;
+  language: string | null;
+  kind: IngredientAliasKind;
+  ingredientId: number;
+}
+
+export interface IngredientAliasCreateInterface {
+  name: string;
+  language: string | null;
+  kind: IngredientAliasKind;
+  ingredientId: number;
+}
+
+export interface IngredientAliasUpdateInterface extends IdentifiableInterface {
+  name?: string;
+  language?: string | null;
+  kind?: IngredientAliasKind;
+}
@@ -0,0 +1,38 @@
+import type { IdentifiableInterface } from "../identifiable-interface.ts";
+import type { TimestampedInterface } from "../timestamped-interface.ts";
+
+/**
+ * One of the 14 allergens which have to be declared in the EU.
+ */
+export type Allergen =
+  | "Gluten"
+  | "Crustaceans"
+  | "Eggs"
+  | "Fish"
+  | "Peanuts"
+  | "Soybeans"
+  | "Milk"
+  | "Nuts"
+  | "Celery"
+  | "Mustard"
+  | "Sesame"
+  | "Sulphites"
+  | "Lupin"
+  | "Molluscs";
+
+export interface IngredientAllergenInterface
+  extends IdentifiableInterface,
+    TimestampedInterface {
+  ingredientId: number;
+  allergen: Allergen;
+}
+
+export interface IngredientAllergenCreateInterface {
+  ingredientId: number;
+  allergen: Allergen;
+}
+
+export interface IngredientAllergenUpdateInterface
+  extends IdentifiableInterface {
+  allergen?: Allergen;
+}
@@ -0,0 +1,23 @@
+import type { IdentifiableInterface } from "../identifiable-interface.ts";
+import type {
+  SortableInterface,
+  SortableUpdateInterface,
+} from "../sortable-interface.ts";
+import type { TimestampedInterface } from "../timestamped-interface.ts";
+
+export interface IngredientCategoryInterface
+  extends IdentifiableInterface,
+    SortableInterface,
+    TimestampedInterface {
+  name: string;
+}
+
+export interface IngredientCategoryCreateInterface extends SortableInterface {
+  name: string;
+}
+
+export interface IngredientCategoryUpdateInterface
+  extends IdentifiableInterface,
+    SortableUpdateInterface {
+  name?: string;
+}
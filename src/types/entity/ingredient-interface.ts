@@ -1,13 +1,31 @@
 import type { IdentifiableInterface } from "../identifiable-interface.ts";
+import type { TimestampedInterface } from "../timestamped-interface.ts";
 
-export interface IngredientInterface extends IdentifiableInterface {
+export interface IngredientInterface
+  extends IdentifiableInterface,
+    TimestampedInterface {
   name: string;
+  ingredientCategoryId: number | null;
+  vegetarian: boolean | null;
+  vegan: boolean | null;
+  glutenFree: boolean | null;
+  lactoseFree: boolean | null;
 }
 
 export interface IngredientCreateInterface {
   name: string;
+  ingredientCategoryId: number | null;
+  vegetarian: boolean | null;
+  vegan: boolean | null;
+  glutenFree: boolean | null;
+  lactoseFree: boolean | null;
 }
 
 export interface IngredientUpdateInterface extends IdentifiableInterface {
   name?: string;
+  ingredientCategoryId?: number | null;
+  vegetarian?: boolean | null;
+  vegan?: boolean | null;
+  glutenFree?: boolean | null;
+  lactoseFree?: boolean | null;
 }
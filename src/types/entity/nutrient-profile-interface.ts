@@ -0,0 +1,44 @@
+import type { IdentifiableInterface } from "../identifiable-interface.ts";
+import type { TimestampedInterface } from "../timestamped-interface.ts";
+
+/**
+ * The nutrients of an ingredient per 100 g, the energy in kcal and all others in g.
+ *
+ * The density in g/ml and the mass of a piece in g allow converting volumes and unitless quantities to grams.
+ */
+export interface NutrientProfileInterface
+  extends IdentifiableInterface,
+    TimestampedInterface {
+  ingredientId: number;
+  kcal: number | null;
+  protein: number | null;
+  fat: number | null;
+  carbohydrate: number | null;
+  fibre: number | null;
+  salt: number | null;
+  density: number | null;
+  pieceMass: number | null;
+}
+
+export interface NutrientProfileCreateInterface {
+  ingredientId: number;
+  kcal: number | null;
+  protein: number | null;
+  fat: number | null;
+  carbohydrate: number | null;
+  fibre: number | null;
+  salt: number | null;
+  density: number | null;
+  pieceMass: number | null;
+}
+
+export interface NutrientProfileUpdateInterface extends IdentifiableInterface {
+  kcal?: number | null;
+  protein?: number | null;
+  fat?: number | null;
+  carbohydrate?: number | null;
+  fibre?: number | null;
+  salt?: number | null;
+  density?: number | null;
+  pieceMass?: number | null;
+}
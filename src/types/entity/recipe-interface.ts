@@ -1,13 +1,41 @@
 import type { IdentifiableInterface } from "../identifiable-interface.ts";
+import type { RecipeSnapshot } from "../recipe-revision.ts";
+import type { TimestampedInterface } from "../timestamped-interface.ts";
+import type { FileInterface } from "./file-interface.ts";
 
-export interface RecipeInterface extends IdentifiableInterface {
+export interface RecipeInterface
+  extends IdentifiableInterface,
+    TimestampedInterface {
   name: string;
+  deletedAt: number | null;
+  forkedFromId: number | null;
+  prepTime: number | null;
+  cookTime: number | null;
+  restTime: number | null;
+  totalTime: number | null;
 }
 
 export interface RecipeCreateInterface {
   name: string;
+  prepTime?: number | null;
+  cookTime?: number | null;
+  restTime?: number | null;
+  totalTime?: number | null;
 }
 
 export interface RecipeUpdateInterface extends IdentifiableInterface {
   name?: string;
+  prepTime?: number | null;
+  cookTime?: number | null;
+  restTime?: number | null;
+  totalTime?: number | null;
 }
+
+/**
+ * An exported recipe with its children and the files attached to it and its steps.
+ *
+ * The file contents are not embedded but referenced by their path.
+ */
+export type RecipeExport = RecipeSnapshot & {
+  files: FileInterface[];
+};
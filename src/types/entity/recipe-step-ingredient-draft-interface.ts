@@ -3,10 +3,12 @@ import type {
   SortableInterface,
   SortableUpdateInterface,
 } from "../sortable-interface.ts";
+import type { TimestampedInterface } from "../timestamped-interface.ts";
 
 export interface RecipeStepIngredientDraftInterface
   extends IdentifiableInterface,
-    SortableInterface {
+    SortableInterface,
+    TimestampedInterface {
   text: string;
   recipeStepId: number;
 }
@@ -3,10 +3,12 @@ import type {
   SortableInterface,
   SortableUpdateInterface,
 } from "../sortable-interface.ts";
+import type { TimestampedInterface } from "../timestamped-interface.ts";
 
 export interface RecipeStepIngredientInterface
   extends IdentifiableInterface,
-    SortableInterface {
+    SortableInterface,
+    TimestampedInterface {
   quantity: number | null;
   unit: string | null;
   quality: string | null;
@@ -3,16 +3,20 @@ import type {
   SortableInterface,
   SortableUpdateInterface,
 } from "../sortable-interface.ts";
+import type { TimestampedInterface } from "../timestamped-interface.ts";
 
 export interface RecipeStepInterface
   extends IdentifiableInterface,
-    SortableInterface {
+    SortableInterface,
+    TimestampedInterface {
   description: string;
+  duration: number | null;
   recipeId: number;
 }
 
 export interface RecipeStepCreateInterface extends SortableInterface {
   description: string;
+  duration: number | null;
   recipeId: number;
 }
 
@@ -20,4 +24,5 @@ export interface RecipeStepUpdateInterface
   extends IdentifiableInterface,
     SortableUpdateInterface {
   description?: string;
+  duration?: number | null;
 }
@@ -0,0 +1,18 @@
+import type { IdentifiableInterface } from "../identifiable-interface.ts";
+import type { TimestampedInterface } from "../timestamped-interface.ts";
+
+export interface RecipeTagInterface
+  extends IdentifiableInterface,
+    TimestampedInterface {
+  recipeId: number;
+  tagId: number;
+}
+
+export interface RecipeTagCreateInterface {
+  recipeId: number;
+  tagId: number;
+}
+
+export interface RecipeTagUpdateInterface extends IdentifiableInterface {
+  tagId?: number;
+}
@@ -0,0 +1,21 @@
+import type { IdentifiableInterface } from "../identifiable-interface.ts";
+import type { TimestampedInterface } from "../timestamped-interface.ts";
+
+export interface TagInterface
+  extends IdentifiableInterface,
+    TimestampedInterface {
+  name: string;
+}
+
+export interface TagCreateInterface {
+  name: string;
+}
+
+export interface TagUpdateInterface extends IdentifiableInterface {
+  name?: string;
+}
+
+export interface TagUsageInterface extends IdentifiableInterface {
+  name: string;
+  count: number;
+}
@@ -1,4 +1,5 @@
 import type { IdentifiableInterface } from "../identifiable-interface.ts";
+import type { TimestampedInterface } from "../timestamped-interface.ts";
 
 export const enum Unit {
   MASS_KILOGRAM = "MassKilogram",
@@ -9,7 +10,9 @@ export const enum Unit {
   VOLUME_US_CUP = "VolumeUsCup",
 }
 
-export interface UnitNameInterface extends IdentifiableInterface {
+export interface UnitNameInterface
+  extends IdentifiableInterface,
+    TimestampedInterface {
   name: string;
   unit: Unit;
 }
@@ -1,6 +1,23 @@
-export interface FilterInterface<Condition, OrderBy> {
+export interface FilterInterface<Condition, OrderBy, Id = number> {
   condition?: Condition;
   orderBy?: Array<OrderBy>;
+  limit?: number;
+  offset?: number;
+  after?: Id;
 }
 
+export type ListPage<Id> = {
+  ids: Id[];
+  total: number;
+};
+
 export type Order = "asc" | "desc";
+
+export type TimestampCondition = {
+  createdSince?: number;
+  createdBefore?: number;
+  updatedSince?: number;
+  updatedBefore?: number;
+};
+
+export type TimestampOrderBy = { createdAt: Order } | { updatedAt: Order };
@@ -0,0 +1,17 @@
+import type {
+  FilterInterface,
+  Order,
+  TimestampCondition,
+  TimestampOrderBy,
+} from "../filter-interface.ts";
+
+export type CollectionCondition = TimestampCondition & {
+  name?: string;
+};
+
+export type CollectionOrderBy = { name: Order } | TimestampOrderBy;
+
+export type CollectionFilter = FilterInterface<
+  CollectionCondition,
+  CollectionOrderBy
+>;
@@ -0,0 +1,18 @@
+import type {
+  FilterInterface,
+  Order,
+  TimestampCondition,
+  TimestampOrderBy,
+} from "../filter-interface.ts";
+
+export type CollectionRecipeCondition = TimestampCondition & {
+  collectionId?: number;
+  recipeId?: number;
+};
+
+export type CollectionRecipeOrderBy = { order: Order } | TimestampOrderBy;
+
+export type CollectionRecipeFilter = FilterInterface<
+  CollectionRecipeCondition,
+  CollectionRecipeOrderBy
+>;
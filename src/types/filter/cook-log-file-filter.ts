@@ -0,0 +1,17 @@
+import type {
+  FilterInterface,
+  Order,
+  TimestampCondition,
+  TimestampOrderBy,
+} from "../filter-interface.ts";
+
+export type CookLogFileCondition = TimestampCondition & {
+  cookLogId?: number;
+};
+
+export type CookLogFileOrderBy = { order: Order } | TimestampOrderBy;
+
+export type CookLogFileFilter = FilterInterface<
+  CookLogFileCondition,
+  CookLogFileOrderBy
+>;
@@ -0,0 +1,14 @@
+import type {
+  FilterInterface,
+  Order,
+  TimestampCondition,
+  TimestampOrderBy,
+} from "../filter-interface.ts";
+
+export type CookLogCondition = TimestampCondition & {
+  recipeId?: number;
+};
+
+export type CookLogOrderBy = { cookedAt: Order } | TimestampOrderBy;
+
+export type CookLogFilter = FilterInterface<CookLogCondition, CookLogOrderBy>;
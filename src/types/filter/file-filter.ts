@@ -1,9 +1,14 @@
-import type { FilterInterface, Order } from "../filter-interface.ts";
+import type {
+  FilterInterface,
+  Order,
+  TimestampCondition,
+  TimestampOrderBy,
+} from "../filter-interface.ts";
 
-export type FileCondition = {
+export type FileCondition = TimestampCondition & {
   name?: string;
 };
 
-export type FileOrderBy = { name: Order };
+export type FileOrderBy = { name: Order } | TimestampOrderBy;
 
 export type FileFilter = FilterInterface<FileCondition, FileOrderBy>;
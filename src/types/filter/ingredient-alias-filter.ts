@@ -0,0 +1,20 @@
+import type { IngredientAliasKind } from "../entity/ingredient-alias-interface.ts";
+import type {
+  FilterInterface,
+  Order,
+  TimestampCondition,
+  TimestampOrderBy,
+} from "../filter-interface.ts";
+
+export type IngredientAliasCondition = TimestampCondition & {
+  ingredientId?: number;
+  language?: string;
+  kind?: IngredientAliasKind;
+};
+
+export type IngredientAliasOrderBy = { name: Order } | TimestampOrderBy;
+
+export type IngredientAliasFilter = FilterInterface<
+  IngredientAliasCondition,
+  IngredientAliasOrderBy
+>;
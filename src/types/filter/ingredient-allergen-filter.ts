@@ -0,0 +1,19 @@
+import type { Allergen } from "../entity/ingredient-allergen-interface.ts";
+import type {
+  FilterInterface,
+  Order,
+  TimestampCondition,
+  TimestampOrderBy,
+} from "../filter-interface.ts";
+
+export type IngredientAllergenCondition = TimestampCondition & {
+  ingredientId?: number;
+  allergen?: Allergen;
+};
+
+export type IngredientAllergenOrderBy = { allergen: Order } | TimestampOrderBy;
+
+export type IngredientAllergenFilter = FilterInterface<
+  IngredientAllergenCondition,
+  IngredientAllergenOrderBy
+>;
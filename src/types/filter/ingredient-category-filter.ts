@@ -0,0 +1,20 @@
+import type {
+  FilterInterface,
+  Order,
+  TimestampCondition,
+  TimestampOrderBy,
+} from "../filter-interface.ts";
+
+export type IngredientCategoryCondition = TimestampCondition & {
+  name?: string;
+};
+
+export type IngredientCategoryOrderBy =
+  | { name: Order }
+  | { order: Order }
+  | TimestampOrderBy;
+
+export type IngredientCategoryFilter = FilterInterface<
+  IngredientCategoryCondition,
+  IngredientCategoryOrderBy
+>;
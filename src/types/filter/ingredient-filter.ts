@@ -1,12 +1,22 @@
-import type { FilterInterface, Order } from "../filter-interface.ts";
+import type {
+  FilterInterface,
+  Order,
+  TimestampCondition,
+  TimestampOrderBy,
+} from "../filter-interface.ts";
 
-export type IngredientCondition = {
+export type IngredientCondition = TimestampCondition & {
   name?: string;
   nameExact?: string;
   recipeStepId?: number;
+  recipeId?: number;
+  ingredientCategoryId?: number;
 };
 
-export type IngredientOrderBy = { name: Order };
+export type IngredientOrderBy =
+  | { name: Order }
+  | { ingredientCategory: Order }
+  | TimestampOrderBy;
 
 export type IngredientFilter = FilterInterface<
   IngredientCondition,
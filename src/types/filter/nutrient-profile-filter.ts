@@ -0,0 +1,17 @@
+import type {
+  FilterInterface,
+  Order,
+  TimestampCondition,
+  TimestampOrderBy,
+} from "../filter-interface.ts";
+
+export type NutrientProfileCondition = TimestampCondition & {
+  ingredientId?: number;
+};
+
+export type NutrientProfileOrderBy = { kcal: Order } | TimestampOrderBy;
+
+export type NutrientProfileFilter = FilterInterface<
+  NutrientProfileCondition,
+  NutrientProfileOrderBy
+>;
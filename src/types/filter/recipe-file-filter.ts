@@ -1,10 +1,15 @@
-import type { FilterInterface, Order } from "../filter-interface.ts";
+import type {
+  FilterInterface,
+  Order,
+  TimestampCondition,
+  TimestampOrderBy,
+} from "../filter-interface.ts";
 
-export type RecipeFileCondition = {
+export type RecipeFileCondition = TimestampCondition & {
   recipeId?: number;
 };
 
-export type RecipeFileOrderBy = { order: Order };
+export type RecipeFileOrderBy = { order: Order } | TimestampOrderBy;
 
 export type RecipeFileFilter = FilterInterface<
   RecipeFileCondition,
@@ -1,9 +1,34 @@
-import type { FilterInterface, Order } from "../filter-interface.ts";
+import type { Diet } from "../dietary.ts";
+import type { Allergen } from "../entity/ingredient-allergen-interface.ts";
+import type {
+  FilterInterface,
+  Order,
+  TimestampCondition,
+  TimestampOrderBy,
+} from "../filter-interface.ts";
 
-export type RecipeCondition = {
+export type RecipeCondition = TimestampCondition & {
   name?: string;
+  trashed?: boolean;
+  tagsAll?: number[];
+  tagsAny?: number[];
+  tagsNone?: number[];
+  collectionId?: number;
+  cookedSince?: number;
+  notCookedSince?: number;
+  maxTotalTime?: number;
+  maxActiveTime?: number;
+  excludeAllergens?: Allergen[];
+  diets?: Diet[];
 };
 
-export type RecipeOrderBy = { name: Order };
+export type RecipeOrderBy =
+  | { name: Order }
+  | { lastCooked: Order }
+  | { timesCooked: Order }
+  | { averageRating: Order }
+  | { totalTime: Order }
+  | { activeTime: Order }
+  | TimestampOrderBy;
 
 export type RecipeFilter = FilterInterface<RecipeCondition, RecipeOrderBy>;
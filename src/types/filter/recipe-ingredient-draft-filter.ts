@@ -1,10 +1,15 @@
-import type { FilterInterface, Order } from "../filter-interface.ts";
+import type {
+  FilterInterface,
+  Order,
+  TimestampCondition,
+  TimestampOrderBy,
+} from "../filter-interface.ts";
 
-export type RecipeIngredientDraftCondition = {
+export type RecipeIngredientDraftCondition = TimestampCondition & {
   recipeId?: number;
 };
 
-export type RecipeIngredientDraftOrderBy = { order: Order };
+export type RecipeIngredientDraftOrderBy = { order: Order } | TimestampOrderBy;
 
 export type RecipeIngredientDraftFilter = FilterInterface<
   RecipeIngredientDraftCondition,
@@ -1,10 +1,15 @@
-import type { FilterInterface, Order } from "../filter-interface.ts";
+import type {
+  FilterInterface,
+  Order,
+  TimestampCondition,
+  TimestampOrderBy,
+} from "../filter-interface.ts";
 
-export type RecipeStepFileCondition = {
+export type RecipeStepFileCondition = TimestampCondition & {
   recipeStepId?: number;
 };
 
-export type RecipeStepFileOrderBy = { order: Order };
+export type RecipeStepFileOrderBy = { order: Order } | TimestampOrderBy;
 
 export type RecipeStepFileFilter = FilterInterface<
   RecipeStepFileCondition,
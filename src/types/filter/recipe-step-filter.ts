@@ -1,10 +1,15 @@
-import type { FilterInterface, Order } from "../filter-interface.ts";
+import type {
+  FilterInterface,
+  Order,
+  TimestampCondition,
+  TimestampOrderBy,
+} from "../filter-interface.ts";
 
-export type RecipeStepCondition = {
+export type RecipeStepCondition = TimestampCondition & {
   recipeId?: number;
 };
 
-export type RecipeStepOrderBy = { order: Order };
+export type RecipeStepOrderBy = { order: Order } | TimestampOrderBy;
 
 export type RecipeStepFilter = FilterInterface<
   RecipeStepCondition,
@@ -1,10 +1,17 @@
-import type { FilterInterface, Order } from "../filter-interface.ts";
+import type {
+  FilterInterface,
+  Order,
+  TimestampCondition,
+  TimestampOrderBy,
+} from "../filter-interface.ts";
 
-export type RecipeStepIngredientDraftCondition = {
+export type RecipeStepIngredientDraftCondition = TimestampCondition & {
   recipeStepId?: number;
 };
 
-export type RecipeStepIngredientDraftOrderBy = { order: Order };
+export type RecipeStepIngredientDraftOrderBy =
+  | { order: Order }
+  | TimestampOrderBy;
 
 export type RecipeStepIngredientDraftFilter = FilterInterface<
   RecipeStepIngredientDraftCondition,
@@ -1,11 +1,16 @@
-import type { FilterInterface, Order } from "../filter-interface.ts";
+import type {
+  FilterInterface,
+  Order,
+  TimestampCondition,
+  TimestampOrderBy,
+} from "../filter-interface.ts";
 
-export type RecipeStepIngredientCondition = {
+export type RecipeStepIngredientCondition = TimestampCondition & {
   recipeStepId?: number;
   ingredientId?: number;
 };
 
-export type RecipeStepIngredientOrderBy = { order: Order };
+export type RecipeStepIngredientOrderBy = { order: Order } | TimestampOrderBy;
 
 export type RecipeStepIngredientFilter = FilterInterface<
   RecipeStepIngredientCondition,
@@ -0,0 +1,18 @@
+import type {
+  FilterInterface,
+  Order,
+  TimestampCondition,
+  TimestampOrderBy,
+} from "../filter-interface.ts";
+
+export type RecipeTagCondition = TimestampCondition & {
+  recipeId?: number;
+  tagId?: number;
+};
+
+export type RecipeTagOrderBy = { tagName: Order } | TimestampOrderBy;
+
+export type RecipeTagFilter = FilterInterface<
+  RecipeTagCondition,
+  RecipeTagOrderBy
+>;
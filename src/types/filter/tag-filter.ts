@@ -0,0 +1,16 @@
+import type {
+  FilterInterface,
+  Order,
+  TimestampCondition,
+  TimestampOrderBy,
+} from "../filter-interface.ts";
+
+export type TagCondition = TimestampCondition & {
+  name?: string;
+  nameExact?: string;
+  recipeId?: number;
+};
+
+export type TagOrderBy = { name: Order } | TimestampOrderBy;
+
+export type TagFilter = FilterInterface<TagCondition, TagOrderBy>;
@@ -1,10 +1,15 @@
-import type { FilterInterface } from "../filter-interface.ts";
+import type {
+  FilterInterface,
+  TimestampCondition,
+  TimestampOrderBy,
+} from "../filter-interface.ts";
 
-export type UnitNameCondition = object;
+export type UnitNameCondition = TimestampCondition;
 
-export type UnitNameOrderBy = object;
+export type UnitNameOrderBy = TimestampOrderBy;
 
 export type UnitNameFilter = FilterInterface<
   UnitNameCondition,
-  UnitNameOrderBy
+  UnitNameOrderBy,
+  string
 >;
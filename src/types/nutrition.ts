@@ -0,0 +1,59 @@
+/**
+ * The headers of the columns of a CSV dump of a food composition database to import as nutrient profiles.
+ *
+ * Columns without a header are not imported.
+ */
+export type NutrientColumns = {
+  name: string;
+  kcal?: string;
+  protein?: string;
+  fat?: string;
+  carbohydrate?: string;
+  fibre?: string;
+  salt?: string;
+  density?: string;
+  pieceMass?: string;
+};
+
+export type NutrientImport = {
+  created: number;
+  updated: number;
+  unmatched: number;
+};
+
+/**
+ * The nutrients of an amount of food, the energy in kcal and all others in g.
+ */
+export type Nutrients = {
+  kcal: number;
+  protein: number;
+  fat: number;
+  carbohydrate: number;
+  fibre: number;
+  salt: number;
+};
+
+export type UnresolvedReason =
+  | "noNutrientProfile"
+  | "noQuantity"
+  | "unknownUnit"
+  | "noDensity"
+  | "noPieceMass";
+
+export type UnresolvedIngredient = {
+  recipeStepIngredientId: number;
+  ingredientId: number;
+  ingredientName: string;
+  reason: UnresolvedReason;
+};
+
+/**
+ * The nutrition of a recipe.
+ *
+ * The total is a lower bound when some recipe step ingredients are unresolved.
+ */
+export type RecipeNutrition = {
+  total: Nutrients;
+  perServing: Nutrients | null;
+  unresolved: UnresolvedIngredient[];
+};
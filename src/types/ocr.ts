@@ -0,0 +1,67 @@
+export type OcrLanguage = {
+  name: string;
+  source: "user" | "bundled";
+};
+
+export type OcrSettings = {
+  pageSegMode?: "auto" | "singleColumn" | "singleBlock" | "sparseText";
+  preprocessing?: OcrPreprocessing;
+};
+
+export type OcrPreprocessing = {
+  exifOrientation?: boolean;
+  crop?: OcrCropRegion | null;
+  detectOrientation?: boolean;
+  deskew?: boolean;
+  threshold?: boolean;
+};
+
+export type OcrCropRegion = {
+  x: number;
+  y: number;
+  width: number;
+  height: number;
+};
+
+export type OcrFormat = "structure" | "text";
+
+export type OcrOutput = { structure: HocrPage[] } | { text: string };
+
+export type BoundingBox = {
+  x0: number;
+  y0: number;
+  x1: number;
+  y1: number;
+};
+
+export type HocrPage = {
+  id: string | null;
+  bbox: BoundingBox;
+  blocks: HocrBlock[];
+};
+
+export type HocrBlock = {
+  id: string | null;
+  bbox: BoundingBox;
+  paragraphs: HocrParagraph[];
+};
+
+export type HocrParagraph = {
+  id: string | null;
+  bbox: BoundingBox;
+  lang: string | null;
+  lines: HocrLine[];
+};
+
+export type HocrLine = {
+  id: string | null;
+  bbox: BoundingBox;
+  words: HocrWord[];
+};
+
+export type HocrWord = {
+  id: string | null;
+  bbox: BoundingBox;
+  confidence: number | null;
+  text: string;
+};
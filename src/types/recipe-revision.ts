@@ -0,0 +1,47 @@
+import type { IngredientInterface } from "./entity/ingredient-interface.ts";
+import type { RecipeFileInterface } from "./entity/recipe-file-interface.ts";
+import type { RecipeIngredientDraftInterface } from "./entity/recipe-ingredient-draft-interface.ts";
+import type { RecipeInterface } from "./entity/recipe-interface.ts";
+import type { RecipeStepFileInterface } from "./entity/recipe-step-file-interface.ts";
+import type { RecipeStepIngredientDraftInterface } from "./entity/recipe-step-ingredient-draft-interface.ts";
+import type { RecipeStepIngredientInterface } from "./entity/recipe-step-ingredient-interface.ts";
+import type { RecipeStepInterface } from "./entity/recipe-step-interface.ts";
+
+/**
+ * A saved revision of a recipe, without its snapshot.
+ *
+ * The creation time is in seconds since the Unix epoch.
+ */
+export type RecipeRevisionSummary = {
+  id: number;
+  createdAt: number;
+};
+
+/**
+ * A difference of one field of one entity between two recipe revisions.
+ *
+ * The old or new value is null when the entity does not exist in the respective revision.
+ */
+export type RevisionDifference = {
+  entity: string;
+  id: number;
+  field: string;
+  old: unknown;
+  new: unknown;
+};
+
+/**
+ * The full aggregate of a recipe at one point in time.
+ *
+ * The ingredients referenced by the recipe step ingredients are included.
+ */
+export type RecipeSnapshot = {
+  recipe: RecipeInterface;
+  recipeSteps: RecipeStepInterface[];
+  recipeStepIngredients: RecipeStepIngredientInterface[];
+  recipeStepIngredientDrafts: RecipeStepIngredientDraftInterface[];
+  recipeStepFiles: RecipeStepFileInterface[];
+  recipeFiles: RecipeFileInterface[];
+  recipeIngredientDrafts: RecipeIngredientDraftInterface[];
+  ingredients: IngredientInterface[];
+};
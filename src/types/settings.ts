@@ -0,0 +1,8 @@
+/**
+ * The settings of the application.
+ *
+ * Without a trash retention, recipes stay in the trash until they are purged explicitly.
+ */
+export type Settings = {
+  trashRetentionDays: number | null;
+};
@@ -0,0 +1,11 @@
+/**
+ * A duration of a recipe step, which may be a range.
+ *
+ * The minimum and maximum are in seconds.
+ * The text is where the duration was recognized in the description, or null for the explicit duration of the recipe step.
+ */
+export type StepDuration = {
+  text: string | null;
+  min: number;
+  max: number;
+};
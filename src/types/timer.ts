@@ -0,0 +1,11 @@
+/**
+ * A named countdown timer running in the backend.
+ *
+ * The duration and remaining duration are in seconds.
+ */
+export type Timer = {
+  id: string;
+  name: string;
+  duration: number;
+  remaining: number;
+};
@@ -0,0 +1,4 @@
+export interface TimestampedInterface {
+  createdAt: number;
+  updatedAt: number;
+}